@@ -0,0 +1,85 @@
+//! Frame times of a `KeybindsPanel` with many actions, to notice when it gets
+//! slower with the number of rows. Run with `cargo bench`.
+
+use egui::{vec2, Event, Key, KeyboardShortcut, Modifiers, MouseWheelUnit, Pos2, RawInput, Rect};
+use egui_keybind::{Hotkeys, KeybindsPanel, Shortcut};
+use std::time::{Duration, Instant};
+
+/// How many frames are measured for each number of rows.
+const FRAMES: u32 = 200;
+
+/// Register `count` actions, each with a different shortcut.
+fn hotkeys(count: usize) -> Hotkeys {
+    let modifiers = [
+        Modifiers::NONE,
+        Modifiers::CTRL,
+        Modifiers::ALT,
+        Modifiers::SHIFT,
+        Modifiers::CTRL | Modifiers::SHIFT,
+        Modifiers::CTRL | Modifiers::ALT,
+    ];
+    let shortcuts = modifiers.into_iter().flat_map(|modifiers| {
+        Key::ALL
+            .iter()
+            .map(move |key| KeyboardShortcut::new(modifiers, *key))
+    });
+    let mut hotkeys = Hotkeys::new();
+    for (index, keyboard) in shortcuts.take(count).enumerate() {
+        hotkeys.register(
+            format!("action {index}"),
+            Shortcut::new(Some(keyboard), None),
+        );
+    }
+    hotkeys
+}
+
+/// How many frames scroll down before scrolling back up, so even the
+/// smallest panel never reaches its end.
+const SCROLL_FRAMES: u32 = 30;
+
+/// Get the average time of a frame that shows a panel with `count` rows,
+/// scrolling down and up through it.
+fn frame_time(count: usize) -> Duration {
+    let mut hotkeys = hotkeys(count);
+    let ctx = egui::Context::default();
+    let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+    let mut frame = |events: Vec<Event>| {
+        let input = RawInput {
+            screen_rect: Some(screen_rect),
+            events,
+            ..Default::default()
+        };
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                KeybindsPanel::new(&mut hotkeys, "keybinds").show(ui);
+            });
+        });
+    };
+    frame(vec![Event::PointerMoved(screen_rect.center())]);
+
+    let start = Instant::now();
+    for index in 0..FRAMES {
+        let direction = match (index / SCROLL_FRAMES) % 2 {
+            0 => -1.0,
+            _ => 1.0,
+        };
+        frame(vec![Event::MouseWheel {
+            unit: MouseWheelUnit::Point,
+            delta: vec2(0.0, 50.0 * direction),
+            modifiers: Modifiers::NONE,
+        }]);
+    }
+    start.elapsed() / FRAMES
+}
+
+fn main() {
+    let few = frame_time(100);
+    let many = frame_time(500);
+    println!("100 rows: {few:?} per frame");
+    println!("500 rows: {many:?} per frame");
+    // only the visible rows are built, so the number of rows shouldn't matter much
+    assert!(
+        many < few * 3,
+        "500 rows took {many:?} per frame, 100 rows took {few:?}"
+    );
+}
@@ -0,0 +1,130 @@
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_keybind::{CheatSheet, CommandPalette, Hotkeys, KeybindsPanel, Shortcut};
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([320.0, 240.0]),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "egui-keybind hotkeys example",
+        options,
+        Box::new(|cc| Ok(Box::new(HotkeysApp::new(cc)))),
+    )
+}
+
+fn shortcut(modifiers: Modifiers, key: Key) -> Shortcut {
+    Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None)
+}
+
+struct HotkeysApp {
+    hotkeys: Hotkeys,
+    /// Hotkeys that are kept when the app is restarted.
+    persistent: Hotkeys,
+    last_action: Option<String>,
+}
+
+fn default_hotkeys() -> Hotkeys {
+    let mut hotkeys = Hotkeys::new();
+    hotkeys
+        .register_in("File", "New", shortcut(Modifiers::COMMAND, Key::N))
+        .register_in("File", "Open", shortcut(Modifiers::COMMAND, Key::O))
+        .register_in("File", "Save", shortcut(Modifiers::COMMAND, Key::S))
+        .register_in(
+            "File",
+            "Save as",
+            shortcut(Modifiers::COMMAND | Modifiers::SHIFT, Key::S),
+        )
+        .register_in("Edit", "Find", shortcut(Modifiers::COMMAND, Key::F))
+        .register_in(
+            "View",
+            "Zoom in",
+            Shortcut::new(None, Some(egui::PointerButton::Extra2)),
+        );
+    hotkeys
+}
+
+fn persistent_hotkeys() -> Hotkeys {
+    let mut hotkeys = Hotkeys::new();
+    hotkeys
+        .register("Undo", shortcut(Modifiers::COMMAND, Key::Z))
+        .register("Redo", shortcut(Modifiers::COMMAND, Key::Y));
+    hotkeys
+}
+
+/// The key the shortcuts of [HotkeysApp::hotkeys] are saved under.
+const HOTKEYS_KEY: &str = "hotkeys";
+
+impl HotkeysApp {
+    fn new(cc: &eframe::CreationContext) -> Self {
+        // load the shortcuts that were saved when the app was closed
+        let mut hotkeys = default_hotkeys();
+        if let Some(storage) = cc.storage {
+            hotkeys.load_from_storage(storage, HOTKEYS_KEY);
+        }
+        Self {
+            hotkeys,
+            persistent: Hotkeys::load_or_default(
+                &cc.egui_ctx,
+                "persistent hotkeys",
+                persistent_hotkeys,
+            ),
+            last_action: None,
+        }
+    }
+}
+
+impl eframe::App for HotkeysApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        self.hotkeys.save_to_storage(storage, HOTKEYS_KEY);
+    }
+
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // check which actions were pressed
+        if let Some(action) = self.hotkeys.triggered(ctx).last() {
+            self.last_action = Some(action.id().to_string());
+        }
+        if let Some(action) = self.persistent.triggered(ctx).last() {
+            self.last_action = Some(action.id().to_string());
+        }
+        // actions can also be chosen by name, opened with Ctrl+Shift+P
+        if let Some(id) = CommandPalette::new(&self.hotkeys, "command palette").show(ctx) {
+            self.last_action = Some(id);
+        }
+        // all shortcuts at a glance, opened with Shift+/ ("?")
+        CheatSheet::new(&self.hotkeys, "cheat sheet")
+            .with_open_bind(shortcut(Modifiers::SHIFT, Key::Slash))
+            .with_unbound(true)
+            .show(ctx);
+
+        egui::Window::new("Kept after restart").show(ctx, |ui| {
+            KeybindsPanel::new(&mut self.persistent, "persistent hotkeys").show(ui);
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let output = KeybindsPanel::new(&mut self.hotkeys, "hotkeys")
+                .with_clipboard(true)
+                .show(ui);
+            if let Some(id) = output.changed {
+                self.last_action = Some(format!("rebind {id}"));
+            }
+
+            ui.separator();
+            match &self.last_action {
+                Some(action) => ui.label(format!("Last action: {action}")),
+                None => ui.label("Press a shortcut to run an action"),
+            };
+            ui.horizontal(|ui| {
+                if ui.button("Reset all").clicked() {
+                    self.hotkeys.reset_all();
+                }
+                if ui.button("Command palette").clicked() {
+                    CommandPalette::open(ctx, "command palette");
+                }
+                if ui.button("Shortcuts").clicked() {
+                    CheatSheet::open(ctx, "cheat sheet");
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use egui_keybind::{Bind, KeybindList, Shortcut, Shortcuts};
+
+fn main() -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([320.0, 320.0]),
+        ..Default::default()
+    };
+    eframe::run_native(
+        "egui-keybind list example",
+        options,
+        Box::new(|_cc| Ok(Box::<ListApp>::default())),
+    )
+}
+
+struct ListApp {
+    shortcuts: Shortcuts,
+    json: String,
+    error: Option<String>,
+    times_pressed: usize,
+}
+
+impl Default for ListApp {
+    fn default() -> Self {
+        let shortcuts = Shortcuts::new(vec![
+            Shortcut::new(
+                Some(egui::KeyboardShortcut::new(
+                    egui::Modifiers::CTRL,
+                    egui::Key::S,
+                )),
+                None,
+            ),
+            Shortcut::new(None, Some(egui::PointerButton::Extra1)),
+        ]);
+        Self {
+            json: serde_json::to_string_pretty(&shortcuts).unwrap(),
+            shortcuts,
+            error: None,
+            times_pressed: 0,
+        }
+    }
+}
+
+impl eframe::App for ListApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Save");
+            if ui
+                .add(KeybindList::new(&mut self.shortcuts, "save_shortcuts"))
+                .changed()
+            {
+                self.json = serde_json::to_string_pretty(&self.shortcuts).unwrap();
+                self.error = None;
+            }
+
+            if ctx.input_mut(|i| self.shortcuts.pressed(i)) {
+                self.times_pressed += 1;
+            }
+            ui.label(format!("Pressed {} times", self.times_pressed));
+
+            ui.separator();
+            ui.label("Serialized (edit and load to round-trip):");
+            ui.code_editor(&mut self.json);
+            if ui.button("Load").clicked() {
+                match serde_json::from_str(&self.json) {
+                    Ok(shortcuts) => {
+                        self.shortcuts = shortcuts;
+                        self.error = None;
+                    }
+                    Err(err) => self.error = Some(err.to_string()),
+                }
+            }
+            if let Some(error) = &self.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        });
+    }
+}
@@ -1,4 +1,7 @@
-use egui_keybind::{Bind, Keybind, Shortcut};
+use egui_keybind::{
+    shortcut_button, Bind, BindPair, BindTooltip, DropPolicy, Keybind, KeybindPicker,
+    ModifierEditor, PointerButtonPicker, Shortcut,
+};
 
 fn main() -> Result<(), eframe::Error> {
     let options = eframe::NativeOptions {
@@ -16,8 +19,17 @@ struct ExampleApp {
     shortcut: Shortcut,
     mouse_shortcut: Option<egui::PointerButton>,
     default_shortcut: Shortcut,
+    show_hint: bool,
     x_reset_shortcut: Shortcut,
+    small_shortcut: Shortcut,
+    swap_shortcuts: [Shortcut; 2],
+    picked_shortcut: Shortcut,
+    popup_shortcut: Shortcut,
+    zoom_in: Shortcut,
+    zoom_out: Shortcut,
+    zoom: i32,
     times_pressed: usize,
+    notes: String,
 }
 
 impl Default for ExampleApp {
@@ -32,8 +44,44 @@ impl Default for ExampleApp {
                 )),
                 None,
             ),
+            show_hint: false,
             x_reset_shortcut: Shortcut::NONE,
+            small_shortcut: Shortcut::NONE,
+            swap_shortcuts: [
+                Shortcut::new(
+                    Some(egui::KeyboardShortcut::new(
+                        egui::Modifiers::CTRL,
+                        egui::Key::C,
+                    )),
+                    None,
+                ),
+                Shortcut::new(
+                    Some(egui::KeyboardShortcut::new(
+                        egui::Modifiers::CTRL,
+                        egui::Key::V,
+                    )),
+                    None,
+                ),
+            ],
+            picked_shortcut: Shortcut::NONE,
+            popup_shortcut: Shortcut::NONE,
+            zoom_in: Shortcut::new(
+                Some(egui::KeyboardShortcut::new(
+                    egui::Modifiers::CTRL,
+                    egui::Key::Plus,
+                )),
+                None,
+            ),
+            zoom_out: Shortcut::new(
+                Some(egui::KeyboardShortcut::new(
+                    egui::Modifiers::CTRL,
+                    egui::Key::Minus,
+                )),
+                None,
+            ),
+            zoom: 0,
             times_pressed: 0,
+            notes: String::new(),
         }
     }
 }
@@ -42,14 +90,14 @@ impl eframe::App for ExampleApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             egui::ScrollArea::vertical().show(ui, |ui| {
-                self.show_example(ctx, ui);
+                self.show_example(ui);
             });
         });
     }
 }
 
 impl ExampleApp {
-    fn show_example(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+    fn show_example(&mut self, ui: &mut egui::Ui) {
         ui.heading("egui-keybind example");
 
         ui.add_space(4.0);
@@ -67,11 +115,16 @@ impl ExampleApp {
         ));
 
         ui.separator();
-        ui.label("A keybind that is Ctrl+Shift+D by default:");
+        ui.label("A keybind that is Ctrl+Shift+D by default, and shows a hint:");
         ui.add(
             Keybind::new(&mut self.default_shortcut, "default_keybind")
                 .with_text("I have text too"),
         );
+        self.default_shortcut
+            .toggled_unless_typing(ui.ctx(), &mut self.show_hint);
+        if self.show_hint {
+            ui.label("Press it again to hide this hint.");
+        }
 
         ui.separator();
         ui.label("A keybind that resets to X when Escape is pressed:");
@@ -88,6 +141,72 @@ impl ExampleApp {
                 .with_reset_key(Some(egui::Key::Escape)),
         );
 
+        ui.separator();
+        ui.label("A small keybind in a toolbar, shown in the tooltip of \"Open\":");
+        ui.horizontal(|ui| {
+            let _ = ui.small_button("New");
+            let _ = ui
+                .small_button("Open")
+                .on_hover_bind("Open file", &self.small_shortcut);
+            ui.add(Keybind::new(&mut self.small_shortcut, "small_keybind").small());
+        });
+
+        ui.separator();
+        ui.label("Drag one of these keybinds onto the other to swap them:");
+        let [copy, paste] = &mut self.swap_shortcuts;
+        ui.add(
+            Keybind::new(copy, "swap_copy")
+                .with_text("Copy")
+                .with_drag_and_drop(Some(DropPolicy::Swap)),
+        );
+        ui.add(
+            Keybind::new(paste, "swap_paste")
+                .with_text("Paste")
+                .with_drag_and_drop(Some(DropPolicy::Swap)),
+        );
+
+        ui.separator();
+        ui.label("A keybind that is picked from a list instead of pressed:");
+        ui.horizontal(|ui| {
+            ui.add(KeybindPicker::new(
+                &mut self.picked_shortcut,
+                "picked_keybind",
+            ));
+            ui.add(PointerButtonPicker::new(
+                &mut self.picked_shortcut,
+                "picked_pointer_button",
+            ));
+        });
+        ui.add(ModifierEditor::new(&mut self.picked_shortcut));
+
+        ui.separator();
+        ui.label("A keybind that is recorded in a popup, with suggestions:");
+        let suggestions = ["Ctrl+F".parse().unwrap(), "F3".parse().unwrap()];
+        ui.add(
+            Keybind::new(&mut self.popup_shortcut, "popup_keybind")
+                .with_popup_editor(true)
+                .with_suggestions(&suggestions),
+        );
+
+        ui.separator();
+        ui.label("A pair of keybinds in one row, for zooming in and out:");
+        egui::Grid::new("zoom_grid").num_columns(2).show(ui, |ui| {
+            ui.add(BindPair::new(
+                "Zoom",
+                &mut self.zoom_in,
+                &mut self.zoom_out,
+                "zoom_pair",
+            ));
+            ui.end_row();
+        });
+        if self.zoom_in.pressed_unless_typing(ui.ctx()) {
+            self.zoom += 1;
+        }
+        if self.zoom_out.pressed_unless_typing(ui.ctx()) {
+            self.zoom -= 1;
+        }
+        ui.label(format!("Zoom level: {}", self.zoom));
+
         ui.separator();
 
         // display keybind text
@@ -96,12 +215,23 @@ impl ExampleApp {
             "First keybind: {keybind_text} (you can use modifier keys!)"
         ));
 
-        if ctx.input_mut(|i| self.shortcut.pressed(i)) {
+        // a button that is also clicked by the first keybind, unless it's a
+        // single key and the text field has focus
+        ui.horizontal(|ui| {
+            if shortcut_button(ui, "Count", &self.shortcut).clicked() {
+                self.times_pressed += 1;
+            }
+            ui.add(
+                egui::TextEdit::singleline(&mut self.notes).hint_text("Typing here doesn't count"),
+            );
+        });
+        // the X keybind counts as well, checked on its own
+        if self.x_reset_shortcut.pressed_unless_typing(ui.ctx()) {
             self.times_pressed += 1;
         }
         if keybind_text != "None" {
             ui.label(format!(
-                "{keybind_text} has been pressed {} times",
+                "Count has been clicked or {keybind_text} pressed {} times",
                 self.times_pressed
             ));
         }
@@ -0,0 +1,40 @@
+//! A headless test of a settings screen: click a keybind, press Ctrl+K and
+//! check that the setting changed. Run it with
+//! `cargo run --example testing --features testing`.
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_keybind::{CaptureInput, Keybind, KeybindHarnessExt, Shortcut};
+use egui_kittest::{kittest::Queryable, Harness};
+
+/// The settings of the app.
+struct Settings {
+    search: Shortcut,
+}
+
+/// The settings screen of the app, as it would be shown in the real app.
+fn settings_screen(ctx: &egui::Context, settings: &mut Settings) {
+    egui::CentralPanel::default().show(ctx, |ui| {
+        ui.add(Keybind::new(&mut settings.search, "search").with_text("Search"));
+    });
+}
+
+fn main() {
+    let settings = Settings {
+        search: "Ctrl+F".parse().unwrap(),
+    };
+    let mut harness = Harness::new_state(settings_screen, settings);
+    assert!(!harness.is_capturing("search"));
+
+    // clicking the keybind starts recording, like it would for a user
+    harness.get_by_label_contains("Search").simulate_click();
+    harness.run();
+    assert!(harness.is_capturing("search"));
+
+    // press Ctrl+K
+    let ctrl_k = KeyboardShortcut::new(Modifiers::CTRL, Key::K);
+    assert!(harness.simulate_capture("search", CaptureInput::Keys(ctrl_k)));
+    assert!(!harness.is_capturing("search"));
+    assert_eq!(harness.state().search.to_string(), "Ctrl+K");
+
+    println!("the search shortcut is now {}", harness.state().search);
+}
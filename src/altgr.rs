@@ -0,0 +1,256 @@
+use crate::{Bind, Hotkeys};
+use egui::{os::OperatingSystem, Context, Event, InputState, Modifiers};
+
+/// How a key press with both Ctrl and Alt is treated while capturing (see
+/// [`Keybind::with_altgr_policy`](crate::Keybind::with_altgr_policy)) and
+/// matching (see [`Hotkeys::set_altgr_policy`]).
+///
+/// On Windows, AltGr arrives as Ctrl+Alt, so typing AltGr+Q on a German
+/// layout would record a Ctrl+Alt+Q bind. [egui] doesn't say which side a
+/// modifier was pressed on, so AltGr can't be told apart from pressing the
+/// left Ctrl and Alt together. [`AltGrPolicy::StripCtrl`] treats both as Alt,
+/// so Ctrl+Alt binds can't be recorded or pressed with it.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{AltGrPolicy, Hotkeys, Keybind, Shortcut};
+///
+/// // what AltGr+Q looks like on Windows
+/// let altgr_q = Event::Key {
+///     key: Key::Q,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL | Modifiers::COMMAND | Modifiers::ALT,
+/// };
+///
+/// // record a shortcut by clicking a keybind and pressing AltGr+Q
+/// let capture = |policy| {
+///     let ctx = egui::Context::default();
+///     let mut shortcut = Shortcut::NONE;
+///     let mut frame = |events: Vec<Event>| {
+///         let mut rect = egui::Rect::NOTHING;
+///         let input = RawInput {
+///             events,
+///             ..Default::default()
+///         };
+///         let _ = ctx.run(input, |ctx| {
+///             egui::CentralPanel::default().show(ctx, |ui| {
+///                 let keybind =
+///                     Keybind::new(&mut shortcut, "keybind").with_altgr_policy(Some(policy));
+///                 rect = ui.add(keybind).rect;
+///             });
+///         });
+///         rect
+///     };
+///     let pos = frame(vec![]).center();
+///     let click = |pressed| Event::PointerButton {
+///         pos,
+///         button: PointerButton::Primary,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     };
+///     frame(vec![Event::PointerMoved(pos), click(true), click(false)]);
+///     frame(vec![altgr_q.clone()]);
+///     shortcut.to_string()
+/// };
+///
+/// // trigger actions by pressing AltGr+Q
+/// let trigger = |policy| {
+///     let mut hotkeys = Hotkeys::new();
+///     hotkeys
+///         .register("alt q", "Alt+Q".parse().unwrap())
+///         .register("ctrl alt q", "Ctrl+Alt+Q".parse().unwrap())
+///         .set_altgr_policy(Some(policy));
+///     let ctx = egui::Context::default();
+///     let mut triggered = vec![];
+///     let input = RawInput {
+///         events: vec![altgr_q.clone()],
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         triggered = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+///     });
+///     triggered
+/// };
+///
+/// assert_eq!(capture(AltGrPolicy::StripCtrl), "Alt+Q");
+/// assert_eq!(trigger(AltGrPolicy::StripCtrl), ["alt q"]);
+/// // the text form keeps the command modifier that Windows adds to Ctrl
+/// assert_eq!(capture(AltGrPolicy::Keep), "CmdOrCtrl+Ctrl+Alt+Q");
+/// assert_eq!(trigger(AltGrPolicy::Keep), ["ctrl alt q"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AltGrPolicy {
+    /// Ctrl+Alt stays Ctrl+Alt.
+    Keep,
+    /// Ctrl+Alt is treated as Alt, as if it was AltGr.
+    StripCtrl,
+}
+
+impl AltGrPolicy {
+    /// The policy that fits an operating system: [`AltGrPolicy::StripCtrl`]
+    /// on Windows, where AltGr arrives as Ctrl+Alt, and
+    /// [`AltGrPolicy::Keep`] everywhere else.
+    pub fn for_os(os: OperatingSystem) -> Self {
+        match os {
+            OperatingSystem::Windows => Self::StripCtrl,
+            _ => Self::Keep,
+        }
+    }
+
+    /// Apply the policy to the modifiers of a key press.
+    pub fn normalize(self, modifiers: Modifiers) -> Modifiers {
+        let is_altgr = modifiers.ctrl && modifiers.alt && !modifiers.mac_cmd;
+        match self {
+            Self::StripCtrl if is_altgr => Modifiers {
+                ctrl: false,
+                command: false,
+                ..modifiers
+            },
+            _ => modifiers,
+        }
+    }
+
+    /// Check if a bind is pressed like [`Bind::pressed`], with the policy
+    /// applied to the modifiers of the key presses. The key presses in
+    /// `input` keep their modifiers, so other binds that are checked after
+    /// this still see them as they were. Only the presses the bind consumed
+    /// are removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{AltGrPolicy, Bind, Shortcut};
+    ///
+    /// let alt_q: Shortcut = "Alt+Q".parse().unwrap();
+    /// let ctrl_alt_q: Shortcut = "Ctrl+Alt+Q".parse().unwrap();
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::Q,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL | Modifiers::ALT,
+    /// });
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| {
+    ///     ctx.input_mut(|input| {
+    ///         assert!(!AltGrPolicy::StripCtrl.pressed(&ctrl_alt_q, input));
+    ///         // the key press is still Ctrl+Alt+Q for everything else
+    ///         assert!(ctrl_alt_q.pressed(&mut input.clone()));
+    ///         assert!(AltGrPolicy::StripCtrl.pressed(&alt_q, input));
+    ///         // and it's gone once it's consumed
+    ///         assert!(!ctrl_alt_q.pressed(input));
+    ///     });
+    /// });
+    /// ```
+    pub fn pressed<B: Bind>(self, bind: &B, input: &mut InputState) -> bool {
+        self.normalized(input, |input| bind.pressed(input))
+    }
+
+    /// Run `f` with the policy applied to the key presses of `input`, on a
+    /// copy of it if that changes any of them. Key presses that `f` consumes
+    /// from the copy are removed from `input` as well.
+    pub(crate) fn normalized<R>(
+        self,
+        input: &mut InputState,
+        f: impl FnOnce(&mut InputState) -> R,
+    ) -> R {
+        let normalize_event = |event: &Event| match event {
+            Event::Key { modifiers, .. } if self.normalize(*modifiers) != *modifiers => {
+                let mut event = event.clone();
+                if let Event::Key { modifiers, .. } = &mut event {
+                    *modifiers = self.normalize(*modifiers);
+                }
+                Some(event)
+            }
+            _ => None,
+        };
+        if !input
+            .events
+            .iter()
+            .any(|event| normalize_event(event).is_some())
+        {
+            return f(input);
+        }
+        let mut normalized = input.clone();
+        for event in &mut normalized.events {
+            if let Some(normalized) = normalize_event(event) {
+                *event = normalized;
+            }
+        }
+        let result = f(&mut normalized);
+        // consuming only removes events, so the ones left are in the same order
+        let mut left = normalized.events.into_iter().peekable();
+        input.events.retain(|event| {
+            let event = normalize_event(event).unwrap_or_else(|| event.clone());
+            let kept = left.peek() == Some(&event);
+            if kept {
+                left.next();
+            }
+            kept
+        });
+        result
+    }
+}
+
+impl Hotkeys {
+    /// Set how key presses with both Ctrl and Alt are treated by
+    /// [`Hotkeys::triggered`], see [AltGrPolicy]. The key presses of the
+    /// frame are only normalized while the actions are matched (see
+    /// [`AltGrPolicy::pressed`]), so everything that checks them after it
+    /// sees the modifiers as they were. If [None], the policy fits the
+    /// operating system (see [`AltGrPolicy::for_os`]), which is the default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{AltGrPolicy, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("alt q", "Alt+Q".parse().unwrap())
+    ///     .set_altgr_policy(Some(AltGrPolicy::StripCtrl));
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::W,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL | Modifiers::ALT,
+    /// });
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| {
+    ///     assert_eq!(hotkeys.triggered(ctx).count(), 0);
+    ///     // the key press that no action took is left as it was
+    ///     let modifiers = ctx.input(|input| match input.events[..] {
+    ///         [Event::Key { modifiers, .. }] => modifiers,
+    ///         _ => unreachable!(),
+    ///     });
+    ///     assert_eq!(modifiers, Modifiers::CTRL | Modifiers::ALT);
+    /// });
+    /// ```
+    pub fn set_altgr_policy(&mut self, policy: Option<AltGrPolicy>) {
+        self.altgr = policy;
+    }
+
+    /// Get how key presses with both Ctrl and Alt are treated, see
+    /// [`Hotkeys::set_altgr_policy`].
+    #[inline]
+    pub fn altgr_policy(&self) -> Option<AltGrPolicy> {
+        self.altgr
+    }
+
+    /// Get the policy that is used on a [Context], see
+    /// [`Hotkeys::set_altgr_policy`].
+    pub(crate) fn altgr_policy_for(&self, ctx: &Context) -> AltGrPolicy {
+        self.altgr.unwrap_or_else(|| AltGrPolicy::for_os(ctx.os()))
+    }
+}
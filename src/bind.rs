@@ -1,4 +1,55 @@
-use egui::{InputState, Key, KeyboardShortcut, ModifierNames, PointerButton};
+use crate::{
+    names::{push_canonical_modifiers, push_keyboard_shortcut},
+    parse::ESCAPED_COMMA,
+    pointer_button_name, CommandPolicy, GestureDirection, KeyNameMap, TextInputPolicy, TextKey,
+    WheelDirection,
+};
+use egui::{
+    Context, Event, InputState, Key, KeyboardShortcut, ModifierNames, Modifiers, PointerButton,
+};
+
+/// Which kinds of input a [Bind] can be set to, see [`Bind::input_kinds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InputKinds {
+    /// Whether the bind can be set to a keyboard shortcut.
+    pub keyboard: bool,
+    /// Whether the bind can be set to a pointer button.
+    pub pointer: bool,
+}
+
+impl InputKinds {
+    /// Keyboard shortcuts and pointer buttons.
+    pub const ALL: Self = Self {
+        keyboard: true,
+        pointer: true,
+    };
+
+    /// Only keyboard shortcuts.
+    pub const KEYBOARD: Self = Self {
+        keyboard: true,
+        pointer: false,
+    };
+
+    /// Only pointer buttons.
+    pub const POINTER: Self = Self {
+        keyboard: false,
+        pointer: true,
+    };
+
+    /// Whether a captured keyboard shortcut and/or pointer button has a part
+    /// that a bind of these kinds can be set to. Nothing at all is accepted,
+    /// since that unbinds the bind.
+    pub fn accepts(
+        self,
+        keyboard: Option<KeyboardShortcut>,
+        pointer: Option<PointerButton>,
+    ) -> bool {
+        match (keyboard, pointer) {
+            (None, None) => true,
+            _ => (self.keyboard && keyboard.is_some()) || (self.pointer && pointer.is_some()),
+        }
+    }
+}
 
 /// A trait can can be used for keybindings.
 ///
@@ -24,7 +75,156 @@ pub trait Bind: Clone {
     /// The formatted keybind as a [String].
     fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String;
 
-    /// Check if the keybind is pressed.
+    /// Append the current keybind to a [String], formatted like [Bind::format].
+    /// This lets one buffer be reused for many binds, instead of allocating a
+    /// new [String] for each of them.
+    ///
+    /// By default this appends the result of [Bind::format]. The binds of
+    /// this crate write into `out` directly, without allocating.
+    ///
+    /// # Arguments
+    /// * `out` - The [String] to append to. It isn't cleared first.
+    /// * `names` - The [ModifierNames] to use.
+    /// * `is_mac` - Whether to use MacOS symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, ModifierNames, Modifiers, PointerButton};
+    /// use egui_keybind::{Bind, PointerTrigger, Shortcut, Shortcuts};
+    ///
+    /// // appending gives the same text as `format`, for all names and platforms
+    /// fn check<B: Bind>(bind: B) {
+    ///     let mut out = String::new();
+    ///     for names in [ModifierNames::NAMES, ModifierNames::SYMBOLS] {
+    ///         for is_mac in [false, true] {
+    ///             out.clear();
+    ///             out.push_str("Bind: ");
+    ///             bind.format_into(&mut out, &names, is_mac);
+    ///             assert_eq!(out, format!("Bind: {}", bind.format(&names, is_mac)));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// for keyboard in [
+    ///     KeyboardShortcut::new(Modifiers::NONE, Key::Escape),
+    ///     KeyboardShortcut::new(Modifiers::CTRL | Modifiers::SHIFT, Key::S),
+    ///     KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::ALT, Key::ArrowUp),
+    ///     KeyboardShortcut::new(Modifiers::MAC_CMD | Modifiers::CTRL, Key::Plus),
+    /// ] {
+    ///     check(keyboard);
+    ///     check(Some(keyboard));
+    ///     check(keyboard.logical_key);
+    ///     check(Some(keyboard.logical_key));
+    ///     let shortcut = Shortcut::new(Some(keyboard), None);
+    ///     check(shortcut);
+    ///     check(Shortcut::new(Some(keyboard), Some(PointerButton::Middle)));
+    ///     check(Shortcuts::new(vec![shortcut]));
+    ///
+    ///     // the same as egui's own formatting, which leaves out the logo key
+    ///     // outside of Mac
+    ///     for names in [ModifierNames::NAMES, ModifierNames::SYMBOLS] {
+    ///         for is_mac in [false, true] {
+    ///             let mut out = String::new();
+    ///             Bind::format_into(&keyboard, &mut out, &names, is_mac);
+    ///             if is_mac || !keyboard.modifiers.mac_cmd {
+    ///                 assert_eq!(out, keyboard.format(&names, is_mac));
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// let win_plus = KeyboardShortcut::new(Modifiers::MAC_CMD | Modifiers::CTRL, Key::Plus);
+    /// assert_eq!(Bind::format(&win_plus, &ModifierNames::NAMES, false), "Ctrl+Cmd+Plus");
+    /// for button in [PointerButton::Primary, PointerButton::Extra2] {
+    ///     check(button);
+    ///     check(Some(button));
+    ///     check(Shortcut::new(None, Some(button)).with_pointer_trigger(PointerTrigger::Click));
+    /// }
+    /// check(None::<KeyboardShortcut>);
+    /// check(None::<Key>);
+    /// check(None::<PointerButton>);
+    /// check(Shortcut::NONE);
+    /// check(Shortcuts::default());
+    /// check("Alt+§+Mouse Right".parse::<Shortcut>().unwrap());
+    /// ```
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        out.push_str(&self.format(names, is_mac));
+    }
+
+    /// Format the current keybind as a [String], using one separator between
+    /// all of its parts (modifiers, key and pointer button alike).
+    ///
+    /// # Arguments
+    /// * `names` - The [ModifierNames] to use. Its `concat` is replaced by `separator`.
+    /// * `is_mac` - Whether to use MacOS symbols.
+    /// * `separator` - What goes between the names, e.g. `"+"`, `" + "` or `"-"`.
+    ///
+    /// # Returns
+    /// The formatted keybind as a [String].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::ModifierNames;
+    /// use egui_keybind::{Bind, Shortcut};
+    ///
+    /// let shortcut: Shortcut = "Ctrl+Shift+S+Mouse Right".parse().unwrap();
+    /// let format = |names, separator| shortcut.format_with_separator(names, false, separator);
+    /// assert_eq!(format(&ModifierNames::NAMES, "+"), "Ctrl+Shift+S+Mouse Right");
+    /// assert_eq!(format(&ModifierNames::NAMES, " + "), "Ctrl + Shift + S + Mouse Right");
+    /// assert_eq!(format(&ModifierNames::NAMES, "-"), "Ctrl-Shift-S-Mouse Right");
+    /// // symbols go without one, but the pointer button is still set apart
+    /// assert_eq!(format(&ModifierNames::SYMBOLS, ""), "⌃⇧S+Mouse Right");
+    /// assert_eq!(format(&ModifierNames::SYMBOLS, " "), "⌃ ⇧ S Mouse Right");
+    /// ```
+    fn format_with_separator(
+        &self,
+        names: &ModifierNames<'_>,
+        is_mac: bool,
+        separator: &str,
+    ) -> String {
+        self.format(
+            &ModifierNames {
+                concat: separator,
+                ..*names
+            },
+            is_mac,
+        )
+    }
+
+    /// Format the current keybind as a [String], taking key and pointer button
+    /// names from a [KeyNameMap].
+    ///
+    /// By default this ignores the [KeyNameMap] and calls [Bind::format].
+    ///
+    /// # Arguments
+    /// * `names` - The [ModifierNames] to use.
+    /// * `key_names` - The [KeyNameMap] to use.
+    /// * `is_mac` - Whether to use MacOS symbols.
+    ///
+    /// # Returns
+    /// The formatted keybind as a [String].
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        _key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        self.format(names, is_mac)
+    }
+
+    /// Clear the keybind, so it's not bound to anything.
+    ///
+    /// By default this calls [Bind::set] with [None] for both the keyboard
+    /// shortcut and the pointer button, which does nothing for binds that
+    /// can't be unbound.
+    fn clear(&mut self) {
+        self.set(None, None);
+    }
+
+    /// Check if the keybind is pressed. Key presses are matched with their
+    /// modifiers as they are, see [`AltGrPolicy::pressed`](crate::AltGrPolicy::pressed)
+    /// to treat Ctrl+Alt as AltGr.
     ///
     /// # Arguments
     /// * `input` - The [InputState] to check with.
@@ -32,6 +232,342 @@ pub trait Bind: Clone {
     /// # Returns
     /// Whether the keybind is pressed.
     fn pressed(&self, input: &mut InputState) -> bool;
+
+    /// Check if the keybind is pressed like [Bind::pressed], unless a widget
+    /// wants keyboard input ([`Context::wants_keyboard_input`]), e.g. while
+    /// typing into a [TextEdit](egui::TextEdit), and the keybind has no
+    /// modifier other than Shift. Same as [Bind::pressed_with_policy] with
+    /// [`TextInputPolicy::WithModifiers`].
+    ///
+    /// This locks the [Context] on its own, so don't call it from inside
+    /// [`Context::input`] or similar closures, which would deadlock.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput, TextEdit};
+    /// use egui_keybind::{Bind, Shortcut};
+    ///
+    /// let delete: Shortcut = "Delete".parse().unwrap();
+    /// let ctx = egui::Context::default();
+    /// let mut text = String::new();
+    /// let mut frame = |typing: bool| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key: Key::Delete,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::NONE,
+    ///     });
+    ///     let mut pressed = false;
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         pressed = delete.pressed_unless_typing(ctx);
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let response = ui.add(TextEdit::singleline(&mut text));
+    ///             if typing {
+    ///                 response.request_focus();
+    ///             }
+    ///         });
+    ///     });
+    ///     pressed
+    /// };
+    ///
+    /// frame(true);
+    /// // Delete edits the text instead while the text field has focus
+    /// assert!(!frame(true));
+    /// ctx.memory_mut(|memory| memory.surrender_focus(memory.focused().unwrap()));
+    /// frame(false);
+    /// assert!(frame(false));
+    /// ```
+    fn pressed_unless_typing(&self, ctx: &Context) -> bool {
+        self.pressed_with_policy(ctx, TextInputPolicy::WithModifiers)
+    }
+
+    /// Check if the keybind is pressed like [Bind::pressed], following a
+    /// [TextInputPolicy] while a widget wants keyboard input, e.g.
+    /// [`TextInputPolicy::Never`] to cover keybinds with modifiers as well.
+    /// Ctrl+Alt is treated like the operating system of the context sends
+    /// AltGr, see [`AltGrPolicy::for_os`](crate::AltGrPolicy::for_os). See
+    /// [Bind::pressed_unless_typing].
+    fn pressed_with_policy(&self, ctx: &Context, policy: TextInputPolicy) -> bool {
+        // `wants_keyboard_input` and `input_mut` lock the context one after the other
+        let typing = ctx.wants_keyboard_input();
+        let altgr = crate::AltGrPolicy::for_os(ctx.os());
+        (!typing || policy.allows(self)) && ctx.input_mut(|input| altgr.pressed(self, input))
+    }
+
+    /// Flip a boolean if the keybind is pressed, e.g. to show and hide an
+    /// overlay with a hotkey. Like [Bind::pressed], this consumes the
+    /// presses, so the boolean is flipped at most once per frame.
+    ///
+    /// # Arguments
+    /// * `input` - The [InputState] to check with.
+    /// * `state` - The boolean to flip.
+    ///
+    /// # Returns
+    /// Whether the boolean was flipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Bind, Shortcut};
+    ///
+    /// let mute: Shortcut = "Ctrl+M".parse().unwrap();
+    /// let ctx = egui::Context::default();
+    /// let mut muted = false;
+    /// let mut frame = |presses: usize| {
+    ///     let mut input = RawInput::default();
+    ///     for _ in 0..presses {
+    ///         input.events.push(Event::Key {
+    ///             key: Key::M,
+    ///             physical_key: None,
+    ///             pressed: true,
+    ///             repeat: false,
+    ///             modifiers: Modifiers::CTRL,
+    ///         });
+    ///     }
+    ///     let mut toggled = [false; 2];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         ctx.input_mut(|i| {
+    ///             // the second check finds the press already consumed
+    ///             toggled = [mute.toggled(i, &mut muted), mute.toggled(i, &mut muted)];
+    ///         });
+    ///     });
+    ///     toggled
+    /// };
+    ///
+    /// assert_eq!(frame(0), [false, false]);
+    /// assert_eq!(frame(1), [true, false]);
+    /// // two presses on one frame are consumed together
+    /// assert_eq!(frame(2), [true, false]);
+    /// assert_eq!(frame(1), [true, false]);
+    /// drop(frame);
+    /// assert!(muted);
+    /// ```
+    fn toggled(&self, input: &mut InputState, state: &mut bool) -> bool {
+        let pressed = self.pressed(input);
+        if pressed {
+            *state = !*state;
+        }
+        pressed
+    }
+
+    /// Flip a boolean if the keybind is pressed like
+    /// [Bind::pressed_unless_typing], e.g. with a single key that shouldn't
+    /// toggle anything while typing into a text field. See [Bind::toggled].
+    ///
+    /// This locks the [Context] on its own, so don't call it from inside
+    /// [`Context::input`] or similar closures, which would deadlock.
+    ///
+    /// # Returns
+    /// Whether the boolean was flipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Bind, Shortcut};
+    ///
+    /// let pause: Shortcut = "Space".parse().unwrap();
+    /// let ctx = egui::Context::default();
+    /// let mut paused = false;
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::Space,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::NONE,
+    /// });
+    /// let _ = ctx.run(input, |ctx| {
+    ///     assert!(pause.toggled_unless_typing(ctx, &mut paused));
+    ///     assert!(!pause.toggled_unless_typing(ctx, &mut paused));
+    /// });
+    /// assert!(paused);
+    /// ```
+    fn toggled_unless_typing(&self, ctx: &Context, state: &mut bool) -> bool {
+        let pressed = self.pressed_unless_typing(ctx);
+        if pressed {
+            *state = !*state;
+        }
+        pressed
+    }
+
+    /// Check if the keybind is bound to anything.
+    ///
+    /// By default this is always true.
+    ///
+    /// # Returns
+    /// Whether the keybind is bound.
+    fn is_bound(&self) -> bool {
+        true
+    }
+
+    /// Get the alternate shortcuts that are bound in addition to this keybind,
+    /// e.g. the secondary shortcuts of [Shortcuts](crate::Shortcuts).
+    ///
+    /// By default there are none.
+    fn alternates(&self) -> &[Shortcut] {
+        &[]
+    }
+
+    /// Get the keyboard part of the keybind, if any.
+    ///
+    /// By default this is [None]. Widgets that edit a part of the keybind,
+    /// like the [KeybindPicker](crate::KeybindPicker), write this back with
+    /// [`Bind::set`], so a keybind with a keyboard shortcut should return it.
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        None
+    }
+
+    /// Get the pointer button part of the keybind, if any.
+    ///
+    /// By default this is [None]. Like [`Bind::keyboard`], this is written
+    /// back with [`Bind::set`].
+    fn pointer(&self) -> Option<PointerButton> {
+        None
+    }
+
+    /// Get the text key of the keybind, if any, see [TextKey].
+    ///
+    /// By default this is [None].
+    fn text(&self) -> Option<TextKey> {
+        None
+    }
+
+    /// Set the keybind to a [TextKey] and/or a [PointerButton], instead of
+    /// a keyboard shortcut.
+    ///
+    /// By default the keybind can't store text keys, and this does nothing.
+    ///
+    /// # Returns
+    /// Whether the keybind was set, i.e. if it can store text keys.
+    fn set_text(&mut self, _text: TextKey, _pointer: Option<PointerButton>) -> bool {
+        false
+    }
+
+    /// Set the keybind to a pointer button that has to be held for a while,
+    /// see [LongPressBind](crate::LongPressBind).
+    ///
+    /// By default the keybind can't store long presses, and this does nothing.
+    ///
+    /// # Arguments
+    /// * `button` - The button to hold.
+    /// * `duration` - How long the button has to be held, in seconds.
+    ///
+    /// # Returns
+    /// Whether the keybind was set, i.e. if it can store long presses.
+    fn set_long_press(&mut self, _button: PointerButton, _duration: f32) -> bool {
+        false
+    }
+
+    /// Set the keybind to turning the mouse wheel with modifiers held, see
+    /// [WheelBind](crate::WheelBind).
+    ///
+    /// By default the keybind can't store wheel turns, and this does nothing.
+    ///
+    /// # Arguments
+    /// * `modifiers` - The modifiers that have to be held.
+    /// * `direction` - Which way the wheel is turned.
+    ///
+    /// # Returns
+    /// Whether the keybind was set, i.e. if it can store wheel turns.
+    fn set_wheel(&mut self, _modifiers: Modifiers, _direction: WheelDirection) -> bool {
+        false
+    }
+
+    /// Set the keybind to dragging the pointer with a button held, see
+    /// [GestureBind](crate::GestureBind).
+    ///
+    /// By default the keybind can't store gestures, and this does nothing.
+    ///
+    /// # Arguments
+    /// * `button` - The button to hold while dragging.
+    /// * `direction` - Which way the pointer is dragged.
+    ///
+    /// # Returns
+    /// Whether the keybind was set, i.e. if it can store gestures.
+    fn set_gesture(&mut self, _button: PointerButton, _direction: GestureDirection) -> bool {
+        false
+    }
+
+    /// Get the kinds of input the keybind can be set to. While capturing,
+    /// the [Keybind](crate::Keybind) widget ignores input of other kinds
+    /// instead of passing it to [Bind::set], e.g. a stray mouse button press
+    /// while recording a keyboard-only bind.
+    ///
+    /// By default this is [`InputKinds::ALL`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, KeyboardShortcut, Modifiers, PointerButton, RawInput};
+    /// use egui_keybind::{Bind, InputKinds, Keybind};
+    ///
+    /// let mut bind = Some(KeyboardShortcut::new(Modifiers::CTRL, Key::S));
+    /// assert_eq!(bind.input_kinds(), InputKinds::KEYBOARD);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut frame = |bind: &mut Option<KeyboardShortcut>, events: Vec<Event>| {
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let _ = ctx.run(
+    ///         RawInput {
+    ///             events,
+    ///             ..Default::default()
+    ///         },
+    ///         |ctx| {
+    ///             egui::CentralPanel::default().show(ctx, |ui| {
+    ///                 rect = ui.add(Keybind::new(bind, "save")).rect;
+    ///             });
+    ///         },
+    ///     );
+    ///     rect
+    /// };
+    /// let button = |pos, button, pressed| Event::PointerButton {
+    ///     pos,
+    ///     button,
+    ///     pressed,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    ///
+    /// // click the widget to start recording
+    /// let pos = frame(&mut bind, vec![]).center();
+    /// let click = vec![
+    ///     Event::PointerMoved(pos),
+    ///     button(pos, PointerButton::Primary, true),
+    ///     button(pos, PointerButton::Primary, false),
+    /// ];
+    /// frame(&mut bind, click);
+    ///
+    /// // a stray side button press doesn't erase the keyboard shortcut
+    /// frame(&mut bind, vec![button(pos, PointerButton::Extra1, true)]);
+    /// assert_eq!(bind, Some(KeyboardShortcut::new(Modifiers::CTRL, Key::S)));
+    ///
+    /// // and the widget keeps recording
+    /// let key = Event::Key {
+    ///     key: Key::O,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL,
+    /// };
+    /// frame(&mut bind, vec![key]);
+    /// assert_eq!(bind, Some(KeyboardShortcut::new(Modifiers::CTRL, Key::O)));
+    /// ```
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::ALL
+    }
+
+    /// Get a hash of everything the formatted bind depends on, so a
+    /// [Keybind](crate::Keybind) can reuse the text it laid out on earlier
+    /// frames while this stays the same.
+    ///
+    /// By default this is [None], and the bind is formatted every frame.
+    fn cache_key(&self) -> Option<u64> {
+        None
+    }
 }
 
 /// A [Bind] implementation for [egui]'s [KeyboardShortcut].
@@ -43,35 +579,207 @@ impl Bind for KeyboardShortcut {
     }
 
     fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
-        self.format(names, is_mac)
+        let mut string = String::new();
+        Bind::format_into(self, &mut string, names, is_mac);
+        string
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        let key_name = match names.is_short {
+            true => self.logical_key.symbol_or_name(),
+            false => self.logical_key.name(),
+        };
+        push_keyboard_shortcut(out, self, names, key_name, is_mac);
+    }
+
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        key_names.format_keyboard_shortcut(self, names, is_mac)
     }
 
     fn pressed(&self, input: &mut InputState) -> bool {
         input.consume_shortcut(self)
     }
-}
 
-impl Bind for Option<KeyboardShortcut> {
-    fn set(&mut self, keyboard: Option<KeyboardShortcut>, _pointer: Option<PointerButton>) {
-        *self = keyboard;
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        Some(*self)
     }
 
-    fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
-        self.as_ref().map_or_else(
-            || "None".to_string(),
-            |shortcut| shortcut.format(names, is_mac),
-        )
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::KEYBOARD
     }
 
-    fn pressed(&self, input: &mut InputState) -> bool {
-        if let Some(shortcut) = self {
-            input.consume_shortcut(shortcut)
-        } else {
-            false
+    fn cache_key(&self) -> Option<u64> {
+        Some(egui::util::hash(self))
+    }
+}
+
+/// Set an optional bind with `set`, starting from the bind `new` returns if
+/// it's [None]. See [`Bind::set_text`].
+///
+/// # Returns
+/// Whether the bind was set. It's left as it is otherwise.
+fn set_option<B>(
+    bind: &mut Option<B>,
+    new: impl FnOnce() -> Option<B>,
+    set: impl FnOnce(&mut B) -> bool,
+) -> bool {
+    match bind {
+        Some(bind) => set(bind),
+        None => {
+            let Some(mut new) = new() else {
+                return false;
+            };
+            let was_set = set(&mut new);
+            if was_set {
+                *bind = Some(new);
+            }
+            was_set
         }
     }
 }
 
+/// Implement [Bind] for an [Option] of a bind, where [None] is unbound and
+/// formatted as `"None"`.
+///
+/// Setting it to no keyboard shortcut and no pointer button makes it [None]
+/// if `$unbinds` is true, and does nothing otherwise. Otherwise, the bind
+/// inside is set, or created with `$new` from the
+/// keyboard shortcut and pointer button if it's [None]. If `$new` returns
+/// [None], e.g. for only a pointer button and a bind that ignores those, it
+/// stays [None]. `$new` is called with no shortcut and no button for the
+/// bind the text key, long press, wheel turn or gesture setters start from.
+///
+/// A blanket `impl<B: Bind> Bind for Option<B>` can't coexist with these,
+/// and would need every bind to have an unbound value to start from.
+macro_rules! option_bind {
+    ($(#[$meta:meta])* $bind:ty, $kinds:expr, $new:expr, $unbinds:expr) => {
+        $(#[$meta])*
+        impl Bind for Option<$bind> {
+            fn set(&mut self, keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+                if keyboard.is_none() && pointer.is_none() {
+                    if $unbinds {
+                        *self = None;
+                    }
+                    return;
+                }
+                match self {
+                    Some(bind) => bind.set(keyboard, pointer),
+                    None => {
+                        let new = option_bind!(@new $bind, $new);
+                        *self = new(keyboard, pointer).map(|mut bind| {
+                            bind.set(keyboard, pointer);
+                            bind
+                        });
+                    }
+                }
+            }
+
+            fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+                let mut string = String::new();
+                self.format_into(&mut string, names, is_mac);
+                string
+            }
+
+            fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+                match self {
+                    Some(bind) => Bind::format_into(bind, out, names, is_mac),
+                    None => out.push_str("None"),
+                }
+            }
+
+            fn format_with_key_names(
+                &self,
+                names: &ModifierNames<'_>,
+                key_names: &KeyNameMap,
+                is_mac: bool,
+            ) -> String {
+                self.as_ref().map_or_else(
+                    || "None".to_string(),
+                    |bind| bind.format_with_key_names(names, key_names, is_mac),
+                )
+            }
+
+            fn clear(&mut self) {
+                *self = None;
+            }
+
+            fn pressed(&self, input: &mut InputState) -> bool {
+                self.as_ref().is_some_and(|bind| bind.pressed(input))
+            }
+
+            fn is_bound(&self) -> bool {
+                self.as_ref().is_some_and(|bind| bind.is_bound())
+            }
+
+            fn alternates(&self) -> &[Shortcut] {
+                self.as_ref().map_or(&[], |bind| bind.alternates())
+            }
+
+            fn keyboard(&self) -> Option<KeyboardShortcut> {
+                self.as_ref().and_then(|bind| bind.keyboard())
+            }
+
+            fn pointer(&self) -> Option<PointerButton> {
+                self.as_ref().and_then(|bind| bind.pointer())
+            }
+
+            fn text(&self) -> Option<TextKey> {
+                self.as_ref().and_then(|bind| bind.text())
+            }
+
+            fn set_text(&mut self, text: TextKey, pointer: Option<PointerButton>) -> bool {
+                let new = option_bind!(@new $bind, $new);
+                set_option(self, || new(None, None), |bind| bind.set_text(text, pointer))
+            }
+
+            fn set_long_press(&mut self, button: PointerButton, duration: f32) -> bool {
+                let new = option_bind!(@new $bind, $new);
+                set_option(self, || new(None, None), |bind| bind.set_long_press(button, duration))
+            }
+
+            fn set_wheel(&mut self, modifiers: Modifiers, direction: WheelDirection) -> bool {
+                let new = option_bind!(@new $bind, $new);
+                set_option(self, || new(None, None), |bind| bind.set_wheel(modifiers, direction))
+            }
+
+            fn set_gesture(&mut self, button: PointerButton, direction: GestureDirection) -> bool {
+                let new = option_bind!(@new $bind, $new);
+                set_option(self, || new(None, None), |bind| bind.set_gesture(button, direction))
+            }
+
+            fn input_kinds(&self) -> InputKinds {
+                $kinds
+            }
+
+            fn cache_key(&self) -> Option<u64> {
+                match self {
+                    Some(bind) => bind.cache_key().map(|key| egui::util::hash((true, key))),
+                    None => Some(egui::util::hash(false)),
+                }
+            }
+        }
+    };
+    // `$new` as a function pointer, so its argument types are known
+    (@new $bind:ty, $new:expr) => {{
+        let new: fn(Option<KeyboardShortcut>, Option<PointerButton>) -> Option<$bind> = $new;
+        new
+    }};
+}
+
+option_bind!(
+    /// Ignores pointer buttons, so setting only a pointer button keeps the
+    /// keyboard shortcut.
+    KeyboardShortcut,
+    InputKinds::KEYBOARD,
+    |keyboard, _pointer| keyboard,
+    true
+);
+
 /// A [Bind] implementation for [egui]'s [Key]. Ignores modifiers.
 impl Bind for Key {
     fn set(&mut self, keyboard: Option<KeyboardShortcut>, _pointer: Option<PointerButton>) {
@@ -84,32 +792,66 @@ impl Bind for Key {
         self.name().to_string()
     }
 
+    fn format_into(&self, out: &mut String, _names: &ModifierNames<'_>, _is_mac: bool) {
+        out.push_str(self.name());
+    }
+
+    fn format_with_key_names(
+        &self,
+        _names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        _is_mac: bool,
+    ) -> String {
+        key_names.key_name(*self, false).to_string()
+    }
+
     fn pressed(&self, input: &mut InputState) -> bool {
         input.key_pressed(*self)
     }
-}
 
-impl Bind for Option<Key> {
-    fn set(&mut self, keyboard: Option<KeyboardShortcut>, _pointer: Option<PointerButton>) {
-        if let Some(keyboard) = keyboard {
-            *self = Some(keyboard.logical_key)
-        }
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        Some(KeyboardShortcut::new(Modifiers::NONE, *self))
     }
 
-    fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
-        self.as_ref()
-            .map_or_else(|| "None".to_string(), |key| key.name().to_string())
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::KEYBOARD
     }
 
-    fn pressed(&self, input: &mut InputState) -> bool {
-        if let Some(key) = self {
-            input.key_pressed(*key)
-        } else {
-            false
-        }
+    fn cache_key(&self) -> Option<u64> {
+        Some(egui::util::hash(self))
     }
 }
 
+option_bind!(
+    /// Ignores modifiers and pointer buttons, so setting only a pointer
+    /// button keeps the key. Setting it to nothing keeps the key as well,
+    /// use [`Bind::clear`] to make it [None].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::Bind;
+    ///
+    /// let mut key: Option<Key> = None;
+    /// key.set(None, Some(PointerButton::Middle));
+    /// assert_eq!(key, None);
+    /// key.set(Some(KeyboardShortcut::new(Modifiers::CTRL, Key::S)), None);
+    /// assert_eq!(key, Some(Key::S));
+    /// key.set(None, Some(PointerButton::Middle));
+    /// assert_eq!(key, Some(Key::S));
+    /// assert_eq!(key.format(&egui::ModifierNames::NAMES, false), "S");
+    /// key.set(None, None);
+    /// assert_eq!(key, Some(Key::S));
+    /// key.clear();
+    /// assert_eq!(key.format(&egui::ModifierNames::NAMES, false), "None");
+    /// ```
+    Key,
+    InputKinds::KEYBOARD,
+    |keyboard, _pointer| keyboard.map(|keyboard| keyboard.logical_key),
+    false
+);
+
 /// A [Bind] implementation for [egui]'s [PointerButton]. Ignores keys and modifiers.
 impl Bind for PointerButton {
     fn set(&mut self, _keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
@@ -119,41 +861,297 @@ impl Bind for PointerButton {
     }
 
     fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
-        format!("{:?}", self)
+        pointer_button_name(*self).to_string()
     }
 
+    fn format_into(&self, out: &mut String, _names: &ModifierNames<'_>, _is_mac: bool) {
+        out.push_str(pointer_button_name(*self));
+    }
+
+    fn format_with_key_names(
+        &self,
+        _names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        _is_mac: bool,
+    ) -> String {
+        key_names.pointer_button_name(*self).to_string()
+    }
+
+    /// Triggered when the button goes down, like [`PointerTrigger::Press`].
     fn pressed(&self, input: &mut InputState) -> bool {
-        input.pointer.button_pressed(*self)
+        PointerTrigger::Press.matches(*self, input)
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        Some(*self)
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::POINTER
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        Some(egui::util::hash(*self as usize))
     }
 }
 
-impl Bind for Option<PointerButton> {
-    fn set(&mut self, _keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
-        *self = pointer;
+option_bind!(
+    /// Ignores keyboard shortcuts, so setting only a keyboard shortcut keeps the
+    /// pointer button.
+    PointerButton,
+    InputKinds::POINTER,
+    |_keyboard, pointer| pointer,
+    true
+);
+
+/// Whether modifiers that are held besides the ones of a keyboard shortcut
+/// keep it from matching, see [`Shortcut::with_match_policy`].
+///
+/// Ctrl and Cmd always have to match (with [`Modifiers::command`] matching
+/// either, depending on the platform), so only Shift and Alt can be extra.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{Bind, MatchPolicy, Shortcut};
+///
+/// let ctx = egui::Context::default();
+/// let pressed = |shortcut: &str, policy, modifiers| {
+///     let shortcut: Shortcut = shortcut.parse().unwrap();
+///     let shortcut = shortcut.with_match_policy(policy);
+///     let mut input = RawInput::default();
+///     input.events.push(Event::Key {
+///         key: Key::X,
+///         physical_key: None,
+///         pressed: true,
+///         repeat: false,
+///         modifiers,
+///     });
+///     let mut pressed = false;
+///     let _ = ctx.run(input, |ctx| {
+///         pressed = ctx.input_mut(|input| shortcut.pressed(input));
+///     });
+///     pressed
+/// };
+///
+/// let ctrl = Modifiers::CTRL | Modifiers::COMMAND;
+/// for (shortcut, modifiers, lenient, exact) in [
+///     ("X", Modifiers::NONE, true, true),
+///     ("X", Modifiers::SHIFT, true, false),
+///     ("X", Modifiers::ALT, true, false),
+///     ("X", ctrl, false, false),
+///     ("Shift+X", Modifiers::SHIFT, true, true),
+///     ("Shift+X", Modifiers::NONE, false, false),
+///     ("Shift+X", Modifiers::SHIFT | Modifiers::ALT, true, false),
+///     ("Ctrl+X", ctrl, true, true),
+///     ("Ctrl+X", ctrl | Modifiers::SHIFT, true, false),
+///     ("Ctrl+X", Modifiers::SHIFT, false, false),
+/// ] {
+///     let policies = [MatchPolicy::Lenient, MatchPolicy::Exact];
+///     let matched = policies.map(|policy| pressed(shortcut, policy, modifiers));
+///     assert_eq!(matched, [lenient, exact], "{shortcut} with {modifiers:?}");
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchPolicy {
+    /// Extra Shift and Alt are ignored, so `Ctrl+Shift+S` also presses
+    /// `Ctrl+S`. Check the more specific shortcuts first then. This is the
+    /// default, and what [egui] does for menu accelerators.
+    #[default]
+    Lenient,
+    /// Extra modifiers keep the shortcut from matching, so `Shift+X` and `X`
+    /// can be different actions.
+    Exact,
+}
+
+impl MatchPolicy {
+    /// Whether the `pressed` modifiers match the `bound` modifiers of a
+    /// keyboard shortcut.
+    pub fn matches(self, pressed: Modifiers, bound: Modifiers) -> bool {
+        let (alt, shift) = match self {
+            Self::Lenient => (!bound.alt || pressed.alt, !bound.shift || pressed.shift),
+            Self::Exact => (bound.alt == pressed.alt, bound.shift == pressed.shift),
+        };
+        alt && shift && ctrl_cmd_matches(pressed, bound)
     }
 
-    fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
-        self.as_ref()
-            .map_or_else(|| "None".to_string(), |button| format!("{:?}", button))
+    /// Check if a keyboard shortcut is pressed with modifiers that match by
+    /// this policy, and consume its key presses if it is, like
+    /// [`InputState::consume_shortcut`]. Copy, cut and paste events count as
+    /// presses of Ctrl+C, Ctrl+X and Ctrl+V (Cmd on Mac), since some
+    /// platforms, like the web, send them instead of the key press. They are
+    /// left in the input, so a focused [TextEdit](egui::TextEdit) still gets
+    /// the clipboard.
+    pub fn consume(self, input: &mut InputState, shortcut: &KeyboardShortcut) -> bool {
+        let held = input.modifiers;
+        let mut found = false;
+        input.events.retain(|event| {
+            let is_match = match clipboard_key_press(event, held) {
+                Some((key, modifiers)) => {
+                    key == shortcut.logical_key && self.matches(modifiers, shortcut.modifiers)
+                }
+                None => matches!(
+                    event,
+                    Event::Key { key, pressed: true, modifiers, .. }
+                    if *key == shortcut.logical_key && self.matches(*modifiers, shortcut.modifiers)
+                ),
+            };
+            found |= is_match;
+            !is_match || matches!(event, Event::Copy | Event::Cut | Event::Paste(_))
+        });
+        found
+    }
+}
+
+/// Get the key press that a clipboard event stands for: C for
+/// [`Event::Copy`], X for [`Event::Cut`] and V for [`Event::Paste`], with the
+/// `held` modifiers (see [`InputState::modifiers`]).
+///
+/// Some platforms, like the web, send these instead of the key press when
+/// Ctrl+C (Cmd+C on Mac) and so on is pressed. Events that were sent without
+/// Ctrl or Cmd held, e.g. a paste from Shift+Insert, are [None].
+pub(crate) fn clipboard_key_press(event: &Event, held: Modifiers) -> Option<(Key, Modifiers)> {
+    let key = match event {
+        Event::Copy => Key::C,
+        Event::Cut => Key::X,
+        Event::Paste(_) => Key::V,
+        _ => return None,
+    };
+    held.command.then_some((key, held))
+}
+
+/// Whether Ctrl and Cmd of the `pressed` modifiers match the `bound` ones.
+/// [`Modifiers::command`] is Ctrl on most platforms and Cmd on Mac, so Ctrl
+/// only counts as extra on Mac, where Cmd is set.
+fn ctrl_cmd_matches(pressed: Modifiers, bound: Modifiers) -> bool {
+    if bound.mac_cmd {
+        return pressed.mac_cmd && pressed.ctrl == bound.ctrl;
     }
+    if !bound.ctrl && !bound.command {
+        return !pressed.ctrl && !pressed.command && !pressed.mac_cmd;
+    }
+    let needs_ctrl = bound.ctrl || (bound.command && !pressed.mac_cmd);
+    (!bound.ctrl || pressed.ctrl)
+        && (!bound.command || pressed.command)
+        && (needs_ctrl || !pressed.ctrl)
+}
 
-    fn pressed(&self, input: &mut InputState) -> bool {
-        if let Some(button) = self {
-            input.pointer.button_pressed(*button)
-        } else {
-            false
+/// When the pointer button of a [Shortcut] triggers it, see
+/// [`Shortcut::with_pointer_trigger`].
+///
+/// # Example
+///
+/// A drag only triggers a shortcut on [`PointerTrigger::Press`] and
+/// [`PointerTrigger::Release`], since it's not a click:
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Bind, PointerTrigger, Shortcut};
+///
+/// let button = |pos, pressed| Event::PointerButton {
+///     pos,
+///     button: PointerButton::Primary,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let (start, end) = (egui::pos2(10.0, 10.0), egui::pos2(200.0, 10.0));
+/// let frames = [
+///     vec![Event::PointerMoved(start), button(start, true)],
+///     vec![Event::PointerMoved(end)],
+///     vec![button(end, false)],
+/// ];
+///
+/// for (trigger, expected) in [
+///     (PointerTrigger::Press, [true, false, false]),
+///     (PointerTrigger::Click, [false, false, false]),
+///     (PointerTrigger::Release, [false, false, true]),
+/// ] {
+///     let shortcut = Shortcut::new(None, Some(PointerButton::Primary))
+///         .with_pointer_trigger(trigger);
+///     let ctx = egui::Context::default();
+///     let pressed: Vec<bool> = frames
+///         .iter()
+///         .map(|events| {
+///             let mut pressed = false;
+///             let input = RawInput {
+///                 events: events.clone(),
+///                 ..Default::default()
+///             };
+///             let _ = ctx.run(input, |ctx| {
+///                 pressed = ctx.input_mut(|input| shortcut.pressed(input));
+///             });
+///             pressed
+///         })
+///         .collect();
+///     assert_eq!(pressed, expected, "{trigger:?}");
+/// }
+///
+/// // the trigger is saved after the button, unless it's the default
+/// let shortcut: Shortcut = "Ctrl+Z+Mouse Right Release".parse().unwrap();
+/// assert_eq!(shortcut.pointer_trigger(), PointerTrigger::Release);
+/// assert_eq!(shortcut.to_string(), "Ctrl+Z+Mouse Right Release");
+/// assert_eq!("Middle".parse::<Shortcut>().unwrap().to_string(), "Middle");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointerTrigger {
+    /// Trigger when the button goes down, like a key press. This is the
+    /// default.
+    #[default]
+    Press,
+    /// Trigger when the button is released without the pointer being
+    /// dragged or held for long, see [`egui::PointerState::button_clicked`].
+    Click,
+    /// Trigger when the button is released, even after a drag.
+    Release,
+}
+
+impl PointerTrigger {
+    /// The name of the trigger, e.g. `"Click"`, as written after the pointer
+    /// button of a [Shortcut] by [`Bind::format`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Press => "Press",
+            Self::Click => "Click",
+            Self::Release => "Release",
+        }
+    }
+
+    /// Parse the name of a trigger like [`PointerTrigger::name`],
+    /// case-insensitive.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        [Self::Press, Self::Click, Self::Release]
+            .into_iter()
+            .find(|trigger| trigger.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Whether a pointer button triggers on this frame.
+    pub(crate) fn matches(self, button: PointerButton, input: &InputState) -> bool {
+        match self {
+            Self::Press => input.pointer.button_pressed(button),
+            Self::Click => input.pointer.button_clicked(button),
+            Self::Release => input.pointer.button_released(button),
         }
     }
 }
 
 /// A keybind that can be set with either the keyboard or a mouse.
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shortcut {
     /// Keyboard shortcut, if any. This can be set along with the mouse shortcut.
     keyboard: Option<KeyboardShortcut>,
     /// Mouse button, if any. This can be set along with the keyboard shortcut.
     pointer: Option<PointerButton>,
+    /// When the mouse button triggers the shortcut.
+    trigger: PointerTrigger,
+    /// Whether extra modifiers keep the keyboard shortcut from matching.
+    match_policy: MatchPolicy,
+    /// Whether Ctrl and Cmd of the keyboard shortcut are cross-platform.
+    command_policy: CommandPolicy,
+    /// Key that is bound by the character it types, instead of the keyboard shortcut.
+    text: Option<TextKey>,
 }
 
 impl Shortcut {
@@ -161,6 +1159,10 @@ impl Shortcut {
     pub const NONE: Self = Self {
         keyboard: None,
         pointer: None,
+        trigger: PointerTrigger::Press,
+        match_policy: MatchPolicy::Lenient,
+        command_policy: CommandPolicy::AsBound,
+        text: None,
     };
 
     /// Create a new [Shortcut].
@@ -173,6 +1175,85 @@ impl Shortcut {
         Self {
             keyboard,
             pointer,
+            trigger: PointerTrigger::Press,
+            match_policy: MatchPolicy::Lenient,
+            command_policy: CommandPolicy::AsBound,
+            text: None,
+        }
+    }
+
+    /// Set when the pointer button triggers the shortcut, e.g.
+    /// [`PointerTrigger::Release`] for an action that should happen after a
+    /// drag. By default it triggers when the button goes down. Only used if
+    /// the shortcut has a pointer button, and kept when the shortcut is
+    /// rebound with [`Bind::set`].
+    pub fn with_pointer_trigger(mut self, trigger: PointerTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// When the pointer button triggers the shortcut, see
+    /// [`Shortcut::with_pointer_trigger`].
+    #[inline]
+    pub const fn pointer_trigger(&self) -> PointerTrigger {
+        self.trigger
+    }
+
+    /// Set whether modifiers that are held besides the ones of the keyboard
+    /// shortcut keep it from matching, e.g. [`MatchPolicy::Exact`] so that
+    /// Shift+X doesn't press X. By default extra Shift and Alt are ignored.
+    ///
+    /// Like [`Shortcut::with_pointer_trigger`], this is kept when the
+    /// shortcut is rebound with [`Bind::set`]. It's not a part of the text
+    /// form of the shortcut, so shortcuts loaded into [Hotkeys](crate::Hotkeys)
+    /// get the policy of the default shortcut of their action.
+    pub fn with_match_policy(mut self, policy: MatchPolicy) -> Self {
+        self.match_policy = policy;
+        self
+    }
+
+    /// Whether extra modifiers keep the keyboard shortcut from matching, see
+    /// [`Shortcut::with_match_policy`].
+    #[inline]
+    pub const fn match_policy(&self) -> MatchPolicy {
+        self.match_policy
+    }
+
+    /// Set whether Ctrl and Cmd of the keyboard shortcut mean the primary
+    /// modifier of the platform, e.g. [`CommandPolicy::CrossPlatform`] so
+    /// that a Ctrl+S default is pressed with ⌘S on macOS. By default they
+    /// are matched as they were bound.
+    ///
+    /// Like [`Shortcut::with_match_policy`], this is kept when the shortcut
+    /// is rebound with [`Bind::set`], and shortcuts loaded into
+    /// [Hotkeys](crate::Hotkeys) get the policy of the default shortcut of
+    /// their action.
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.command_policy = policy;
+        self
+    }
+
+    /// Whether Ctrl and Cmd of the keyboard shortcut are cross-platform, see
+    /// [`Shortcut::with_command_policy`].
+    #[inline]
+    pub const fn command_policy(&self) -> CommandPolicy {
+        self.command_policy
+    }
+
+    /// The keyboard shortcut as it's matched, with the command policy applied.
+    pub(crate) fn matched_keyboard(&self) -> Option<KeyboardShortcut> {
+        self.keyboard
+            .map(|keyboard| self.command_policy.normalize(keyboard))
+    }
+
+    /// Change the keyboard shortcut, keeping everything else.
+    pub(crate) fn map_keyboard(
+        &self,
+        f: impl FnOnce(KeyboardShortcut) -> KeyboardShortcut,
+    ) -> Self {
+        Self {
+            keyboard: self.keyboard.map(f),
+            ..*self
         }
     }
 
@@ -187,43 +1268,287 @@ impl Shortcut {
     pub const fn pointer(&self) -> Option<PointerButton> {
         self.pointer
     }
+
+    /// Append the shortcut to `out`, like [`Bind::format_with_key_names`].
+    fn format_into_with_key_names(
+        &self,
+        out: &mut String,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) {
+        let start = out.len();
+        match (self.text, self.keyboard) {
+            (Some(text), _) => text.format_into(out, names, is_mac),
+            (None, Some(kb)) => key_names.format_keyboard_shortcut_into(out, &kb, names, is_mac),
+            (None, None) => {}
+        }
+        if let Some(pointer) = self.pointer {
+            if out.len() > start {
+                out.push_str(pointer_separator(names));
+            }
+            out.push_str(key_names.pointer_button_name(pointer));
+            if self.trigger != PointerTrigger::Press {
+                out.push(' ');
+                out.push_str(self.trigger.name());
+            }
+        }
+        if out.len() == start {
+            out.push_str("None");
+        }
+    }
 }
 
 impl Bind for Shortcut {
     fn set(&mut self, keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
         self.keyboard = keyboard;
         self.pointer = pointer;
+        self.text = None;
     }
 
     fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
-        let mut string = self.keyboard.map_or_else(
-            || String::with_capacity(9),
-            |kb| Into::<KeyboardShortcut>::into(kb).format(names, is_mac),
-        );
-        if let Some(pointer) = self.pointer {
-            if !string.is_empty() {
-                string.push('+');
+        let mut string = String::new();
+        self.format_into(&mut string, names, is_mac);
+        string
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        self.format_into_with_key_names(out, names, &KeyNameMap::default(), is_mac);
+    }
+
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        let mut string = String::new();
+        self.format_into_with_key_names(&mut string, names, key_names, is_mac);
+        string
+    }
+
+    /// Checks the pointer button first, and only consumes the keyboard
+    /// shortcut if the whole shortcut is pressed, so it's still there for
+    /// other binds otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Bind, Shortcut};
+    ///
+    /// let with_button: Shortcut = "Ctrl+S+Middle".parse().unwrap();
+    /// let keyboard: Shortcut = "Ctrl+S".parse().unwrap();
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::S,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL,
+    /// });
+    /// let _ = ctx.run(input, |ctx| {
+    ///     ctx.input_mut(|input| {
+    ///         // no button was pressed, so Ctrl+S is left for the keyboard-only shortcut
+    ///         assert!(!with_button.pressed(input));
+    ///         assert!(keyboard.pressed(input));
+    ///     });
+    /// });
+    /// ```
+    fn pressed(&self, input: &mut InputState) -> bool {
+        if let Some(button) = self.pointer {
+            if !self.trigger.matches(button, input) {
+                return false;
             }
-            string.push_str(&pointer.format(names, is_mac));
         }
-        if string.is_empty() {
-            string.push_str("None");
+        if let Some(text) = &self.text {
+            return text.consume(input, self.match_policy);
+        }
+        match &self.matched_keyboard() {
+            Some(kb) => self.match_policy.consume(input, kb),
+            None => self.pointer.is_some(),
         }
-        string
     }
 
-    fn pressed(&self, input: &mut InputState) -> bool {
-        let mut pressed = false;
-        if let Some(kb) = &self.keyboard {
-            pressed = input.consume_shortcut(kb);
+    fn is_bound(&self) -> bool {
+        self.keyboard.is_some() || self.pointer.is_some() || self.text.is_some()
+    }
+
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        self.keyboard
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        self.pointer
+    }
+
+    fn text(&self) -> Option<TextKey> {
+        self.text
+    }
+
+    fn set_text(&mut self, text: TextKey, pointer: Option<PointerButton>) -> bool {
+        self.keyboard = None;
+        self.pointer = pointer;
+        self.text = Some(text);
+        true
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        Some(egui::util::hash((
+            self.keyboard,
+            self.pointer.map(|button| button as usize),
+            self.trigger,
+            self.match_policy,
+            self.command_policy,
+            self.text,
+        )))
+    }
+}
+
+option_bind!(
+    /// A [Shortcut] that may be unbound. Setting it to no keyboard shortcut
+    /// and no pointer button makes it [None], which is formatted as
+    /// `"None"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{Bind, Shortcut, TextKey};
+    ///
+    /// let mut bind: Option<Shortcut> = None;
+    /// assert!(!bind.is_bound());
+    /// assert_eq!(bind.format(&egui::ModifierNames::NAMES, false), "None");
+    ///
+    /// let ctrl_s = KeyboardShortcut::new(Modifiers::CTRL, Key::S);
+    /// bind.set(Some(ctrl_s), Some(PointerButton::Extra1));
+    /// assert_eq!(bind, Some("Ctrl+S+Mouse 4".parse().unwrap()));
+    /// assert_eq!(bind.keyboard(), Some(ctrl_s));
+    /// assert_eq!(bind.pointer(), Some(PointerButton::Extra1));
+    ///
+    /// // no keyboard shortcut and no pointer button unbinds it
+    /// bind.set(None, None);
+    /// assert_eq!(bind, None);
+    ///
+    /// // it can hold everything a shortcut can, e.g. text keys
+    /// assert!(bind.set_text(TextKey::new(Modifiers::NONE, 'ä'), None));
+    /// assert_eq!(bind.text(), Some(TextKey::new(Modifiers::NONE, 'ä')));
+    /// bind.clear();
+    /// assert_eq!(bind, None);
+    /// ```
+    Shortcut,
+    InputKinds::ALL,
+    |_keyboard, _pointer| Some(Shortcut::NONE),
+    true
+);
+
+/// Formats the shortcut in its canonical text form, e.g. `"Ctrl+Shift+S"`,
+/// which is parsed back with [`str::parse`] into the same shortcut. This is
+/// the form shortcuts are saved in, see [`Hotkeys::to_saved`](crate::Hotkeys::to_saved).
+///
+/// Unlike [`Bind::format`], which shows the modifiers like on one platform,
+/// every flag of [Modifiers] is kept: [`Modifiers::mac_cmd`] is `"Cmd"` and
+/// [`Modifiers::command`] is `"CmdOrCtrl"`. A shortcut recorded on Windows
+/// or Linux has both Ctrl and the command modifier, and one recorded on
+/// macOS has both Cmd and the command modifier, see [Modifiers].
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+/// use egui_keybind::{Bind, PointerTrigger, Shortcut, TextKey};
+///
+/// let cmd_s = Shortcut::new(Some(KeyboardShortcut::new(Modifiers::MAC_CMD, Key::S)), None);
+/// assert_eq!(cmd_s.to_string(), "Cmd+S");
+/// let recorded_on_mac = KeyboardShortcut::new(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::S);
+/// assert_eq!(Shortcut::new(Some(recorded_on_mac), None).to_string(), "CmdOrCtrl+Cmd+S");
+///
+/// // every combination of modifiers survives the text form
+/// let flags = [
+///     Modifiers::ALT,
+///     Modifiers::CTRL,
+///     Modifiers::SHIFT,
+///     Modifiers::MAC_CMD,
+///     Modifiers::COMMAND,
+/// ];
+/// for bits in 0..1 << flags.len() {
+///     let modifiers = (0..flags.len())
+///         .filter(|i| bits & 1 << i != 0)
+///         .fold(Modifiers::NONE, |modifiers, i| modifiers | flags[i]);
+///     let mut text = Shortcut::NONE;
+///     text.set_text(TextKey::new(modifiers, 'ä'), None);
+///     let shortcuts = [
+///         Shortcut::new(Some(KeyboardShortcut::new(modifiers, Key::S)), None),
+///         Shortcut::new(Some(KeyboardShortcut::new(modifiers, Key::Plus)), None),
+///         Shortcut::new(Some(KeyboardShortcut::new(modifiers, Key::F4)), Some(PointerButton::Middle))
+///             .with_pointer_trigger(PointerTrigger::Release),
+///         text,
+///     ];
+///     for shortcut in shortcuts {
+///         let string = shortcut.to_string();
+///         assert_eq!(string.parse::<Shortcut>(), Ok(shortcut), "{string}");
+///     }
+/// }
+/// for pointer in [None, Some(PointerButton::Primary), Some(PointerButton::Extra2)] {
+///     let shortcut = Shortcut::new(None, pointer);
+///     assert_eq!(shortcut.to_string().parse::<Shortcut>(), Ok(shortcut));
+/// }
+///
+/// // "Left" and "Right" on their own are the arrow keys
+/// let left_click = Shortcut::new(None, Some(PointerButton::Primary));
+/// assert_eq!(left_click.to_string(), "Mouse Left");
+/// assert_eq!(left_click.format(&egui::ModifierNames::NAMES, false), "Mouse Left");
+/// let arrow_left = KeyboardShortcut::new(Modifiers::NONE, Key::ArrowLeft);
+/// assert_eq!("Left".parse::<Shortcut>(), Ok(Shortcut::new(Some(arrow_left), None)));
+/// let with_button = Shortcut::new(Some(arrow_left), Some(PointerButton::Secondary));
+/// assert_eq!("Left+Right".parse::<Shortcut>(), Ok(with_button));
+/// assert_eq!(with_button.to_string(), "Left+Mouse Right");
+/// ```
+impl std::fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        match (self.text, self.keyboard) {
+            (Some(text), _) => {
+                push_canonical_modifiers(&mut out, &text.modifiers);
+                match text.character {
+                    ',' => out.push_str(ESCAPED_COMMA),
+                    character => out.push(character),
+                }
+            }
+            (None, Some(kb)) => {
+                push_canonical_modifiers(&mut out, &kb.modifiers);
+                out.push_str(kb.logical_key.name());
+            }
+            (None, None) => {}
         }
-        if let Some(button) = self.pointer {
-            if self.keyboard.is_none() {
-                return input.pointer.button_clicked(button);
+        if let Some(pointer) = self.pointer {
+            if !out.is_empty() {
+                out.push('+');
+            }
+            out.push_str(pointer_button_name(pointer));
+            if self.trigger != PointerTrigger::Press {
+                out.push(' ');
+                out.push_str(self.trigger.name());
             }
-            pressed &= input.pointer.button_clicked(button);
         }
-        pressed
+        match out.is_empty() {
+            true => f.write_str("None"),
+            false => f.write_str(&out),
+        }
+    }
+}
+
+/// Get the separator that goes between the keyboard and pointer parts of a keybind.
+///
+/// Symbol names have no separator, but the pointer button is always a word,
+/// so it still needs one to stay readable.
+pub(crate) fn pointer_separator<'a>(names: &ModifierNames<'a>) -> &'a str {
+    if names.concat.is_empty() {
+        "+"
+    } else {
+        names.concat
     }
 }
 
@@ -238,3 +1563,32 @@ impl From<Shortcut> for Option<PointerButton> {
         value.pointer
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::RawInput;
+
+    #[test]
+    fn paste_shortcut_leaves_the_paste_to_a_text_edit() {
+        let ctx = Context::default();
+        let paste: Shortcut = "Ctrl+V".parse().unwrap();
+        let mut text = String::new();
+        let mut pressed = false;
+        for events in [vec![], vec![Event::Paste("pasted".into())]] {
+            let input = RawInput {
+                events,
+                modifiers: Modifiers::CTRL | Modifiers::COMMAND,
+                ..Default::default()
+            };
+            let _ = ctx.run(input, |ctx| {
+                pressed = ctx.input_mut(|input| paste.pressed(input));
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.text_edit_singleline(&mut text).request_focus();
+                });
+            });
+        }
+        assert!(pressed);
+        assert_eq!(text, "pasted");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{bind_tooltip, Bind, KeyNameMap, TextInputPolicy};
+use egui::{os::OperatingSystem, Button, ModifierNames, Response, Ui, Widget, WidgetText};
+
+/// A button that is also clicked by pressing its bind, and shows the bind
+/// dimmed after its text, like in a menu.
+///
+/// The returned [Response] is [clicked](Response::clicked) if the button was
+/// clicked or the bind was pressed (see [`Bind::pressed`]) on this frame.
+/// The bind is only checked while the button is enabled, and by default not
+/// while typing into a text field if it has no modifier other than Shift
+/// (see [`ShortcutButton::with_text_input_policy`]).
+///
+/// See also [shortcut_button].
+///
+/// ```
+/// use egui::{Event, Id, Key, Modifiers, RawInput};
+/// use egui_keybind::{Shortcut, ShortcutButton};
+///
+/// let save: Shortcut = "Ctrl+S".parse().unwrap();
+/// let split: Shortcut = "S".parse().unwrap();
+/// let ctx = egui::Context::default();
+/// let mut run = |modifiers, typing| {
+///     let mut input = RawInput::default();
+///     input.events.push(Event::Key {
+///         key: Key::S,
+///         physical_key: None,
+///         pressed: true,
+///         repeat: false,
+///         modifiers,
+///     });
+///     let mut clicked = vec![];
+///     let _ = ctx.run(input, |ctx| {
+///         if typing {
+///             ctx.memory_mut(|memory| memory.request_focus(Id::new("text")));
+///         }
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             for (text, bind) in [("Save", &save), ("Split", &split)] {
+///                 if ui.add(ShortcutButton::new(text, bind)).clicked() {
+///                     clicked.push(text);
+///                 }
+///             }
+///         });
+///     });
+///     clicked
+/// };
+///
+/// assert_eq!(run(Modifiers::CTRL, false), ["Save"]);
+/// assert_eq!(run(Modifiers::NONE, false), ["Split"]);
+/// // typing "s" into a text field doesn't click "Split"
+/// assert!(run(Modifiers::NONE, true).is_empty());
+/// ```
+pub struct ShortcutButton<'a, B: Bind> {
+    text: WidgetText,
+    bind: &'a B,
+    modifier_names: &'a ModifierNames<'a>,
+    key_names: Option<&'a KeyNameMap>,
+    in_tooltip: bool,
+    text_input_policy: TextInputPolicy,
+}
+
+impl<'a, B: Bind> ShortcutButton<'a, B> {
+    /// Create a new [ShortcutButton].
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text of the button, e.g. `"Save"`.
+    /// * `bind` - The bind that clicks the button.
+    pub fn new(text: impl Into<WidgetText>, bind: &'a B) -> Self {
+        Self {
+            text: text.into(),
+            bind,
+            modifier_names: &ModifierNames::NAMES,
+            key_names: None,
+            in_tooltip: false,
+            text_input_policy: TextInputPolicy::WithModifiers,
+        }
+    }
+
+    /// Set the modifier names to format the bind with. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+
+    /// Set the table of key and pointer button names to format the bind
+    /// with. By default this is [None], which uses the English names.
+    pub fn with_key_names(mut self, key_names: Option<&'a KeyNameMap>) -> Self {
+        self.key_names = key_names;
+        self
+    }
+
+    /// Show the bind in the hover text of the button instead of after its
+    /// text (see [bind_tooltip]), e.g. for small toolbar buttons. By default
+    /// this is `false`.
+    pub fn with_shortcut_in_tooltip(mut self, in_tooltip: bool) -> Self {
+        self.in_tooltip = in_tooltip;
+        self
+    }
+
+    /// Set whether the bind can click the button while a widget wants
+    /// keyboard input. By default this is [`TextInputPolicy::WithModifiers`].
+    pub fn with_text_input_policy(mut self, policy: TextInputPolicy) -> Self {
+        self.text_input_policy = policy;
+        self
+    }
+}
+
+impl<'a, B: Bind> Widget for ShortcutButton<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let is_mac = ui.ctx().os() == OperatingSystem::Mac;
+        let tooltip = self.in_tooltip.then(|| {
+            let text = self.text.text().to_string();
+            bind_tooltip(
+                &text,
+                self.bind,
+                self.modifier_names,
+                self.key_names,
+                is_mac,
+            )
+        });
+        let mut button = Button::new(self.text);
+        if !self.in_tooltip && self.bind.is_bound() {
+            let formatted = match self.key_names {
+                Some(key_names) => {
+                    self.bind
+                        .format_with_key_names(self.modifier_names, key_names, is_mac)
+                }
+                None => self.bind.format(self.modifier_names, is_mac),
+            };
+            button = button.shortcut_text(formatted);
+        }
+        let mut response = ui.add(button);
+        if let Some(tooltip) = tooltip {
+            response = response.on_hover_text(tooltip);
+        }
+
+        if response.enabled
+            && self.bind.is_bound()
+            && self
+                .bind
+                .pressed_with_policy(ui.ctx(), self.text_input_policy)
+        {
+            // what egui does for buttons clicked with the keyboard
+            response.fake_primary_click = true;
+        }
+        response
+    }
+}
+
+/// Show a [ShortcutButton], which is also clicked by pressing its bind.
+///
+/// # Example
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_keybind::{shortcut_button, Shortcut};
+///
+/// let save: Shortcut = "Ctrl+S".parse().unwrap();
+/// if shortcut_button(ui, "Save", &save).clicked() {
+///     // clicked, or Ctrl+S was pressed
+/// }
+/// # });
+/// ```
+pub fn shortcut_button<B: Bind>(ui: &mut Ui, text: impl Into<WidgetText>, bind: &B) -> Response {
+    ui.add(ShortcutButton::new(text, bind))
+}
@@ -0,0 +1,660 @@
+use crate::{
+    bind::clipboard_key_press, AltGrPolicy, CaptureStrategy, CommandPolicy, GestureBind,
+    GestureDirection, LongPressBind, Shortcut, TextKey, WheelBind, WheelDirection,
+    DEFAULT_GESTURE_DISTANCE,
+};
+use egui::{
+    Context, Event, Key, KeyboardShortcut, Modifiers, MouseWheelUnit, PointerButton, Pos2, Vec2,
+};
+
+/// What [`ShortcutCapture::poll`] saw on a frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureStatus {
+    /// Nothing was captured yet, poll again on the next frame.
+    Pending,
+    /// A shortcut was captured, the capture is over.
+    Done(Shortcut),
+    /// A pointer button was held and released with
+    /// [`ShortcutCapture::with_hold_measuring`], the capture is over.
+    LongPress(LongPressBind),
+    /// The mouse wheel was turned with [`ShortcutCapture::with_wheel`], the
+    /// capture is over.
+    Wheel(WheelBind),
+    /// The pointer was dragged with a button held with
+    /// [`ShortcutCapture::with_gestures`], the capture is over.
+    Gesture(GestureBind),
+    /// The cancel key was pressed, the capture is over.
+    Cancelled,
+}
+
+/// Pointer buttons that are held shorter than this while measuring holds,
+/// in seconds, are captured as a plain press.
+pub const MIN_LONG_PRESS: f32 = 0.25;
+
+/// How far the mouse wheel has to be turned to be captured, in points. One
+/// notch of most mice is enough, but a touchpad has to be swiped a bit.
+pub const WHEEL_CAPTURE_THRESHOLD: f32 = 20.0;
+
+/// Records the next shortcut the user presses, without a widget, e.g. for a
+/// custom-painted row that starts recording when it's clicked. This is the
+/// same capture that the [Keybind](crate::Keybind) widget uses, so it reads
+/// the same events: key presses (keys that were already held are ignored
+/// until they're released), copy, cut and paste events, pointer buttons
+/// other than [`PointerButton::Primary`] and [`PointerButton::Secondary`],
+/// and with [`ShortcutCapture::with_text_fallback`] typed text.
+///
+/// Call [`ShortcutCapture::poll`] once per frame until it's no longer
+/// [`CaptureStatus::Pending`], then drop the handle. Input that is filtered
+/// out (see [`ShortcutCapture::with_forbidden_keys`],
+/// [`ShortcutCapture::with_require_modifier`] and
+/// [`ShortcutCapture::with_pointer`]) is ignored, and capturing goes on.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{CaptureStatus, ShortcutCapture};
+///
+/// let ctx = egui::Context::default();
+/// let key = |key, modifiers| Event::Key {
+///     key,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers,
+/// };
+/// let poll = |capture: &mut ShortcutCapture, events: Vec<Event>| {
+///     let mut status = CaptureStatus::Pending;
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| status = capture.poll(ctx));
+///     status
+/// };
+///
+/// let mut capture = ShortcutCapture::start(&ctx);
+/// assert_eq!(poll(&mut capture, vec![]), CaptureStatus::Pending);
+/// let status = poll(&mut capture, vec![key(Key::S, Modifiers::CTRL)]);
+/// assert_eq!(status, CaptureStatus::Done("Ctrl+S".parse().unwrap()));
+///
+/// // Escape cancels by default
+/// let mut capture = ShortcutCapture::start(&ctx);
+/// assert_eq!(poll(&mut capture, vec![key(Key::Escape, Modifiers::NONE)]), CaptureStatus::Cancelled);
+///
+/// // filtered input is ignored
+/// let mut capture = ShortcutCapture::start(&ctx)
+///     .with_forbidden_keys(&[Key::F1])
+///     .with_require_modifier(true)
+///     .with_pointer(false);
+/// let side_button = Event::PointerButton {
+///     pos: egui::Pos2::ZERO,
+///     button: PointerButton::Extra1,
+///     pressed: true,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let ignored = vec![
+///     key(Key::F1, Modifiers::CTRL),
+///     key(Key::A, Modifiers::SHIFT),
+///     side_button,
+/// ];
+/// assert_eq!(poll(&mut capture, ignored), CaptureStatus::Pending);
+/// // A is still held, so pressing it again without releasing it is ignored
+/// assert_eq!(poll(&mut capture, vec![key(Key::A, Modifiers::ALT)]), CaptureStatus::Pending);
+/// let status = poll(&mut capture, vec![key(Key::B, Modifiers::ALT | Modifiers::SHIFT)]);
+/// assert_eq!(status, CaptureStatus::Done("Alt+Shift+B".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShortcutCapture {
+    state: CaptureState,
+    settings: CaptureSettings,
+}
+
+impl ShortcutCapture {
+    /// Start capturing. Keys that are held right now, e.g. the key that
+    /// started the capture, are ignored until they're released.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The [Context] whose input is captured.
+    pub fn start(ctx: &Context) -> Self {
+        Self {
+            state: CaptureState::new(ctx),
+            settings: CaptureSettings {
+                cancel_key: Some(Key::Escape),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set which press is captured when several land in the same frame, see
+    /// [CaptureStrategy]. By default this is [`CaptureStrategy::Last`].
+    pub fn with_capture_strategy(mut self, strategy: CaptureStrategy) -> Self {
+        self.settings.strategy = strategy;
+        self
+    }
+
+    /// Set how a key press with both Ctrl and Alt is captured, see
+    /// [AltGrPolicy]. If [None], the policy fits the operating system (see
+    /// [`AltGrPolicy::for_os`]).
+    ///
+    /// By default this is [None].
+    pub fn with_altgr_policy(mut self, policy: Option<AltGrPolicy>) -> Self {
+        self.settings.altgr = policy;
+        self
+    }
+
+    /// Set how Ctrl and Cmd are captured, see [CommandPolicy]. By default
+    /// the modifiers are stored as they were pressed.
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.settings.command_policy = policy;
+        self
+    }
+
+    /// Set whether copy, cut and paste events are captured as Ctrl+C, Ctrl+X
+    /// and Ctrl+V (Cmd on Mac), see
+    /// [`Keybind::with_clipboard_events`](crate::Keybind::with_clipboard_events).
+    ///
+    /// By default this is `true`.
+    pub fn with_clipboard_events(mut self, enabled: bool) -> Self {
+        self.settings.clipboard_events = enabled;
+        self
+    }
+
+    /// Set whether keys that [egui] has no [Key] for are captured by the
+    /// character they type, as a [TextKey], see
+    /// [`Keybind::with_text_fallback`](crate::Keybind::with_text_fallback).
+    ///
+    /// By default this is `false`.
+    pub fn with_text_fallback(mut self, enabled: bool) -> Self {
+        self.settings.text_fallback = enabled;
+        self
+    }
+
+    /// Set keys that are never captured, e.g. keys the app reserves for
+    /// itself. By default no keys are forbidden.
+    pub fn with_forbidden_keys(mut self, keys: &[Key]) -> Self {
+        self.settings.forbidden_keys = keys.to_vec();
+        self
+    }
+
+    /// Set whether keys are only captured with a modifier other than Shift,
+    /// e.g. Ctrl+K but not K or Shift+K. This also applies to text keys, but
+    /// not to pointer buttons.
+    ///
+    /// By default this is `false`.
+    pub fn with_require_modifier(mut self, require_modifier: bool) -> Self {
+        self.settings.require_modifier = require_modifier;
+        self
+    }
+
+    /// Set whether pointer buttons are captured. By default this is `true`.
+    pub fn with_pointer(mut self, allow_pointer: bool) -> Self {
+        self.settings.pointer = allow_pointer;
+        self
+    }
+
+    /// Set whether pointer buttons are measured: instead of being captured
+    /// when they go down, they're captured when they're released, as a
+    /// [LongPressBind] that has to be held as long as they were (rounded to
+    /// a tenth of a second). Holds shorter than [MIN_LONG_PRESS] are
+    /// captured as a plain press.
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, RawInput};
+    /// use egui_keybind::{CaptureStatus, LongPressBind, ShortcutCapture};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut capture = ShortcutCapture::start(&ctx).with_hold_measuring(true);
+    /// let mut poll = |time: f64, pressed: Option<bool>| {
+    ///     let mut input = RawInput {
+    ///         time: Some(time),
+    ///         ..Default::default()
+    ///     };
+    ///     input.events.extend(pressed.map(|pressed| Event::PointerButton {
+    ///         pos: egui::Pos2::ZERO,
+    ///         button: PointerButton::Middle,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     }));
+    ///     let mut status = CaptureStatus::Pending;
+    ///     let _ = ctx.run(input, |ctx| status = capture.poll(ctx));
+    ///     status
+    /// };
+    ///
+    /// assert_eq!(poll(1.0, Some(true)), CaptureStatus::Pending);
+    /// assert_eq!(poll(1.3, None), CaptureStatus::Pending);
+    /// assert_eq!(
+    ///     poll(1.52, Some(false)),
+    ///     CaptureStatus::LongPress(LongPressBind::new(PointerButton::Middle, 0.5))
+    /// );
+    /// ```
+    pub fn with_hold_measuring(mut self, enabled: bool) -> Self {
+        self.settings.measure_hold = enabled;
+        self
+    }
+
+    /// Set whether turning the mouse wheel is captured, as a [WheelBind]
+    /// with the modifiers that are held when it has been turned by
+    /// [WHEEL_CAPTURE_THRESHOLD].
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, MouseWheelUnit, RawInput, Vec2};
+    /// use egui_keybind::{CaptureStatus, ShortcutCapture, WheelBind, WheelDirection};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut capture = ShortcutCapture::start(&ctx).with_wheel(true);
+    /// let mut poll = |y: f32| {
+    ///     let input = RawInput {
+    ///         events: vec![Event::MouseWheel {
+    ///             unit: MouseWheelUnit::Point,
+    ///             delta: Vec2::new(0.0, y),
+    ///             modifiers: Modifiers::CTRL,
+    ///         }],
+    ///         modifiers: Modifiers::CTRL,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut status = CaptureStatus::Pending;
+    ///     let _ = ctx.run(input, |ctx| status = capture.poll(ctx));
+    ///     status
+    /// };
+    ///
+    /// // a touchpad swipe is captured once it's long enough
+    /// assert_eq!(poll(8.0), CaptureStatus::Pending);
+    /// assert_eq!(poll(8.0), CaptureStatus::Pending);
+    /// let zoom_in = WheelBind::new(Modifiers::CTRL, WheelDirection::Up);
+    /// assert_eq!(poll(8.0), CaptureStatus::Wheel(zoom_in));
+    /// ```
+    pub fn with_wheel(mut self, enabled: bool) -> Self {
+        self.settings.wheel = enabled;
+        self
+    }
+
+    /// Set whether pointer buttons are captured as mouse gestures: a drag
+    /// with any button but [`PointerButton::Primary`] held is captured when
+    /// the button is released, as a [GestureBind] in the direction the drag
+    /// predominantly went. Drags shorter than [DEFAULT_GESTURE_DISTANCE] are
+    /// ignored, and pointer buttons aren't captured on their own.
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+    /// use egui_keybind::{CaptureStatus, GestureBind, GestureDirection, ShortcutCapture};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut capture = ShortcutCapture::start(&ctx).with_gestures(true);
+    /// let mut poll = |events: Vec<Event>| {
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut status = CaptureStatus::Pending;
+    ///     let _ = ctx.run(input, |ctx| status = capture.poll(ctx));
+    ///     status
+    /// };
+    /// let button = |x, y, pressed| Event::PointerButton {
+    ///     pos: Pos2::new(x, y),
+    ///     button: PointerButton::Secondary,
+    ///     pressed,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    ///
+    /// // too short
+    /// assert_eq!(poll(vec![button(100.0, 100.0, true)]), CaptureStatus::Pending);
+    /// assert_eq!(poll(vec![button(120.0, 100.0, false)]), CaptureStatus::Pending);
+    ///
+    /// assert_eq!(poll(vec![button(100.0, 100.0, true)]), CaptureStatus::Pending);
+    /// assert_eq!(poll(vec![Event::PointerMoved(Pos2::new(90.0, 40.0))]), CaptureStatus::Pending);
+    /// let up = GestureBind::new(PointerButton::Secondary, GestureDirection::Up);
+    /// assert_eq!(poll(vec![button(90.0, 20.0, false)]), CaptureStatus::Gesture(up));
+    /// ```
+    pub fn with_gestures(mut self, enabled: bool) -> Self {
+        self.settings.gestures = enabled;
+        self
+    }
+
+    /// Set the key that cancels the capture, or [None] to capture it like
+    /// any other key. By default this is [`Key::Escape`].
+    pub fn with_cancel_key(mut self, key: Option<Key>) -> Self {
+        self.settings.cancel_key = key;
+        self
+    }
+
+    /// Check the input of this frame for a shortcut.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The [Context] the capture was started with.
+    ///
+    /// # Returns
+    ///
+    /// The captured shortcut, [`CaptureStatus::Cancelled`] if the cancel key
+    /// was pressed, or [`CaptureStatus::Pending`] if neither happened yet.
+    /// With [`ShortcutCapture::with_hold_measuring`] a held pointer button
+    /// is [`CaptureStatus::LongPress`].
+    pub fn poll(&mut self, ctx: &Context) -> CaptureStatus {
+        self.state.poll(ctx, &self.settings)
+    }
+}
+
+/// How input is captured, see [ShortcutCapture]. The [Keybind](crate::Keybind)
+/// widget builds these from its own settings every frame.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CaptureSettings {
+    pub(crate) strategy: CaptureStrategy,
+    pub(crate) altgr: Option<AltGrPolicy>,
+    pub(crate) command_policy: CommandPolicy,
+    pub(crate) clipboard_events: bool,
+    pub(crate) text_fallback: bool,
+    /// Whether keyboard shortcuts are captured.
+    pub(crate) keyboard: bool,
+    /// Whether pointer buttons are captured.
+    pub(crate) pointer: bool,
+    pub(crate) forbidden_keys: Vec<Key>,
+    pub(crate) require_modifier: bool,
+    /// The operating system whose reserved shortcuts are ignored, if any,
+    /// see [`crate::reserved_shortcut`].
+    pub(crate) reserved_os: Option<egui::os::OperatingSystem>,
+    pub(crate) cancel_key: Option<Key>,
+    /// Whether pointer buttons are captured when they're released, with how
+    /// long they were held.
+    pub(crate) measure_hold: bool,
+    /// Whether turning the mouse wheel is captured.
+    pub(crate) wheel: bool,
+    /// Whether pointer buttons are captured as mouse gestures.
+    pub(crate) gestures: bool,
+}
+
+impl Default for CaptureSettings {
+    fn default() -> Self {
+        Self {
+            strategy: CaptureStrategy::default(),
+            altgr: None,
+            command_policy: CommandPolicy::default(),
+            clipboard_events: true,
+            text_fallback: false,
+            keyboard: true,
+            pointer: true,
+            forbidden_keys: vec![],
+            require_modifier: false,
+            reserved_os: None,
+            cancel_key: None,
+            measure_hold: false,
+            wheel: false,
+            gestures: false,
+        }
+    }
+}
+
+impl CaptureSettings {
+    /// Whether a key press with `modifiers` passes the filters.
+    fn allows(&self, key: Option<Key>, modifiers: Modifiers) -> bool {
+        let forbidden = key.is_some_and(|key| self.forbidden_keys.contains(&key));
+        let reserved = key.zip(self.reserved_os).is_some_and(|(key, os)| {
+            crate::reserved_shortcut(KeyboardShortcut::new(modifiers, key), os).is_some()
+        });
+        let has_modifier =
+            modifiers.alt || modifiers.ctrl || modifiers.command || modifiers.mac_cmd;
+        !forbidden && !reserved && (!self.require_modifier || has_modifier)
+    }
+
+    /// Run a shortcut that wasn't captured, e.g. one that was typed in as
+    /// text, through the filters captured input goes through.
+    ///
+    /// # Returns
+    /// The shortcut normalized like a captured one, or [None] if capturing
+    /// would have ignored it.
+    pub(crate) fn filter(&self, shortcut: &Shortcut) -> Option<Shortcut> {
+        let keyboard = shortcut.keyboard();
+        if keyboard
+            .is_some_and(|kb| !self.keyboard || !self.allows(Some(kb.logical_key), kb.modifiers))
+        {
+            return None;
+        }
+        let text = crate::Bind::text(shortcut);
+        if text.is_some_and(|text| {
+            !self.keyboard || !self.text_fallback || !self.allows(None, text.modifiers)
+        }) {
+            return None;
+        }
+        // primary and secondary clicks start and stop capturing instead
+        let pointer = shortcut.pointer();
+        if pointer.is_some_and(|button| {
+            !self.pointer
+                || self.gestures
+                || matches!(button, PointerButton::Primary | PointerButton::Secondary)
+        }) {
+            return None;
+        }
+        if keyboard.is_none() && text.is_none() && pointer.is_none() {
+            return None;
+        }
+
+        let keyboard = keyboard.map(|kb| self.command_policy.normalize(kb));
+        let mut filtered =
+            Shortcut::new(keyboard, pointer).with_pointer_trigger(shortcut.pointer_trigger());
+        if let Some(text) = text {
+            crate::Bind::set_text(&mut filtered, text, pointer);
+        }
+        Some(filtered)
+    }
+}
+
+/// What a capture remembers between frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CaptureState {
+    /// Keys that went down during the capture and weren't released yet. Some
+    /// backends send auto-repeat without the repeat flag, so their presses
+    /// are ignored until they're released. This includes the keys that were
+    /// held when the capture started, e.g. Space when it started it.
+    held: Vec<Key>,
+    /// The pointer button that is being measured and when it went down, see
+    /// [`ShortcutCapture::with_hold_measuring`].
+    measuring: Option<(PointerButton, f64)>,
+    /// How far the mouse wheel was turned during the capture, in points.
+    wheel: Vec2,
+    /// The button of the gesture that is being performed and where it went
+    /// down, see [`ShortcutCapture::with_gestures`].
+    gesture: Option<(PointerButton, Pos2)>,
+}
+
+impl CaptureState {
+    /// Start capturing, with the keys that are held right now.
+    pub(crate) fn new(ctx: &Context) -> Self {
+        Self {
+            held: ctx.input(|i| i.keys_down.iter().copied().collect()),
+            measuring: None,
+            wheel: Vec2::ZERO,
+            gesture: None,
+        }
+    }
+
+    /// Get the new key presses of a frame, and remember which keys are held.
+    fn fresh_presses(&mut self, events: &[Event]) -> Vec<(Key, Modifiers)> {
+        let mut presses = vec![];
+        for event in events {
+            match event {
+                Event::Key {
+                    key,
+                    pressed: true,
+                    modifiers,
+                    repeat: false,
+                    ..
+                } if !self.held.contains(key) => {
+                    self.held.push(*key);
+                    presses.push((*key, *modifiers));
+                }
+                Event::Key {
+                    key,
+                    pressed: false,
+                    ..
+                } => self.held.retain(|held| held != key),
+                _ => {}
+            }
+        }
+        presses
+    }
+
+    /// Follow the gesture that is being performed with the events of a frame.
+    ///
+    /// # Returns
+    /// The gesture, if its button was released after a long enough drag.
+    fn track_gesture(&mut self, events: &[Event]) -> Option<GestureBind> {
+        for event in events {
+            match *event {
+                Event::PointerButton {
+                    pos,
+                    button,
+                    pressed: true,
+                    ..
+                } if button != PointerButton::Primary => self.gesture = Some((button, pos)),
+                Event::PointerButton {
+                    pos,
+                    button,
+                    pressed: false,
+                    ..
+                } => {
+                    let Some((held, origin)) = self.gesture else {
+                        continue;
+                    };
+                    if held != button {
+                        continue;
+                    }
+                    self.gesture = None;
+                    let drag = pos - origin;
+                    if drag.length() >= DEFAULT_GESTURE_DISTANCE {
+                        if let Some(direction) = GestureDirection::of(drag) {
+                            return Some(GestureBind::new(button, direction));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Check the input of this frame for a shortcut, see [`ShortcutCapture::poll`].
+    pub(crate) fn poll(&mut self, ctx: &Context, settings: &CaptureSettings) -> CaptureStatus {
+        let strategy = settings.strategy;
+        let altgr = settings
+            .altgr
+            .unwrap_or_else(|| AltGrPolicy::for_os(ctx.os()));
+        let line_scroll_speed = ctx.options(|options| options.line_scroll_speed);
+        ctx.input(|i| {
+            // held keys are ignored, even if they're not marked as repeats
+            let presses = self.fresh_presses(&i.events);
+            if settings.cancel_key.is_some_and(|key| i.key_pressed(key)) {
+                return CaptureStatus::Cancelled;
+            }
+            if let Some((button, since)) = self.measuring {
+                if i.pointer.button_down(button) {
+                    return CaptureStatus::Pending;
+                }
+                self.measuring = None;
+                let held = (i.time - since) as f32;
+                if held < MIN_LONG_PRESS {
+                    return CaptureStatus::Done(Shortcut::new(None, Some(button)));
+                }
+                let duration = (held * 10.0).round() / 10.0;
+                return CaptureStatus::LongPress(LongPressBind::new(button, duration));
+            }
+
+            if settings.gestures {
+                if let Some(gesture) = self.track_gesture(&i.events) {
+                    return CaptureStatus::Gesture(gesture);
+                }
+            }
+
+            let allowed =
+                |(key, modifiers): &(Key, Modifiers)| settings.allows(Some(*key), *modifiers);
+            let kb = strategy.pick(presses.into_iter().filter(allowed));
+            // some platforms only send copy, cut and paste events for their shortcuts
+            let kb = kb.or_else(|| {
+                let clipboard = i
+                    .events
+                    .iter()
+                    .filter_map(|e| clipboard_key_press(e, i.modifiers));
+                strategy.pick(
+                    clipboard
+                        .filter(|_| settings.clipboard_events)
+                        .filter(allowed),
+                )
+            });
+
+            // capture mouse input
+            let pointer = strategy.pick(i.events.iter().filter_map(|e| match e {
+                Event::PointerButton {
+                    button,
+                    pressed: true,
+                    ..
+                } if *button != PointerButton::Primary && *button != PointerButton::Secondary => {
+                    Some(*button)
+                }
+                _ => None,
+            }));
+
+            // input that can't be captured is ignored, and capturing goes on
+            let kb = kb.filter(|_| settings.keyboard);
+            // pointer buttons are only captured as gestures then
+            let pointer = pointer.filter(|_| settings.pointer && !settings.gestures);
+            if let (None, Some(button), true) = (kb, pointer, settings.measure_hold) {
+                // wait for the button to be released
+                self.measuring = Some((button, i.time));
+                return CaptureStatus::Pending;
+            }
+            if kb.is_some() || pointer.is_some() {
+                let keyboard = kb.map(|(key, modifiers)| {
+                    let keyboard = KeyboardShortcut::new(altgr.normalize(modifiers), key);
+                    settings.command_policy.normalize(keyboard)
+                });
+                return CaptureStatus::Done(Shortcut::new(keyboard, pointer));
+            }
+
+            if settings.wheel {
+                for event in &i.events {
+                    if let Event::MouseWheel { unit, delta, .. } = event {
+                        self.wheel += match unit {
+                            MouseWheelUnit::Point => *delta,
+                            MouseWheelUnit::Line => *delta * line_scroll_speed,
+                            MouseWheelUnit::Page => *delta * i.screen_rect.height(),
+                        };
+                    }
+                }
+                // the modifiers are the ones held when the wheel has turned far enough
+                if self.wheel.x.abs().max(self.wheel.y.abs()) >= WHEEL_CAPTURE_THRESHOLD {
+                    if let Some(direction) = WheelDirection::of(self.wheel) {
+                        self.wheel = Vec2::ZERO;
+                        return CaptureStatus::Wheel(WheelBind::new(i.modifiers, direction));
+                    }
+                }
+            }
+
+            // keys egui has no `Key` for only type text, bind them by their character
+            if settings.text_fallback && settings.keyboard {
+                let text = strategy.pick(
+                    i.events
+                        .iter()
+                        .filter_map(|e| TextKey::typed(e, i.modifiers))
+                        .filter(|text| settings.allows(None, text.modifiers)),
+                );
+                let mut shortcut = Shortcut::NONE;
+                if text.is_some_and(|text| crate::Bind::set_text(&mut shortcut, text, None)) {
+                    return CaptureStatus::Done(shortcut);
+                }
+            }
+            CaptureStatus::Pending
+        })
+    }
+}
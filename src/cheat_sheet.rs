@@ -0,0 +1,367 @@
+use crate::{Bind, DisplayNames, Hotkey, Hotkeys, Shortcut};
+use egui::{
+    os::OperatingSystem, Align2, Color32, Context, Frame, Grid, Id, Key, KeyboardShortcut,
+    ModifierNames, Modifiers, Order, RichText, ScrollArea, Ui,
+};
+
+/// How wide each category of a [CheatSheet] is. Categories wrap into as many
+/// columns as fit on the screen.
+const COLUMN_WIDTH: f32 = 260.0;
+
+/// A full-screen overlay listing the current shortcuts of all actions in
+/// [Hotkeys], like the one many apps show when pressing `?`.
+///
+/// The overlay dims the app and blocks clicks to it, and shows the actions
+/// grouped by category (see
+/// [`Hotkeys::register_in`]), each category in a column with the formatted
+/// shortcuts of its actions. The columns wrap to fit the screen, and scroll
+/// if they don't fit. It's opened and closed with a shortcut (see
+/// [`CheatSheet::with_open_bind`]) or with [`CheatSheet::open`], and closed
+/// with Escape or any click.
+///
+/// Only the open bind and Escape are consumed, so the shortcuts it lists can
+/// still be pressed while it's open. Whether it's open is kept in [egui]'s
+/// memory.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let _ = ctx.run(Default::default(), |ctx| {
+/// use egui_keybind::{CheatSheet, Hotkeys};
+///
+/// let mut hotkeys = Hotkeys::new();
+/// hotkeys
+///     .register_in("File", "Save", "Ctrl+S".parse().unwrap())
+///     .register_in("Edit", "Find", "Ctrl+F".parse().unwrap());
+///
+/// for action in hotkeys.triggered(ctx) {
+///     // run the action
+/// }
+/// CheatSheet::new(&hotkeys, "cheat sheet").show(ctx);
+/// # });
+/// ```
+pub struct CheatSheet<'a> {
+    hotkeys: &'a Hotkeys,
+    id: Id,
+    open_bind: Shortcut,
+    modifier_names: Option<&'a ModifierNames<'a>>,
+    display_names: Option<DisplayNames<'a>>,
+    show_unbound: bool,
+}
+
+/// State of a [CheatSheet] in egui's memory.
+#[derive(Clone, Copy, Default)]
+struct CheatSheetState {
+    open: bool,
+    /// Whether the overlay was opened on this frame, so the click that
+    /// opened it doesn't close it.
+    opened: bool,
+}
+
+/// Show a [CheatSheet] of all actions in [Hotkeys] with the default settings,
+/// opened with `?` (Shift+/ on US keyboards).
+///
+/// # Arguments
+///
+/// * `ctx` - The [Context] to show the overlay in.
+/// * `hotkeys` - The actions to list.
+pub fn cheat_sheet_ui(ctx: &Context, hotkeys: &Hotkeys) {
+    CheatSheet::new(hotkeys, "cheat sheet").show(ctx);
+}
+
+impl<'a> CheatSheet<'a> {
+    /// Create a new [CheatSheet] for the given [Hotkeys].
+    ///
+    /// # Arguments
+    ///
+    /// * `hotkeys` - The actions to list.
+    /// * `id` - ID for the [CheatSheet] in [egui]'s memory.
+    pub fn new(hotkeys: &'a Hotkeys, id: impl Into<Id>) -> Self {
+        Self {
+            hotkeys,
+            id: id.into(),
+            open_bind: Shortcut::new(
+                Some(KeyboardShortcut::new(Modifiers::NONE, Key::Questionmark)),
+                None,
+            ),
+            modifier_names: None,
+            display_names: None,
+            show_unbound: false,
+        }
+    }
+
+    /// Set the shortcut that opens and closes the overlay. By default this
+    /// is `?`, which is matched by the key it types, so it's Shift+/ on US
+    /// keyboards and whatever types `?` on other layouts.
+    /// [`Shortcut::NONE`] leaves opening it to [`CheatSheet::open`]. It's
+    /// not checked while typing into a text field, unless it has a modifier
+    /// other than Shift (see
+    /// [`TextInputPolicy::WithModifiers`](crate::TextInputPolicy::WithModifiers)).
+    pub fn with_open_bind(mut self, open_bind: Shortcut) -> Self {
+        self.open_bind = open_bind;
+        self
+    }
+
+    /// Set the modifier names to use for the shortcuts. By default this is
+    /// [`ModifierNames::SYMBOLS`] on Mac and [`ModifierNames::NAMES`] elsewhere.
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = Some(modifier_names);
+        self
+    }
+
+    /// Set a function that gets the name to show for an action or category
+    /// from its ID, e.g. to translate it. By default the name of
+    /// [`Hotkeys::display_name`] is shown for actions, and the category
+    /// itself, translated with [`Hotkeys::translate`], for categories.
+    pub fn with_display_names(mut self, display_names: impl Fn(&str) -> String + 'a) -> Self {
+        self.display_names = Some(Box::new(display_names));
+        self
+    }
+
+    /// Show the actions without any shortcut, greyed out. By default they
+    /// are hidden.
+    pub fn with_unbound(mut self, show_unbound: bool) -> Self {
+        self.show_unbound = show_unbound;
+        self
+    }
+
+    /// Open the overlay with the given ID, e.g. from a help menu.
+    pub fn open(ctx: &Context, id: impl Into<Id>) {
+        let state = CheatSheetState {
+            open: true,
+            opened: true,
+        };
+        ctx.data_mut(|data| data.insert_temp(id.into(), state));
+    }
+
+    /// Close the overlay with the given ID.
+    pub fn close(ctx: &Context, id: impl Into<Id>) {
+        ctx.data_mut(|data| data.remove::<CheatSheetState>(id.into()));
+    }
+
+    /// Whether the overlay with the given ID is open.
+    pub fn is_open(ctx: &Context, id: impl Into<Id>) -> bool {
+        ctx.data(|data| data.get_temp::<CheatSheetState>(id.into()))
+            .is_some_and(|state| state.open)
+    }
+
+    /// Get the name to show for a category.
+    fn name(&self, category: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(category),
+            None => self.hotkeys.translate(category),
+        }
+    }
+
+    /// Get the name to show for an action.
+    fn action_name(&self, id: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(id),
+            None => self.hotkeys.display_name(id),
+        }
+    }
+
+    /// Show the overlay if it's open, and open or close it if its shortcut
+    /// was pressed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{CheatSheet, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    ///
+    /// let key = |key, modifiers| Event::Key {
+    ///     key,
+    ///     physical_key: Some(Key::Slash),
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers,
+    /// };
+    /// let ctx = egui::Context::default();
+    /// let mut run = |hotkeys: &mut Hotkeys, events: Vec<Event>| {
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         CheatSheet::new(hotkeys, "cheat sheet").show(ctx);
+    ///         ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///     });
+    ///     ids
+    /// };
+    ///
+    /// // Shift+/ types "?" on US keyboards
+    /// run(&mut hotkeys, vec![key(Key::Questionmark, Modifiers::SHIFT)]);
+    /// assert!(CheatSheet::is_open(&ctx, "cheat sheet"));
+    ///
+    /// // the listed shortcuts still work
+    /// let ids = run(&mut hotkeys, vec![key(Key::S, Modifiers::CTRL)]);
+    /// assert_eq!(ids, ["save"]);
+    /// assert!(CheatSheet::is_open(&ctx, "cheat sheet"));
+    ///
+    /// run(&mut hotkeys, vec![key(Key::Escape, Modifiers::NONE)]);
+    /// assert!(!CheatSheet::is_open(&ctx, "cheat sheet"));
+    /// ```
+    ///
+    /// Clicks close it without reaching the app below it:
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+    /// use egui_keybind::{CheatSheet, Hotkeys};
+    ///
+    /// let hotkeys = Hotkeys::new();
+    /// let ctx = egui::Context::default();
+    /// // run a frame, and get whether the button below the overlay was clicked
+    /// let frame = |events: Vec<Event>| {
+    ///     let mut clicked = false;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             clicked = ui.button("Below").clicked();
+    ///         });
+    ///         CheatSheet::new(&hotkeys, "cheat sheet").show(ctx);
+    ///     });
+    ///     clicked
+    /// };
+    /// let pos = Pos2::new(16.0, 16.0);
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    /// let click = || vec![Event::PointerMoved(pos), button(true), button(false)];
+    ///
+    /// frame(vec![]);
+    /// assert!(frame(click()));
+    /// CheatSheet::open(&ctx, "cheat sheet");
+    /// frame(vec![]);
+    /// assert!(!frame(click()));
+    /// assert!(!CheatSheet::is_open(&ctx, "cheat sheet"));
+    /// ```
+    pub fn show(self, ctx: &Context) {
+        if self.open_bind.is_bound() && self.open_bind.pressed_unless_typing(ctx) {
+            match Self::is_open(ctx, self.id) {
+                true => Self::close(ctx, self.id),
+                false => Self::open(ctx, self.id),
+            }
+        }
+        let Some(mut state) = ctx
+            .data(|data| data.get_temp::<CheatSheetState>(self.id))
+            .filter(|state| state.open)
+        else {
+            return;
+        };
+        let escape = ctx.input_mut(|input| input.consume_key(Modifiers::NONE, Key::Escape));
+        let clicked = ctx.input(|input| input.pointer.any_click());
+        if escape || (clicked && !state.opened) {
+            Self::close(ctx, self.id);
+            return;
+        }
+        state.opened = false;
+        ctx.data_mut(|data| data.insert_temp(self.id, state));
+
+        // the dim area covers the app, so clicks don't reach it
+        let screen = ctx.screen_rect();
+        let dim = egui::Area::new(self.id.with("dim"))
+            .order(Order::Foreground)
+            .fixed_pos(screen.min)
+            .default_size(screen.size())
+            .show(ctx, |ui| ui.set_min_size(screen.size()))
+            .response;
+        ctx.layer_painter(dim.layer_id)
+            .rect_filled(screen, 0.0, Color32::from_black_alpha(160));
+
+        let is_mac = ctx.os() == OperatingSystem::Mac;
+        let modifier_names = self.modifier_names.unwrap_or(match is_mac {
+            true => &ModifierNames::SYMBOLS,
+            false => &ModifierNames::NAMES,
+        });
+        let groups: Vec<(Option<&str>, Vec<&Hotkey>)> = self
+            .hotkeys
+            .grouped()
+            .into_iter()
+            .filter_map(|(category, hotkeys)| {
+                let hotkeys: Vec<&Hotkey> = hotkeys
+                    .into_iter()
+                    .filter(|hotkey| self.show_unbound || hotkey.current().is_bound())
+                    .collect();
+                (!hotkeys.is_empty()).then_some((category, hotkeys))
+            })
+            .collect();
+
+        egui::Area::new(self.id.with("area"))
+            .order(Order::Tooltip)
+            .anchor(Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    let max = screen.size() - egui::vec2(64.0, 64.0);
+                    ui.set_max_size(max);
+                    ui.heading("Keyboard shortcuts");
+                    ui.separator();
+                    if groups.is_empty() {
+                        ui.weak("No shortcuts");
+                        return;
+                    }
+                    ScrollArea::vertical().show(ui, |ui| {
+                        ui.horizontal_wrapped(|ui| {
+                            for (category, hotkeys) in &groups {
+                                ui.allocate_ui(egui::vec2(COLUMN_WIDTH, 0.0), |ui| {
+                                    ui.vertical(|ui| {
+                                        ui.set_width(COLUMN_WIDTH);
+                                        self.column(ui, *category, hotkeys, modifier_names, is_mac);
+                                    });
+                                });
+                            }
+                        });
+                    });
+                });
+            });
+    }
+
+    /// Show the actions of a category.
+    fn column(
+        &self,
+        ui: &mut Ui,
+        category: Option<&str>,
+        hotkeys: &[&Hotkey],
+        modifier_names: &ModifierNames<'_>,
+        is_mac: bool,
+    ) {
+        let name = category.map_or_else(|| "Other".to_string(), |category| self.name(category));
+        ui.label(RichText::new(name).strong());
+        Grid::new(self.id.with(("category", category)))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for hotkey in hotkeys {
+                    let shortcuts: Vec<String> = hotkey
+                        .bindings()
+                        .filter(|shortcut| shortcut.is_bound())
+                        .map(|shortcut| {
+                            let mut text = shortcut.format(modifier_names, is_mac);
+                            if let Some(second) = hotkey.second_step() {
+                                text = format!("{text} {}", second.format(modifier_names, is_mac));
+                            }
+                            text
+                        })
+                        .collect();
+                    let name = self.action_name(hotkey.id());
+                    if shortcuts.is_empty() {
+                        ui.weak(name);
+                        ui.weak("None");
+                    } else {
+                        ui.label(name);
+                        ui.monospace(shortcuts.join(", "));
+                    }
+                    ui.end_row();
+                }
+            });
+        ui.add_space(8.0);
+    }
+}
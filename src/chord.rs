@@ -0,0 +1,243 @@
+use crate::{peek, specificity, AltGrPolicy, Bind, Hotkeys, Shortcut, TriggerSource, Triggered};
+use egui::{Context, Event, InputState, Key, Modifiers};
+use std::{cmp::Reverse, time::Duration};
+
+/// How long [Hotkeys] waits for the second step of a chord by default, see
+/// [`Hotkeys::set_chord_timeout`].
+pub const DEFAULT_CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// The pending chord of [Hotkeys] and how long it waits for the second step.
+#[derive(Debug, Clone)]
+pub(crate) struct Chords {
+    timeout: Duration,
+    pending: Option<PendingChord>,
+}
+
+impl Default for Chords {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_CHORD_TIMEOUT,
+            pending: None,
+        }
+    }
+}
+
+/// A chord whose first step was pressed.
+#[derive(Debug, Clone)]
+struct PendingChord {
+    first: Shortcut,
+    /// When the first step was pressed, in [`InputState::time`](egui::InputState::time).
+    since: f64,
+    /// The action on the first step alone, which is triggered if the chord
+    /// doesn't complete.
+    fallback: Option<Triggered>,
+}
+
+impl Hotkeys {
+    /// Get the first step of the chord that is waiting for its second step
+    /// (see [`Hotkeys::register_chord`]), e.g. to show `"Ctrl+K was pressed,
+    /// waiting…"` in a status bar.
+    ///
+    /// # Returns
+    ///
+    /// The first step formatted like [Shortcut]'s [Display](std::fmt::Display),
+    /// e.g. `"Ctrl+K"`, or [None] if no chord is pending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("clear", "Ctrl+K".parse().unwrap())
+    ///     .register_chord("comment", "Ctrl+K".parse().unwrap(), "Ctrl+C".parse().unwrap())
+    ///     .register_chord("uncomment", "Ctrl+K".parse().unwrap(), "Ctrl+U".parse().unwrap());
+    ///
+    /// let ctx = egui::Context::default();
+    /// let press = |hotkeys: &mut Hotkeys, key| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::CTRL,
+    ///     });
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///     });
+    ///     ids
+    /// };
+    ///
+    /// // "clear" waits to see whether a chord follows
+    /// assert!(press(&mut hotkeys, Key::K).is_empty());
+    /// assert_eq!(hotkeys.pending_chord().as_deref(), Some("Ctrl+K"));
+    /// assert_eq!(press(&mut hotkeys, Key::U), ["uncomment"]);
+    /// assert_eq!(hotkeys.pending_chord(), None);
+    ///
+    /// // another key triggers "clear" after all, and then itself
+    /// assert!(press(&mut hotkeys, Key::K).is_empty());
+    /// hotkeys.register("find", "Ctrl+F".parse().unwrap());
+    /// assert_eq!(press(&mut hotkeys, Key::F), ["clear", "find"]);
+    /// ```
+    pub fn pending_chord(&self) -> Option<String> {
+        let pending = self.chords.pending.as_ref()?;
+        Some(pending.first.to_string())
+    }
+
+    /// Abort the pending chord, like pressing Escape. Nothing is triggered.
+    pub fn cancel_chord(&mut self) {
+        self.chords.pending = None;
+    }
+
+    /// Set how long to wait for the second step of a chord after the first
+    /// one was pressed. By default this is [DEFAULT_CHORD_TIMEOUT].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::Hotkeys;
+    /// use std::time::Duration;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("clear", "Ctrl+K".parse().unwrap())
+    ///     .register_chord("comment", "Ctrl+K".parse().unwrap(), "Ctrl+C".parse().unwrap())
+    ///     .set_chord_timeout(Duration::from_secs(1));
+    ///
+    /// let ctx = egui::Context::default();
+    /// let run = |hotkeys: &mut Hotkeys, time, key: Option<Key>| {
+    ///     let mut input = RawInput {
+    ///         time: Some(time),
+    ///         ..Default::default()
+    ///     };
+    ///     input.events.extend(key.map(|key| Event::Key {
+    ///         key,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: if key == Key::Escape { Modifiers::NONE } else { Modifiers::CTRL },
+    ///     }));
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///     });
+    ///     ids
+    /// };
+    ///
+    /// assert!(run(&mut hotkeys, 0.0, Some(Key::K)).is_empty());
+    /// assert!(run(&mut hotkeys, 0.5, None).is_empty());
+    /// assert!(hotkeys.pending_chord().is_some());
+    /// // the chord expired, so "clear" is triggered late
+    /// assert_eq!(run(&mut hotkeys, 1.5, None), ["clear"]);
+    /// assert!(hotkeys.pending_chord().is_none());
+    /// // too late for the second step
+    /// assert!(run(&mut hotkeys, 1.6, Some(Key::C)).is_empty());
+    ///
+    /// // Escape aborts the chord without triggering anything
+    /// assert!(run(&mut hotkeys, 2.0, Some(Key::K)).is_empty());
+    /// assert!(run(&mut hotkeys, 2.1, Some(Key::Escape)).is_empty());
+    /// assert!(hotkeys.pending_chord().is_none());
+    /// assert!(run(&mut hotkeys, 5.0, None).is_empty());
+    /// ```
+    pub fn set_chord_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.chords.timeout = timeout;
+        self
+    }
+
+    /// Get how long to wait for the second step of a chord, see
+    /// [`Hotkeys::set_chord_timeout`].
+    #[inline]
+    pub fn chord_timeout(&self) -> Duration {
+        self.chords.timeout
+    }
+
+    /// Complete, abort or start a chord with the input of this frame, see
+    /// [`Hotkeys::register_chord`].
+    ///
+    /// # Returns
+    ///
+    /// The completed chord, or the action on the first step alone if the
+    /// chord didn't complete.
+    pub(crate) fn dispatch_chords(
+        &mut self,
+        ctx: &Context,
+        typing: bool,
+        altgr: AltGrPolicy,
+    ) -> Vec<Triggered> {
+        let timeout = self.chords.timeout.as_secs_f64();
+        let mut triggered = vec![];
+        let pending = ctx.input_mut(|input| {
+            altgr.normalized(input, |input| {
+                if let Some(pending) = self.chords.pending.take() {
+                    if input.consume_key(Modifiers::NONE, Key::Escape) {
+                        return None;
+                    }
+                    let expired = input.time - pending.since >= timeout;
+                    let pressed = input
+                        .events
+                        .iter()
+                        .any(|event| matches!(event, Event::Key { pressed: true, .. }));
+                    if !expired && !pressed {
+                        return Some(pending);
+                    }
+                    if !expired {
+                        if let Some(completed) = self.complete_chord(&pending.first, input) {
+                            triggered.push(completed);
+                            return None;
+                        }
+                    }
+                    // the chord didn't complete, so the key is handled as usual
+                    triggered.extend(pending.fallback);
+                }
+
+                let candidates = self.candidates(typing, true);
+                let (_, _, first) = candidates
+                    .into_iter()
+                    .find(|(_, _, shortcut)| peek(shortcut, input))?;
+                let fallback = self
+                    .candidates(typing, false)
+                    .into_iter()
+                    .find(|(_, _, shortcut)| peek(shortcut, input))
+                    .map(|(_, hotkey, shortcut)| {
+                        Triggered::new(hotkey.id(), TriggerSource::of(shortcut))
+                    });
+                // consuming the first step suppresses the action on it alone
+                first.pressed(input);
+                Some(PendingChord {
+                    first: *first,
+                    since: input.time,
+                    fallback,
+                })
+            })
+        });
+
+        if let Some(pending) = &pending {
+            // repaint when the chord expires, even without input
+            let remaining = timeout - (ctx.input(|input| input.time) - pending.since);
+            ctx.request_repaint_after_secs(remaining.max(0.0) as f32);
+        }
+        self.chords.pending = pending;
+        triggered
+    }
+
+    /// Find the chord on `first` whose second step was pressed, the most
+    /// specific one first like [`Hotkeys::triggered`], and consume it.
+    fn complete_chord(&self, first: &Shortcut, input: &mut InputState) -> Option<Triggered> {
+        let mut chords: Vec<(&str, &Shortcut)> = self
+            .iter()
+            .filter(|hotkey| self.is_hotkey_active(hotkey))
+            .filter(|hotkey| hotkey.bindings().any(|binding| binding == first))
+            .filter_map(|hotkey| Some((hotkey.id(), hotkey.second_step()?)))
+            .collect();
+        chords.sort_by_key(|(_, second)| Reverse(specificity(second)));
+        let (id, second) = chords
+            .into_iter()
+            .find(|(_, second)| second.pressed(input))?;
+        Some(Triggered::new(id, TriggerSource::of(second)))
+    }
+}
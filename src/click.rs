@@ -0,0 +1,179 @@
+use crate::{pointer_button_name, Bind, InputKinds, KeyNameMap};
+use egui::{InputState, KeyboardShortcut, ModifierNames, PointerButton};
+
+/// A single, double or triple click of a pointer button, e.g. a double
+/// click of the middle mouse button. Clicks are detected by egui, see
+/// [`egui::PointerState::button_double_clicked`].
+///
+/// A double click is part of a triple click, so both can arrive on the same
+/// frame if the app didn't run in between. By default only the bind with the
+/// highest click count fires then, e.g. a triple click doesn't also fire a
+/// double click bind. [`ClickBind::with_overlap`] makes it fire anyway.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Bind, ClickBind};
+///
+/// let double = ClickBind::new(PointerButton::Middle, 2);
+/// let triple = ClickBind::new(PointerButton::Middle, 3);
+/// assert_eq!(double.to_string(), "Double Middle");
+/// assert_eq!(triple.to_string(), "Triple Middle");
+/// assert_eq!(ClickBind::new(PointerButton::Extra1, 3).to_string(), "Triple Mouse 4");
+///
+/// let ctx = egui::Context::default();
+/// let mut time = 0.0;
+/// let mut frame = |clicks: usize| {
+///     time += 10.0;
+///     let mut input = RawInput {
+///         time: Some(time),
+///         ..Default::default()
+///     };
+///     for pressed in [true, false].repeat(clicks) {
+///         input.events.push(Event::PointerButton {
+///             pos: egui::Pos2::ZERO,
+///             button: PointerButton::Middle,
+///             pressed,
+///             modifiers: Modifiers::NONE,
+///         });
+///     }
+///     let mut fired = vec![];
+///     let _ = ctx.run(input, |ctx| {
+///         ctx.input_mut(|input| {
+///             for bind in [double, triple, double.with_overlap(true)] {
+///                 fired.push(bind.pressed(input));
+///             }
+///         });
+///     });
+///     fired
+/// };
+///
+/// assert_eq!(frame(1), [false, false, false]);
+/// assert_eq!(frame(2), [true, false, true]);
+/// // a triple click doesn't fire the double click bind, unless it opts in
+/// assert_eq!(frame(3), [false, true, true]);
+///
+/// // serde stores the click count
+/// # #[cfg(feature = "serde")] {
+/// let json = serde_json::to_string(&triple).unwrap();
+/// assert_eq!(json, r#"{"button":"Middle","count":3,"overlap":false}"#);
+/// let saved = r#"{"button":"Middle","count":2}"#;
+/// assert_eq!(serde_json::from_str::<ClickBind>(saved).unwrap(), double);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickBind {
+    /// The button to click.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::button_by_name"))]
+    pub button: PointerButton,
+    /// How many times the button is clicked, from 1 to 3.
+    pub count: u8,
+    /// Whether the bind also fires when more clicks arrive on the same frame.
+    #[cfg_attr(feature = "serde", serde(default))]
+    overlap: bool,
+}
+
+impl ClickBind {
+    /// Create a new [ClickBind].
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - The button to click.
+    /// * `count` - How many times the button is clicked. egui detects up to
+    ///   triple clicks, so this is clamped to 1 to 3.
+    pub fn new(button: PointerButton, count: u8) -> Self {
+        Self {
+            button,
+            count: count.clamp(1, 3),
+            overlap: false,
+        }
+    }
+
+    /// Set whether the bind also fires when a click with a higher count
+    /// arrives on the same frame, e.g. a double click bind on a triple
+    /// click. By default this is `false`.
+    pub fn with_overlap(mut self, overlap: bool) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Get whether the bind also fires on higher click counts on the same
+    /// frame, see [`ClickBind::with_overlap`].
+    #[inline]
+    pub fn overlap(&self) -> bool {
+        self.overlap
+    }
+
+    /// The word for the click count, e.g. `"Double"`.
+    fn count_name(&self) -> &'static str {
+        match self.count {
+            0 | 1 => "Click",
+            2 => "Double",
+            _ => "Triple",
+        }
+    }
+}
+
+impl std::fmt::Display for ClickBind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.count_name(),
+            pointer_button_name(self.button)
+        )
+    }
+}
+
+/// Ignores keyboard shortcuts. Setting a pointer button keeps the click count.
+impl Bind for ClickBind {
+    fn set(&mut self, _keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        if let Some(pointer) = pointer {
+            self.button = pointer;
+        }
+    }
+
+    fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
+        self.to_string()
+    }
+
+    fn format_with_key_names(
+        &self,
+        _names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        _is_mac: bool,
+    ) -> String {
+        format!(
+            "{} {}",
+            self.count_name(),
+            key_names.pointer_button_name(self.button)
+        )
+    }
+
+    /// Triggered on the frame where the button is clicked the given number
+    /// of times.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        let pointer = &input.pointer;
+        let double = pointer.button_double_clicked(self.button);
+        let triple = pointer.button_triple_clicked(self.button);
+        match self.count {
+            0 | 1 => pointer.button_clicked(self.button) && (self.overlap || !double && !triple),
+            2 => double && (self.overlap || !triple),
+            _ => triple,
+        }
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        Some(self.button)
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::POINTER
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        Some(egui::util::hash((self.button as usize, self.count)))
+    }
+}
@@ -0,0 +1,177 @@
+use crate::Shortcut;
+use egui::{KeyboardShortcut, Modifiers};
+
+/// Convert between the Ctrl and Cmd conventions of different platforms.
+///
+/// A shortcut that was captured on macOS has [`Modifiers::MAC_CMD`] where the
+/// same shortcut captured on Windows or Linux has [`Modifiers::CTRL`]. These
+/// helpers turn one into the other, or into [`Modifiers::COMMAND`], which
+/// means Cmd on macOS and Ctrl everywhere else.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// use egui_keybind::CmdCtrl;
+///
+/// // Cmd+S, as captured on macOS
+/// let mac = KeyboardShortcut::new(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::S);
+/// // Ctrl+S, as captured on Windows
+/// let windows = KeyboardShortcut::new(Modifiers::CTRL | Modifiers::COMMAND, Key::S);
+///
+/// assert_eq!(mac.swap_cmd_ctrl(), windows);
+/// assert_eq!(windows.swap_cmd_ctrl(), mac);
+/// assert_eq!(mac.as_cmd_or_ctrl(), KeyboardShortcut::new(Modifiers::COMMAND, Key::S));
+/// assert_eq!(mac.as_cmd_or_ctrl(), windows.as_cmd_or_ctrl());
+///
+/// // Ctrl+Cmd+F on macOS keeps Ctrl, since Cmd is the primary modifier there
+/// let both = Modifiers::CTRL | Modifiers::MAC_CMD;
+/// assert_eq!(both.as_cmd_or_ctrl(), Modifiers::CTRL | Modifiers::COMMAND);
+/// ```
+pub trait CmdCtrl: Sized {
+    /// Replace the primary modifier (Cmd if it's held, otherwise Ctrl) with
+    /// [`Modifiers::COMMAND`], so it matches Cmd on macOS and Ctrl
+    /// everywhere else.
+    fn as_cmd_or_ctrl(&self) -> Self;
+
+    /// Swap Ctrl and Cmd, e.g. to turn a shortcut that was captured on
+    /// macOS into the one Windows and Linux users expect.
+    /// [`Modifiers::COMMAND`] is kept as it is.
+    fn swap_cmd_ctrl(&self) -> Self;
+}
+
+impl CmdCtrl for Modifiers {
+    fn as_cmd_or_ctrl(&self) -> Self {
+        match (self.mac_cmd, self.ctrl) {
+            (true, _) => Modifiers {
+                mac_cmd: false,
+                command: true,
+                ..*self
+            },
+            (false, true) => Modifiers {
+                ctrl: false,
+                command: true,
+                ..*self
+            },
+            (false, false) => *self,
+        }
+    }
+
+    fn swap_cmd_ctrl(&self) -> Self {
+        Modifiers {
+            ctrl: self.mac_cmd,
+            mac_cmd: self.ctrl,
+            ..*self
+        }
+    }
+}
+
+impl CmdCtrl for KeyboardShortcut {
+    fn as_cmd_or_ctrl(&self) -> Self {
+        KeyboardShortcut::new(self.modifiers.as_cmd_or_ctrl(), self.logical_key)
+    }
+
+    fn swap_cmd_ctrl(&self) -> Self {
+        KeyboardShortcut::new(self.modifiers.swap_cmd_ctrl(), self.logical_key)
+    }
+}
+
+impl CmdCtrl for Shortcut {
+    /// Apply [`CmdCtrl::as_cmd_or_ctrl`] to the keyboard shortcut, keeping
+    /// everything else.
+    fn as_cmd_or_ctrl(&self) -> Self {
+        self.map_keyboard(|keyboard| keyboard.as_cmd_or_ctrl())
+    }
+
+    /// Apply [`CmdCtrl::swap_cmd_ctrl`] to the keyboard shortcut, keeping
+    /// everything else.
+    fn swap_cmd_ctrl(&self) -> Self {
+        self.map_keyboard(|keyboard| keyboard.swap_cmd_ctrl())
+    }
+}
+
+/// How Ctrl and Cmd of a [Shortcut] are matched, see
+/// [`Shortcut::with_command_policy`] and
+/// [`Keybind::with_command_policy`](crate::Keybind::with_command_policy).
+///
+/// # Example
+///
+/// A Ctrl+S default is pressed with ⌘S on macOS only if it's cross-platform:
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{Bind, CommandPolicy, Hotkeys, Shortcut};
+///
+/// // ⌘S, as egui reports it on macOS
+/// let cmd_s = Event::Key {
+///     key: Key::S,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::MAC_CMD | Modifiers::COMMAND,
+/// };
+///
+/// let frame = |event: &Event, run: &mut dyn FnMut(&egui::Context)| {
+///     let ctx = egui::Context::default();
+///     let input = RawInput {
+///         events: vec![event.clone()],
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| run(ctx));
+/// };
+///
+/// for (policy, expected) in [
+///     (CommandPolicy::AsBound, false),
+///     (CommandPolicy::CrossPlatform, true),
+/// ] {
+///     let save = "Ctrl+S".parse::<Shortcut>().unwrap().with_command_policy(policy);
+///     let mut hotkeys = Hotkeys::new();
+///     hotkeys.register("save", save);
+///
+///     frame(&cmd_s, &mut |ctx| {
+///         assert_eq!(ctx.input_mut(|input| save.pressed(input)), expected);
+///     });
+///     frame(&cmd_s, &mut |ctx| {
+///         assert_eq!(hotkeys.triggered(ctx).count() == 1, expected);
+///     });
+/// }
+///
+/// // Ctrl alone (as egui reports it on macOS) isn't the primary modifier there
+/// let save = "Cmd+S".parse::<Shortcut>().unwrap();
+/// let save = save.with_command_policy(CommandPolicy::CrossPlatform);
+/// let ctrl_s = Event::Key {
+///     key: Key::S,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL,
+/// };
+/// frame(&ctrl_s, &mut |ctx| {
+///     assert!(!ctx.input_mut(|input| save.pressed(input)));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CommandPolicy {
+    /// Ctrl and Cmd are matched as they were bound, so a Ctrl+S shortcut
+    /// needs Ctrl on macOS as well. This is the default.
+    #[default]
+    AsBound,
+    /// Ctrl and Cmd both mean the primary modifier of the platform the app
+    /// runs on (see [`CmdCtrl::as_cmd_or_ctrl`]), so a Ctrl+S shortcut is
+    /// pressed with ⌘S on macOS and Cmd+S with Ctrl+S everywhere else.
+    ///
+    /// A [Keybind](crate::Keybind) with this policy captures the primary
+    /// modifier as [`Modifiers::COMMAND`].
+    CrossPlatform,
+}
+
+impl CommandPolicy {
+    /// Apply the policy to the modifiers of a bound keyboard shortcut.
+    pub fn normalize(self, keyboard: KeyboardShortcut) -> KeyboardShortcut {
+        match self {
+            Self::AsBound => keyboard,
+            Self::CrossPlatform => keyboard.as_cmd_or_ctrl(),
+        }
+    }
+}
@@ -0,0 +1,451 @@
+use crate::{Bind, CommandPolicy, MatchPolicy, PointerTrigger, Shortcut, TextKey};
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use std::fmt;
+
+/// The current version: `[2, kind, modifiers, key, button, trigger, policies,
+/// character (4 bytes, little-endian)]`. Versions start at 2, so that the first
+/// byte tells the encoding apart from the layout serde derived for binary
+/// formats before it, which starts with the 0 or 1 of an [Option].
+pub(crate) const VERSION_2: u8 = 2;
+
+/// Bits of the kind byte.
+const KIND_KEYBOARD: u8 = 1 << 0;
+const KIND_POINTER: u8 = 1 << 1;
+const KIND_TEXT: u8 = 1 << 2;
+
+/// Bits of the modifiers byte.
+const MOD_ALT: u8 = 1 << 0;
+const MOD_CTRL: u8 = 1 << 1;
+const MOD_SHIFT: u8 = 1 << 2;
+const MOD_MAC_CMD: u8 = 1 << 3;
+const MOD_COMMAND: u8 = 1 << 4;
+
+/// Bits of the policies byte.
+const POLICY_EXACT: u8 = 1 << 0;
+const POLICY_CROSS_PLATFORM: u8 = 1 << 1;
+
+/// Why [`Shortcut::from_compact_bytes`] couldn't decode a shortcut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompactShortcutError {
+    /// The bytes start with a version that this crate doesn't know, e.g.
+    /// because they were written by a newer version.
+    UnknownVersion(u8),
+    /// The bytes have the wrong length for their version.
+    Length {
+        /// The version of the bytes.
+        version: u8,
+        /// The number of bytes.
+        len: usize,
+    },
+    /// A field has a value that isn't in its table, e.g. an unknown key code.
+    InvalidField {
+        /// The name of the field, e.g. `"key"`.
+        field: &'static str,
+        /// The value of the field.
+        value: u32,
+    },
+}
+
+impl fmt::Display for CompactShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVersion(version) => write!(f, "unknown shortcut encoding {version}"),
+            Self::Length { version, len } => {
+                write!(
+                    f,
+                    "{len} bytes is the wrong length for shortcut encoding {version}"
+                )
+            }
+            Self::InvalidField { field, value } => {
+                write!(f, "invalid {field} {value} in the encoded shortcut")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompactShortcutError {}
+
+/// Get the code of a key in the encoding. Printable keys are their ASCII
+/// character (letters uppercase), F1 to F35 are `0x81` to `0xA3`, and the
+/// other keys have fixed control codes. Never change these.
+fn key_code(key: Key) -> u8 {
+    match key {
+        Key::Backspace => 0x08,
+        Key::Tab => 0x09,
+        Key::Enter => 0x0D,
+        Key::ArrowLeft => 0x11,
+        Key::ArrowUp => 0x12,
+        Key::ArrowRight => 0x13,
+        Key::ArrowDown => 0x14,
+        Key::Insert => 0x15,
+        Key::Home => 0x16,
+        Key::End => 0x17,
+        Key::PageUp => 0x18,
+        Key::PageDown => 0x19,
+        Key::Escape => 0x1B,
+        Key::Copy => 0x1C,
+        Key::Cut => 0x1D,
+        Key::Paste => 0x1E,
+        Key::Delete => 0x7F,
+        Key::Space => b' ',
+        Key::Colon => b':',
+        Key::Comma => b',',
+        Key::Minus => b'-',
+        Key::Period => b'.',
+        Key::Plus => b'+',
+        Key::Equals => b'=',
+        Key::Semicolon => b';',
+        Key::OpenBracket => b'[',
+        Key::CloseBracket => b']',
+        Key::Backtick => b'`',
+        Key::Backslash => b'\\',
+        Key::Slash => b'/',
+        Key::Pipe => b'|',
+        Key::Questionmark => b'?',
+        Key::Quote => b'\'',
+        Key::Num0 => b'0',
+        Key::Num1 => b'1',
+        Key::Num2 => b'2',
+        Key::Num3 => b'3',
+        Key::Num4 => b'4',
+        Key::Num5 => b'5',
+        Key::Num6 => b'6',
+        Key::Num7 => b'7',
+        Key::Num8 => b'8',
+        Key::Num9 => b'9',
+        Key::A => b'A',
+        Key::B => b'B',
+        Key::C => b'C',
+        Key::D => b'D',
+        Key::E => b'E',
+        Key::F => b'F',
+        Key::G => b'G',
+        Key::H => b'H',
+        Key::I => b'I',
+        Key::J => b'J',
+        Key::K => b'K',
+        Key::L => b'L',
+        Key::M => b'M',
+        Key::N => b'N',
+        Key::O => b'O',
+        Key::P => b'P',
+        Key::Q => b'Q',
+        Key::R => b'R',
+        Key::S => b'S',
+        Key::T => b'T',
+        Key::U => b'U',
+        Key::V => b'V',
+        Key::W => b'W',
+        Key::X => b'X',
+        Key::Y => b'Y',
+        Key::Z => b'Z',
+        Key::F1 => 0x81,
+        Key::F2 => 0x82,
+        Key::F3 => 0x83,
+        Key::F4 => 0x84,
+        Key::F5 => 0x85,
+        Key::F6 => 0x86,
+        Key::F7 => 0x87,
+        Key::F8 => 0x88,
+        Key::F9 => 0x89,
+        Key::F10 => 0x8A,
+        Key::F11 => 0x8B,
+        Key::F12 => 0x8C,
+        Key::F13 => 0x8D,
+        Key::F14 => 0x8E,
+        Key::F15 => 0x8F,
+        Key::F16 => 0x90,
+        Key::F17 => 0x91,
+        Key::F18 => 0x92,
+        Key::F19 => 0x93,
+        Key::F20 => 0x94,
+        Key::F21 => 0x95,
+        Key::F22 => 0x96,
+        Key::F23 => 0x97,
+        Key::F24 => 0x98,
+        Key::F25 => 0x99,
+        Key::F26 => 0x9A,
+        Key::F27 => 0x9B,
+        Key::F28 => 0x9C,
+        Key::F29 => 0x9D,
+        Key::F30 => 0x9E,
+        Key::F31 => 0x9F,
+        Key::F32 => 0xA0,
+        Key::F33 => 0xA1,
+        Key::F34 => 0xA2,
+        Key::F35 => 0xA3,
+    }
+}
+
+/// Get the code of a pointer button in the encoding. Never change these.
+fn button_code(button: PointerButton) -> u8 {
+    match button {
+        PointerButton::Primary => 1,
+        PointerButton::Secondary => 2,
+        PointerButton::Middle => 3,
+        PointerButton::Extra1 => 4,
+        PointerButton::Extra2 => 5,
+    }
+}
+
+/// Get the code of a pointer trigger in the encoding. Never change these.
+fn trigger_code(trigger: PointerTrigger) -> u8 {
+    match trigger {
+        PointerTrigger::Press => 0,
+        PointerTrigger::Click => 1,
+        PointerTrigger::Release => 2,
+    }
+}
+
+pub(crate) fn encode_modifiers(modifiers: Modifiers) -> u8 {
+    let mut bits = 0;
+    for (held, bit) in [
+        (modifiers.alt, MOD_ALT),
+        (modifiers.ctrl, MOD_CTRL),
+        (modifiers.shift, MOD_SHIFT),
+        (modifiers.mac_cmd, MOD_MAC_CMD),
+        (modifiers.command, MOD_COMMAND),
+    ] {
+        if held {
+            bits |= bit;
+        }
+    }
+    bits
+}
+
+pub(crate) fn decode_modifiers(bits: u8) -> Result<Modifiers, CompactShortcutError> {
+    let all = MOD_ALT | MOD_CTRL | MOD_SHIFT | MOD_MAC_CMD | MOD_COMMAND;
+    if bits & !all != 0 {
+        return Err(invalid("modifiers", bits.into()));
+    }
+    Ok(Modifiers {
+        alt: bits & MOD_ALT != 0,
+        ctrl: bits & MOD_CTRL != 0,
+        shift: bits & MOD_SHIFT != 0,
+        mac_cmd: bits & MOD_MAC_CMD != 0,
+        command: bits & MOD_COMMAND != 0,
+    })
+}
+
+pub(crate) fn invalid(field: &'static str, value: u32) -> CompactShortcutError {
+    CompactShortcutError::InvalidField { field, value }
+}
+
+/// Look a code up in a table that is given by a function over all values.
+fn decode<T: Copy>(
+    field: &'static str,
+    code: u8,
+    all: impl IntoIterator<Item = T>,
+    encode: impl Fn(T) -> u8,
+) -> Result<T, CompactShortcutError> {
+    all.into_iter()
+        .find(|&value| encode(value) == code)
+        .ok_or(invalid(field, code.into()))
+}
+
+impl Shortcut {
+    /// The length of [`Shortcut::to_compact_bytes`].
+    pub const COMPACT_LEN: usize = 11;
+
+    /// Encode the shortcut in a compact, versioned binary form that doesn't
+    /// depend on the layout of [egui]'s types, so it can be decoded by later
+    /// versions of this crate, whichever [egui] they use.
+    ///
+    /// The first byte is the version of the encoding, followed by fixed-size
+    /// fields. Keys, modifiers and pointer buttons are written with this
+    /// crate's own tables instead of [egui]'s enum ordinals.
+    ///
+    /// With the `serde` feature, binary formats like postcard or bincode use
+    /// this encoding for [Shortcut]s, while human-readable formats like JSON
+    /// or RON use a map with named fields. Shortcuts that binary formats wrote
+    /// with version 0.4 of this crate, with the layout derived from egui
+    /// 0.29's types, are still read.
+    ///
+    /// # Returns
+    ///
+    /// The encoded shortcut, to decode with [`Shortcut::from_compact_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{Bind, MatchPolicy, PointerTrigger, Shortcut, TextKey};
+    ///
+    /// let save: Shortcut = "Ctrl+S".parse().unwrap();
+    /// assert_eq!(save.to_compact_bytes(), [2, 1, 2, b'S', 0, 0, 0, 0, 0, 0, 0]);
+    ///
+    /// let mut shortcuts = vec![
+    ///     Shortcut::NONE,
+    ///     "Alt+§".parse().unwrap(),
+    ///     "Mouse Right Release".parse().unwrap(),
+    ///     Shortcut::new(None, Some(PointerButton::Extra2)).with_match_policy(MatchPolicy::Exact),
+    /// ];
+    /// let mut text = Shortcut::NONE;
+    /// text.set_text(TextKey::new(Modifiers::SHIFT, 'Ñ'), Some(PointerButton::Middle));
+    /// shortcuts.push(text.with_pointer_trigger(PointerTrigger::Click));
+    /// let modifiers = [
+    ///     Modifiers::NONE,
+    ///     Modifiers::CTRL | Modifiers::SHIFT,
+    ///     Modifiers::ALT | Modifiers::MAC_CMD,
+    ///     Modifiers::COMMAND,
+    /// ];
+    /// for &key in Key::ALL {
+    ///     for modifiers in modifiers {
+    ///         let keyboard = Some(KeyboardShortcut::new(modifiers, key));
+    ///         shortcuts.push(Shortcut::new(keyboard, None));
+    ///         shortcuts.push(Shortcut::new(keyboard, Some(PointerButton::Extra1)));
+    ///     }
+    /// }
+    /// for shortcut in shortcuts {
+    ///     let bytes = shortcut.to_compact_bytes();
+    ///     assert_eq!(Shortcut::from_compact_bytes(&bytes), Ok(shortcut));
+    /// }
+    /// ```
+    pub fn to_compact_bytes(&self) -> [u8; Self::COMPACT_LEN] {
+        let mut kind = 0;
+        let mut modifiers = Modifiers::NONE;
+        let mut key = 0;
+        let mut character = 0;
+        if let Some(keyboard) = self.keyboard() {
+            kind |= KIND_KEYBOARD;
+            modifiers = keyboard.modifiers;
+            key = key_code(keyboard.logical_key);
+        }
+        if let Some(text) = self.text() {
+            kind |= KIND_TEXT;
+            modifiers = text.modifiers;
+            character = u32::from(text.character);
+        }
+        let button = self.pointer().map_or(0, |button| {
+            kind |= KIND_POINTER;
+            button_code(button)
+        });
+
+        let mut policies = 0;
+        if self.match_policy() == MatchPolicy::Exact {
+            policies |= POLICY_EXACT;
+        }
+        if self.command_policy() == CommandPolicy::CrossPlatform {
+            policies |= POLICY_CROSS_PLATFORM;
+        }
+
+        let [c0, c1, c2, c3] = character.to_le_bytes();
+        [
+            VERSION_2,
+            kind,
+            encode_modifiers(modifiers),
+            key,
+            button,
+            trigger_code(self.pointer_trigger()),
+            policies,
+            c0,
+            c1,
+            c2,
+            c3,
+        ]
+    }
+
+    /// Decode a shortcut that was encoded with
+    /// [`Shortcut::to_compact_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// A [CompactShortcutError] if the bytes aren't an encoded shortcut, or
+    /// were encoded by a newer version of the encoding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{CompactShortcutError, Shortcut};
+    ///
+    /// let bytes = [2, 3, 1, b'Z', 2, 2, 0, 0, 0, 0, 0];
+    /// let shortcut = Shortcut::from_compact_bytes(&bytes).unwrap();
+    /// assert_eq!(shortcut.to_string(), "Alt+Z+Mouse Right Release");
+    ///
+    /// assert_eq!(
+    ///     Shortcut::from_compact_bytes(&[9, 0]),
+    ///     Err(CompactShortcutError::UnknownVersion(9))
+    /// );
+    /// assert_eq!(
+    ///     Shortcut::from_compact_bytes(&[2, 1, 0]),
+    ///     Err(CompactShortcutError::Length { version: 2, len: 3 })
+    /// );
+    /// assert_eq!(
+    ///     Shortcut::from_compact_bytes(&[2, 1, 0, 0xFF, 0, 0, 0, 0, 0, 0, 0]),
+    ///     Err(CompactShortcutError::InvalidField { field: "key", value: 0xFF })
+    /// );
+    /// ```
+    pub fn from_compact_bytes(bytes: &[u8]) -> Result<Self, CompactShortcutError> {
+        let version = bytes.first().copied().unwrap_or_default();
+        if version != VERSION_2 {
+            return Err(CompactShortcutError::UnknownVersion(version));
+        }
+        if bytes.len() != Self::COMPACT_LEN {
+            return Err(CompactShortcutError::Length {
+                version,
+                len: bytes.len(),
+            });
+        }
+
+        let kind = bytes[1];
+        let known_kinds = KIND_KEYBOARD | KIND_POINTER | KIND_TEXT;
+        if kind & !known_kinds != 0 || kind & KIND_KEYBOARD != 0 && kind & KIND_TEXT != 0 {
+            return Err(invalid("kind", kind.into()));
+        }
+        let modifiers = decode_modifiers(bytes[2])?;
+        let pointer = match kind & KIND_POINTER {
+            0 => None,
+            _ => Some(decode(
+                "button",
+                bytes[4],
+                [
+                    PointerButton::Primary,
+                    PointerButton::Secondary,
+                    PointerButton::Middle,
+                    PointerButton::Extra1,
+                    PointerButton::Extra2,
+                ],
+                button_code,
+            )?),
+        };
+        let keyboard = match kind & KIND_KEYBOARD {
+            0 => None,
+            _ => Some(KeyboardShortcut::new(
+                modifiers,
+                decode("key", bytes[3], Key::ALL.iter().copied(), key_code)?,
+            )),
+        };
+        let mut shortcut = Shortcut::new(keyboard, pointer);
+        if kind & KIND_TEXT != 0 {
+            let code = u32::from_le_bytes([bytes[7], bytes[8], bytes[9], bytes[10]]);
+            let character = char::from_u32(code).ok_or(invalid("character", code))?;
+            shortcut.set_text(TextKey::new(modifiers, character), pointer);
+        }
+        let trigger = decode(
+            "trigger",
+            bytes[5],
+            [
+                PointerTrigger::Press,
+                PointerTrigger::Click,
+                PointerTrigger::Release,
+            ],
+            trigger_code,
+        )?;
+        let policies = bytes[6];
+        if policies & !(POLICY_EXACT | POLICY_CROSS_PLATFORM) != 0 {
+            return Err(invalid("policies", policies.into()));
+        }
+        let match_policy = match policies & POLICY_EXACT {
+            0 => MatchPolicy::Lenient,
+            _ => MatchPolicy::Exact,
+        };
+        let command_policy = match policies & POLICY_CROSS_PLATFORM {
+            0 => CommandPolicy::AsBound,
+            _ => CommandPolicy::CrossPlatform,
+        };
+        Ok(shortcut
+            .with_pointer_trigger(trigger)
+            .with_match_policy(match_policy)
+            .with_command_policy(command_policy))
+    }
+}
@@ -0,0 +1,722 @@
+use crate::{Bind, Hotkey, Hotkeys, MatchPolicy, Shortcut};
+use egui::{KeyboardShortcut, Modifiers};
+use std::fmt;
+
+/// How the actions of a [Conflict] share their shortcut. Ordered from least
+/// to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConflictKind {
+    /// The shortcut of one action is a part of the shortcut of another, so
+    /// pressing the longer shortcut also triggers the shorter one, e.g. `S`
+    /// and `Shift+S` when extra Shift and Alt are ignored (see
+    /// [MatchPolicy]), or `Mouse 4` and `Ctrl+W+Mouse 4`. `S` and `Ctrl+S` don't
+    /// overlap, since Ctrl is never ignored.
+    Shadowed,
+    /// The actions have the same shortcut.
+    Duplicate,
+}
+
+/// A group of actions in [Hotkeys] whose shortcuts conflict, see [`Hotkeys::conflicts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    kind: ConflictKind,
+    ids: Vec<String>,
+}
+
+impl Conflict {
+    /// How the actions share their shortcut.
+    #[inline]
+    pub fn kind(&self) -> ConflictKind {
+        self.kind
+    }
+
+    /// The IDs of the conflicting actions.
+    ///
+    /// For [`ConflictKind::Duplicate`] these are in the order the actions
+    /// were registered. For [`ConflictKind::Shadowed`] these are always two:
+    /// the action with the shorter shortcut, then the action it shadows.
+    #[inline]
+    pub fn ids(&self) -> &[String] {
+        &self.ids
+    }
+
+    /// Whether an action is a part of this conflict.
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.iter().any(|conflicting| conflicting == id)
+    }
+}
+
+/// All conflicts between the actions in [Hotkeys], see [`Hotkeys::conflicts`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConflictReport {
+    conflicts: Vec<Conflict>,
+}
+
+impl ConflictReport {
+    /// Whether there are no conflicts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    /// Whether any actions have the same shortcut, e.g. to block saving.
+    pub fn has_duplicates(&self) -> bool {
+        self.conflicts
+            .iter()
+            .any(|conflict| conflict.kind == ConflictKind::Duplicate)
+    }
+
+    /// Get the most severe conflict an action is a part of, e.g. to show a
+    /// warning next to it. [None] if the action doesn't conflict with any other.
+    pub fn kind_of(&self, id: &str) -> Option<ConflictKind> {
+        self.conflicts
+            .iter()
+            .filter(|conflict| conflict.contains(id))
+            .map(Conflict::kind)
+            .max()
+    }
+
+    /// Iterate over all conflicts an action is a part of.
+    pub fn conflicts_of<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a Conflict> {
+        self.conflicts
+            .iter()
+            .filter(move |conflict| conflict.contains(id))
+    }
+
+    /// Iterate over all conflicts. Duplicates come first.
+    pub fn iter(&self) -> std::slice::Iter<'_, Conflict> {
+        self.conflicts.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ConflictReport {
+    type Item = &'a Conflict;
+    type IntoIter = std::slice::Iter<'a, Conflict>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// What [`Hotkeys::assign`] does when the shortcut is already used by
+/// another action that can be active at the same time, see
+/// [`Hotkeys::set_conflict_policy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ConflictPolicy {
+    /// Remove the shortcut from the other actions, so they may become unbound.
+    #[default]
+    Replace,
+    /// Give the other action the previous shortcut of the assigned action
+    /// in place of the shortcut.
+    Swap,
+    /// Keep all shortcuts as they are.
+    Reject,
+}
+
+/// What [`Hotkeys::assign`] changed, e.g. to tell the user about it.
+///
+/// [Display](fmt::Display) describes it with action IDs, e.g.
+/// `"Moved Ctrl+D from duplicate to delete"`. Use [`Assignment::describe`]
+/// to show the names of the actions instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Assignment {
+    /// The action already had the shortcut, so nothing was changed.
+    Unchanged {
+        /// The ID of the action.
+        id: String,
+        /// The shortcut.
+        shortcut: Shortcut,
+    },
+    /// The shortcut was assigned to the action, without any conflicts.
+    Assigned {
+        /// The ID of the action.
+        id: String,
+        /// The new shortcut of the action.
+        shortcut: Shortcut,
+    },
+    /// The shortcut was removed from other actions and assigned to the
+    /// action, see [`ConflictPolicy::Replace`].
+    Replaced {
+        /// The ID of the action.
+        id: String,
+        /// The new shortcut of the action.
+        shortcut: Shortcut,
+        /// The IDs of the actions the shortcut was removed from.
+        from: Vec<String>,
+    },
+    /// The action and another one swapped shortcuts, see [`ConflictPolicy::Swap`].
+    Swapped {
+        /// The ID of the action.
+        id: String,
+        /// The new shortcut of the action.
+        shortcut: Shortcut,
+        /// The ID of the action that had the shortcut.
+        with: String,
+        /// The new shortcut of the other action, which may be unbound.
+        given: Shortcut,
+    },
+    /// The shortcut is used by another action, so nothing was changed, see
+    /// [`ConflictPolicy::Reject`].
+    Rejected {
+        /// The ID of the action.
+        id: String,
+        /// The shortcut that was not assigned.
+        shortcut: Shortcut,
+        /// The ID of the action that uses the shortcut.
+        owner: String,
+    },
+}
+
+impl Assignment {
+    /// The ID of the assigned action.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Unchanged { id, .. }
+            | Self::Assigned { id, .. }
+            | Self::Replaced { id, .. }
+            | Self::Swapped { id, .. }
+            | Self::Rejected { id, .. } => id,
+        }
+    }
+
+    /// The assigned shortcut.
+    pub fn shortcut(&self) -> &Shortcut {
+        match self {
+            Self::Unchanged { shortcut, .. }
+            | Self::Assigned { shortcut, .. }
+            | Self::Replaced { shortcut, .. }
+            | Self::Swapped { shortcut, .. }
+            | Self::Rejected { shortcut, .. } => shortcut,
+        }
+    }
+
+    /// Whether any shortcut was changed, so the app knows when to save the
+    /// hotkeys again.
+    pub fn is_changed(&self) -> bool {
+        !matches!(self, Self::Unchanged { .. } | Self::Rejected { .. })
+    }
+
+    /// Describe what was changed, with the name of each action from `names`,
+    /// e.g. `"Moved Ctrl+D from Duplicate to Delete"`.
+    pub fn describe(&self, names: impl Fn(&str) -> String) -> String {
+        let shortcut = self.shortcut();
+        let name = names(self.id());
+        match self {
+            Self::Unchanged { .. } => format!("{name} already uses {shortcut}"),
+            Self::Assigned { .. } => format!("Assigned {shortcut} to {name}"),
+            Self::Replaced { from, .. } => {
+                let from: Vec<String> = from.iter().map(|id| names(id)).collect();
+                format!("Moved {shortcut} from {} to {name}", from.join(", "))
+            }
+            Self::Swapped { with, given, .. } if given.is_bound() => format!(
+                "Swapped {shortcut} and {given} between {} and {name}",
+                names(with)
+            ),
+            Self::Swapped { with, .. } => {
+                format!("Moved {shortcut} from {} to {name}", names(with))
+            }
+            Self::Rejected { owner, .. } => {
+                format!("{shortcut} is already used by {}", names(owner))
+            }
+        }
+    }
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(str::to_string))
+    }
+}
+
+/// Normalize a shortcut for comparison. [`Modifiers::command`] is treated as
+/// Ctrl, which is what it means outside of macOS, unless Cmd is set as well.
+/// Cross-platform shortcuts (see [`crate::CommandPolicy`]) are compared by their
+/// primary modifier.
+pub(crate) fn normalize(shortcut: &Shortcut) -> Shortcut {
+    let keyboard = shortcut.matched_keyboard().map(|keyboard| {
+        let modifiers = keyboard.modifiers;
+        KeyboardShortcut::new(
+            Modifiers {
+                ctrl: modifiers.ctrl || (modifiers.command && !modifiers.mac_cmd),
+                command: false,
+                ..modifiers
+            },
+            keyboard.logical_key,
+        )
+    });
+    let mut normalized = Shortcut::new(keyboard, shortcut.pointer());
+    if let Some(text) = shortcut.text() {
+        normalized.set_text(text, shortcut.pointer());
+    }
+    normalized
+}
+
+/// Whether the normalized shortcut `a` is a part of the different normalized
+/// shortcut `b`, so pressing `b` also triggers `a`. That's only the case if
+/// `b` has the same key with extra Shift or Alt, and `a` matches them
+/// leniently (see [MatchPolicy]), or if `a` is only a pointer button.
+fn shadows(a: &Shortcut, b: &Shortcut, policy: MatchPolicy) -> bool {
+    let keyboard = match (a.keyboard(), b.keyboard(), a.text(), b.text()) {
+        (Some(a), Some(b), _, _) => {
+            a.logical_key == b.logical_key && policy.matches(b.modifiers, a.modifiers)
+        }
+        (None, _, Some(a), Some(b)) => {
+            a.character == b.character && policy.matches(b.modifiers, a.modifiers)
+        }
+        (None, _, None, _) => true,
+        _ => false,
+    };
+    let pointer = a.pointer().is_none() || a.pointer() == b.pointer();
+    a != b && keyboard && pointer
+}
+
+impl Hotkeys {
+    /// Find the actions whose current shortcuts conflict with each other,
+    /// including their alternate shortcuts (see [`Hotkeys::add_binding`]).
+    ///
+    /// Actions with the same shortcut are grouped into one
+    /// [`ConflictKind::Duplicate`]. Each action whose shortcut is triggered by
+    /// another one as well (e.g. `S` by `Shift+S`, but not by `Ctrl+S`) forms
+    /// a [`ConflictKind::Shadowed`] pair with it, unless it matches modifiers
+    /// exactly (see [`Shortcut::with_match_policy`]). Chords (see
+    /// [`Hotkeys::register_chord`]) only conflict with chords that have the
+    /// same second step, not with an action on their first step alone.
+    /// Unbound actions never conflict. Actions conflict across categories,
+    /// since those only group them for showing. Actions in different scopes
+    /// conflict too (see [`Hotkeys::register_scoped`]), unless the scopes
+    /// are declared to never be active together with
+    /// [`Hotkeys::add_exclusive_scopes`]. Actions attached to different
+    /// windows or areas (see [`Hotkeys::register_in_area`]) don't conflict.
+    /// Gamepad buttons are checked separately with
+    /// `Hotkeys::gamepad_conflicts` (`gamepad` feature).
+    ///
+    /// This compares every pair of actions, so with hundreds of actions it's
+    /// best to only call it again when a shortcut changes, like the
+    /// [KeybindsPanel](crate::KeybindsPanel) does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{ConflictKind, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// for (id, shortcut) in [
+    ///     ("save", "Ctrl+S"),
+    ///     ("save copy", "Ctrl+S"),
+    ///     ("save as", "Ctrl+Shift+S"),
+    ///     ("back", "Mouse 4"),
+    ///     ("history", "Mouse 4"),
+    ///     ("close tab", "Ctrl+W+Middle"),
+    ///     ("paste", "Middle"),
+    ///     ("open", "Ctrl+O"),
+    ///     ("step", "S"),
+    /// ] {
+    ///     hotkeys.register(id, shortcut.parse().unwrap());
+    /// }
+    ///
+    /// let report = hotkeys.conflicts();
+    /// assert!(report.has_duplicates());
+    /// assert_eq!(report.kind_of("save"), Some(ConflictKind::Duplicate));
+    /// assert_eq!(report.kind_of("history"), Some(ConflictKind::Duplicate));
+    /// assert_eq!(report.kind_of("save as"), Some(ConflictKind::Shadowed));
+    /// assert_eq!(report.kind_of("close tab"), Some(ConflictKind::Shadowed));
+    /// assert_eq!(report.kind_of("open"), None);
+    /// // pressing Ctrl+S never triggers S
+    /// assert_eq!(report.kind_of("step"), None);
+    ///
+    /// let groups: Vec<_> = report.iter().map(|conflict| conflict.ids()).collect();
+    /// assert_eq!(
+    ///     groups,
+    ///     [
+    ///         &["save", "save copy"][..],
+    ///         &["back", "history"],
+    ///         &["save", "save as"],
+    ///         &["save copy", "save as"],
+    ///         &["paste", "close tab"],
+    ///     ]
+    /// );
+    ///
+    /// hotkeys.set("save copy", "Ctrl+Alt+S".parse().unwrap());
+    /// hotkeys.set("history", "Mouse 5".parse().unwrap());
+    /// assert!(!hotkeys.conflicts().has_duplicates());
+    /// ```
+    pub fn conflicts(&self) -> ConflictReport {
+        // actions in exclusive scopes are never active together, so every combination
+        // of one scope from each exclusive group is checked on its own
+        let mut scopes: Vec<Vec<&str>> = vec![vec![]];
+        for group in self.exclusive_scopes() {
+            let used: Vec<&str> = group
+                .iter()
+                .map(String::as_str)
+                .filter(|scope| self.iter().any(|hotkey| hotkey.scope() == *scope))
+                .collect();
+            if used.len() > 1 {
+                scopes = scopes
+                    .iter()
+                    .flat_map(|chosen| {
+                        used.iter()
+                            .map(move |scope| [&chosen[..], &[*scope]].concat())
+                    })
+                    .collect();
+            }
+        }
+        // and actions in different windows or areas
+        let mut areas: Vec<Option<egui::Id>> = vec![];
+        for hotkey in self.iter() {
+            if hotkey.area().is_some() && !areas.contains(&hotkey.area()) {
+                areas.push(hotkey.area());
+            }
+        }
+        if areas.is_empty() {
+            areas.push(None);
+        }
+
+        let (mut duplicates, mut shadowed) = (vec![], vec![]);
+        for (scope, area) in scopes
+            .iter()
+            .flat_map(|scope| areas.iter().map(move |area| (scope, *area)))
+        {
+            let hotkeys = self.iter().filter(|hotkey| {
+                !scope
+                    .iter()
+                    .any(|scope| self.are_scopes_exclusive(scope, hotkey.scope()))
+                    && (hotkey.area().is_none() || hotkey.area() == area)
+            });
+            for conflict in find_conflicts(hotkeys) {
+                let found = match conflict.kind {
+                    ConflictKind::Duplicate => &mut duplicates,
+                    ConflictKind::Shadowed => &mut shadowed,
+                };
+                // most actions are checked with every combination of scopes and
+                // every area, so skip what was already found
+                let is_subset = |of: &Conflict| conflict.ids.iter().all(|id| of.contains(id));
+                if !found.iter().any(is_subset) {
+                    found.retain(|other: &Conflict| {
+                        !other.ids.iter().all(|id| conflict.contains(id))
+                    });
+                    found.push(conflict);
+                }
+            }
+        }
+        duplicates.append(&mut shadowed);
+        ConflictReport {
+            conflicts: duplicates,
+        }
+    }
+
+    /// Hash everything [`Hotkeys::conflicts`] depends on, to know when the
+    /// conflicts have to be found again.
+    pub(crate) fn conflicts_key(&self) -> u64 {
+        let exclusive = egui::util::hash(self.exclusive_scopes());
+        self.iter().fold(exclusive, |key, hotkey| {
+            let bindings = hotkey.bindings().fold(0, |key, binding| {
+                egui::util::hash((key, binding.cache_key()))
+            });
+            let second = hotkey.second_step().and_then(Bind::cache_key);
+            egui::util::hash((
+                key,
+                hotkey.id(),
+                hotkey.scope(),
+                hotkey.area(),
+                second,
+                bindings,
+            ))
+        })
+    }
+}
+
+impl Hotkeys {
+    /// Set the current shortcut of an action, resolving a conflict with the
+    /// actions that already use it with the [ConflictPolicy] (see
+    /// [`Hotkeys::set_conflict_policy`]). Only actions that can be active at
+    /// the same time conflict, like with [`Hotkeys::conflicts`]. Unbinding an
+    /// action never conflicts.
+    /// All actions that were changed are one entry in the history, see
+    /// [`Hotkeys::undo`].
+    ///
+    /// With [`ConflictPolicy::Swap`], only the first action that uses the
+    /// shortcut gets the previous shortcut. Any other actions that use it
+    /// keep it.
+    ///
+    /// # Returns
+    ///
+    /// What was changed, or [None] if the action is not registered or the
+    /// active profile is a preset (see [`Hotkeys::add_preset`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Assignment, ConflictPolicy, Hotkeys, Shortcut};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("duplicate", "Ctrl+D".parse().unwrap())
+    ///     .register("delete", "Delete".parse().unwrap());
+    ///
+    /// // the default policy takes the shortcut away from the other action
+    /// assert_eq!(hotkeys.conflict_policy(), ConflictPolicy::Replace);
+    /// let assignment = hotkeys.assign("delete", "Ctrl+D".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Replaced { .. }));
+    /// assert_eq!(assignment.to_string(), "Moved Ctrl+D from duplicate to delete");
+    /// assert_eq!(hotkeys.current("duplicate"), Some(&Shortcut::NONE));
+    /// assert_eq!(hotkeys.current("delete").unwrap().to_string(), "Ctrl+D");
+    ///
+    /// // assigning the shortcut an action already has does nothing
+    /// let assignment = hotkeys.assign("delete", "Ctrl+D".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Unchanged { .. }));
+    /// assert!(!assignment.is_changed());
+    ///
+    /// // shortcuts that are not used by any other action are just assigned
+    /// let assignment = hotkeys.assign("duplicate", "Ctrl+Shift+D".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Assigned { .. }));
+    ///
+    /// assert!(hotkeys.assign("print", "Ctrl+P".parse().unwrap()).is_none());
+    /// ```
+    ///
+    /// Swapping:
+    ///
+    /// ```
+    /// use egui_keybind::{Assignment, ConflictPolicy, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("duplicate", "Ctrl+D".parse().unwrap())
+    ///     .register("delete", "Delete".parse().unwrap());
+    /// hotkeys.add_binding("duplicate", "Ctrl+Shift+D".parse().unwrap());
+    /// hotkeys.set_conflict_policy(ConflictPolicy::Swap);
+    ///
+    /// let assignment = hotkeys.assign("delete", "Ctrl+D".parse().unwrap()).unwrap();
+    /// assert_eq!(
+    ///     assignment.describe(|id| id.to_uppercase()),
+    ///     "Swapped Ctrl+D and Delete between DUPLICATE and DELETE"
+    /// );
+    /// assert_eq!(hotkeys.current("duplicate").unwrap().to_string(), "Delete");
+    /// assert_eq!(hotkeys.current("delete").unwrap().to_string(), "Ctrl+D");
+    ///
+    /// // alternate shortcuts are swapped in place
+    /// let assignment = hotkeys.assign("delete", "Ctrl+Shift+D".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Swapped { .. }));
+    /// let duplicate = hotkeys.get("duplicate").unwrap();
+    /// let bindings: Vec<String> = duplicate.bindings().map(ToString::to_string).collect();
+    /// assert_eq!(bindings, ["Delete", "Ctrl+D"]);
+    ///
+    /// // self-assignment does nothing
+    /// let assignment = hotkeys.assign("delete", "Ctrl+Shift+D".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Unchanged { .. }));
+    /// ```
+    ///
+    /// Rejecting:
+    ///
+    /// ```
+    /// use egui_keybind::{Assignment, ConflictPolicy, Hotkeys, Shortcut};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("duplicate", "Ctrl+D".parse().unwrap())
+    ///     .register("delete", "Delete".parse().unwrap())
+    ///     .register_scoped("editor", "bookmark", "F2".parse().unwrap())
+    ///     .register_scoped("browser", "rename", "F3".parse().unwrap());
+    /// hotkeys.set_conflict_policy(ConflictPolicy::Reject);
+    ///
+    /// let assignment = hotkeys.assign("delete", "Ctrl+D".parse().unwrap()).unwrap();
+    /// assert_eq!(assignment.to_string(), "Ctrl+D is already used by duplicate");
+    /// assert!(!assignment.is_changed());
+    /// assert_eq!(hotkeys.current("delete").unwrap().to_string(), "Delete");
+    ///
+    /// // self-assignment is not a conflict
+    /// let assignment = hotkeys.assign("delete", "Delete".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Unchanged { .. }));
+    ///
+    /// // actions in exclusive scopes don't conflict, and unbinding never does
+    /// hotkeys.add_exclusive_scopes(&["editor", "browser"]);
+    /// let assignment = hotkeys.assign("rename", "F2".parse().unwrap()).unwrap();
+    /// assert!(matches!(assignment, Assignment::Assigned { .. }));
+    /// let assignment = hotkeys.assign("delete", Shortcut::NONE).unwrap();
+    /// assert!(assignment.is_changed());
+    /// ```
+    pub fn assign(&mut self, id: &str, shortcut: Shortcut) -> Option<Assignment> {
+        self.record(|hotkeys| hotkeys.resolve(id, 0, shortcut))
+    }
+
+    /// Set a shortcut of an action like [`Hotkeys::assign`], where 0 is the
+    /// current shortcut and the alternate shortcuts come after it (see
+    /// [`Hotkey::bindings`]), e.g. when an alternate shortcut is recorded.
+    ///
+    /// # Returns
+    ///
+    /// What was changed, or [None] if the action is not registered, has no
+    /// shortcut at `index`, or the active profile is a preset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Assignment, ConflictPolicy, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("redo", "Ctrl+Y".parse().unwrap())
+    ///     .register("zoom", "Ctrl+Shift+Z".parse().unwrap());
+    /// hotkeys.add_binding("redo", "Ctrl+Shift+Y".parse().unwrap());
+    /// hotkeys.set_conflict_policy(ConflictPolicy::Reject);
+    ///
+    /// let assignment = hotkeys.assign_binding("redo", 1, "Ctrl+Shift+Z".parse().unwrap());
+    /// assert!(matches!(assignment, Some(Assignment::Rejected { .. })));
+    /// let redo = hotkeys.get("redo").unwrap();
+    /// assert_eq!(redo.alternates().as_slice()[0].to_string(), "Ctrl+Shift+Y");
+    ///
+    /// hotkeys.set_conflict_policy(ConflictPolicy::Replace);
+    /// let assignment = hotkeys.assign_binding("redo", 1, "Ctrl+Shift+Z".parse().unwrap());
+    /// assert_eq!(assignment.unwrap().to_string(), "Moved Ctrl+Shift+Z from zoom to redo");
+    /// let redo = hotkeys.get("redo").unwrap();
+    /// let bindings: Vec<String> = redo.bindings().map(ToString::to_string).collect();
+    /// assert_eq!(bindings, ["Ctrl+Y", "Ctrl+Shift+Z"]);
+    ///
+    /// assert!(hotkeys.assign_binding("redo", 2, "Ctrl+R".parse().unwrap()).is_none());
+    /// ```
+    pub fn assign_binding(
+        &mut self,
+        id: &str,
+        index: usize,
+        shortcut: Shortcut,
+    ) -> Option<Assignment> {
+        self.record(|hotkeys| hotkeys.resolve(id, index, shortcut))
+    }
+
+    /// Assign a shortcut like [`Hotkeys::assign_binding`], without recording it.
+    fn resolve(&mut self, id: &str, index: usize, shortcut: Shortcut) -> Option<Assignment> {
+        if self.is_profile_read_only(self.active_profile()) {
+            return None;
+        }
+        let hotkey = self.get(id)?;
+        let id = id.to_string();
+        let previous = *hotkey.bindings().nth(index)?;
+        if previous == shortcut {
+            return Some(Assignment::Unchanged { id, shortcut });
+        }
+
+        // the other actions that use the shortcut, and the index of the binding they use it for
+        let mut owners: Vec<(String, usize)> = vec![];
+        if shortcut.is_bound() {
+            let normalized = normalize(&shortcut);
+            for other in self.iter() {
+                let overlaps = self.can_overlap(hotkey, other);
+                // a chord doesn't use the shortcut of its first step alone
+                let same_chord =
+                    other.second_step().map(normalize) == hotkey.second_step().map(normalize);
+                if other.id() == id || !overlaps || !same_chord {
+                    continue;
+                }
+                for (index, binding) in other.bindings().enumerate() {
+                    if normalize(binding) == normalized {
+                        owners.push((other.id().to_string(), index));
+                    }
+                }
+            }
+        }
+
+        let assignment = match (self.conflict_policy(), owners.first()) {
+            (_, None) => Assignment::Assigned {
+                id: id.clone(),
+                shortcut,
+            },
+            (ConflictPolicy::Reject, Some((owner, _))) => {
+                return Some(Assignment::Rejected {
+                    id,
+                    shortcut,
+                    owner: owner.clone(),
+                });
+            }
+            (ConflictPolicy::Swap, Some((with, with_index))) => {
+                if let Some(other) = self.get_mut(with) {
+                    match (previous.is_bound(), with_index) {
+                        (true, 0) => *other.current_mut() = previous,
+                        (true, index) => other.alternates_mut().as_mut_vec()[index - 1] = previous,
+                        (false, _) => {
+                            self.remove_binding(with, *with_index);
+                        }
+                    }
+                }
+                Assignment::Swapped {
+                    id: id.clone(),
+                    shortcut,
+                    with: with.clone(),
+                    given: previous,
+                }
+            }
+            (ConflictPolicy::Replace, Some(_)) => {
+                // later bindings first, so the earlier indices stay the same
+                for (owner, index) in owners.iter().rev() {
+                    self.remove_binding(owner, *index);
+                }
+                let mut from: Vec<String> = owners.into_iter().map(|(owner, _)| owner).collect();
+                from.dedup();
+                Assignment::Replaced {
+                    id: id.clone(),
+                    shortcut,
+                    from,
+                }
+            }
+        };
+        if let Some(hotkey) = self.get_mut(&id) {
+            match index {
+                0 => *hotkey.current_mut() = shortcut,
+                index => hotkey.alternates_mut().as_mut_vec()[index - 1] = shortcut,
+            }
+        }
+        Some(assignment)
+    }
+}
+
+/// The IDs of the actions that use a shortcut, and the match policy they use it with.
+type Users<'a> = Vec<(&'a str, MatchPolicy)>;
+
+/// Find the conflicts between the current shortcuts of some actions, see
+/// [`Hotkeys::conflicts`].
+fn find_conflicts<'a>(hotkeys: impl Iterator<Item = &'a Hotkey>) -> Vec<Conflict> {
+    // group the bound actions by their shortcut and the second step of chords, in the
+    // order they were registered, with the match policy each action uses it with
+    let mut groups: Vec<(Shortcut, Option<Shortcut>, Users)> = vec![];
+    for hotkey in hotkeys {
+        let second = hotkey.second_step().map(normalize);
+        for binding in hotkey.bindings().filter(|shortcut| shortcut.is_bound()) {
+            let shortcut = normalize(binding);
+            let entry = (hotkey.id(), binding.match_policy());
+            let group = groups
+                .iter_mut()
+                .find(|(other, other_second, _)| *other == shortcut && *other_second == second);
+            match group {
+                Some((_, _, ids)) if ids.iter().any(|(id, _)| *id == hotkey.id()) => {}
+                Some((_, _, ids)) => ids.push(entry),
+                None => groups.push((shortcut, second, vec![entry])),
+            }
+        }
+    }
+
+    let mut conflicts: Vec<Conflict> = groups
+        .iter()
+        .filter(|(_, _, ids)| ids.len() > 1)
+        .map(|(_, _, ids)| Conflict {
+            kind: ConflictKind::Duplicate,
+            ids: ids.iter().map(|(id, _)| id.to_string()).collect(),
+        })
+        .collect();
+    for (shorter, shorter_second, shorter_ids) in &groups {
+        for (longer, longer_second, longer_ids) in &groups {
+            if shorter_second != longer_second {
+                continue;
+            }
+            for (shorter_id, policy) in shorter_ids {
+                if !shadows(shorter, longer, *policy) {
+                    continue;
+                }
+                for (longer_id, _) in longer_ids.iter().filter(|(id, _)| id != shorter_id) {
+                    conflicts.push(Conflict {
+                        kind: ConflictKind::Shadowed,
+                        ids: vec![shorter_id.to_string(), longer_id.to_string()],
+                    });
+                }
+            }
+        }
+    }
+    conflicts
+}
@@ -0,0 +1,213 @@
+use crate::{Bind, GestureDirection, InputKinds, KeyNameMap, Shortcut, TextKey, WheelDirection};
+use egui::{InputState, KeyboardShortcut, ModifierNames, Modifiers, PointerButton};
+use std::cell::Cell;
+
+/// A bind that fires at most once per interval, e.g. so that a nervous
+/// double tap doesn't start an expensive export twice.
+///
+/// The first press fires right away. Presses within
+/// [`Debounced::min_interval`] seconds after it are swallowed: they don't
+/// fire, but the inner bind still consumes them, so they don't reach other
+/// handlers either. The time is taken from [`InputState::time`].
+///
+/// When it last fired is runtime state: it isn't serialized or compared, and
+/// [`Debounced::reset`] forgets it.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{Bind, Debounced, Shortcut};
+///
+/// let export = Debounced::new("Ctrl+E".parse::<Shortcut>().unwrap(), 0.5);
+/// let ctx = egui::Context::default();
+/// let press = |time: f64| {
+///     let mut input = RawInput {
+///         time: Some(time),
+///         ..Default::default()
+///     };
+///     input.events.push(Event::Key {
+///         key: Key::E,
+///         physical_key: None,
+///         pressed: true,
+///         repeat: false,
+///         modifiers: Modifiers::CTRL,
+///     });
+///     let mut result = (false, false);
+///     let _ = ctx.run(input, |ctx| {
+///         ctx.input_mut(|input| {
+///             let pressed = export.pressed(input);
+///             // swallowed presses are consumed too
+///             let leaked = input.consume_key(Modifiers::CTRL, Key::E);
+///             result = (pressed, leaked);
+///         });
+///     });
+///     result
+/// };
+///
+/// assert_eq!(press(1.0), (true, false));
+/// // within half a second of the first press
+/// assert_eq!(press(1.2), (false, false));
+/// assert_eq!(press(1.49), (false, false));
+/// // the window starts at the press that fired, not at the swallowed ones
+/// assert_eq!(press(1.5), (true, false));
+/// assert_eq!(press(1.9), (false, false));
+/// assert_eq!(press(2.1), (true, false));
+///
+/// export.reset();
+/// assert_eq!(press(2.2), (true, false));
+///
+/// # #[cfg(feature = "serde")] {
+/// let json = serde_json::to_string(&export).unwrap();
+/// assert!(!json.contains("last_fired"));
+/// assert_eq!(serde_json::from_str::<Debounced<Shortcut>>(&json).unwrap(), export);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Debounced<B: Bind> {
+    /// The bind.
+    pub inner: B,
+    /// How long after firing the bind doesn't fire again, in seconds.
+    pub min_interval: f32,
+    /// When the bind last fired, in [`InputState::time`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_fired: Cell<Option<f64>>,
+}
+
+impl<B: Bind> Debounced<B> {
+    /// Create a new [Debounced] bind.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The bind.
+    /// * `min_interval` - How long after firing the bind doesn't fire again,
+    ///   in seconds.
+    pub fn new(inner: B, min_interval: f32) -> Self {
+        Self {
+            inner,
+            min_interval,
+            last_fired: Cell::new(None),
+        }
+    }
+
+    /// Get when the bind last fired, in [`InputState::time`], or [None] if
+    /// it hasn't fired since it was created or [reset](Debounced::reset).
+    #[inline]
+    pub fn last_fired(&self) -> Option<f64> {
+        self.last_fired.get()
+    }
+
+    /// Forget when the bind last fired, so the next press fires right away.
+    pub fn reset(&self) {
+        self.last_fired.set(None);
+    }
+}
+
+impl<B: Bind + Default> Default for Debounced<B> {
+    fn default() -> Self {
+        Self::new(B::default(), 0.0)
+    }
+}
+
+/// Ignores when the binds last fired.
+impl<B: Bind + PartialEq> PartialEq for Debounced<B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.min_interval == other.min_interval
+    }
+}
+
+impl<B: Bind> std::fmt::Display for Debounced<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format(&ModifierNames::NAMES, false))
+    }
+}
+
+/// Forwards everything to the inner bind, except that presses are
+/// rate-limited.
+impl<B: Bind> Bind for Debounced<B> {
+    fn set(&mut self, keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        self.inner.set(keyboard, pointer);
+    }
+
+    fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+        self.inner.format(names, is_mac)
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        self.inner.format_into(out, names, is_mac);
+    }
+
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        self.inner.format_with_key_names(names, key_names, is_mac)
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Triggered when the inner bind is, unless it fired less than
+    /// [`Debounced::min_interval`] seconds ago.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        // always ask the inner bind, so that swallowed presses are consumed
+        if !self.inner.pressed(input) {
+            return false;
+        }
+        if let Some(last) = self.last_fired.get() {
+            if input.time - last < self.min_interval as f64 {
+                return false;
+            }
+        }
+        self.last_fired.set(Some(input.time));
+        true
+    }
+
+    fn is_bound(&self) -> bool {
+        self.inner.is_bound()
+    }
+
+    fn alternates(&self) -> &[Shortcut] {
+        self.inner.alternates()
+    }
+
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        self.inner.keyboard()
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        self.inner.pointer()
+    }
+
+    fn text(&self) -> Option<TextKey> {
+        self.inner.text()
+    }
+
+    fn set_text(&mut self, text: TextKey, pointer: Option<PointerButton>) -> bool {
+        self.inner.set_text(text, pointer)
+    }
+
+    fn set_long_press(&mut self, button: PointerButton, duration: f32) -> bool {
+        self.inner.set_long_press(button, duration)
+    }
+
+    fn set_wheel(&mut self, modifiers: Modifiers, direction: WheelDirection) -> bool {
+        self.inner.set_wheel(modifiers, direction)
+    }
+
+    fn set_gesture(&mut self, button: PointerButton, direction: GestureDirection) -> bool {
+        self.inner.set_gesture(button, direction)
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        self.inner.input_kinds()
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        self.inner.cache_key()
+    }
+}
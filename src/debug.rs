@@ -0,0 +1,397 @@
+use crate::{matches_events, peek, Bind, Hotkeys, Shortcut, Triggered};
+use egui::{Context, Event, InputState, KeyboardShortcut, RichText, ScrollArea, Window};
+use std::{collections::VecDeque, fmt};
+
+/// How many frames the debug log of [Hotkeys] keeps, see
+/// [`Hotkeys::set_debug_enabled`]. Frames without any input are not kept.
+pub const DEBUG_LOG_FRAMES: usize = 30;
+
+/// Whether an action whose shortcut matched the input of a frame was
+/// triggered, and why not if it wasn't. See [`Hotkeys::debug_frames`].
+///
+/// [Display](fmt::Display) describes it, e.g. `"lost to save"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugOutcome {
+    /// The action was triggered.
+    Triggered,
+    /// The scope of the action is not active, see [`Hotkeys::set_active_scopes`].
+    ScopeInactive,
+    /// The window or area of the action doesn't have focus, see
+    /// [`Hotkeys::register_in_area`].
+    AreaNotFocused,
+    /// A widget wanted keyboard input, and the [TextInputPolicy](crate::TextInputPolicy)
+    /// of the action doesn't allow the shortcut then.
+    TextInput,
+    /// Another action used the key press, because its shortcut is more
+    /// specific or it was registered first. Holds the ID of that action.
+    LostTo(String),
+    /// The key press was the first step of a chord that is now pending, see
+    /// [`Hotkeys::pending_chord`].
+    ChordPending,
+    /// The key press was consumed before [`Hotkeys::triggered`] was called,
+    /// e.g. by a widget or by [`Hotkeys::pressed`].
+    ConsumedElsewhere,
+}
+
+impl fmt::Display for DebugOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Triggered => write!(f, "triggered"),
+            Self::ScopeInactive => write!(f, "scope not active"),
+            Self::AreaNotFocused => write!(f, "area not focused"),
+            Self::TextInput => write!(f, "suppressed while typing"),
+            Self::LostTo(id) => write!(f, "lost to {id}"),
+            Self::ChordPending => write!(f, "waiting for the next step of a chord"),
+            Self::ConsumedElsewhere => write!(f, "consumed before Hotkeys::triggered"),
+        }
+    }
+}
+
+/// An action whose shortcut matched the input of a frame, see [DebugFrame].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugMatch {
+    id: String,
+    shortcut: Shortcut,
+    outcome: DebugOutcome,
+}
+
+impl DebugMatch {
+    /// The ID of the action.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The shortcut of the action that matched. For completed chords this is
+    /// the second step, and for OS-global hotkeys and gamepad buttons it's
+    /// [`Shortcut::NONE`].
+    #[inline]
+    pub fn shortcut(&self) -> &Shortcut {
+        &self.shortcut
+    }
+
+    /// Whether the action was triggered, and why not if it wasn't.
+    #[inline]
+    pub fn outcome(&self) -> &DebugOutcome {
+        &self.outcome
+    }
+}
+
+/// What [`Hotkeys::triggered`] did on one frame, see [`Hotkeys::debug_frames`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugFrame {
+    pass: u64,
+    events: Vec<String>,
+    matches: Vec<DebugMatch>,
+}
+
+impl DebugFrame {
+    /// The pass of the frame, see [`Context::cumulative_pass_nr`].
+    #[inline]
+    pub fn pass(&self) -> u64 {
+        self.pass
+    }
+
+    /// The key and pointer button presses of the frame, e.g. `"Ctrl+S pressed"`,
+    /// including the ones that were consumed before [`Hotkeys::triggered`].
+    #[inline]
+    pub fn events(&self) -> &[String] {
+        &self.events
+    }
+
+    /// The actions whose shortcuts matched the presses, in the order they
+    /// were registered.
+    #[inline]
+    pub fn matches(&self) -> &[DebugMatch] {
+        &self.matches
+    }
+}
+
+/// The presses of a frame and the actions they match before
+/// [`Hotkeys::triggered`] handles them, with the outcome if it's known
+/// already.
+pub(crate) struct DebugMatches {
+    events: Vec<String>,
+    matches: Vec<(String, Shortcut, Option<DebugOutcome>)>,
+}
+
+/// Describe a key or pointer button press, or [None] for other events.
+fn describe_event(event: &Event) -> Option<String> {
+    match event {
+        Event::Key {
+            key,
+            pressed: true,
+            repeat,
+            modifiers,
+            ..
+        } => {
+            let shortcut = Shortcut::new(Some(KeyboardShortcut::new(*modifiers, *key)), None);
+            let state = if *repeat { "repeated" } else { "pressed" };
+            Some(format!("{shortcut} {state}"))
+        }
+        Event::PointerButton {
+            button,
+            pressed: true,
+            ..
+        } => Some(format!("{} pressed", Shortcut::new(None, Some(*button)))),
+        _ => None,
+    }
+}
+
+/// Whether two shortcuts are pressed with the same key, or the same pointer
+/// button if they have no key.
+fn same_press(a: &Shortcut, b: &Shortcut) -> bool {
+    let key = |shortcut: &Shortcut| shortcut.keyboard().map(|keyboard| keyboard.logical_key);
+    match (key(a), key(b)) {
+        (Some(a), Some(b)) => a == b,
+        (None, None) => a.pointer() == b.pointer(),
+        _ => false,
+    }
+}
+
+impl Hotkeys {
+    /// Set whether [`Hotkeys::triggered`] records what it does on each frame,
+    /// for [`Hotkeys::debug_ui`] and [`Hotkeys::debug_frames`]: which actions
+    /// were triggered, which matched the input but weren't and why, and the
+    /// key and pointer button presses. Nothing is recorded by default, and
+    /// nothing is done for it then. Turning it off clears the log.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Id, Key, Modifiers, RawInput};
+    /// use egui_keybind::{DebugOutcome, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("stamp", "S".parse().unwrap())
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("save as", "Ctrl+Shift+S".parse().unwrap())
+    ///     .register_scoped("canvas", "split", "Ctrl+Shift+S".parse().unwrap())
+    ///     .set_active_scopes(&[]);
+    /// hotkeys.set_debug_enabled(true);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut press = |hotkeys: &mut Hotkeys, modifiers, typing| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key: Key::S,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers,
+    ///     });
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         if typing {
+    ///             ctx.memory_mut(|memory| memory.request_focus(Id::new("text")));
+    ///         }
+    ///         let _ = hotkeys.triggered(ctx);
+    ///     });
+    ///     let frame = hotkeys.debug_frames().last().unwrap();
+    ///     let matches: Vec<String> = frame
+    ///         .matches()
+    ///         .iter()
+    ///         .map(|found| format!("{}: {}", found.id(), found.outcome()))
+    ///         .collect();
+    ///     (frame.events().to_vec(), matches)
+    /// };
+    ///
+    /// let (events, matches) = press(&mut hotkeys, Modifiers::CTRL | Modifiers::SHIFT, false);
+    /// assert_eq!(events, ["Ctrl+Shift+S pressed"]);
+    /// assert_eq!(
+    ///     matches,
+    ///     ["save: lost to save as", "save as: triggered", "split: scope not active"]
+    /// );
+    ///
+    /// let (_, matches) = press(&mut hotkeys, Modifiers::NONE, true);
+    /// assert_eq!(matches, ["stamp: suppressed while typing"]);
+    ///
+    /// // turning it off clears the log
+    /// hotkeys.set_debug_enabled(false);
+    /// assert_eq!(hotkeys.debug_frames().count(), 0);
+    /// ```
+    pub fn set_debug_enabled(&mut self, enabled: bool) {
+        match (enabled, &self.debug) {
+            (true, None) => self.debug = Some(VecDeque::new()),
+            (false, _) => self.debug = None,
+            (true, Some(_)) => {}
+        }
+    }
+
+    /// Whether [`Hotkeys::triggered`] records what it does, see
+    /// [`Hotkeys::set_debug_enabled`].
+    #[inline]
+    pub fn is_debug_enabled(&self) -> bool {
+        self.debug.is_some()
+    }
+
+    /// Iterate over the recorded frames with any input, oldest first, see
+    /// [`Hotkeys::set_debug_enabled`]. At most [DEBUG_LOG_FRAMES] are kept.
+    pub fn debug_frames(&self) -> impl DoubleEndedIterator<Item = &DebugFrame> {
+        self.debug.iter().flatten()
+    }
+
+    /// Show a window with the recorded frames, newest first, and a checkbox
+    /// to turn recording on and off (see [`Hotkeys::set_debug_enabled`]),
+    /// e.g. while tuning the shortcuts of an app. Call this every frame after
+    /// [`Hotkeys::triggered`].
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// # let _ = ctx.run(Default::default(), |ctx| {
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    /// hotkeys.set_debug_enabled(cfg!(debug_assertions));
+    ///
+    /// for action in hotkeys.triggered(ctx) {
+    ///     // run the action
+    /// }
+    /// hotkeys.debug_ui(ctx);
+    /// # });
+    /// ```
+    pub fn debug_ui(&mut self, ctx: &Context) {
+        Window::new("Hotkeys debug")
+            .default_width(320.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let mut enabled = self.is_debug_enabled();
+                    if ui.checkbox(&mut enabled, "Record").changed() {
+                        self.set_debug_enabled(enabled);
+                    }
+                    if ui.button("Clear").clicked() {
+                        self.debug.iter_mut().for_each(VecDeque::clear);
+                    }
+                });
+                if let Some(chord) = self.pending_chord() {
+                    ui.label(format!("{chord} was pressed, waiting for the next step"));
+                }
+                ui.separator();
+
+                ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                    for frame in self.debug_frames().rev() {
+                        ui.label(RichText::new(format!("Pass {}", frame.pass())).strong());
+                        if !frame.events().is_empty() {
+                            ui.weak(frame.events().join(", "));
+                        }
+                        for found in frame.matches() {
+                            let color = match found.outcome() {
+                                DebugOutcome::Triggered => ui.visuals().text_color(),
+                                DebugOutcome::LostTo(_) | DebugOutcome::ChordPending => {
+                                    ui.visuals().warn_fg_color
+                                }
+                                _ => ui.visuals().error_fg_color,
+                            };
+                            let shortcut = match found.shortcut().is_bound() {
+                                true => format!(" ({})", found.shortcut()),
+                                false => String::new(),
+                            };
+                            ui.colored_label(
+                                color,
+                                format!("{}{shortcut}: {}", found.id(), found.outcome()),
+                            );
+                        }
+                        ui.add_space(4.0);
+                    }
+                });
+            });
+    }
+
+    /// Find the actions whose shortcuts match the input before
+    /// [`Hotkeys::triggered`] handles it.
+    pub(crate) fn debug_matches(&self, input: &InputState, typing: bool) -> DebugMatches {
+        let raw = &input.raw.events;
+        let mut matches = vec![];
+        for hotkey in self {
+            let policy = hotkey
+                .text_input_policy()
+                .unwrap_or(self.text_input_policy());
+            for shortcut in hotkey.bindings().filter(|shortcut| shortcut.is_bound()) {
+                let outcome = if peek(shortcut, input) {
+                    if !self.is_scope_active(hotkey.scope()) {
+                        Some(DebugOutcome::ScopeInactive)
+                    } else if !self.is_area_focused(hotkey) {
+                        Some(DebugOutcome::AreaNotFocused)
+                    } else if typing && !policy.allows(shortcut) {
+                        Some(DebugOutcome::TextInput)
+                    } else {
+                        // decided by `Hotkeys::triggered`
+                        None
+                    }
+                } else if matches_events(shortcut, raw, input) {
+                    Some(DebugOutcome::ConsumedElsewhere)
+                } else {
+                    continue;
+                };
+                matches.push((hotkey.id().to_string(), *shortcut, outcome));
+            }
+        }
+        DebugMatches {
+            events: raw.iter().filter_map(describe_event).collect(),
+            matches,
+        }
+    }
+
+    /// Record a frame in the debug log, with the actions that were triggered.
+    pub(crate) fn debug_record(&mut self, pass: u64, found: DebugMatches, triggered: &[Triggered]) {
+        let is_triggered = |id: &str| triggered.iter().any(|action| action.id() == id);
+        let mut matches: Vec<DebugMatch> = found
+            .matches
+            .into_iter()
+            .map(|(id, shortcut, outcome)| {
+                let outcome = outcome.unwrap_or_else(|| {
+                    if is_triggered(&id) {
+                        return DebugOutcome::Triggered;
+                    }
+                    let winner = triggered.iter().map(Triggered::id).find(|other| {
+                        *other != id
+                            && self.get(other).is_some_and(|hotkey| {
+                                hotkey
+                                    .bindings()
+                                    .any(|binding| same_press(binding, &shortcut))
+                            })
+                    });
+                    match winner {
+                        Some(winner) => DebugOutcome::LostTo(winner.to_string()),
+                        None if self.pending_chord().is_some() => DebugOutcome::ChordPending,
+                        None => DebugOutcome::ConsumedElsewhere,
+                    }
+                });
+                DebugMatch {
+                    id,
+                    shortcut,
+                    outcome,
+                }
+            })
+            .collect();
+        // completed chords and OS-global hotkeys don't match the shortcuts of their actions
+        for action in triggered {
+            if !matches.iter().any(|found| found.id == action.id()) {
+                let shortcut = self
+                    .get(action.id())
+                    .and_then(|hotkey| hotkey.second_step());
+                matches.push(DebugMatch {
+                    id: action.id().to_string(),
+                    shortcut: shortcut.copied().unwrap_or(Shortcut::NONE),
+                    outcome: DebugOutcome::Triggered,
+                });
+            }
+        }
+
+        if found.events.is_empty() && matches.is_empty() {
+            return;
+        }
+        let Some(frames) = &mut self.debug else {
+            return;
+        };
+        frames.push_back(DebugFrame {
+            pass,
+            events: found.events,
+            matches,
+        });
+        while frames.len() > DEBUG_LOG_FRAMES {
+            frames.pop_front();
+        }
+    }
+}
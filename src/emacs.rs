@@ -0,0 +1,493 @@
+use crate::{Bind, ParseShortcutError, PointerTrigger, Shortcut, TextKey};
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+
+/// Which modifier `M-` (Meta) is in Emacs notation, see
+/// [`Shortcut::parse_emacs`]. `s-` (Super) is the other one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmacsMeta {
+    /// `M-` is Alt (Option on macOS) and `s-` is Cmd, like in Emacs by
+    /// default.
+    #[default]
+    Alt,
+    /// `M-` is Cmd and `s-` is Alt (Option), like in Emacs with
+    /// `mac-command-modifier` set to `meta` and `mac-option-modifier` set to
+    /// `super`.
+    Cmd,
+}
+
+impl EmacsMeta {
+    /// Get the modifiers that `M-` and `s-` are.
+    fn meta_and_super(self) -> (Modifiers, Modifiers) {
+        match self {
+            Self::Alt => (Modifiers::ALT, Modifiers::MAC_CMD),
+            Self::Cmd => (Modifiers::MAC_CMD, Modifiers::ALT),
+        }
+    }
+}
+
+/// Pointer buttons and their numbers in Emacs notation, e.g. `1` of
+/// `<mouse-1>`.
+const EMACS_POINTER_BUTTONS: [(PointerButton, &str); 5] = [
+    (PointerButton::Primary, "1"),
+    (PointerButton::Middle, "2"),
+    (PointerButton::Secondary, "3"),
+    (PointerButton::Extra1, "8"),
+    (PointerButton::Extra2, "9"),
+];
+
+/// Get the name of a key that Emacs writes without angle brackets, e.g.
+/// `"RET"`.
+fn emacs_special_name(key: Key) -> Option<&'static str> {
+    Some(match key {
+        Key::Enter => "RET",
+        Key::Space => "SPC",
+        Key::Tab => "TAB",
+        Key::Backspace => "DEL",
+        Key::Escape => "ESC",
+        _ => return None,
+    })
+}
+
+/// Get the character a key is written as in Emacs notation, e.g. `'s'` or
+/// `'['`, if it isn't written by name.
+fn emacs_key_char(key: Key) -> Option<char> {
+    Some(match key {
+        Key::Colon => ':',
+        Key::Comma => ',',
+        Key::Backslash => '\\',
+        Key::Slash => '/',
+        Key::Pipe => '|',
+        Key::Questionmark => '?',
+        Key::OpenBracket => '[',
+        Key::CloseBracket => ']',
+        Key::Backtick => '`',
+        Key::Minus => '-',
+        Key::Period => '.',
+        Key::Plus => '+',
+        Key::Equals => '=',
+        Key::Semicolon => ';',
+        Key::Quote => '\'',
+        _ => {
+            let mut chars = key.name().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => c.to_ascii_lowercase(),
+                _ => return None,
+            }
+        }
+    })
+}
+
+/// Get the function key name of a key that is written in angle brackets,
+/// e.g. `"prior"` of `<prior>` or `"F5"` of `<f5>`, before lowercasing.
+fn emacs_function_key(key: Key) -> &'static str {
+    match key {
+        Key::PageUp => "prior",
+        Key::PageDown => "next",
+        _ => key.name(),
+    }
+}
+
+/// Parse a key written without angle brackets, e.g. `"RET"`.
+fn parse_emacs_special(name: &str) -> Option<Key> {
+    Key::ALL
+        .iter()
+        .copied()
+        .find(|key| emacs_special_name(*key) == Some(name))
+}
+
+/// Parse a function key name, e.g. `"f5"` or `"prior"`.
+fn parse_emacs_function_key(name: &str) -> Option<Key> {
+    Some(match name {
+        "return" => Key::Enter,
+        "tab" => Key::Tab,
+        "escape" => Key::Escape,
+        "backspace" => Key::Backspace,
+        _ => {
+            return Key::ALL.iter().copied().find(|key| {
+                emacs_key_char(*key).is_none()
+                    && emacs_special_name(*key).is_none()
+                    && emacs_function_key(*key).to_ascii_lowercase() == name
+            })
+        }
+    })
+}
+
+/// Parse a mouse event name, e.g. `"mouse-1"` or `"down-mouse-3"`.
+fn parse_emacs_mouse(name: &str) -> Option<(PointerButton, PointerTrigger)> {
+    let (name, trigger) = match name.strip_prefix("down-") {
+        Some(name) => (name, PointerTrigger::Press),
+        None => (name, PointerTrigger::Click),
+    };
+    let number = name.strip_prefix("mouse-")?;
+    EMACS_POINTER_BUTTONS
+        .iter()
+        .find(|(_, emacs_number)| *emacs_number == number)
+        .map(|(button, _)| (*button, trigger))
+}
+
+/// Split the modifier prefixes off the start of a step, e.g. `"C-M-"` of
+/// `"C-M-x"`. A prefix is only split off if something follows it, so `"C--"`
+/// is Ctrl and `"-"`.
+fn split_emacs_modifiers(
+    mut step: &str,
+    meta: EmacsMeta,
+) -> Result<(Modifiers, &str), ParseShortcutError> {
+    let (meta, sup) = meta.meta_and_super();
+    let mut modifiers = Modifiers::NONE;
+    loop {
+        let mut chars = step.chars();
+        let modifier = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), Some('-'), Some(_)) => match c {
+                'C' => Modifiers::CTRL,
+                'M' => meta,
+                'S' => Modifiers::SHIFT,
+                's' => sup,
+                // egui doesn't report Hyper, or Alt apart from Meta
+                'H' | 'A' => return Err(ParseShortcutError::Unsupported(step[..2].to_string())),
+                _ => break,
+            },
+            _ => break,
+        };
+        modifiers = modifiers | modifier;
+        step = &step[2..];
+    }
+    Ok((modifiers, step))
+}
+
+/// Parse a single step of an Emacs key sequence, e.g. `"C-x"` or `"<f5>"`.
+fn parse_emacs_step(step: &str, meta: EmacsMeta) -> Result<Shortcut, ParseShortcutError> {
+    let (mut modifiers, rest) = split_emacs_modifiers(step, meta)?;
+
+    let function = rest
+        .strip_prefix('<')
+        .and_then(|rest| rest.strip_suffix('>'))
+        .filter(|inner| !inner.is_empty());
+    let key = if let Some(inner) = function {
+        // modifiers can be inside the brackets too, e.g. "<C-f5>"
+        let (inner_modifiers, name) = split_emacs_modifiers(inner, meta)?;
+        modifiers = modifiers | inner_modifiers;
+        if let Some((button, trigger)) = parse_emacs_mouse(name) {
+            if !modifiers.is_none() {
+                return Err(ParseShortcutError::Unsupported(step.to_string()));
+            }
+            return Ok(Shortcut::new(None, Some(button)).with_pointer_trigger(trigger));
+        }
+        parse_emacs_function_key(name)
+            .ok_or_else(|| ParseShortcutError::UnknownName(name.to_string()))?
+    } else if let Some(key) = parse_emacs_special(rest) {
+        key
+    } else {
+        let mut chars = rest.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return Err(ParseShortcutError::UnknownName(rest.to_string()));
+        };
+        if c.is_ascii_uppercase() {
+            // "C-X" could be Ctrl+X or Ctrl+Shift+X, so it has to be written
+            // as "C-x" or "C-S-x"
+            if !modifiers.is_none() {
+                return Err(ParseShortcutError::Ambiguous(step.to_string()));
+            }
+            modifiers = Modifiers::SHIFT;
+        }
+        match Key::from_name(rest) {
+            Some(key) => key,
+            None => {
+                let mut shortcut = Shortcut::NONE;
+                shortcut.set_text(TextKey::new(modifiers, c), None);
+                return Ok(shortcut);
+            }
+        }
+    };
+    Ok(Shortcut::new(
+        Some(KeyboardShortcut::new(modifiers, key)),
+        None,
+    ))
+}
+
+/// Append the modifier prefixes of Emacs notation, e.g. `"C-M-"`.
+fn push_emacs_modifiers(out: &mut String, modifiers: Modifiers, meta: EmacsMeta) {
+    let (has_meta, has_super) = match meta {
+        EmacsMeta::Alt => (modifiers.alt, modifiers.mac_cmd),
+        EmacsMeta::Cmd => (modifiers.mac_cmd, modifiers.alt),
+    };
+    if modifiers.ctrl || modifiers.command && !modifiers.mac_cmd {
+        out.push_str("C-");
+    }
+    if has_meta {
+        out.push_str("M-");
+    }
+    if modifiers.shift {
+        out.push_str("S-");
+    }
+    if has_super {
+        out.push_str("s-");
+    }
+}
+
+impl Shortcut {
+    /// Parse a shortcut written in Emacs notation, e.g. `"C-s"`, `"M-x"`,
+    /// `"RET"` or `"S-<f5>"`, as written by [`Shortcut::to_emacs_string`].
+    ///
+    /// Modifier prefixes are `C-` (Ctrl), `M-` (Meta), `S-` (Shift) and `s-`
+    /// (Super), where `meta` decides whether Meta is Alt and Super is Cmd or
+    /// the other way around. Keys are characters, `RET`, `SPC`, `TAB`, `DEL`
+    /// (Backspace), `ESC`, or function keys in angle brackets like `<f5>`,
+    /// `<delete>`, `<home>`, `<prior>` (Page Up), `<next>` (Page Down) or
+    /// `<up>`. Modifiers can also be inside the brackets, e.g. `<C-f5>`.
+    /// Mouse buttons are `<mouse-1>` to `<mouse-3>`, `<mouse-8>` and
+    /// `<mouse-9>`, which trigger on click, or `<down-mouse-1>` etc. to
+    /// trigger when pressed. Characters without a key, e.g. `"§"`, are bound
+    /// as a [TextKey]. An empty string is no shortcut.
+    ///
+    /// Like in Emacs, names are case-sensitive: `S-` is Shift and `s-` is
+    /// Super. A bare uppercase letter includes Shift, so `"X"` is Shift+X.
+    /// With modifiers it is ambiguous and an error, so write Ctrl+Shift+X as
+    /// `"C-S-x"` rather than `"C-X"`. Hyper (`H-`) and Emacs' own Alt
+    /// modifier (`A-`) are errors, since egui doesn't report them.
+    ///
+    /// Use [`Shortcut::parse_emacs_chord`] for key sequences like
+    /// `"C-x C-s"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The shortcut in Emacs notation, e.g. `"C-s"`.
+    /// * `meta` - Which modifier `M-` is.
+    ///
+    /// # Returns
+    ///
+    /// The shortcut, or an error naming the part that couldn't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{Bind, EmacsMeta, ParseShortcutError, PointerTrigger, Shortcut, TextKey};
+    ///
+    /// let key = |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+    /// let parse = |s| Shortcut::parse_emacs(s, EmacsMeta::Alt);
+    /// assert_eq!(parse("C-s"), Ok(key(Modifiers::CTRL, Key::S)));
+    /// assert_eq!(parse("M-x"), Ok(key(Modifiers::ALT, Key::X)));
+    /// assert_eq!(parse("s-x"), Ok(key(Modifiers::MAC_CMD, Key::X)));
+    /// assert_eq!(parse("X"), Ok(key(Modifiers::SHIFT, Key::X)));
+    /// assert_eq!(parse("C-S-x"), Ok(key(Modifiers::CTRL | Modifiers::SHIFT, Key::X)));
+    /// assert_eq!(parse("RET"), Ok(key(Modifiers::NONE, Key::Enter)));
+    /// assert_eq!(parse("C-SPC"), Ok(key(Modifiers::CTRL, Key::Space)));
+    /// assert_eq!(parse("DEL"), Ok(key(Modifiers::NONE, Key::Backspace)));
+    /// assert_eq!(parse("<delete>"), Ok(key(Modifiers::NONE, Key::Delete)));
+    /// assert_eq!(parse("S-<f5>"), Ok(key(Modifiers::SHIFT, Key::F5)));
+    /// assert_eq!(parse("<C-f5>"), Ok(key(Modifiers::CTRL, Key::F5)));
+    /// assert_eq!(parse("M-<prior>"), Ok(key(Modifiers::ALT, Key::PageUp)));
+    /// assert_eq!(parse("C--"), Ok(key(Modifiers::CTRL, Key::Minus)));
+    /// assert_eq!(parse(""), Ok(Shortcut::NONE));
+    ///
+    /// let mut text = Shortcut::NONE;
+    /// text.set_text(TextKey::new(Modifiers::CTRL | Modifiers::ALT, '§'), None);
+    /// assert_eq!(parse("C-M-§"), Ok(text));
+    /// assert_eq!(
+    ///     parse("<down-mouse-2>"),
+    ///     Ok(Shortcut::new(None, Some(PointerButton::Middle))),
+    /// );
+    /// assert_eq!(
+    ///     parse("<mouse-3>"),
+    ///     Ok(Shortcut::new(None, Some(PointerButton::Secondary))
+    ///         .with_pointer_trigger(PointerTrigger::Click)),
+    /// );
+    ///
+    /// // Meta is Cmd and Super is Alt
+    /// let parse_cmd = |s| Shortcut::parse_emacs(s, EmacsMeta::Cmd);
+    /// assert_eq!(parse_cmd("M-x"), Ok(key(Modifiers::MAC_CMD, Key::X)));
+    /// assert_eq!(parse_cmd("s-x"), Ok(key(Modifiers::ALT, Key::X)));
+    ///
+    /// // ambiguous and unsupported input
+    /// assert_eq!(parse("C-X"), Err(ParseShortcutError::Ambiguous("C-X".into())));
+    /// assert_eq!(parse("H-x"), Err(ParseShortcutError::Unsupported("H-".into())));
+    /// assert_eq!(parse("<f99>"), Err(ParseShortcutError::UnknownName("f99".into())));
+    /// assert_eq!(parse("ret"), Err(ParseShortcutError::UnknownName("ret".into())));
+    /// assert_eq!(
+    ///     parse("C-<mouse-1>"),
+    ///     Err(ParseShortcutError::Unsupported("C-<mouse-1>".into())),
+    /// );
+    /// assert_eq!(parse("C-x C-s"), Err(ParseShortcutError::TooManySteps));
+    /// ```
+    pub fn parse_emacs(s: &str, meta: EmacsMeta) -> Result<Self, ParseShortcutError> {
+        match Self::parse_emacs_chord(s, meta)? {
+            (shortcut, None) => Ok(shortcut),
+            (_, Some(_)) => Err(ParseShortcutError::TooManySteps),
+        }
+    }
+
+    /// Parse a shortcut or key sequence written in Emacs notation, e.g.
+    /// `"C-x C-s"`. Steps are separated by spaces and each step is parsed
+    /// like [`Shortcut::parse_emacs`].
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The key sequence in Emacs notation, e.g. `"C-x C-s"`.
+    /// * `meta` - Which modifier `M-` is.
+    ///
+    /// # Returns
+    ///
+    /// The first step and the second step if it's a chord, which can be
+    /// passed to [`Hotkeys::register_chord`](crate::Hotkeys::register_chord),
+    /// or an error naming the part that couldn't be parsed. Sequences with
+    /// more than two steps are [`ParseShortcutError::TooManySteps`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{EmacsMeta, ParseShortcutError, Shortcut};
+    ///
+    /// let ctrl = |key| Shortcut::new(Some(KeyboardShortcut::new(Modifiers::CTRL, key)), None);
+    /// let (first, second) = Shortcut::parse_emacs_chord("C-x C-s", EmacsMeta::Alt).unwrap();
+    /// assert_eq!((first, second), (ctrl(Key::X), Some(ctrl(Key::S))));
+    ///
+    /// // written back one step at a time
+    /// let steps = [first, second.unwrap()].map(|step| step.to_emacs_string(EmacsMeta::Alt));
+    /// assert_eq!(steps.join(" "), "C-x C-s");
+    ///
+    /// assert_eq!(
+    ///     Shortcut::parse_emacs_chord("C-c C-x C-l", EmacsMeta::Alt),
+    ///     Err(ParseShortcutError::TooManySteps),
+    /// );
+    /// assert_eq!(
+    ///     Shortcut::parse_emacs_chord("C-x c-s", EmacsMeta::Alt),
+    ///     Err(ParseShortcutError::UnknownName("c-s".into())),
+    /// );
+    /// ```
+    pub fn parse_emacs_chord(
+        s: &str,
+        meta: EmacsMeta,
+    ) -> Result<(Self, Option<Self>), ParseShortcutError> {
+        let mut steps = s.split_whitespace();
+        let Some(first) = steps.next() else {
+            return Ok((Self::NONE, None));
+        };
+        let first = parse_emacs_step(first, meta)?;
+        let second = steps
+            .next()
+            .map(|step| parse_emacs_step(step, meta))
+            .transpose()?;
+        if steps.next().is_some() {
+            return Err(ParseShortcutError::TooManySteps);
+        }
+        Ok((first, second))
+    }
+
+    /// Write the shortcut in Emacs notation, e.g. `"C-s"`, `"X"` or
+    /// `"S-<f5>"`, which [`Shortcut::parse_emacs`] parses back into the same
+    /// shortcut with the same `meta`.
+    ///
+    /// Letters are written lowercase, with Shift as `S-`, except for
+    /// Shift+letter alone, which is written as the uppercase letter. Emacs
+    /// has no cross-platform modifier, so [`Modifiers::COMMAND`] is written as
+    /// `C-`, and no release trigger, so [`PointerTrigger::Release`] is written
+    /// like [`PointerTrigger::Click`]. Emacs binds keys and mouse buttons
+    /// separately, so a shortcut with both is written as its key. No
+    /// shortcut is an empty string.
+    ///
+    /// # Arguments
+    ///
+    /// * `meta` - Which modifier `M-` is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{EmacsMeta, PointerTrigger, Shortcut};
+    ///
+    /// let key = |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+    /// let emacs = |shortcut: Shortcut| shortcut.to_emacs_string(EmacsMeta::Alt);
+    /// assert_eq!(emacs(key(Modifiers::CTRL, Key::S)), "C-s");
+    /// assert_eq!(emacs(key(Modifiers::SHIFT, Key::X)), "X");
+    /// assert_eq!(emacs(key(Modifiers::CTRL | Modifiers::ALT, Key::X)), "C-M-x");
+    /// assert_eq!(emacs(key(Modifiers::SHIFT, Key::F5)), "S-<f5>");
+    /// assert_eq!(emacs(key(Modifiers::NONE, Key::Enter)), "RET");
+    /// assert_eq!(emacs(key(Modifiers::ALT, Key::PageDown)), "M-<next>");
+    /// assert_eq!(key(Modifiers::ALT, Key::X).to_emacs_string(EmacsMeta::Cmd), "s-x");
+    /// assert_eq!(emacs(Shortcut::NONE), "");
+    ///
+    /// // every key round-trips with every combination of modifiers
+    /// let all = [Modifiers::CTRL, Modifiers::SHIFT, Modifiers::ALT, Modifiers::MAC_CMD];
+    /// for meta in [EmacsMeta::Alt, EmacsMeta::Cmd] {
+    ///     for bits in 0..1 << all.len() {
+    ///         let modifiers = (0..all.len())
+    ///             .filter(|i| bits & 1 << i != 0)
+    ///             .fold(Modifiers::NONE, |modifiers, i| modifiers | all[i]);
+    ///         for &k in Key::ALL {
+    ///             let shortcut = key(modifiers, k);
+    ///             let emacs = shortcut.to_emacs_string(meta);
+    ///             assert_eq!(Shortcut::parse_emacs(&emacs, meta), Ok(shortcut), "{emacs:?}");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // and so does every pointer button
+    /// let buttons = [
+    ///     PointerButton::Primary,
+    ///     PointerButton::Secondary,
+    ///     PointerButton::Middle,
+    ///     PointerButton::Extra1,
+    ///     PointerButton::Extra2,
+    /// ];
+    /// for button in buttons {
+    ///     for trigger in [PointerTrigger::Press, PointerTrigger::Click] {
+    ///         let shortcut = Shortcut::new(None, Some(button)).with_pointer_trigger(trigger);
+    ///         let emacs = emacs(shortcut);
+    ///         assert_eq!(Shortcut::parse_emacs(&emacs, EmacsMeta::Alt), Ok(shortcut), "{emacs:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn to_emacs_string(&self, meta: EmacsMeta) -> String {
+        let mut out = String::new();
+        let (modifiers, key) = match (self.text(), self.keyboard()) {
+            (Some(text), _) => (text.modifiers, Err(text.character)),
+            (None, Some(keyboard)) => (keyboard.modifiers, Ok(keyboard.logical_key)),
+            (None, None) => {
+                if let Some(button) = self.pointer() {
+                    let (_, number) = EMACS_POINTER_BUTTONS
+                        .iter()
+                        .find(|(emacs_button, _)| *emacs_button == button)
+                        .expect("every pointer button has an Emacs number");
+                    if self.pointer_trigger() == PointerTrigger::Press {
+                        out.push_str("<down-mouse-");
+                    } else {
+                        out.push_str("<mouse-");
+                    }
+                    out.push_str(number);
+                    out.push('>');
+                }
+                return out;
+            }
+        };
+
+        let character = match key {
+            Ok(key) => emacs_key_char(key),
+            Err(character) => Some(character),
+        };
+        if modifiers == Modifiers::SHIFT && key.is_ok() {
+            if let Some(c) = character.filter(char::is_ascii_lowercase) {
+                out.push(c.to_ascii_uppercase());
+                return out;
+            }
+        }
+        push_emacs_modifiers(&mut out, modifiers, meta);
+        match (character, key) {
+            (Some(c), _) | (None, Err(c)) => out.push(c),
+            (None, Ok(key)) => match emacs_special_name(key) {
+                Some(name) => out.push_str(name),
+                None => {
+                    out.push('<');
+                    out.extend(
+                        emacs_function_key(key)
+                            .chars()
+                            .map(|c| c.to_ascii_lowercase()),
+                    );
+                    out.push('>');
+                }
+            },
+        }
+        out
+    }
+}
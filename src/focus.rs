@@ -0,0 +1,163 @@
+use crate::{Hotkey, Hotkeys, Shortcut};
+use egui::{Context, Id, LayerId};
+
+/// Which window or area has focus, as last noted by
+/// [`Hotkeys::note_focused_area`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct AreaFocus {
+    /// The area with focus, if any.
+    focused: Option<Id>,
+    /// The layer that was last clicked, which keeps focus until another one
+    /// is clicked.
+    clicked: Option<LayerId>,
+}
+
+impl Hotkey {
+    /// The window or area the action is attached to, if any (see
+    /// [`Hotkeys::register_in_area`]).
+    #[inline]
+    pub fn area(&self) -> Option<Id> {
+        self.area
+    }
+}
+
+impl Hotkeys {
+    /// Register an action that is only active while a window or area has
+    /// focus, like [`Hotkeys::register`], e.g. actions of a node editor
+    /// window that mean something else in a log window.
+    ///
+    /// An area has focus when a widget in it has keyboard focus, or else
+    /// when it was clicked last, or else when it's the top window. Focus is
+    /// noted by [`Hotkeys::triggered`], see [`Hotkeys::note_focused_area`].
+    /// Actions in areas that can't have focus together don't conflict.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The ID of the window or area, e.g. `Id::new("Nodes")` for
+    ///   `Window::new("Nodes")`, or the ID set with `Window::id`.
+    /// * `id` - The ID of the action, e.g. `"delete_node"`.
+    /// * `default` - The default shortcut of the action.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Id, Key, Modifiers, PointerButton, RawInput, Window};
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in_area(Id::new("Nodes"), "delete_node", "Delete".parse().unwrap())
+    ///     .register_in_area(Id::new("Log"), "clear_log", "Delete".parse().unwrap());
+    /// assert!(hotkeys.conflicts().is_empty());
+    ///
+    /// let ctx = egui::Context::default();
+    /// let run = |hotkeys: &mut Hotkeys, mut events: Vec<Event>| {
+    ///     events.push(Event::Key {
+    ///         key: Key::Delete,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::NONE,
+    ///     });
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(
+    ///         RawInput {
+    ///             events,
+    ///             ..Default::default()
+    ///         },
+    ///         |ctx| {
+    ///             ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///             for (title, x) in [("Nodes", 0.0), ("Log", 300.0)] {
+    ///                 Window::new(title)
+    ///                     .fixed_pos([x, 0.0])
+    ///                     .show(ctx, |ui| ui.label(title));
+    ///             }
+    ///         },
+    ///     );
+    ///     ids
+    /// };
+    /// let click = |x: f32| {
+    ///     let pos = egui::pos2(x, 20.0);
+    ///     vec![
+    ///         Event::PointerMoved(pos),
+    ///         Event::PointerButton {
+    ///             pos,
+    ///             button: PointerButton::Primary,
+    ///             pressed: true,
+    ///             modifiers: Modifiers::NONE,
+    ///         },
+    ///     ]
+    /// };
+    ///
+    /// // the windows are shown once before they can be clicked
+    /// run(&mut hotkeys, vec![]);
+    /// assert_eq!(run(&mut hotkeys, click(50.0)), ["delete_node"]);
+    /// assert_eq!(hotkeys.focused_area(), Some(Id::new("Nodes")));
+    /// assert_eq!(run(&mut hotkeys, click(350.0)), ["clear_log"]);
+    /// // focus stays with the window that was clicked last
+    /// assert_eq!(run(&mut hotkeys, vec![]), ["clear_log"]);
+    /// ```
+    pub fn register_in_area(
+        &mut self,
+        area: impl Into<Id>,
+        id: impl Into<String>,
+        default: Shortcut,
+    ) -> &mut Self {
+        let id = id.into();
+        self.register(id.clone(), default)
+            .set_area(&id, Some(area.into()))
+    }
+
+    /// Attach an action to a window or area, or detach it with [None], see
+    /// [`Hotkeys::register_in_area`]. Does nothing if the action is not
+    /// registered.
+    pub fn set_area(&mut self, id: &str, area: Option<Id>) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            hotkey.area = area;
+        }
+        self
+    }
+
+    /// Note which window or area has focus, for the actions attached to
+    /// areas (see [`Hotkeys::register_in_area`]). [`Hotkeys::triggered`]
+    /// calls this on every frame, so only call it when checking actions with
+    /// [`Hotkeys::pressed_ctx`] instead, before checking them.
+    ///
+    /// The area of the widget with keyboard focus has focus. Without one,
+    /// the area that was clicked last has it, or the top window if none was
+    /// clicked yet.
+    pub fn note_focused_area(&mut self, ctx: &Context) {
+        let pressed = ctx.input(|input| {
+            input
+                .pointer
+                .any_pressed()
+                .then(|| input.pointer.interact_pos())
+                .flatten()
+        });
+        if let Some(pos) = pressed {
+            self.focus.clicked = ctx.layer_id_at(pos);
+        }
+        let keyboard = ctx
+            .memory(|memory| memory.focused())
+            .and_then(|id| ctx.read_response(id))
+            .map(|response| response.layer_id);
+        let layer = keyboard
+            .or(self.focus.clicked)
+            .or_else(|| ctx.top_layer_id());
+        self.focus.focused = layer.map(|layer| layer.id);
+    }
+
+    /// Get the ID of the window or area that has focus, as last noted by
+    /// [`Hotkeys::note_focused_area`].
+    #[inline]
+    pub fn focused_area(&self) -> Option<Id> {
+        self.focus.focused
+    }
+
+    /// Whether the area of an action has focus, or it has no area.
+    pub(crate) fn is_area_focused(&self, hotkey: &Hotkey) -> bool {
+        hotkey
+            .area
+            .is_none_or(|area| self.focus.focused == Some(area))
+    }
+}
@@ -0,0 +1,357 @@
+use crate::{
+    hotkeys::{is_gamepad_part, GAMEPAD_PREFIX},
+    Hotkey, Hotkeys, ParseShortcutError, TriggerSource, Triggered,
+};
+use egui::{ComboBox, Id, Ui};
+use std::{fmt, str::FromStr};
+
+/// A gamepad button that can trigger an action in [Hotkeys], see
+/// [`Hotkeys::register_gamepad`]. Face buttons are named by their position,
+/// like in [gilrs](https://docs.rs/gilrs), so the same action is on the same
+/// button on every controller.
+///
+/// [egui] doesn't read gamepads, so the app passes the presses of a gamepad
+/// library to [`Hotkeys::press_gamepad`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GamepadButton {
+    /// The bottom face button, e.g. A on Xbox controllers or Cross on PlayStation.
+    South,
+    /// The right face button, e.g. B on Xbox controllers or Circle on PlayStation.
+    East,
+    /// The top face button, e.g. Y on Xbox controllers or Triangle on PlayStation.
+    North,
+    /// The left face button, e.g. X on Xbox controllers or Square on PlayStation.
+    West,
+    /// The left shoulder button.
+    LeftBumper,
+    /// The right shoulder button.
+    RightBumper,
+    /// The left trigger.
+    LeftTrigger,
+    /// The right trigger.
+    RightTrigger,
+    /// The left middle button, e.g. Back or Share.
+    Select,
+    /// The right middle button, e.g. Start or Options.
+    Start,
+    /// The center button, e.g. the Xbox or PS button.
+    Mode,
+    /// Pressing the left stick.
+    LeftStick,
+    /// Pressing the right stick.
+    RightStick,
+    /// Up on the D-pad.
+    DPadUp,
+    /// Down on the D-pad.
+    DPadDown,
+    /// Left on the D-pad.
+    DPadLeft,
+    /// Right on the D-pad.
+    DPadRight,
+}
+
+impl GamepadButton {
+    /// All gamepad buttons.
+    pub const ALL: [Self; 17] = [
+        Self::South,
+        Self::East,
+        Self::North,
+        Self::West,
+        Self::LeftBumper,
+        Self::RightBumper,
+        Self::LeftTrigger,
+        Self::RightTrigger,
+        Self::Select,
+        Self::Start,
+        Self::Mode,
+        Self::LeftStick,
+        Self::RightStick,
+        Self::DPadUp,
+        Self::DPadDown,
+        Self::DPadLeft,
+        Self::DPadRight,
+    ];
+
+    /// The name of the button, e.g. `"South"` or `"LeftBumper"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::South => "South",
+            Self::East => "East",
+            Self::North => "North",
+            Self::West => "West",
+            Self::LeftBumper => "LeftBumper",
+            Self::RightBumper => "RightBumper",
+            Self::LeftTrigger => "LeftTrigger",
+            Self::RightTrigger => "RightTrigger",
+            Self::Select => "Select",
+            Self::Start => "Start",
+            Self::Mode => "Mode",
+            Self::LeftStick => "LeftStick",
+            Self::RightStick => "RightStick",
+            Self::DPadUp => "DPadUp",
+            Self::DPadDown => "DPadDown",
+            Self::DPadLeft => "DPadLeft",
+            Self::DPadRight => "DPadRight",
+        }
+    }
+}
+
+impl fmt::Display for GamepadButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parses the name of a gamepad button like [`GamepadButton::name`],
+/// case-insensitive. The Xbox names of the face buttons (`"A"`, `"B"`,
+/// `"X"`, `"Y"`) are accepted as well.
+impl FromStr for GamepadButton {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseShortcutError::Empty);
+        }
+        let xbox = match s.to_ascii_lowercase().as_str() {
+            "a" => Some(Self::South),
+            "b" => Some(Self::East),
+            "x" => Some(Self::West),
+            "y" => Some(Self::North),
+            _ => None,
+        };
+        xbox.or_else(|| {
+            Self::ALL
+                .into_iter()
+                .find(|button| button.name().eq_ignore_ascii_case(s))
+        })
+        .ok_or_else(|| ParseShortcutError::UnknownName(s.to_string()))
+    }
+}
+
+impl Hotkey {
+    /// The current gamepad button of the action, if it has one (see
+    /// [`Hotkeys::register_gamepad`]).
+    #[inline]
+    pub fn gamepad(&self) -> Option<GamepadButton> {
+        self.gamepad
+    }
+
+    /// The gamepad button the action was registered with, if any.
+    #[inline]
+    pub fn default_gamepad(&self) -> Option<GamepadButton> {
+        self.default_gamepad
+    }
+
+    /// Format the gamepad button for [`Hotkey::format_bindings`]. An action
+    /// without a button is only saved if it has a default button, so that
+    /// saved shortcuts from before it got one still load it.
+    pub(crate) fn format_gamepad(&self) -> Option<String> {
+        match (self.gamepad, self.default_gamepad) {
+            (Some(button), _) => Some(format!("{GAMEPAD_PREFIX}{button}")),
+            (None, Some(_)) => Some(format!("{GAMEPAD_PREFIX}None")),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Take the gamepad button out of the parts of a string made by
+/// [`Hotkey::format_bindings`].
+///
+/// # Returns
+///
+/// The button, `Some(None)` if it was saved as `"Gamepad None"`, or [None]
+/// if there is no button.
+pub(crate) fn take_gamepad(
+    bindings: &mut Vec<&str>,
+) -> Result<Option<Option<GamepadButton>>, ParseShortcutError> {
+    let Some(index) = bindings.iter().position(|part| is_gamepad_part(part)) else {
+        return Ok(None);
+    };
+    let name = bindings.remove(index).trim_start()[GAMEPAD_PREFIX.len()..].trim();
+    if name.eq_ignore_ascii_case("none") {
+        return Ok(Some(None));
+    }
+    name.parse().map(|button| Some(Some(button)))
+}
+
+/// Show a combo box to pick the gamepad button of an action, for the
+/// [KeybindsPanel](crate::KeybindsPanel). Returns whether it was changed.
+pub(crate) fn gamepad_combo(ui: &mut Ui, id: Id, button: &mut Option<GamepadButton>) -> bool {
+    let before = *button;
+    let text = button.map_or_else(|| "None".to_string(), |button| button.to_string());
+    ComboBox::from_id_salt(id)
+        .selected_text(text)
+        .show_ui(ui, |ui| {
+            ui.selectable_value(button, None, "None");
+            for option in GamepadButton::ALL {
+                ui.selectable_value(button, Some(option), option.name());
+            }
+        });
+    *button != before
+}
+
+impl Hotkeys {
+    /// Bind a gamepad button to an action as its default, next to its
+    /// keyboard and pointer shortcuts. The action is triggered by
+    /// [`Hotkeys::triggered`] when the button is passed to
+    /// [`Hotkeys::press_gamepad`], with [`TriggerSource::Gamepad`], e.g. to
+    /// show gamepad button glyphs in the UI after that. Does nothing if the
+    /// action is not registered.
+    ///
+    /// The button is saved with the shortcuts of the action, e.g.
+    /// `"Space, Gamepad South"`. Gamepad buttons only conflict with each
+    /// other, see [`Hotkeys::gamepad_conflicts`]. Changing them is not
+    /// recorded in the history (see [`Hotkeys::undo`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{GamepadButton, Hotkeys, TriggerSource};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("jump", "Space".parse().unwrap())
+    ///     .register_gamepad("jump", GamepadButton::South);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let run = |hotkeys: &mut Hotkeys, input| {
+    ///     let mut sources = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         sources = hotkeys
+    ///             .triggered(ctx)
+    ///             .map(|action| (action.id().to_string(), action.source()))
+    ///             .collect();
+    ///     });
+    ///     sources
+    /// };
+    ///
+    /// // e.g. from a gilrs button event
+    /// hotkeys.press_gamepad(GamepadButton::South);
+    /// let sources = run(&mut hotkeys, RawInput::default());
+    /// assert_eq!(sources, [("jump".to_string(), TriggerSource::Gamepad)]);
+    ///
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::Space,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::NONE,
+    /// });
+    /// let sources = run(&mut hotkeys, input);
+    /// assert_eq!(sources, [("jump".to_string(), TriggerSource::Keyboard)]);
+    ///
+    /// // both are saved
+    /// hotkeys.set_gamepad("jump", Some(GamepadButton::North));
+    /// let saved = hotkeys.to_saved();
+    /// assert_eq!(saved, [("jump".to_string(), "Space, Gamepad North".to_string())]);
+    /// hotkeys.reset_all();
+    /// hotkeys.load_saved(saved);
+    /// assert_eq!(hotkeys.get("jump").unwrap().gamepad(), Some(GamepadButton::North));
+    /// ```
+    pub fn register_gamepad(&mut self, id: &str, button: GamepadButton) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            if hotkey.gamepad == hotkey.default_gamepad {
+                hotkey.gamepad = Some(button);
+            }
+            hotkey.default_gamepad = Some(button);
+        }
+        self
+    }
+
+    /// Set the current gamepad button of an action, or remove it with [None].
+    /// Does nothing if the action is not registered or the active profile is
+    /// a preset. The change can be undone, see [`Hotkeys::undo`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{GamepadButton, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("jump", "Space".parse().unwrap())
+    ///     .register_gamepad("jump", GamepadButton::South);
+    /// hotkeys.set_gamepad("jump", Some(GamepadButton::East));
+    /// assert!(hotkeys.is_modified("jump"));
+    ///
+    /// let undone = hotkeys.undo().unwrap();
+    /// assert_eq!(undone[0].gamepad_after(), Some(GamepadButton::East));
+    /// assert_eq!(hotkeys.get("jump").unwrap().gamepad(), Some(GamepadButton::South));
+    /// hotkeys.redo();
+    /// assert_eq!(hotkeys.get("jump").unwrap().gamepad(), Some(GamepadButton::East));
+    /// ```
+    pub fn set_gamepad(&mut self, id: &str, button: Option<GamepadButton>) -> &mut Self {
+        self.record(|hotkeys| {
+            if let Some(hotkey) = hotkeys.editable_mut(id) {
+                hotkey.gamepad = button;
+            }
+        });
+        self
+    }
+
+    /// Pass a gamepad button press to [Hotkeys], e.g. from the events of a
+    /// gamepad library. The action bound to it is triggered by the next call
+    /// to [`Hotkeys::triggered`], if its scope is active then. Gamepad
+    /// buttons are not affected by the [TextInputPolicy](crate::TextInputPolicy).
+    pub fn press_gamepad(&mut self, button: GamepadButton) {
+        self.gamepad_pressed.push(button);
+    }
+
+    /// Find the gamepad buttons that are bound to more than one action in
+    /// scopes that can be active together (see
+    /// [`Hotkeys::add_exclusive_scopes`]). Keyboard and pointer shortcuts
+    /// are checked separately by [`Hotkeys::conflicts`].
+    ///
+    /// # Returns
+    ///
+    /// Each button with the IDs of its actions, in the order they were
+    /// registered. Only the first one is triggered by the button, besides
+    /// the ones that always fire (see [`Hotkeys::set_always_fire`]).
+    pub fn gamepad_conflicts(&self) -> Vec<(GamepadButton, Vec<String>)> {
+        let mut conflicts = vec![];
+        for button in GamepadButton::ALL {
+            let bound: Vec<&Hotkey> = self
+                .iter()
+                .filter(|hotkey| hotkey.gamepad == Some(button))
+                .collect();
+            let conflicting: Vec<String> = bound
+                .iter()
+                .filter(|hotkey| {
+                    bound
+                        .iter()
+                        .any(|other| other.id() != hotkey.id() && self.can_overlap(hotkey, other))
+                })
+                .map(|hotkey| hotkey.id().to_string())
+                .collect();
+            if !conflicting.is_empty() {
+                conflicts.push((button, conflicting));
+            }
+        }
+        conflicts
+    }
+
+    /// Get the actions triggered by the gamepad buttons pressed since the
+    /// last call, see [`Hotkeys::press_gamepad`].
+    pub(crate) fn dispatch_gamepad(&mut self) -> Vec<Triggered> {
+        let mut triggered: Vec<Triggered> = vec![];
+        for button in std::mem::take(&mut self.gamepad_pressed) {
+            let mut first = true;
+            for hotkey in self.iter() {
+                if hotkey.gamepad != Some(button) || !self.is_hotkey_active(hotkey) {
+                    continue;
+                }
+                if !first && !hotkey.always_fires() {
+                    continue;
+                }
+                first &= hotkey.always_fires();
+                if !triggered.iter().any(|action| action.id() == hotkey.id()) {
+                    triggered.push(Triggered::new(hotkey.id(), TriggerSource::Gamepad));
+                }
+            }
+        }
+        triggered
+    }
+}
@@ -0,0 +1,328 @@
+use crate::{pointer_button_name, Bind, InputKinds, KeyNameMap, TextInputPolicy};
+use egui::{
+    Context, Event, Id, InputState, KeyboardShortcut, ModifierNames, PointerButton, Pos2, Vec2,
+};
+
+/// How far the pointer has to be dragged for a [GestureBind] by default, in
+/// points.
+pub const DEFAULT_GESTURE_DISTANCE: f32 = 50.0;
+
+/// Which way the pointer is dragged for a [GestureBind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GestureDirection {
+    /// Dragged to the left.
+    Left,
+    /// Dragged to the right.
+    Right,
+    /// Dragged up.
+    Up,
+    /// Dragged down.
+    Down,
+}
+
+impl GestureDirection {
+    /// The arrow for the direction, e.g. `"←"` for [`GestureDirection::Left`].
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Left => "←",
+            Self::Right => "→",
+            Self::Up => "↑",
+            Self::Down => "↓",
+        }
+    }
+
+    /// Get the direction a drag predominantly goes in, i.e. the one of its
+    /// longer axis.
+    ///
+    /// # Returns
+    ///
+    /// The direction, or [None] if the drag goes diagonally or not at all.
+    pub fn of(drag: Vec2) -> Option<Self> {
+        let (x, y) = (drag.x.abs(), drag.y.abs());
+        if x > y {
+            Some(if drag.x < 0.0 {
+                Self::Left
+            } else {
+                Self::Right
+            })
+        } else if y > x {
+            // egui's y axis points down
+            Some(if drag.y < 0.0 { Self::Up } else { Self::Down })
+        } else {
+            None
+        }
+    }
+}
+
+/// A mouse gesture: dragging the pointer with a button held, e.g. dragging
+/// left with the secondary button held to go back, like in some browsers.
+///
+/// The bind fires once, on the frame where the button is released, if the
+/// pointer was released farther than [`GestureBind::min_distance`] from
+/// where the button went down, predominantly in [`GestureBind::direction`].
+/// A shorter drag doesn't fire, and neither does one that comes back, so a
+/// gesture can be taken back before releasing the button.
+///
+/// egui forgets where the button went down when it's released, so the bind
+/// remembers it in the [Context] when it's checked with
+/// [`Bind::pressed_unless_typing`] or [`Bind::pressed_with_policy`], which
+/// has to happen on every frame. [Bind::pressed] only has the [InputState]
+/// of one frame, so it only sees a gesture if the button went down on the
+/// frame it's released.
+///
+/// The [Keybind](crate::Keybind) widget records the gesture the user
+/// performs while it's recording, see
+/// [`ShortcutCapture::with_gestures`](crate::ShortcutCapture::with_gestures).
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+/// use egui_keybind::{Bind, GestureBind, GestureDirection};
+///
+/// let binds = [
+///     GestureBind::new(PointerButton::Secondary, GestureDirection::Left),
+///     GestureBind::new(PointerButton::Secondary, GestureDirection::Right),
+///     GestureBind::new(PointerButton::Secondary, GestureDirection::Up),
+///     GestureBind::new(PointerButton::Secondary, GestureDirection::Down),
+/// ];
+/// assert_eq!(binds[0].to_string(), "Mouse Right-drag ←");
+///
+/// let ctx = egui::Context::default();
+/// let mut frame = |events: Vec<Event>| {
+///     let mut fired = vec![];
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         fired = binds.iter().map(|bind| bind.pressed_unless_typing(ctx)).collect();
+///     });
+///     fired
+/// };
+/// let button = |pos: Pos2, pressed| Event::PointerButton {
+///     pos,
+///     button: PointerButton::Secondary,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+/// // drag along a path, and get which binds fired on each frame
+/// let mut drag = |path: &[(f32, f32)]| {
+///     let start = Pos2::new(200.0, 200.0);
+///     let mut fired = vec![frame(vec![Event::PointerMoved(start), button(start, true)])];
+///     let mut pos = start;
+///     for (x, y) in path {
+///         pos = start + egui::vec2(*x, *y);
+///         fired.push(frame(vec![Event::PointerMoved(pos)]));
+///     }
+///     fired.push(frame(vec![button(pos, false)]));
+///     fired
+/// };
+/// let none = vec![false; 4];
+///
+/// // every direction fires once, when the button is released
+/// let left = drag(&[(-20.0, 5.0), (-60.0, 10.0), (-120.0, 0.0)]);
+/// assert_eq!(left.len(), 5);
+/// assert_eq!(left[4], [true, false, false, false]);
+/// assert!(left[..4].iter().all(|fired| *fired == none));
+/// let right = drag(&[(80.0, -10.0)]);
+/// assert_eq!(right[2], [false, true, false, false]);
+/// let up = drag(&[(0.0, -30.0), (5.0, -70.0)]);
+/// assert_eq!(up[3], [false, false, true, false]);
+/// let down = drag(&[(-10.0, 55.0)]);
+/// assert_eq!(down[2], [false, false, false, true]);
+///
+/// // a drag that is too short doesn't fire, and neither does one that comes back
+/// assert!(drag(&[(-30.0, 0.0), (-45.0, 0.0)]).iter().all(|fired| *fired == none));
+/// assert!(drag(&[(-80.0, 0.0), (-10.0, 0.0)]).iter().all(|fired| *fired == none));
+/// // or one that ends up going another way
+/// let turned = drag(&[(-80.0, 0.0), (-80.0, 120.0)]);
+/// assert_eq!(turned[3], [false, false, false, true]);
+/// ```
+///
+/// Recording one with the [Keybind](crate::Keybind) widget:
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+/// use egui_keybind::{GestureBind, GestureDirection, Keybind};
+///
+/// let ctx = egui::Context::default();
+/// let mut bind = GestureBind::new(PointerButton::Secondary, GestureDirection::Left);
+/// let mut frame = |events: Vec<Event>| {
+///     let mut rect = Rect::NOTHING;
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             rect = ui.add(Keybind::new(&mut bind, "gesture")).rect;
+///         });
+///     });
+///     rect
+/// };
+/// let button = |pos: Pos2, button, pressed| Event::PointerButton {
+///     pos,
+///     button,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+///
+/// let pos = frame(vec![]).center();
+/// frame(vec![
+///     Event::PointerMoved(pos),
+///     button(pos, PointerButton::Primary, true),
+///     button(pos, PointerButton::Primary, false),
+/// ]);
+/// frame(vec![button(pos, PointerButton::Middle, true)]);
+/// let end = pos + egui::vec2(10.0, 80.0);
+/// frame(vec![Event::PointerMoved(end)]);
+/// frame(vec![button(end, PointerButton::Middle, false)]);
+/// drop(frame);
+/// assert_eq!(bind.to_string(), "Middle-drag ↓");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GestureBind {
+    /// The button to hold while dragging.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::button_by_name"))]
+    pub button: PointerButton,
+    /// Which way the pointer is dragged.
+    pub direction: GestureDirection,
+    /// How far the pointer has to be dragged, in points.
+    pub min_distance: f32,
+}
+
+impl GestureBind {
+    /// Create a new [GestureBind], with a minimum distance of
+    /// [DEFAULT_GESTURE_DISTANCE].
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - The button to hold while dragging.
+    /// * `direction` - Which way the pointer is dragged.
+    pub const fn new(button: PointerButton, direction: GestureDirection) -> Self {
+        Self {
+            button,
+            direction,
+            min_distance: DEFAULT_GESTURE_DISTANCE,
+        }
+    }
+
+    /// Set how far the pointer has to be dragged, in points.
+    pub fn with_min_distance(mut self, min_distance: f32) -> Self {
+        self.min_distance = min_distance;
+        self
+    }
+}
+
+impl GestureBind {
+    /// Whether a drag from where the button went down to where it was
+    /// released is the gesture.
+    fn is_gesture(&self, drag: Vec2) -> bool {
+        drag.length() >= self.min_distance && GestureDirection::of(drag) == Some(self.direction)
+    }
+}
+
+/// Get how far the pointer was dragged with `button` held, if the button was
+/// released on this frame. `origin` is where it went down on an earlier
+/// frame, if it's known.
+fn released_drag(input: &InputState, button: PointerButton, origin: Option<Pos2>) -> Option<Vec2> {
+    let mut origin = origin;
+    for event in &input.events {
+        match event {
+            Event::PointerButton {
+                pos,
+                button: pressed_button,
+                pressed,
+                ..
+            } if *pressed_button == button => match pressed {
+                true => origin = Some(*pos),
+                false => return Some(*pos - origin?),
+            },
+            _ => {}
+        }
+    }
+    None
+}
+
+impl std::fmt::Display for GestureBind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-drag {}",
+            pointer_button_name(self.button),
+            self.direction.arrow()
+        )
+    }
+}
+
+/// Ignores keyboard shortcuts. Setting a pointer button keeps the direction.
+impl Bind for GestureBind {
+    fn set(&mut self, _keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        if let Some(pointer) = pointer {
+            self.button = pointer;
+        }
+    }
+
+    fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
+        self.to_string()
+    }
+
+    fn format_with_key_names(
+        &self,
+        _names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        _is_mac: bool,
+    ) -> String {
+        format!(
+            "{}-drag {}",
+            key_names.pointer_button_name(self.button),
+            self.direction.arrow()
+        )
+    }
+
+    /// Triggered on the frame where the button is released, if it went down
+    /// on the same frame.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        released_drag(input, self.button, None).is_some_and(|drag| self.is_gesture(drag))
+    }
+
+    /// Triggered on the frame where the button is released, remembering
+    /// where it went down in the [Context].
+    fn pressed_with_policy(&self, ctx: &Context, policy: TextInputPolicy) -> bool {
+        // `wants_keyboard_input` and `input` lock the context one after the other
+        let typing = ctx.wants_keyboard_input();
+        let id = Id::new(("egui_keybind::GestureBind", self.button as usize));
+        let origin = ctx.data(|data| data.get_temp::<Pos2>(id));
+        let (held_from, drag) = ctx.input(|input| {
+            let held_from = input
+                .pointer
+                .press_origin()
+                .filter(|_| input.pointer.button_down(self.button));
+            (held_from, released_drag(input, self.button, origin))
+        });
+        // other gesture binds on the same button need it on the frame of the release too
+        ctx.data_mut(|data| match (held_from, drag) {
+            (Some(origin), _) => data.insert_temp(id, origin),
+            (None, Some(_)) => {}
+            (None, None) => data.remove::<Pos2>(id),
+        });
+        (!typing || policy.allows(self)) && drag.is_some_and(|drag| self.is_gesture(drag))
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        Some(self.button)
+    }
+
+    fn set_gesture(&mut self, button: PointerButton, direction: GestureDirection) -> bool {
+        self.button = button;
+        self.direction = direction;
+        true
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::POINTER
+    }
+}
@@ -0,0 +1,557 @@
+use crate::{Bind, Hotkeys, Shortcut};
+use egui::Key;
+use global_hotkey::{
+    hotkey::{Code, HotKey, Modifiers},
+    GlobalHotKeyEvent, GlobalHotKeyManager, HotKeyState,
+};
+use std::fmt;
+
+/// Registers OS-global hotkeys for a [GlobalSync]. This is implemented for
+/// [GlobalHotKeyManager], and can be implemented by a mock in tests.
+pub trait GlobalRegistrar {
+    /// Register a hotkey with the OS.
+    fn register(&mut self, hotkey: HotKey) -> global_hotkey::Result<()>;
+
+    /// Unregister a hotkey that was registered with [`GlobalRegistrar::register`].
+    fn unregister(&mut self, hotkey: HotKey) -> global_hotkey::Result<()>;
+}
+
+impl GlobalRegistrar for GlobalHotKeyManager {
+    fn register(&mut self, hotkey: HotKey) -> global_hotkey::Result<()> {
+        GlobalHotKeyManager::register(self, hotkey)
+    }
+
+    fn unregister(&mut self, hotkey: HotKey) -> global_hotkey::Result<()> {
+        GlobalHotKeyManager::unregister(self, hotkey)
+    }
+}
+
+/// Why a shortcut couldn't be registered as an OS-global hotkey, see
+/// [`GlobalSync::issues`].
+#[derive(Debug)]
+pub enum GlobalBindError {
+    /// The shortcut has a pointer button, which can't be a global hotkey.
+    PointerButton,
+    /// The shortcut has no keyboard key, e.g. because it's a text key (see
+    /// [TextKey](crate::TextKey)) or it's unbound.
+    NoKey,
+    /// The key is pressed without modifiers, so as a global hotkey it would
+    /// be taken from every other app, e.g. while typing.
+    NoModifiers(Key),
+    /// The action is a chord (see [`Hotkeys::register_chord`]), which the OS
+    /// can't wait for.
+    Chord,
+    /// The key has no physical key code, e.g. [`Key::Plus`], which is typed
+    /// with different keys on different keyboard layouts.
+    UnsupportedKey(Key),
+    /// The hotkey is already used by another action, or couldn't be
+    /// registered with the OS.
+    Register(global_hotkey::Error),
+}
+
+impl fmt::Display for GlobalBindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PointerButton => write!(f, "pointer buttons can't be global hotkeys"),
+            Self::NoKey => write!(f, "only keyboard keys can be global hotkeys"),
+            Self::NoModifiers(key) => write!(
+                f,
+                "the key \"{}\" needs a modifier to be a global hotkey",
+                key.name()
+            ),
+            Self::Chord => write!(f, "chords can't be global hotkeys"),
+            Self::UnsupportedKey(key) => {
+                write!(f, "the key \"{}\" can't be a global hotkey", key.name())
+            }
+            Self::Register(err) => write!(f, "couldn't register the global hotkey: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for GlobalBindError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Register(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A shortcut of an action that isn't registered as an OS-global hotkey, see
+/// [`GlobalSync::issues`].
+#[derive(Debug)]
+pub struct GlobalBindIssue {
+    id: String,
+    shortcut: Shortcut,
+    hotkey: Option<HotKey>,
+    error: GlobalBindError,
+}
+
+impl GlobalBindIssue {
+    /// The ID of the action.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The shortcut that isn't registered.
+    #[inline]
+    pub fn shortcut(&self) -> &Shortcut {
+        &self.shortcut
+    }
+
+    /// Why the shortcut isn't registered.
+    #[inline]
+    pub fn error(&self) -> &GlobalBindError {
+        &self.error
+    }
+}
+
+/// Convert a shortcut to an OS-global hotkey. [`Modifiers::command`](egui::Modifiers::command)
+/// is Cmd if `is_mac` is true, and Ctrl otherwise.
+///
+/// # Errors
+///
+/// A [GlobalBindError] if the shortcut has a pointer button, no key, no
+/// modifiers or a key without a physical key code.
+///
+/// # Example
+///
+/// ```
+/// use egui_keybind::{to_global_hotkey, GlobalBindError};
+///
+/// let hotkey = to_global_hotkey(&"Ctrl+Alt+F5".parse().unwrap(), false).unwrap();
+/// assert_eq!(hotkey.into_string(), "control+alt+F5");
+/// let hotkey = to_global_hotkey(&"Cmd+1".parse().unwrap(), true).unwrap();
+/// assert_eq!(hotkey.into_string(), "super+Digit1");
+///
+/// let error = to_global_hotkey(&"Ctrl+Plus".parse().unwrap(), false).unwrap_err();
+/// assert!(matches!(error, GlobalBindError::UnsupportedKey(egui::Key::Plus)));
+/// // a key alone would be taken from every app
+/// let error = to_global_hotkey(&"F5".parse().unwrap(), false).unwrap_err();
+/// assert!(matches!(error, GlobalBindError::NoModifiers(egui::Key::F5)));
+/// let error = to_global_hotkey(&"Ctrl+§".parse().unwrap(), false).unwrap_err();
+/// assert!(matches!(error, GlobalBindError::NoKey));
+/// ```
+pub fn to_global_hotkey(shortcut: &Shortcut, is_mac: bool) -> Result<HotKey, GlobalBindError> {
+    if shortcut.pointer().is_some() {
+        return Err(GlobalBindError::PointerButton);
+    }
+    let Some(keyboard) = shortcut.keyboard() else {
+        return Err(GlobalBindError::NoKey);
+    };
+    let key = keyboard.logical_key;
+    if keyboard.modifiers.is_none() {
+        return Err(GlobalBindError::NoModifiers(key));
+    }
+    let code = key_code(key).ok_or(GlobalBindError::UnsupportedKey(key))?;
+
+    let modifiers = keyboard.modifiers;
+    let mut mods = Modifiers::empty();
+    mods.set(Modifiers::ALT, modifiers.alt);
+    mods.set(Modifiers::SHIFT, modifiers.shift);
+    mods.set(
+        Modifiers::CONTROL,
+        modifiers.ctrl || (modifiers.command && !is_mac),
+    );
+    mods.set(
+        Modifiers::SUPER,
+        modifiers.mac_cmd || (modifiers.command && is_mac),
+    );
+    Ok(HotKey::new(Some(mods), code))
+}
+
+/// Get the physical key code of a key on a US keyboard, if it has one.
+fn key_code(key: Key) -> Option<Code> {
+    let letters = [
+        Code::KeyA,
+        Code::KeyB,
+        Code::KeyC,
+        Code::KeyD,
+        Code::KeyE,
+        Code::KeyF,
+        Code::KeyG,
+        Code::KeyH,
+        Code::KeyI,
+        Code::KeyJ,
+        Code::KeyK,
+        Code::KeyL,
+        Code::KeyM,
+        Code::KeyN,
+        Code::KeyO,
+        Code::KeyP,
+        Code::KeyQ,
+        Code::KeyR,
+        Code::KeyS,
+        Code::KeyT,
+        Code::KeyU,
+        Code::KeyV,
+        Code::KeyW,
+        Code::KeyX,
+        Code::KeyY,
+        Code::KeyZ,
+    ];
+    let digits = [
+        Code::Digit0,
+        Code::Digit1,
+        Code::Digit2,
+        Code::Digit3,
+        Code::Digit4,
+        Code::Digit5,
+        Code::Digit6,
+        Code::Digit7,
+        Code::Digit8,
+        Code::Digit9,
+    ];
+    let function_keys = [
+        Code::F1,
+        Code::F2,
+        Code::F3,
+        Code::F4,
+        Code::F5,
+        Code::F6,
+        Code::F7,
+        Code::F8,
+        Code::F9,
+        Code::F10,
+        Code::F11,
+        Code::F12,
+        Code::F13,
+        Code::F14,
+        Code::F15,
+        Code::F16,
+        Code::F17,
+        Code::F18,
+        Code::F19,
+        Code::F20,
+        Code::F21,
+        Code::F22,
+        Code::F23,
+        Code::F24,
+        Code::F25,
+        Code::F26,
+        Code::F27,
+        Code::F28,
+        Code::F29,
+        Code::F30,
+        Code::F31,
+        Code::F32,
+        Code::F33,
+        Code::F34,
+        Code::F35,
+    ];
+
+    let code = match key {
+        Key::ArrowDown => Code::ArrowDown,
+        Key::ArrowLeft => Code::ArrowLeft,
+        Key::ArrowRight => Code::ArrowRight,
+        Key::ArrowUp => Code::ArrowUp,
+        Key::Escape => Code::Escape,
+        Key::Tab => Code::Tab,
+        Key::Backspace => Code::Backspace,
+        Key::Enter => Code::Enter,
+        Key::Space => Code::Space,
+        Key::Insert => Code::Insert,
+        Key::Delete => Code::Delete,
+        Key::Home => Code::Home,
+        Key::End => Code::End,
+        Key::PageUp => Code::PageUp,
+        Key::PageDown => Code::PageDown,
+        Key::Copy => Code::Copy,
+        Key::Cut => Code::Cut,
+        Key::Paste => Code::Paste,
+        Key::Comma => Code::Comma,
+        Key::Backslash => Code::Backslash,
+        Key::Slash => Code::Slash,
+        Key::OpenBracket => Code::BracketLeft,
+        Key::CloseBracket => Code::BracketRight,
+        Key::Backtick => Code::Backquote,
+        Key::Minus => Code::Minus,
+        Key::Period => Code::Period,
+        Key::Equals => Code::Equal,
+        Key::Semicolon => Code::Semicolon,
+        Key::Quote => Code::Quote,
+        // typed with Shift on a US keyboard, and different keys elsewhere
+        Key::Colon | Key::Pipe | Key::Questionmark | Key::Plus => return None,
+        key => {
+            let name = key.name();
+            let index = |first: char| {
+                let c = name.chars().next()?;
+                (name.len() == 1).then(|| c as usize - first as usize)
+            };
+            if let Some(number) = name.strip_prefix('F').filter(|n| !n.is_empty()) {
+                *function_keys.get(number.parse::<usize>().ok()?.checked_sub(1)?)?
+            } else if name.chars().all(|c| c.is_ascii_uppercase()) {
+                letters[index('A')?]
+            } else if name.chars().all(|c| c.is_ascii_digit()) {
+                digits[index('0')?]
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(code)
+}
+
+/// Keeps the shortcuts of [Hotkeys] registered as OS-global hotkeys, so
+/// they also work while the app is not focused, e.g. in a background utility.
+///
+/// Every bound shortcut of every action is registered, so use separate
+/// [Hotkeys] for the actions that should be global. Call
+/// [`GlobalSync::sync`] after the shortcuts change (or every frame, it only
+/// registers what changed), and [`GlobalSync::poll`] every frame, so the
+/// actions are returned by [`Hotkeys::triggered`] with
+/// [`TriggerSource::Global`](crate::TriggerSource::Global). All hotkeys are
+/// unregistered when the [GlobalSync] is dropped.
+///
+/// Shortcuts that can't be global, e.g. ones with a pointer button or keys
+/// without modifiers, are listed in [`GlobalSync::issues`] instead of being dropped.
+///
+/// # Example
+///
+/// With a mocked [GlobalRegistrar]:
+///
+/// ```
+/// use egui_keybind::{GlobalBindError, GlobalRegistrar, GlobalSync, Hotkeys, TriggerSource};
+/// use global_hotkey::{hotkey::HotKey, GlobalHotKeyEvent, HotKeyState};
+/// use std::{cell::RefCell, rc::Rc};
+///
+/// #[derive(Clone, Default)]
+/// struct Mock(Rc<RefCell<Vec<String>>>);
+///
+/// impl GlobalRegistrar for Mock {
+///     fn register(&mut self, hotkey: HotKey) -> global_hotkey::Result<()> {
+///         self.0.borrow_mut().push(hotkey.into_string());
+///         Ok(())
+///     }
+///
+///     fn unregister(&mut self, hotkey: HotKey) -> global_hotkey::Result<()> {
+///         self.0.borrow_mut().retain(|other| *other != hotkey.into_string());
+///         Ok(())
+///     }
+/// }
+///
+/// let mut hotkeys = Hotkeys::new();
+/// hotkeys
+///     .register("record", "Ctrl+Shift+R".parse().unwrap())
+///     .register("stop", "Ctrl+Shift+S".parse().unwrap())
+///     .register("mark", "Mouse 4".parse().unwrap())
+///     .register("help", "F1".parse().unwrap());
+///
+/// let registered = Mock::default();
+/// let mut sync = GlobalSync::with_registrar(registered.clone());
+/// sync.sync(&hotkeys);
+/// assert_eq!(*registered.0.borrow(), ["shift+control+KeyR", "shift+control+KeyS"]);
+///
+/// // the pointer button and the key without modifiers are reported
+/// assert_eq!(sync.issues().len(), 2);
+/// assert_eq!(sync.issues()[0].id(), "mark");
+/// assert!(matches!(sync.issues()[0].error(), GlobalBindError::PointerButton));
+/// assert!(matches!(sync.issues()[1].error(), GlobalBindError::NoModifiers(_)));
+///
+/// // changed shortcuts are registered again
+/// hotkeys.set("stop", "Ctrl+Shift+T".parse().unwrap());
+/// sync.sync(&hotkeys);
+/// assert_eq!(*registered.0.borrow(), ["shift+control+KeyR", "shift+control+KeyT"]);
+///
+/// // presses are mapped back to the actions
+/// let event = GlobalHotKeyEvent {
+///     id: sync.registered_hotkey("stop").unwrap().id(),
+///     state: HotKeyState::Pressed,
+/// };
+/// assert!(sync.handle_event(&mut hotkeys, event));
+/// let ctx = egui::Context::default();
+/// let _ = ctx.run(Default::default(), |ctx| {
+///     let triggered: Vec<_> = hotkeys.triggered(ctx).collect();
+///     assert_eq!(triggered[0].id(), "stop");
+///     assert_eq!(triggered[0].source(), TriggerSource::Global);
+/// });
+///
+/// // and everything is unregistered when it's dropped
+/// drop(sync);
+/// assert!(registered.0.borrow().is_empty());
+/// ```
+pub struct GlobalSync<R: GlobalRegistrar = GlobalHotKeyManager> {
+    registrar: R,
+    registered: Vec<(String, HotKey)>,
+    issues: Vec<GlobalBindIssue>,
+    is_mac: bool,
+}
+
+impl GlobalSync {
+    /// Create a new [GlobalSync] with a [GlobalHotKeyManager]. On some
+    /// platforms this has to be done on the main thread.
+    ///
+    /// # Errors
+    ///
+    /// The error of [`GlobalHotKeyManager::new`].
+    pub fn new() -> global_hotkey::Result<Self> {
+        Ok(Self::with_registrar(GlobalHotKeyManager::new()?))
+    }
+}
+
+impl<R: GlobalRegistrar> GlobalSync<R> {
+    /// Create a new [GlobalSync] that registers the hotkeys with `registrar`.
+    pub fn with_registrar(registrar: R) -> Self {
+        Self {
+            registrar,
+            registered: vec![],
+            issues: vec![],
+            is_mac: cfg!(target_os = "macos"),
+        }
+    }
+
+    /// Get the [GlobalRegistrar].
+    #[inline]
+    pub fn registrar(&self) -> &R {
+        &self.registrar
+    }
+
+    /// Register the shortcuts of all actions that aren't registered yet, and
+    /// unregister the ones that were changed or removed.
+    ///
+    /// Shortcuts that failed to register are not tried again until they
+    /// change, see [`GlobalSync::issues`].
+    pub fn sync(&mut self, hotkeys: &Hotkeys) {
+        let mut wanted: Vec<(String, Shortcut, Result<HotKey, GlobalBindError>)> = vec![];
+        for hotkey in hotkeys {
+            for shortcut in hotkey.bindings().filter(|shortcut| shortcut.is_bound()) {
+                let global = match hotkey.second_step() {
+                    Some(_) => Err(GlobalBindError::Chord),
+                    None => to_global_hotkey(shortcut, self.is_mac),
+                };
+                wanted.push((hotkey.id().to_string(), *shortcut, global));
+            }
+        }
+
+        let is_wanted = |id: &str, hotkey: &HotKey| {
+            wanted
+                .iter()
+                .any(|(other, _, global)| other == id && global.as_ref().ok() == Some(hotkey))
+        };
+        let (kept, removed): (Vec<_>, Vec<_>) = std::mem::take(&mut self.registered)
+            .into_iter()
+            .partition(|(id, hotkey)| is_wanted(id, hotkey));
+        for (_, hotkey) in removed {
+            // the hotkey is forgotten either way, there's nothing else to do with it
+            let _ = self.registrar.unregister(hotkey);
+        }
+        self.registered = kept;
+
+        let mut issues = vec![];
+        for (id, shortcut, global) in wanted {
+            let hotkey = match global {
+                Ok(hotkey) => hotkey,
+                Err(error) => {
+                    issues.push(GlobalBindIssue {
+                        id,
+                        shortcut,
+                        hotkey: None,
+                        error,
+                    });
+                    continue;
+                }
+            };
+            if self
+                .registered
+                .iter()
+                .any(|(other, registered)| *other == id && *registered == hotkey)
+            {
+                continue;
+            }
+            // don't retry what failed before
+            let failed = self
+                .issues
+                .iter()
+                .position(|issue| issue.id == id && issue.hotkey == Some(hotkey));
+            if let Some(index) = failed {
+                issues.push(self.issues.swap_remove(index));
+                continue;
+            }
+
+            let result = if self.registered.iter().any(|(_, other)| *other == hotkey) {
+                Err(global_hotkey::Error::AlreadyRegistered(hotkey))
+            } else {
+                self.registrar.register(hotkey)
+            };
+            match result {
+                Ok(()) => self.registered.push((id, hotkey)),
+                Err(err) => issues.push(GlobalBindIssue {
+                    id,
+                    shortcut,
+                    hotkey: Some(hotkey),
+                    error: GlobalBindError::Register(err),
+                }),
+            }
+        }
+        self.issues = issues;
+    }
+
+    /// Get the shortcuts that couldn't be registered by the last
+    /// [`GlobalSync::sync`], e.g. to show a warning next to them.
+    #[inline]
+    pub fn issues(&self) -> &[GlobalBindIssue] {
+        &self.issues
+    }
+
+    /// Get the first global hotkey that is registered for an action.
+    pub fn registered_hotkey(&self, id: &str) -> Option<HotKey> {
+        self.registered
+            .iter()
+            .find(|(other, _)| other == id)
+            .map(|(_, hotkey)| *hotkey)
+    }
+
+    /// Get the action of a registered global hotkey from its ID (see [`HotKey::id`]).
+    pub fn action_of(&self, hotkey_id: u32) -> Option<&str> {
+        self.registered
+            .iter()
+            .find(|(_, hotkey)| hotkey.id() == hotkey_id)
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Queue the action of a global hotkey event, so it's returned by the
+    /// next call to [`Hotkeys::triggered`]. Released hotkeys are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Whether the event triggered an action.
+    pub fn handle_event(&self, hotkeys: &mut Hotkeys, event: GlobalHotKeyEvent) -> bool {
+        if event.state() != HotKeyState::Pressed {
+            return false;
+        }
+        match self.action_of(event.id()) {
+            Some(id) => {
+                hotkeys.global_triggered.push(id.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Handle all events from [`GlobalHotKeyEvent::receiver`] with
+    /// [`GlobalSync::handle_event`]. Call this every frame before
+    /// [`Hotkeys::triggered`]. The app may need to be repainted when an event
+    /// arrives while it's in the background, e.g. with
+    /// [`GlobalHotKeyEvent::set_event_handler`] and
+    /// [`Context::request_repaint`](egui::Context::request_repaint).
+    pub fn poll(&self, hotkeys: &mut Hotkeys) {
+        for event in GlobalHotKeyEvent::receiver().try_iter() {
+            self.handle_event(hotkeys, event);
+        }
+    }
+
+    /// Unregister all global hotkeys.
+    pub fn clear(&mut self) {
+        for (_, hotkey) in self.registered.drain(..) {
+            let _ = self.registrar.unregister(hotkey);
+        }
+        self.issues.clear();
+    }
+}
+
+impl<R: GlobalRegistrar> Drop for GlobalSync<R> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
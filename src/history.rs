@@ -0,0 +1,410 @@
+use crate::{Hotkey, Hotkeys, Shortcut, Shortcuts};
+use std::collections::VecDeque;
+
+/// How many entries [Hotkeys] keeps in its history by default, see
+/// [`Hotkeys::set_history_limit`].
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// A change to the shortcuts of one action in the history of [Hotkeys], see
+/// [`Hotkeys::undo`].
+///
+/// The shortcuts are in the order of [`Hotkey::bindings`]: the current
+/// shortcut, which may be unbound, then the alternate ones. With the
+/// `gamepad` feature, the gamepad button of the action is kept as well.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    id: String,
+    before: Snapshot,
+    after: Snapshot,
+}
+
+/// Everything of an action that is kept in the history, see [Change].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Snapshot {
+    shortcuts: Shortcuts,
+    #[cfg(feature = "gamepad")]
+    gamepad: Option<crate::GamepadButton>,
+}
+
+impl Change {
+    /// The ID of the changed action.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The shortcuts of the action before the change.
+    #[inline]
+    pub fn before(&self) -> &Shortcuts {
+        &self.before.shortcuts
+    }
+
+    /// The shortcuts of the action after the change.
+    #[inline]
+    pub fn after(&self) -> &Shortcuts {
+        &self.after.shortcuts
+    }
+
+    /// The gamepad button of the action before the change.
+    #[cfg(feature = "gamepad")]
+    #[inline]
+    pub fn gamepad_before(&self) -> Option<crate::GamepadButton> {
+        self.before.gamepad
+    }
+
+    /// The gamepad button of the action after the change.
+    #[cfg(feature = "gamepad")]
+    #[inline]
+    pub fn gamepad_after(&self) -> Option<crate::GamepadButton> {
+        self.after.gamepad
+    }
+}
+
+/// The undo and redo stacks of [Hotkeys]. Each entry holds all changes of one
+/// operation, e.g. both actions of a swap.
+#[derive(Debug, Clone)]
+pub(crate) struct History {
+    undo: VecDeque<Vec<Change>>,
+    redo: Vec<Vec<Change>>,
+    limit: usize,
+    /// Whether an operation is being recorded, so the operations it is made
+    /// of aren't recorded separately.
+    recording: bool,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: vec![],
+            limit: DEFAULT_HISTORY_LIMIT,
+            recording: false,
+        }
+    }
+}
+
+impl Hotkey {
+    /// Get all shortcuts of the action, and its gamepad button, see [Change].
+    pub(crate) fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            shortcuts: self.bindings().copied().collect::<Vec<_>>().into(),
+            #[cfg(feature = "gamepad")]
+            gamepad: self.gamepad,
+        }
+    }
+
+    /// Set all shortcuts of the action, and its gamepad button, from a
+    /// [Hotkey::snapshot].
+    fn restore(&mut self, snapshot: &Snapshot) {
+        let mut shortcuts = snapshot.shortcuts.iter().copied();
+        *self.current_mut() = shortcuts.next().unwrap_or(Shortcut::NONE);
+        *self.alternates_mut() = shortcuts.collect::<Vec<_>>().into();
+        #[cfg(feature = "gamepad")]
+        {
+            self.gamepad = snapshot.gamepad;
+        }
+    }
+}
+
+impl Hotkeys {
+    /// Run an operation and record everything it changed as one entry in the
+    /// history. Operations run by `operation` are not recorded separately.
+    pub(crate) fn record<R>(&mut self, operation: impl FnOnce(&mut Self) -> R) -> R {
+        if self.history.recording {
+            return operation(self);
+        }
+        let before: Vec<Snapshot> = self.iter().map(Hotkey::snapshot).collect();
+        self.history.recording = true;
+        let result = operation(self);
+        self.history.recording = false;
+
+        let changes = self
+            .iter()
+            .zip(before)
+            .filter_map(|(hotkey, before)| {
+                let after = hotkey.snapshot();
+                (after != before).then(|| Change {
+                    id: hotkey.id().to_string(),
+                    before,
+                    after,
+                })
+            })
+            .collect();
+        self.push_history(changes);
+        result
+    }
+
+    /// Make several changes to the shortcuts as one entry in the history, so
+    /// they are undone together, e.g. when applying a list of changes. The
+    /// shortcuts are only compared before and after the whole batch, instead
+    /// of around each change, so it's also faster than making the changes
+    /// one by one with many actions.
+    ///
+    /// # Returns
+    ///
+    /// What `changes` returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    /// hotkeys.batch(|hotkeys| {
+    ///     hotkeys.set("save", "F2".parse().unwrap());
+    ///     hotkeys.set("find", "F3".parse().unwrap());
+    /// });
+    /// assert_eq!(hotkeys.undo().unwrap().len(), 2);
+    /// ```
+    pub fn batch<R>(&mut self, changes: impl FnOnce(&mut Self) -> R) -> R {
+        self.record(changes)
+    }
+
+    /// Record a change to the shortcuts of an action that was made without
+    /// [`Hotkeys::record`], e.g. by a widget. `before` is a [Hotkey::snapshot]
+    /// from before the change.
+    pub(crate) fn record_edit(&mut self, id: &str, before: Snapshot) {
+        let Some(after) = self.get(id).map(Hotkey::snapshot) else {
+            return;
+        };
+        if after != before {
+            let id = id.to_string();
+            self.push_history(vec![Change { id, before, after }]);
+        }
+    }
+
+    fn push_history(&mut self, changes: Vec<Change>) {
+        let history = &mut self.history;
+        if changes.is_empty() || history.limit == 0 {
+            return;
+        }
+        history.redo.clear();
+        history.undo.push_back(changes);
+        while history.undo.len() > history.limit {
+            history.undo.pop_front();
+        }
+    }
+
+    /// Undo the last change to the shortcuts, e.g. from [`Hotkeys::set`] or
+    /// [`Hotkeys::assign`]. A change that moved a shortcut between actions
+    /// is undone for all of them at once.
+    ///
+    /// [`Hotkeys::reset_all`] and loading shortcuts (e.g. with
+    /// [`Hotkeys::import_string`]) clear the history, so they can't be undone.
+    /// Changes made through [`Hotkeys::bind_mut`], [`Hotkeys::iter_mut`] or
+    /// [`Hotkey::current_mut`] are not recorded. The history is never
+    /// serialized.
+    ///
+    /// # Returns
+    ///
+    /// The changes that were undone, or [None] if there is nothing to undo.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    /// hotkeys.set("save", "F2".parse().unwrap());
+    ///
+    /// assert_eq!(hotkeys.undo().unwrap()[0].id(), "save");
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+S");
+    /// hotkeys.redo();
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "F2");
+    /// ```
+    pub fn undo(&mut self) -> Option<&[Change]> {
+        let changes = self.history.undo.pop_back()?;
+        for change in &changes {
+            if let Some(hotkey) = self.get_mut(&change.id) {
+                hotkey.restore(&change.before);
+            }
+        }
+        self.history.redo.push(changes);
+        self.history.redo.last().map(Vec::as_slice)
+    }
+
+    /// Redo the last change that was undone with [`Hotkeys::undo`].
+    ///
+    /// # Returns
+    ///
+    /// The changes that were redone, or [None] if there is nothing to redo.
+    pub fn redo(&mut self) -> Option<&[Change]> {
+        let changes = self.history.redo.pop()?;
+        for change in &changes {
+            if let Some(hotkey) = self.get_mut(&change.id) {
+                hotkey.restore(&change.after);
+            }
+        }
+        self.history.undo.push_back(changes);
+        self.history.undo.back().map(Vec::as_slice)
+    }
+
+    /// Whether there is a change to undo, e.g. to enable an undo button.
+    #[inline]
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    /// Whether there is a change to redo, e.g. to enable a redo button.
+    #[inline]
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Forget all changes, so they can't be undone or redone.
+    pub fn clear_history(&mut self) {
+        self.history.undo.clear();
+        self.history.redo.clear();
+    }
+
+    /// Set how many changes are kept to undo. The oldest changes are
+    /// forgotten first, and 0 turns the history off. By default this is
+    /// [DEFAULT_HISTORY_LIMIT].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    /// hotkeys.set_history_limit(2);
+    /// for shortcut in ["F1", "F2", "F3"] {
+    ///     hotkeys.set("save", shortcut.parse().unwrap());
+    /// }
+    ///
+    /// hotkeys.undo();
+    /// hotkeys.undo();
+    /// assert!(!hotkeys.can_undo());
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "F1");
+    /// ```
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history.limit = limit;
+        let undo = &mut self.history.undo;
+        while undo.len() > limit {
+            undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConflictPolicy;
+
+    fn current(hotkeys: &Hotkeys, id: &str) -> String {
+        hotkeys.current(id).unwrap().to_string()
+    }
+
+    fn hotkeys() -> Hotkeys {
+        let mut hotkeys = Hotkeys::new();
+        hotkeys
+            .register("save", "Ctrl+S".parse().unwrap())
+            .register("find", "Ctrl+F".parse().unwrap())
+            .register("quit", "Ctrl+Q".parse().unwrap());
+        hotkeys
+    }
+
+    #[test]
+    fn swaps_are_undone_at_once() {
+        let mut hotkeys = hotkeys();
+        hotkeys.set_conflict_policy(ConflictPolicy::Swap);
+        hotkeys.set("save", "Ctrl+Shift+S".parse().unwrap());
+        hotkeys.assign("quit", "Ctrl+F".parse().unwrap());
+        hotkeys.set("save", "F2".parse().unwrap());
+        assert_eq!(current(&hotkeys, "find"), "Ctrl+Q");
+
+        // undo past the swap, which changed two actions
+        assert_eq!(hotkeys.undo().unwrap()[0].id(), "save");
+        let undone = hotkeys.undo().unwrap();
+        let ids: Vec<&str> = undone.iter().map(Change::id).collect();
+        assert_eq!(ids, ["find", "quit"]);
+        assert_eq!(current(&hotkeys, "find"), "Ctrl+F");
+        assert_eq!(current(&hotkeys, "quit"), "Ctrl+Q");
+        assert_eq!(current(&hotkeys, "save"), "Ctrl+Shift+S");
+
+        hotkeys.redo();
+        assert_eq!(current(&hotkeys, "find"), "Ctrl+Q");
+        assert_eq!(current(&hotkeys, "quit"), "Ctrl+F");
+        // a new change can't be redone past
+        hotkeys.set("save", "Ctrl+Alt+S".parse().unwrap());
+        assert!(!hotkeys.can_redo());
+
+        for _ in 0..3 {
+            assert!(hotkeys.undo().is_some());
+        }
+        assert!(hotkeys.undo().is_none());
+        assert!(hotkeys.iter().all(|hotkey| !hotkey.is_modified()));
+    }
+
+    #[test]
+    fn resetting_and_importing_clear_the_history() {
+        let mut hotkeys = hotkeys();
+        hotkeys.set("save", "F2".parse().unwrap());
+        hotkeys.undo();
+        assert!(hotkeys.can_redo());
+        hotkeys.redo();
+        assert!(hotkeys.reset_all());
+        assert!(!hotkeys.can_undo() && !hotkeys.can_redo());
+
+        hotkeys.set("save", "F5".parse().unwrap());
+        hotkeys.import_string(r#"save = "F6""#).unwrap();
+        assert!(!hotkeys.can_undo());
+    }
+
+    #[test]
+    fn batch_is_undone_and_redone_at_once() {
+        let mut hotkeys = hotkeys();
+        hotkeys.set("quit", "Alt+F4".parse().unwrap());
+        let set = hotkeys.batch(|hotkeys| {
+            hotkeys.set("save", "F2".parse().unwrap());
+            hotkeys.add_binding("save", "Ctrl+Shift+S".parse().unwrap());
+            hotkeys.set("find", "F3".parse().unwrap())
+        });
+        assert!(set);
+
+        let undone = hotkeys.undo().unwrap();
+        let ids: Vec<&str> = undone.iter().map(Change::id).collect();
+        assert_eq!(ids, ["save", "find"]);
+        assert_eq!(current(&hotkeys, "save"), "Ctrl+S");
+        assert!(hotkeys.get("save").unwrap().alternates().is_empty());
+        assert_eq!(current(&hotkeys, "find"), "Ctrl+F");
+        // the change before the batch is still there
+        assert_eq!(current(&hotkeys, "quit"), "Alt+F4");
+        assert!(hotkeys.can_undo());
+
+        assert_eq!(hotkeys.redo().unwrap().len(), 2);
+        assert_eq!(
+            hotkeys.get("save").unwrap().format_bindings(),
+            "F2, Ctrl+Shift+S"
+        );
+        assert_eq!(current(&hotkeys, "find"), "F3");
+        assert!(!hotkeys.can_redo());
+    }
+
+    #[test]
+    fn nested_records_are_one_entry() {
+        let mut hotkeys = hotkeys();
+        hotkeys.batch(|hotkeys| {
+            hotkeys.set("save", "F2".parse().unwrap());
+            hotkeys.batch(|hotkeys| {
+                hotkeys.set("find", "F3".parse().unwrap());
+                hotkeys.record(|hotkeys| hotkeys.set("quit", "F4".parse().unwrap()));
+            });
+            // changed and changed back inside the batch
+            hotkeys.set("find", "Ctrl+F".parse().unwrap());
+        });
+        assert_eq!(hotkeys.history.undo.len(), 1);
+
+        let undone = hotkeys.undo().unwrap();
+        let ids: Vec<&str> = undone.iter().map(Change::id).collect();
+        assert_eq!(ids, ["save", "quit"]);
+        assert!(!hotkeys.can_undo());
+        assert!(hotkeys.iter().all(|hotkey| !hotkey.is_modified()));
+        assert!(!hotkeys.history.recording);
+    }
+}
@@ -0,0 +1,1909 @@
+use crate::{
+    parse::split_shortcut_list, Bind, Chords, ConflictPolicy, DebugFrame, History,
+    ParseShortcutError, Shortcut, Shortcuts,
+};
+use egui::{Context, Event, InputState, Modifiers};
+use std::cmp::Reverse;
+
+/// The scope of actions that are active everywhere, see [`Hotkeys::register_scoped`].
+pub const GLOBAL_SCOPE: &str = "global";
+
+/// The prefix of a gamepad button in the saved shortcuts of an action, e.g.
+/// `"Ctrl+J, Gamepad South"`, see [`Hotkeys::to_saved`].
+pub(crate) const GAMEPAD_PREFIX: &str = "Gamepad ";
+
+/// Whether a part of the saved shortcuts of an action is a gamepad button.
+pub(crate) fn is_gamepad_part(part: &str) -> bool {
+    let part = part.trim_start();
+    part.get(..GAMEPAD_PREFIX.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(GAMEPAD_PREFIX))
+}
+
+/// A named action in [Hotkeys], with its default and current shortcut, and
+/// any alternate shortcuts that can trigger it as well.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hotkey {
+    id: String,
+    category: Option<String>,
+    scope: String,
+    text_input_policy: Option<TextInputPolicy>,
+    always_fire: bool,
+    /// The name, description and icon, see [`Hotkeys::set_info`].
+    pub(crate) info: Option<crate::ActionInfo>,
+    /// The second step if the action is a chord, see [`Hotkeys::register_chord`].
+    second: Option<Shortcut>,
+    /// The window or area the action is attached to, see [`Hotkeys::register_in_area`].
+    pub(crate) area: Option<egui::Id>,
+    /// The namespace the action was registered in, see [`Hotkeys::namespace`].
+    pub(crate) namespace: Option<String>,
+    default: Shortcut,
+    current: Shortcut,
+    alternates: Shortcuts,
+    /// The current gamepad button, see [`Hotkeys::register_gamepad`].
+    #[cfg(feature = "gamepad")]
+    pub(crate) gamepad: Option<crate::GamepadButton>,
+    #[cfg(feature = "gamepad")]
+    pub(crate) default_gamepad: Option<crate::GamepadButton>,
+}
+
+impl Hotkey {
+    /// The ID of the action, e.g. `"save"`.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The category of the action, e.g. `"file"`, if it was registered with
+    /// [`Hotkeys::register_in`].
+    #[inline]
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// The scope of the action, e.g. `"canvas"`. This is [GLOBAL_SCOPE] unless
+    /// it was registered with [`Hotkeys::register_scoped`].
+    #[inline]
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    /// The [TextInputPolicy] of the action, if it overrides the one of
+    /// [Hotkeys] (see [`Hotkeys::set_action_text_input_policy`]).
+    #[inline]
+    pub fn text_input_policy(&self) -> Option<TextInputPolicy> {
+        self.text_input_policy
+    }
+
+    /// Whether the action is triggered even when a more specific action
+    /// uses the same key press (see [`Hotkeys::set_always_fire`]).
+    #[inline]
+    pub fn always_fires(&self) -> bool {
+        self.always_fire
+    }
+
+    /// The shortcut to press after one of the shortcuts of the action, if
+    /// it's a chord (see [`Hotkeys::register_chord`]).
+    #[inline]
+    pub fn second_step(&self) -> Option<&Shortcut> {
+        self.second.as_ref()
+    }
+
+    /// The shortcut the action was registered with.
+    #[inline]
+    pub fn default_bind(&self) -> &Shortcut {
+        &self.default
+    }
+
+    /// The current shortcut of the action.
+    #[inline]
+    pub fn current(&self) -> &Shortcut {
+        &self.current
+    }
+
+    /// The current shortcut of the action, to change it, e.g. with a
+    /// [Keybind](crate::Keybind).
+    #[inline]
+    pub fn current_mut(&mut self) -> &mut Shortcut {
+        &mut self.current
+    }
+
+    /// The alternate shortcuts of the action, see [`Hotkeys::add_binding`].
+    #[inline]
+    pub fn alternates(&self) -> &Shortcuts {
+        &self.alternates
+    }
+
+    /// The alternate shortcuts of the action, to change them, e.g. with a
+    /// [KeybindList](crate::KeybindList).
+    #[inline]
+    pub fn alternates_mut(&mut self) -> &mut Shortcuts {
+        &mut self.alternates
+    }
+
+    /// Iterate over all shortcuts of the action, starting with the current one.
+    pub fn bindings(&self) -> impl Iterator<Item = &Shortcut> {
+        std::iter::once(&self.current).chain(self.alternates.iter())
+    }
+
+    /// Reset the current shortcut to the default one, and remove the
+    /// alternate shortcuts. The gamepad button is reset as well.
+    ///
+    /// # Returns
+    ///
+    /// Whether the shortcuts were changed, i.e. if they were modified.
+    pub fn reset(&mut self) -> bool {
+        let changed = self.current != self.default || !self.alternates.is_empty();
+        self.current = self.default;
+        self.alternates = Shortcuts::NONE;
+        #[cfg(feature = "gamepad")]
+        let changed = changed || self.gamepad != self.default_gamepad;
+        #[cfg(feature = "gamepad")]
+        {
+            self.gamepad = self.default_gamepad;
+        }
+        changed
+    }
+
+    /// Whether the current shortcut is different from the default one, or
+    /// there are alternate shortcuts. Shortcuts are the same if they trigger
+    /// the same way on every platform, e.g. with a different
+    /// [PointerTrigger](crate::PointerTrigger) but no pointer button, while
+    /// Ctrl+S is modified from CmdOrCtrl+S, since they only trigger the same
+    /// way outside of Mac. With the `gamepad` feature, a changed gamepad
+    /// button counts as well.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "CmdOrCtrl+S".parse().unwrap());
+    /// hotkeys.set("save", "Ctrl+S".parse().unwrap());
+    /// assert!(hotkeys.get("save").unwrap().is_modified());
+    /// // so resetting it changes something
+    /// assert!(hotkeys.reset("save"));
+    /// assert!(!hotkeys.get("save").unwrap().is_modified());
+    /// ```
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        let modified = !equivalent(&self.current, &self.default) || !self.alternates.is_empty();
+        #[cfg(feature = "gamepad")]
+        let modified = modified || self.gamepad != self.default_gamepad;
+        modified
+    }
+
+    /// Format all shortcuts of the action as one string, e.g.
+    /// `"Ctrl+Y, Ctrl+Shift+Z"`. A single shortcut is formatted on its own.
+    /// With the `gamepad` feature, the gamepad button follows the shortcuts,
+    /// e.g. `"Space, Gamepad South"`.
+    pub(crate) fn format_bindings(&self) -> String {
+        #[allow(unused_mut)]
+        let mut bindings: Vec<String> = self.bindings().map(Shortcut::to_string).collect();
+        #[cfg(feature = "gamepad")]
+        bindings.extend(self.format_gamepad());
+        bindings.join(", ")
+    }
+
+    /// Set all shortcuts of the action from a string made by
+    /// [`Hotkey::format_bindings`]. Nothing is changed if any shortcut
+    /// couldn't be parsed. Without the `gamepad` feature, a gamepad button
+    /// that was saved with it is left out.
+    pub(crate) fn parse_bindings(&mut self, bindings: &str) -> Result<(), ParseShortcutError> {
+        let mut bindings = split_shortcut_list(bindings);
+        // a missing button is the default, e.g. in shortcuts saved before it was added
+        #[cfg(feature = "gamepad")]
+        let gamepad = crate::gamepad::take_gamepad(&mut bindings)?.unwrap_or(self.default_gamepad);
+        #[cfg(not(feature = "gamepad"))]
+        bindings.retain(|part| !is_gamepad_part(part));
+        // the text form doesn't have the policies, so they come from the default
+        let (policy, command) = (self.default.match_policy(), self.default.command_policy());
+        let mut shortcuts = match bindings.is_empty() {
+            true => vec![Shortcut::NONE],
+            false => bindings
+                .into_iter()
+                .map(|binding| {
+                    let shortcut = binding.parse::<Shortcut>()?;
+                    Ok(shortcut
+                        .with_match_policy(policy)
+                        .with_command_policy(command))
+                })
+                .collect::<Result<Vec<Shortcut>, ParseShortcutError>>()?,
+        };
+        self.current = shortcuts.remove(0);
+        self.alternates = Shortcuts::new(shortcuts);
+        #[cfg(feature = "gamepad")]
+        {
+            self.gamepad = gamepad;
+        }
+        Ok(())
+    }
+
+    /// Set the action to a single shortcut that was imported from another
+    /// format, like [`Hotkey::parse_bindings`] does with a single shortcut.
+    pub(crate) fn set_imported(&mut self, shortcut: Shortcut) {
+        // the other formats don't have the policies either
+        self.current = shortcut
+            .with_match_policy(self.default.match_policy())
+            .with_command_policy(self.default.command_policy());
+        self.alternates = Shortcuts::NONE;
+        #[cfg(feature = "gamepad")]
+        {
+            self.gamepad = self.default_gamepad;
+        }
+    }
+
+    /// Get the first shortcut of the action that is pressed and allowed by
+    /// `policy` while `typing`. Stops at the first match, so only that
+    /// shortcut is consumed.
+    fn pressed_binding(
+        &self,
+        input: &mut InputState,
+        policy: TextInputPolicy,
+        typing: bool,
+    ) -> Option<&Shortcut> {
+        self.bindings()
+            .filter(|shortcut| !typing || policy.allows(*shortcut))
+            .find(|shortcut| shortcut.pressed(input))
+    }
+}
+
+/// Whether actions can be triggered while a widget wants keyboard input
+/// ([`Context::wants_keyboard_input`]), e.g. while typing into a
+/// [TextEdit](egui::TextEdit). Only [`Hotkeys::pressed_ctx`],
+/// [`Hotkeys::triggered`], [ShortcutButton](crate::ShortcutButton),
+/// [menu_shortcut_pressed](crate::menu_shortcut_pressed) and
+/// [`Bind::pressed_with_policy`](crate::Bind::pressed_with_policy) follow
+/// the policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TextInputPolicy {
+    /// Actions are always triggered.
+    Always,
+    /// Only actions with a modifier other than Shift (e.g. Ctrl+Enter) or
+    /// without a keyboard shortcut are triggered, so typing a letter doesn't
+    /// trigger the action bound to it. This is the default.
+    #[default]
+    WithModifiers,
+    /// No actions are triggered.
+    Never,
+}
+
+/// How specific a shortcut is: the number of logical modifiers it needs
+/// (Ctrl and Cmd count once each), and one more for a pointer button along
+/// with a key. See [`Hotkeys::triggered`].
+pub(crate) fn specificity(shortcut: &Shortcut) -> usize {
+    let modifiers = shortcut.keyboard().map(|keyboard| keyboard.modifiers);
+    let modifiers = modifiers.map_or(0, |modifiers| {
+        [
+            modifiers.alt,
+            modifiers.shift,
+            modifiers.ctrl,
+            modifiers.command || modifiers.mac_cmd,
+        ]
+        .into_iter()
+        .filter(|&modifier| modifier)
+        .count()
+    });
+    let pointer = shortcut.keyboard().is_some() && shortcut.pointer().is_some();
+    modifiers + usize::from(pointer)
+}
+
+/// The modifiers as they are pressed on Mac (`true`) or on other platforms,
+/// where [`Modifiers::command`] is Cmd or Ctrl.
+fn pressed_modifiers(modifiers: Modifiers, is_mac: bool) -> Modifiers {
+    Modifiers {
+        ctrl: modifiers.ctrl || (!is_mac && modifiers.command),
+        mac_cmd: modifiers.mac_cmd || (is_mac && modifiers.command),
+        command: false,
+        ..modifiers
+    }
+}
+
+/// Whether two shortcuts trigger the same way on every platform, see
+/// [`Hotkey::is_modified`]. Unlike in [`Hotkeys::conflicts`], which compares
+/// them on the current platform, Ctrl+S and Cmd+S are different.
+pub(crate) fn equivalent(a: &Shortcut, b: &Shortcut) -> bool {
+    let pressed = |shortcut: &Shortcut, is_mac| {
+        let keyboard = shortcut.matched_keyboard().map(|keyboard| {
+            let modifiers = pressed_modifiers(keyboard.modifiers, is_mac);
+            (modifiers, keyboard.logical_key)
+        });
+        let text = shortcut
+            .text()
+            .map(|text| (pressed_modifiers(text.modifiers, is_mac), text.character));
+        let pointer = shortcut
+            .pointer()
+            .map(|button| (button, shortcut.pointer_trigger()));
+        let policy = (keyboard.is_some() || text.is_some()).then(|| shortcut.match_policy());
+        (keyboard, text, pointer, policy)
+    };
+    [true, false]
+        .into_iter()
+        .all(|is_mac| pressed(a, is_mac) == pressed(b, is_mac))
+}
+
+/// Check if a shortcut is pressed like [`Bind::pressed`], without consuming
+/// the key press.
+pub(crate) fn peek(shortcut: &Shortcut, input: &InputState) -> bool {
+    matches_events(shortcut, &input.events, input)
+}
+
+/// Check if a shortcut is pressed like [`peek`], with the key presses in
+/// `events` instead of the ones in `input`.
+pub(crate) fn matches_events(shortcut: &Shortcut, events: &[Event], input: &InputState) -> bool {
+    let keyboard = shortcut.matched_keyboard().map(|keyboard| {
+        events.iter().any(|event| {
+            if let Some((key, modifiers)) = crate::bind::clipboard_key_press(event, input.modifiers)
+            {
+                return key == keyboard.logical_key
+                    && shortcut
+                        .match_policy()
+                        .matches(modifiers, keyboard.modifiers);
+            }
+            matches!(
+                event,
+                Event::Key { key, pressed: true, modifiers, .. }
+                if *key == keyboard.logical_key
+                    && shortcut.match_policy().matches(*modifiers, keyboard.modifiers)
+            )
+        })
+    });
+    let keyboard = keyboard.or_else(|| {
+        let text = shortcut.text()?;
+        let policy = shortcut.match_policy();
+        Some(
+            events
+                .iter()
+                .any(|event| text.is_typed_by(event, input.modifiers, policy)),
+        )
+    });
+    let pointer = shortcut
+        .pointer()
+        .map(|button| shortcut.pointer_trigger().matches(button, input));
+    match (keyboard, pointer) {
+        (Some(keyboard), Some(pointer)) => keyboard && pointer,
+        (Some(pressed), None) | (None, Some(pressed)) => pressed,
+        (None, None) => false,
+    }
+}
+
+impl TextInputPolicy {
+    /// Whether a bind can trigger its action while typing.
+    pub(crate) fn allows(self, bind: &impl Bind) -> bool {
+        match self {
+            Self::Always => true,
+            Self::WithModifiers => {
+                let has_modifier = |modifiers: egui::Modifiers| {
+                    modifiers.alt || modifiers.ctrl || modifiers.command || modifiers.mac_cmd
+                };
+                bind.keyboard()
+                    .is_none_or(|keyboard| has_modifier(keyboard.modifiers))
+                    && bind.text().is_none_or(|text| has_modifier(text.modifiers))
+            }
+            Self::Never => false,
+        }
+    }
+}
+
+/// What triggered an action, see [`Hotkeys::triggered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriggerSource {
+    /// A keyboard shortcut.
+    Keyboard,
+    /// A pointer button.
+    Pointer,
+    /// A keyboard shortcut together with a pointer button.
+    KeyboardAndPointer,
+    /// An OS-global hotkey, which also works while the app is not focused.
+    /// Only with the `global` feature, see `GlobalSync`.
+    Global,
+    /// A gamepad button. Only with the `gamepad` feature, see
+    /// `Hotkeys::register_gamepad`.
+    Gamepad,
+}
+
+impl TriggerSource {
+    pub(crate) fn of(shortcut: &Shortcut) -> Self {
+        let keyboard = shortcut.keyboard().is_some() || shortcut.text().is_some();
+        match (keyboard, shortcut.pointer()) {
+            (true, Some(_)) => Self::KeyboardAndPointer,
+            (true, None) => Self::Keyboard,
+            (false, _) => Self::Pointer,
+        }
+    }
+}
+
+/// An action that was triggered, see [`Hotkeys::triggered`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Triggered {
+    id: String,
+    source: TriggerSource,
+}
+
+impl Triggered {
+    pub(crate) fn new(id: impl Into<String>, source: TriggerSource) -> Self {
+        Self {
+            id: id.into(),
+            source,
+        }
+    }
+
+    /// The ID of the action, e.g. `"save"`.
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// What triggered the action.
+    #[inline]
+    pub fn source(&self) -> TriggerSource {
+        self.source
+    }
+}
+
+/// A collection of named actions and their shortcuts, e.g. the keymap of an app.
+///
+/// Actions are registered with a default shortcut, which can then be changed
+/// with a [Keybind](crate::Keybind) through [`Hotkeys::bind_mut`] and checked
+/// with [`Hotkeys::pressed`]. Actions keep the order they were registered in.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// use egui_keybind::{Hotkeys, Keybind, Shortcut};
+///
+/// let mut hotkeys = Hotkeys::new();
+/// let ctrl_s = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+/// hotkeys.register("save", Shortcut::new(Some(ctrl_s), None));
+///
+/// if let Some(bind) = hotkeys.bind_mut("save") {
+///     ui.add(Keybind::new(bind, "save").with_text("Save"));
+/// }
+/// if hotkeys.pressed_ctx(ui.ctx(), "save") {
+///     // save the file
+/// }
+/// # });
+/// ```
+///
+/// With the `serde` feature, the current shortcuts can be saved as action ID
+/// to shortcut string pairs (e.g. `{"save": "Ctrl+S"}`), and loaded back into
+/// the registered actions with [`Hotkeys::load`].
+#[derive(Debug, Clone, Default)]
+pub struct Hotkeys {
+    hotkeys: Vec<Hotkey>,
+    /// Saved shortcuts of actions that aren't registered, kept so they can be
+    /// saved again.
+    pub(crate) unknown: Vec<(String, String)>,
+    /// The scopes that are active besides [GLOBAL_SCOPE], or [None] if all are.
+    active_scopes: Option<Vec<String>>,
+    /// Groups of scopes that are never active together, see
+    /// [`Hotkeys::add_exclusive_scopes`].
+    exclusive_scopes: Vec<Vec<String>>,
+    /// The pass [`Hotkeys::triggered`] was last called in, so actions aren't
+    /// triggered twice in one pass.
+    triggered_pass: Option<u64>,
+    text_input_policy: TextInputPolicy,
+    conflict_policy: ConflictPolicy,
+    /// Translates the names of actions, see [`Hotkeys::set_translator`].
+    pub(crate) translator: crate::info::Translator,
+    /// How Ctrl+Alt is treated, see [`Hotkeys::set_altgr_policy`].
+    pub(crate) altgr: Option<crate::AltGrPolicy>,
+    /// Changes that can be undone, see [`Hotkeys::undo`]. Not a part of the
+    /// keymap, so it's not compared or serialized.
+    pub(crate) history: History,
+    /// The chord that is pending and the chord timeout, see [`Hotkeys::pending_chord`].
+    pub(crate) chords: Chords,
+    /// The profiles and which one is active, see [`Hotkeys::switch_profile`].
+    pub(crate) profiles: crate::Profiles,
+    /// Which window or area has focus, see [`Hotkeys::note_focused_area`].
+    pub(crate) focus: crate::focus::AreaFocus,
+    /// How saved shortcuts of older versions are loaded, see [`Hotkeys::migrate_id`].
+    pub(crate) migrations: crate::migration::Migrations,
+    /// What [`Hotkeys::triggered`] did on the last frames, or [None] if it's
+    /// not recorded, see [`Hotkeys::set_debug_enabled`].
+    pub(crate) debug: Option<std::collections::VecDeque<DebugFrame>>,
+    /// How often each action was triggered, or [None] if it's not counted,
+    /// see [`Hotkeys::set_stats_enabled`].
+    pub(crate) stats: Option<std::collections::HashMap<String, crate::ActionStats>>,
+    /// Counts the changes that can affect [`Hotkeys::to_saved`], so the
+    /// shortcuts are only stored again after they changed.
+    pub(crate) generation: u64,
+    /// Where the shortcuts are stored, see [`Hotkeys::load_or_default`].
+    #[cfg(feature = "serde")]
+    pub(crate) persistence: Option<crate::persistence::Persistence>,
+    /// The actions that were triggered by OS-global hotkeys since the last
+    /// call to [`Hotkeys::triggered`].
+    #[cfg(feature = "global")]
+    pub(crate) global_triggered: Vec<String>,
+    /// The gamepad buttons that were pressed since the last call to
+    /// [`Hotkeys::triggered`], see `Hotkeys::press_gamepad`.
+    #[cfg(feature = "gamepad")]
+    pub(crate) gamepad_pressed: Vec<crate::GamepadButton>,
+}
+
+impl PartialEq for Hotkeys {
+    fn eq(&self, other: &Self) -> bool {
+        self.hotkeys == other.hotkeys
+            && self.unknown == other.unknown
+            && self.active_scopes == other.active_scopes
+            && self.exclusive_scopes == other.exclusive_scopes
+            && self.text_input_policy == other.text_input_policy
+            && self.conflict_policy == other.conflict_policy
+            && self.altgr == other.altgr
+            && self.profiles == other.profiles
+    }
+}
+
+impl Hotkeys {
+    /// Create a new, empty [Hotkeys].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an action with a default shortcut. The current shortcut is
+    /// set to the default.
+    ///
+    /// If the action is already registered, only its default is changed and
+    /// the current shortcut is kept. It's also moved out of its category.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the action, e.g. `"save"`.
+    /// * `default` - The default shortcut of the action.
+    pub fn register(&mut self, id: impl Into<String>, default: Shortcut) -> &mut Self {
+        self.insert(None, id.into(), default)
+    }
+
+    /// Register an action in a category, like [`Hotkeys::register`]. The
+    /// category only groups the actions for showing them, e.g. in a
+    /// [KeybindsPanel](crate::KeybindsPanel); IDs are shared by all categories.
+    ///
+    /// If the action is already registered, it's moved to the category.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The category of the action, e.g. `"file"`.
+    /// * `id` - The ID of the action, e.g. `"save"`.
+    /// * `default` - The default shortcut of the action.
+    pub fn register_in(
+        &mut self,
+        category: impl Into<String>,
+        id: impl Into<String>,
+        default: Shortcut,
+    ) -> &mut Self {
+        self.insert(Some(category.into()), id.into(), default)
+    }
+
+    /// Register an action in a scope, like [`Hotkeys::register`]. Actions
+    /// are only pressed while their scope is active (see
+    /// [`Hotkeys::set_active_scopes`]), so the same shortcut can mean
+    /// different things in different parts of the app, e.g. Delete in a
+    /// canvas and in a list of layers. Actions in [GLOBAL_SCOPE] are always
+    /// active. Re-registering an action keeps its category.
+    ///
+    /// All scopes are active by default, so actions in different scopes
+    /// conflict (see [`Hotkeys::conflicts`]) unless their scopes are declared
+    /// exclusive with [`Hotkeys::add_exclusive_scopes`].
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - The scope of the action, e.g. `"canvas"`.
+    /// * `id` - The ID of the action, e.g. `"delete_shape"`.
+    /// * `default` - The default shortcut of the action.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register_scoped("canvas", "delete_shape", "Delete".parse().unwrap())
+    ///     .register_scoped("layers", "delete_layer", "Delete".parse().unwrap());
+    ///
+    /// // e.g. when the canvas has focus
+    /// hotkeys.set_active_scopes(&["canvas"]);
+    /// assert!(hotkeys.is_active("save"));
+    /// assert!(hotkeys.is_active("delete_shape"));
+    /// assert!(!hotkeys.is_active("delete_layer"));
+    ///
+    /// // scopes can be active together, so the same shortcut conflicts
+    /// let report = hotkeys.conflicts();
+    /// let groups: Vec<_> = report.iter().map(|conflict| conflict.ids()).collect();
+    /// assert_eq!(groups, [&["delete_shape", "delete_layer"]]);
+    ///
+    /// // unless they are never active together
+    /// hotkeys.add_exclusive_scopes(&["canvas", "layers"]);
+    /// assert!(hotkeys.conflicts().is_empty());
+    ///
+    /// // but global actions conflict with the actions of every scope
+    /// hotkeys.register("clear", "Delete".parse().unwrap());
+    /// let report = hotkeys.conflicts();
+    /// let groups: Vec<_> = report.iter().map(|conflict| conflict.ids()).collect();
+    /// assert_eq!(groups, [&["delete_shape", "clear"], &["delete_layer", "clear"]]);
+    ///
+    /// // registering an action again keeps its category
+    /// hotkeys.register_in("edit", "delete_shape", "Delete".parse().unwrap());
+    /// hotkeys.register_scoped("canvas", "delete_shape", "Backspace".parse().unwrap());
+    /// let hotkey = hotkeys.get("delete_shape").unwrap();
+    /// assert_eq!((hotkey.category(), hotkey.scope()), (Some("edit"), "canvas"));
+    /// ```
+    pub fn register_scoped(
+        &mut self,
+        scope: impl Into<String>,
+        id: impl Into<String>,
+        default: Shortcut,
+    ) -> &mut Self {
+        let id = id.into();
+        let category = self.get(&id).and_then(|hotkey| hotkey.category.clone());
+        self.insert(category, id.clone(), default)
+            .set_scope(&id, scope)
+    }
+
+    /// Register an action that is triggered by pressing two shortcuts one
+    /// after the other, e.g. Ctrl+K then Ctrl+C, like [`Hotkeys::register`].
+    /// The first step is the shortcut of the action and can be changed like
+    /// any other, while the second step stays as it was registered.
+    ///
+    /// Chords are only triggered by [`Hotkeys::triggered`]. After the first
+    /// step is pressed, the chord is pending (see [`Hotkeys::pending_chord`])
+    /// until the second step is pressed, another key is pressed, Escape is
+    /// pressed or it times out (see [`Hotkeys::set_chord_timeout`]). While
+    /// it's pending, an action on the first step alone (e.g. Ctrl+K) is not
+    /// triggered. It is triggered late if the chord doesn't complete, unless
+    /// Escape aborted it.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the action, e.g. `"comment"`.
+    /// * `first` - The default shortcut of the first step.
+    /// * `second` - The shortcut of the second step.
+    pub fn register_chord(
+        &mut self,
+        id: impl Into<String>,
+        first: Shortcut,
+        second: Shortcut,
+    ) -> &mut Self {
+        let id = id.into();
+        self.insert(None, id.clone(), first);
+        if let Some(hotkey) = self.get_mut(&id) {
+            hotkey.second = Some(second);
+        }
+        self
+    }
+
+    /// Move an action to a scope, e.g. after registering it in a category
+    /// with [`Hotkeys::register_in`]. Does nothing if the action is not
+    /// registered.
+    pub fn set_scope(&mut self, id: &str, scope: impl Into<String>) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            hotkey.scope = scope.into();
+        }
+        self
+    }
+
+    /// Set the scopes that are active, e.g. the ones of the part of the app
+    /// that has focus. Only the actions in these scopes and in [GLOBAL_SCOPE]
+    /// can be pressed. By default all scopes are active.
+    pub fn set_active_scopes(&mut self, scopes: &[&str]) {
+        self.active_scopes = Some(scopes.iter().map(|scope| scope.to_string()).collect());
+    }
+
+    /// Make all scopes active again, see [`Hotkeys::set_active_scopes`].
+    pub fn activate_all_scopes(&mut self) {
+        self.active_scopes = None;
+    }
+
+    /// Whether a scope is active, see [`Hotkeys::set_active_scopes`].
+    pub fn is_scope_active(&self, scope: &str) -> bool {
+        scope == GLOBAL_SCOPE
+            || self
+                .active_scopes
+                .as_ref()
+                .is_none_or(|active| active.iter().any(|active| active == scope))
+    }
+
+    /// Declare scopes that are never active at the same time, e.g. the modes
+    /// of an editor, so actions in them don't conflict with each other (see
+    /// [`Hotkeys::conflicts`] and [`Hotkeys::assign`]). A scope is only in one
+    /// group, so it's removed from the group it was declared in before.
+    /// [GLOBAL_SCOPE] is never exclusive.
+    ///
+    /// This doesn't change which scopes are active, see
+    /// [`Hotkeys::set_active_scopes`].
+    pub fn add_exclusive_scopes(&mut self, scopes: &[&str]) -> &mut Self {
+        let group: Vec<String> = scopes
+            .iter()
+            .filter(|scope| **scope != GLOBAL_SCOPE)
+            .map(|scope| scope.to_string())
+            .collect();
+        for other in &mut self.exclusive_scopes {
+            other.retain(|scope| !group.contains(scope));
+        }
+        self.exclusive_scopes.retain(|other| other.len() > 1);
+        if group.len() > 1 {
+            self.exclusive_scopes.push(group);
+        }
+        self
+    }
+
+    /// Whether two different scopes were declared to never be active at the
+    /// same time, see [`Hotkeys::add_exclusive_scopes`].
+    pub fn are_scopes_exclusive(&self, a: &str, b: &str) -> bool {
+        a != b
+            && self.exclusive_scopes.iter().any(|group| {
+                group.iter().any(|scope| scope == a) && group.iter().any(|scope| scope == b)
+            })
+    }
+
+    /// Get the groups of scopes that are never active together, see
+    /// [`Hotkeys::add_exclusive_scopes`].
+    pub(crate) fn exclusive_scopes(&self) -> &[Vec<String>] {
+        &self.exclusive_scopes
+    }
+
+    /// Whether two actions can be active at the same time: their scopes
+    /// aren't exclusive and they aren't attached to different areas.
+    pub(crate) fn can_overlap(&self, a: &Hotkey, b: &Hotkey) -> bool {
+        !self.are_scopes_exclusive(a.scope(), b.scope())
+            && (a.area().is_none() || b.area().is_none() || a.area() == b.area())
+    }
+
+    /// Set whether actions can be triggered while a widget wants keyboard
+    /// input. By default this is [`TextInputPolicy::WithModifiers`].
+    pub fn set_text_input_policy(&mut self, policy: TextInputPolicy) {
+        self.text_input_policy = policy;
+    }
+
+    /// Get whether actions can be triggered while a widget wants keyboard
+    /// input, see [`Hotkeys::set_text_input_policy`].
+    #[inline]
+    pub fn text_input_policy(&self) -> TextInputPolicy {
+        self.text_input_policy
+    }
+
+    /// Set what [`Hotkeys::assign`] does when a shortcut is already used by
+    /// another action. By default this is [`ConflictPolicy::Replace`].
+    pub fn set_conflict_policy(&mut self, policy: ConflictPolicy) {
+        self.conflict_policy = policy;
+    }
+
+    /// Get what [`Hotkeys::assign`] does when a shortcut is already used by
+    /// another action, see [`Hotkeys::set_conflict_policy`].
+    #[inline]
+    pub fn conflict_policy(&self) -> ConflictPolicy {
+        self.conflict_policy
+    }
+
+    /// Set whether an action can be triggered while a widget wants keyboard
+    /// input, overriding [`Hotkeys::set_text_input_policy`], e.g. so a
+    /// "submit" action on Enter still works while typing. [None] removes the
+    /// override. Does nothing if the action is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Id, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Hotkeys, TextInputPolicy};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("split", "S".parse().unwrap())
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("submit", "Enter".parse().unwrap())
+    ///     .set_action_text_input_policy("submit", Some(TextInputPolicy::Always));
+    ///
+    /// let mut input = RawInput::default();
+    /// for (key, modifiers) in [
+    ///     (Key::S, Modifiers::NONE),
+    ///     (Key::S, Modifiers::CTRL),
+    ///     (Key::Enter, Modifiers::NONE),
+    /// ] {
+    ///     input.events.push(Event::Key {
+    ///         key,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers,
+    ///     });
+    /// }
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| {
+    ///     // e.g. a focused text edit
+    ///     ctx.memory_mut(|memory| memory.request_focus(Id::new("file_name")));
+    ///     assert!(ctx.wants_keyboard_input());
+    ///
+    ///     let triggered: Vec<_> = hotkeys.triggered(ctx).collect();
+    ///     let ids: Vec<&str> = triggered.iter().map(|action| action.id()).collect();
+    ///     assert_eq!(ids, ["save", "submit"]);
+    /// });
+    /// ```
+    pub fn set_action_text_input_policy(
+        &mut self,
+        id: &str,
+        policy: Option<TextInputPolicy>,
+    ) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            hotkey.text_input_policy = policy;
+        }
+        self
+    }
+
+    /// Set whether an action is triggered even when a more specific action
+    /// uses the same key press, e.g. a status bar hint that should show
+    /// whenever S is pressed, also with Ctrl+S. The action doesn't
+    /// suppress other actions then either. Does nothing if the action is not
+    /// registered. See [`Hotkeys::triggered`].
+    pub fn set_always_fire(&mut self, id: &str, always_fire: bool) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            hotkey.always_fire = always_fire;
+        }
+        self
+    }
+
+    /// Get the shortcut an action was triggered with on this frame, if its
+    /// scope is active and its [TextInputPolicy] allows it while `typing`.
+    fn triggered_binding<'a>(
+        &self,
+        hotkey: &'a Hotkey,
+        input: &mut InputState,
+        typing: bool,
+        altgr: crate::AltGrPolicy,
+    ) -> Option<&'a Shortcut> {
+        // chords are only triggered by `Hotkeys::triggered`
+        if !self.is_hotkey_active(hotkey) || hotkey.second.is_some() {
+            return None;
+        }
+        let policy = hotkey.text_input_policy.unwrap_or(self.text_input_policy);
+        altgr.normalized(input, |input| hotkey.pressed_binding(input, policy, typing))
+    }
+
+    /// Whether an action is registered, its scope is active and its area
+    /// has focus (see [`Hotkeys::register_in_area`]).
+    pub fn is_active(&self, id: &str) -> bool {
+        self.get(id)
+            .is_some_and(|hotkey| self.is_hotkey_active(hotkey))
+    }
+
+    /// Whether the scope of an action is active and its area has focus.
+    pub(crate) fn is_hotkey_active(&self, hotkey: &Hotkey) -> bool {
+        self.is_scope_active(hotkey.scope()) && self.is_area_focused(hotkey)
+    }
+
+    fn insert(&mut self, category: Option<String>, id: String, default: Shortcut) -> &mut Self {
+        match self.get_mut(&id) {
+            Some(hotkey) => {
+                hotkey.category = category;
+                hotkey.default = default;
+            }
+            None => {
+                let mut hotkey = Hotkey {
+                    id,
+                    category,
+                    scope: GLOBAL_SCOPE.to_string(),
+                    text_input_policy: None,
+                    always_fire: false,
+                    info: None,
+                    second: None,
+                    area: None,
+                    namespace: None,
+                    default,
+                    current: default,
+                    alternates: Shortcuts::NONE,
+                    #[cfg(feature = "gamepad")]
+                    gamepad: None,
+                    #[cfg(feature = "gamepad")]
+                    default_gamepad: None,
+                };
+                // use the saved shortcuts if they were loaded before the action was registered,
+                // maybe with an old ID
+                let saved = self
+                    .unknown
+                    .iter()
+                    .position(|(id, _)| *id == hotkey.id)
+                    .or_else(|| {
+                        self.unknown.iter().position(|(id, _)| {
+                            self.migrations.id(id).is_some_and(|id| id == hotkey.id)
+                        })
+                    });
+                if let Some(index) = saved {
+                    let (_, value) = self.unknown.remove(index);
+                    let value = self.migrations.value(&hotkey.id, &value).unwrap_or(value);
+                    let _ = hotkey.parse_bindings(&value);
+                }
+                self.hotkeys.push(hotkey);
+                self.note_change();
+            }
+        }
+        self
+    }
+
+    /// Get a registered action.
+    pub fn get(&self, id: &str) -> Option<&Hotkey> {
+        self.hotkeys.iter().find(|hotkey| hotkey.id == id)
+    }
+
+    /// Note that the shortcuts may have changed, see [`Hotkeys::auto_store`].
+    pub(crate) fn note_change(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    pub(crate) fn get_mut(&mut self, id: &str) -> Option<&mut Hotkey> {
+        self.note_change();
+        self.hotkeys.iter_mut().find(|hotkey| hotkey.id == id)
+    }
+
+    /// Whether an action is registered.
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Get the current shortcut of an action, if it's registered.
+    pub fn current(&self, id: &str) -> Option<&Shortcut> {
+        self.get(id).map(Hotkey::current)
+    }
+
+    /// Get the default shortcut of an action, if it's registered.
+    pub fn default_shortcut(&self, id: &str) -> Option<&Shortcut> {
+        self.get(id).map(Hotkey::default_bind)
+    }
+
+    /// Get the current shortcut of an action to change it, e.g. with a
+    /// [Keybind](crate::Keybind), if it's registered and the active profile
+    /// isn't a preset (see [`Hotkeys::add_preset`]).
+    pub fn bind_mut(&mut self, id: &str) -> Option<&mut Shortcut> {
+        self.editable_mut(id).map(|hotkey| &mut hotkey.current)
+    }
+
+    /// Set the current shortcut of an action. To resolve conflicts with other
+    /// actions, use [`Hotkeys::assign`] instead. To set the shortcuts of many
+    /// actions as one change in the history, use [`Hotkeys::batch`].
+    ///
+    /// # Returns
+    ///
+    /// Whether the shortcut was set. It's not if the action is not registered,
+    /// or if the active profile is a preset (see [`Hotkeys::add_preset`]),
+    /// which has to be duplicated to change it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("left", "ArrowLeft".parse().unwrap())
+    ///     .add_preset("Vim-style", [("left", "H")]);
+    /// assert!(hotkeys.set("left", "A".parse().unwrap()));
+    /// assert!(!hotkeys.set("right", "D".parse().unwrap()));
+    ///
+    /// hotkeys.switch_profile("Vim-style").unwrap();
+    /// assert!(!hotkeys.set("left", "J".parse().unwrap()));
+    /// assert!(hotkeys.bind_mut("left").is_none());
+    /// assert_eq!(hotkeys.current("left").unwrap().to_string(), "H");
+    /// ```
+    pub fn set(&mut self, id: &str, shortcut: Shortcut) -> bool {
+        self.record(|hotkeys| {
+            hotkeys
+                .editable_mut(id)
+                .map(|hotkey| hotkey.current = shortcut)
+                .is_some()
+        })
+    }
+
+    /// Get an action to change its shortcuts, if it's registered and the
+    /// active profile isn't a preset.
+    pub(crate) fn editable_mut(&mut self, id: &str) -> Option<&mut Hotkey> {
+        match self.is_profile_read_only(self.active_profile()) {
+            true => None,
+            false => self.get_mut(id),
+        }
+    }
+
+    /// Add a shortcut to an action. If the action has no current shortcut,
+    /// it becomes the current one, otherwise it's added after the alternate
+    /// shortcuts (see [`Hotkey::alternates`]). Any of the shortcuts of an
+    /// action can trigger it. Does nothing if the action is not registered or
+    /// the active profile is a preset.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{ConflictKind, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("redo", "Ctrl+Y".parse().unwrap());
+    /// hotkeys.add_binding("redo", "Ctrl+Shift+Z".parse().unwrap());
+    /// assert!(hotkeys.is_modified("redo"));
+    ///
+    /// let redo = hotkeys.get("redo").unwrap();
+    /// let bindings: Vec<String> = redo.bindings().map(|shortcut| shortcut.to_string()).collect();
+    /// assert_eq!(bindings, ["Ctrl+Y", "Ctrl+Shift+Z"]);
+    /// let saved = hotkeys.to_saved();
+    /// assert_eq!(saved, [("redo".to_string(), "Ctrl+Y, Ctrl+Shift+Z".to_string())]);
+    ///
+    /// // alternate shortcuts conflict like current ones
+    /// hotkeys.register("zoom", "Ctrl+Shift+Z".parse().unwrap());
+    /// assert_eq!(hotkeys.conflicts().kind_of("zoom"), Some(ConflictKind::Duplicate));
+    ///
+    /// // removing the current shortcut moves the first alternate in its place
+    /// assert_eq!(hotkeys.remove_binding("redo", 0).unwrap().to_string(), "Ctrl+Y");
+    /// assert_eq!(hotkeys.current("redo").unwrap().to_string(), "Ctrl+Shift+Z");
+    /// assert!(hotkeys.get("redo").unwrap().alternates().is_empty());
+    /// ```
+    pub fn add_binding(&mut self, id: &str, shortcut: Shortcut) {
+        self.record(|hotkeys| {
+            if let Some(hotkey) = hotkeys.editable_mut(id) {
+                if hotkey.current.is_bound() {
+                    hotkey.alternates.push(shortcut);
+                } else {
+                    hotkey.current = shortcut;
+                }
+            }
+        });
+    }
+
+    /// Remove a shortcut of an action, where 0 is the current shortcut and
+    /// the alternate shortcuts come after it (see [`Hotkey::bindings`]). If
+    /// the current shortcut is removed, the first alternate one takes its
+    /// place.
+    ///
+    /// # Returns
+    ///
+    /// The removed shortcut, or [None] if the action is not registered, has
+    /// no shortcut at `index`, or the active profile is a preset.
+    pub fn remove_binding(&mut self, id: &str, index: usize) -> Option<Shortcut> {
+        self.record(|hotkeys| {
+            let hotkey = hotkeys.editable_mut(id)?;
+            if index == 0 {
+                let next = match hotkey.alternates.is_empty() {
+                    true => Shortcut::NONE,
+                    false => hotkey.alternates.remove(0),
+                };
+                Some(std::mem::replace(&mut hotkey.current, next))
+            } else if index <= hotkey.alternates.len() {
+                Some(hotkey.alternates.remove(index - 1))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Whether the current shortcut of an action is different from its
+    /// default, e.g. to only show a reset button for modified actions.
+    /// Actions that are not registered are never modified.
+    pub fn is_modified(&self, id: &str) -> bool {
+        self.get(id).is_some_and(Hotkey::is_modified)
+    }
+
+    /// Get the actions whose shortcuts differ from their defaults (see
+    /// [`Hotkey::is_modified`]), in the order they were registered, e.g. to
+    /// list what the user changed. Each [Hotkey] has both its
+    /// [default](Hotkey::default_bind) and its [current](Hotkey::current)
+    /// shortcuts. See also [`Hotkeys::export_diff_string`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{Hotkeys, Shortcut};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap());
+    /// hotkeys.set("find", "F3".parse().unwrap());
+    /// hotkeys.add_binding("quit", "Alt+F4".parse().unwrap());
+    /// // pressed the same outside of Mac, but still a different shortcut
+    /// let command_s = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    /// hotkeys.set("save", Shortcut::new(Some(command_s), None));
+    ///
+    /// let modified: Vec<_> = hotkeys
+    ///     .modified()
+    ///     .into_iter()
+    ///     .map(|hotkey| {
+    ///         let default = hotkey.default_bind().to_string();
+    ///         (hotkey.id(), default, hotkey.current().to_string())
+    ///     })
+    ///     .collect();
+    /// assert_eq!(
+    ///     modified,
+    ///     [
+    ///         ("save", "Ctrl+S".to_string(), "CmdOrCtrl+S".to_string()),
+    ///         ("find", "Ctrl+F".to_string(), "F3".to_string()),
+    ///         ("quit", "Ctrl+Q".to_string(), "Ctrl+Q".to_string()),
+    ///     ]
+    /// );
+    ///
+    /// hotkeys.reset("save");
+    /// hotkeys.reset("find");
+    /// let ids: Vec<&str> = hotkeys.modified().iter().map(|hotkey| hotkey.id()).collect();
+    /// assert_eq!(ids, ["quit"]);
+    /// ```
+    pub fn modified(&self) -> Vec<&Hotkey> {
+        self.hotkeys
+            .iter()
+            .filter(|hotkey| hotkey.is_modified())
+            .collect()
+    }
+
+    /// Reset an action to its default shortcut.
+    ///
+    /// # Returns
+    ///
+    /// Whether the current shortcut was changed, so the app knows when to
+    /// save the hotkeys again. Resetting an unmodified or unregistered action,
+    /// or one of a preset profile, does nothing and returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    /// hotkeys.set("save", "Ctrl+Shift+S".parse().unwrap());
+    /// assert!(hotkeys.is_modified("save"));
+    /// assert!(!hotkeys.is_modified("find"));
+    ///
+    /// assert!(hotkeys.reset("save"));
+    /// assert!(!hotkeys.is_modified("save"));
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+S");
+    ///
+    /// // nothing to reset
+    /// assert!(!hotkeys.reset("save"));
+    /// assert!(!hotkeys.reset("find"));
+    /// assert!(!hotkeys.reset("print"));
+    /// ```
+    pub fn reset(&mut self, id: &str) -> bool {
+        self.record(|hotkeys| hotkeys.editable_mut(id).is_some_and(Hotkey::reset))
+    }
+
+    /// Reset all actions to their default shortcuts. The history is cleared,
+    /// see [`Hotkeys::undo`]. Does nothing if the active profile is a preset.
+    ///
+    /// # Returns
+    ///
+    /// Whether any current shortcut was changed, like [`Hotkeys::reset`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    /// assert!(!hotkeys.reset_all());
+    ///
+    /// hotkeys.set("find", "F3".parse().unwrap());
+    /// assert!(hotkeys.reset_all());
+    /// assert!(hotkeys.iter().all(|hotkey| !hotkey.is_modified()));
+    /// assert!(!hotkeys.reset_all());
+    /// ```
+    pub fn reset_all(&mut self) -> bool {
+        if self.is_profile_read_only(self.active_profile()) {
+            return false;
+        }
+        self.clear_history();
+        self.iter_all_mut()
+            .fold(false, |changed, hotkey| hotkey.reset() | changed)
+    }
+
+    /// Check if any shortcut of an action is pressed (see [`Bind::pressed`]).
+    /// Only the first pressed shortcut is consumed. Actions that are not
+    /// registered or not in an active scope (see [`Hotkeys::set_active_scopes`])
+    /// are never pressed. Unlike [`Hotkeys::triggered`], more specific
+    /// shortcuts of other actions are not taken into account. Without the
+    /// [Context], the operating system isn't known, so Ctrl+Alt is kept
+    /// as it is unless [`Hotkeys::set_altgr_policy`] was set, and whether a
+    /// text field has focus has to be passed in, so the [TextInputPolicy]
+    /// can be followed (see [`Hotkeys::set_text_input_policy`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The [InputState] to check with.
+    /// * `id` - The ID of the action.
+    /// * `typing` - Whether the user is typing into a text field, i.e.
+    ///   [`Context::wants_keyboard_input`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("step", "S".parse().unwrap())
+    ///     .register("save", "Ctrl+S".parse().unwrap());
+    /// let press = |modifiers| Event::Key {
+    ///     key: Key::S,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers,
+    /// };
+    /// let mut input = RawInput::default();
+    /// input.events = vec![press(Modifiers::NONE), press(Modifiers::CTRL)];
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| {
+    ///     ctx.input_mut(|input| {
+    ///         // by default, only shortcuts with modifiers are pressed while typing
+    ///         assert!(!hotkeys.pressed(&mut input.clone(), "step", true));
+    ///         assert!(hotkeys.pressed(&mut input.clone(), "save", true));
+    ///         assert!(hotkeys.pressed(input, "step", false));
+    ///     });
+    /// });
+    /// ```
+    pub fn pressed(&self, input: &mut InputState, id: &str, typing: bool) -> bool {
+        let altgr = self.altgr.unwrap_or(crate::AltGrPolicy::Keep);
+        self.get(id).is_some_and(|hotkey| {
+            self.triggered_binding(hotkey, input, typing, altgr)
+                .is_some()
+        })
+    }
+
+    /// Check if the current shortcut of an action is pressed, like [`Hotkeys::pressed`].
+    /// Also follows the [TextInputPolicy] (see [`Hotkeys::set_text_input_policy`]).
+    pub fn pressed_ctx(&self, ctx: &Context, id: &str) -> bool {
+        let typing = ctx.wants_keyboard_input();
+        let altgr = self.altgr_policy_for(ctx);
+        self.get(id).is_some_and(|hotkey| {
+            ctx.input_mut(|input| {
+                self.triggered_binding(hotkey, input, typing, altgr)
+                    .is_some()
+            })
+        })
+    }
+
+    /// Get all actions with a shortcut that was pressed on this frame, in
+    /// the order they were registered. Actions that are not in an active scope
+    /// (see [`Hotkeys::set_active_scopes`]) or not allowed by the
+    /// [TextInputPolicy] (see [`Hotkeys::set_text_input_policy`]) are skipped.
+    ///
+    /// When several actions match the same key press, only the most specific
+    /// one is triggered: the one whose shortcut needs the most modifiers, so
+    /// an exact match wins over one that ignores an extra Shift or Alt. E.g.
+    /// Ctrl+Shift+S triggers "save as" on Ctrl+Shift+S, not "save" on Ctrl+S.
+    /// Ties go to the action that was registered first, so actions with the
+    /// same shortcut only trigger the first of them. Actions set with
+    /// [`Hotkeys::set_always_fire`] are triggered regardless, and don't
+    /// suppress other actions. Calling this again on the same frame returns
+    /// no actions.
+    ///
+    /// Chords (see [`Hotkeys::register_chord`]) that were completed come
+    /// first, along with actions on the first step of a chord alone that
+    /// are triggered late.
+    ///
+    /// With the `global` feature, actions triggered by OS-global hotkeys
+    /// since the last call (see `GlobalSync::poll`) come last, with
+    /// [`TriggerSource::Global`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Hotkeys, TriggerSource};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("save copy", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    ///
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::Key {
+    ///     key: Key::S,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL,
+    /// });
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| {
+    ///     let triggered: Vec<_> = hotkeys.triggered(ctx).collect();
+    ///     assert_eq!(triggered.len(), 1);
+    ///     assert_eq!(triggered[0].id(), "save");
+    ///     assert_eq!(triggered[0].source(), TriggerSource::Keyboard);
+    ///
+    ///     assert_eq!(hotkeys.triggered(ctx).count(), 0);
+    /// });
+    /// ```
+    ///
+    /// Only the most specific of overlapping shortcuts is triggered, whatever
+    /// order the actions were registered in:
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("stamp", "S".parse().unwrap())
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("save as", "Ctrl+Shift+S".parse().unwrap())
+    ///     .register("sample", "Shift+S".parse().unwrap())
+    ///     .register("hint", "Shift+S".parse().unwrap())
+    ///     .set_always_fire("hint", true);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut press = |modifiers| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key: Key::S,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers,
+    ///     });
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///     });
+    ///     ids
+    /// };
+    ///
+    /// assert_eq!(press(Modifiers::NONE), ["stamp"]);
+    /// assert_eq!(press(Modifiers::CTRL), ["save"]);
+    /// assert_eq!(press(Modifiers::CTRL | Modifiers::SHIFT), ["save as"]);
+    /// // "stamp" also matches, since egui ignores an extra Shift, but "sample"
+    /// // is more specific. "hint" always fires
+    /// assert_eq!(press(Modifiers::SHIFT), ["sample", "hint"]);
+    /// // an extra Alt falls back to the most specific match
+    /// assert_eq!(press(Modifiers::CTRL | Modifiers::ALT), ["save"]);
+    /// ```
+    pub fn triggered(&mut self, ctx: &Context) -> std::vec::IntoIter<Triggered> {
+        #[cfg(feature = "serde")]
+        self.auto_store(ctx);
+        let pass = ctx.cumulative_pass_nr();
+        if self.triggered_pass.replace(pass) == Some(pass) {
+            return Vec::new().into_iter();
+        }
+        self.note_focused_area(ctx);
+        // the key presses are only normalized while matching, see `AltGrPolicy::pressed`
+        let altgr = self.altgr_policy_for(ctx);
+        let typing = ctx.wants_keyboard_input();
+        // only looked at if the debug log is enabled, see `Hotkeys::set_debug_enabled`
+        let debug = self.debug.is_some().then(|| {
+            ctx.input_mut(|input| {
+                altgr.normalized(input, |input| self.debug_matches(input, typing))
+            })
+        });
+        let mut triggered = self.dispatch_chords(ctx, typing, altgr);
+        triggered.extend(
+            ctx.input_mut(|input| altgr.normalized(input, |input| self.dispatch(input, typing))),
+        );
+        #[cfg(feature = "global")]
+        triggered.extend(self.global_triggered.drain(..).map(|id| Triggered {
+            id,
+            source: TriggerSource::Global,
+        }));
+        #[cfg(feature = "gamepad")]
+        triggered.extend(self.dispatch_gamepad());
+        if let Some(matches) = debug {
+            self.debug_record(pass, matches, &triggered);
+        }
+        if self.stats.is_some() {
+            self.count_triggered(ctx.input(|input| input.time), &triggered);
+        }
+        triggered.into_iter()
+    }
+
+    /// Get the actions triggered on this frame by their shortcuts, most
+    /// specific first as described in [`Hotkeys::triggered`].
+    fn dispatch(&self, input: &mut InputState, typing: bool) -> Vec<Triggered> {
+        let candidates = self.candidates(typing, false);
+        let mut sources: Vec<Option<TriggerSource>> = vec![None; self.hotkeys.len()];
+        for (index, hotkey, shortcut) in &candidates {
+            if sources[*index].is_some() {
+                continue;
+            }
+            // consuming the key press suppresses the less specific actions
+            let pressed = match hotkey.always_fire {
+                true => peek(shortcut, input),
+                false => shortcut.pressed(input),
+            };
+            if pressed {
+                sources[*index] = Some(TriggerSource::of(shortcut));
+            }
+        }
+        self.hotkeys
+            .iter()
+            .zip(sources)
+            .filter_map(|(hotkey, source)| {
+                Some(Triggered {
+                    id: hotkey.id.clone(),
+                    source: source?,
+                })
+            })
+            .collect()
+    }
+
+    /// Get the bound shortcuts of the active actions that are allowed while
+    /// `typing`, with their action and its index, in the order they are
+    /// checked by [`Hotkeys::triggered`]. Only the first steps of chords
+    /// if `chords` is true, and only the other actions otherwise.
+    pub(crate) fn candidates(
+        &self,
+        typing: bool,
+        chords: bool,
+    ) -> Vec<(usize, &Hotkey, &Shortcut)> {
+        let mut candidates: Vec<(usize, &Hotkey, &Shortcut)> = vec![];
+        for (index, hotkey) in self.hotkeys.iter().enumerate() {
+            if !self.is_hotkey_active(hotkey) || hotkey.second.is_some() != chords {
+                continue;
+            }
+            let policy = hotkey.text_input_policy.unwrap_or(self.text_input_policy);
+            candidates.extend(
+                hotkey
+                    .bindings()
+                    .filter(|shortcut| shortcut.is_bound() && (!typing || policy.allows(*shortcut)))
+                    .map(|shortcut| (index, hotkey, shortcut)),
+            );
+        }
+        // actions that always fire don't consume the key press, so they go first. The
+        // sort is stable, so ties keep the order the actions were registered in
+        candidates.sort_by_key(|(_, hotkey, shortcut)| {
+            Reverse((hotkey.always_fire, specificity(shortcut)))
+        });
+        candidates
+    }
+
+    /// Number of registered actions.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.hotkeys.len()
+    }
+
+    /// Whether no actions are registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.hotkeys.is_empty()
+    }
+
+    /// Iterate over all actions in the order they were registered.
+    pub fn iter(&self) -> std::slice::Iter<'_, Hotkey> {
+        self.hotkeys.iter()
+    }
+
+    /// Iterate over all actions in the order they were registered, e.g. to
+    /// show a [Keybind](crate::Keybind) for each. Yields nothing while the
+    /// active profile is a preset (see [`Hotkeys::is_profile_read_only`]),
+    /// use [`Hotkeys::iter`] to show its shortcuts.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Hotkey> {
+        match self.is_profile_read_only(self.active_profile()) {
+            true => [].iter_mut(),
+            false => self.iter_all_mut(),
+        }
+    }
+
+    /// Iterate over all actions like [`Hotkeys::iter_mut`], even while the
+    /// active profile is a preset.
+    pub(crate) fn iter_all_mut(&mut self) -> std::slice::IterMut<'_, Hotkey> {
+        self.note_change();
+        self.hotkeys.iter_mut()
+    }
+
+    /// Get the categories of all actions, in the order they were first
+    /// registered in. [None] stands for the actions without a category.
+    pub fn categories(&self) -> Vec<Option<&str>> {
+        let mut categories = vec![];
+        for category in self.hotkeys.iter().map(Hotkey::category) {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        categories
+    }
+
+    /// Get the actions grouped by their category. The categories are in the
+    /// order of [`Hotkeys::categories`], and the actions of each category in
+    /// the order they were registered.
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register_in("edit", "undo", "Ctrl+Z".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap())
+    ///     .register_in("file", "open", "Ctrl+O".parse().unwrap())
+    ///     .register_in("edit", "redo", "Ctrl+Y".parse().unwrap());
+    ///
+    /// let groups: Vec<(Option<&str>, Vec<&str>)> = hotkeys
+    ///     .grouped()
+    ///     .into_iter()
+    ///     .map(|(category, hotkeys)| (category, hotkeys.iter().map(|h| h.id()).collect()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     groups,
+    ///     [
+    ///         (Some("file"), vec!["save", "open"]),
+    ///         (Some("edit"), vec!["undo", "redo"]),
+    ///         (None, vec!["quit"]),
+    ///     ]
+    /// );
+    ///
+    /// // moving an action to another category keeps its place among the actions
+    /// hotkeys.register_in("edit", "quit", "Ctrl+Q".parse().unwrap());
+    /// assert_eq!(hotkeys.categories(), [Some("file"), Some("edit")]);
+    /// let edit: Vec<&str> = hotkeys.grouped()[1].1.iter().map(|h| h.id()).collect();
+    /// assert_eq!(edit, ["undo", "quit", "redo"]);
+    /// ```
+    pub fn grouped(&self) -> Vec<(Option<&str>, Vec<&Hotkey>)> {
+        self.categories()
+            .into_iter()
+            .map(|category| {
+                let hotkeys = self
+                    .hotkeys
+                    .iter()
+                    .filter(|hotkey| hotkey.category() == category)
+                    .collect();
+                (category, hotkeys)
+            })
+            .collect()
+    }
+}
+
+impl Hotkeys {
+    /// Get the current shortcuts as action ID to shortcut string pairs, in
+    /// the format of [`Shortcut`]'s [`Display`](std::fmt::Display)
+    /// implementation. If an action has alternate shortcuts, they follow the
+    /// current one, separated by commas (e.g. `"Ctrl+Y, Ctrl+Shift+Z"`).
+    /// Saved shortcuts of unknown actions are included.
+    ///
+    /// If other profiles than [DEFAULT_PROFILE](crate::DEFAULT_PROFILE) are
+    /// used (see [`Hotkeys::switch_profile`]), these are the shortcuts of the
+    /// active profile, followed by entries for the profiles whose IDs start
+    /// with `"@profile"`.
+    pub fn to_saved(&self) -> Vec<(String, String)> {
+        self.hotkeys
+            .iter()
+            .map(|hotkey| (hotkey.id.clone(), hotkey.format_bindings()))
+            .chain(self.unknown.iter().cloned())
+            .chain(self.saved_profiles())
+            .collect()
+    }
+
+    /// Load saved action ID to shortcut string pairs into the registered actions.
+    ///
+    /// Actions that are missing from `saved` keep their current shortcut. Saved
+    /// shortcuts of actions that are not registered are kept (see
+    /// [`Hotkeys::to_saved`]) and applied if the action is registered later.
+    /// Renamed actions and changed shortcut formats are migrated, see
+    /// [`Hotkeys::migrate_id`]. The saved profiles are restored, and the
+    /// shortcuts are loaded into the profile that was active, see
+    /// [`Hotkeys::switch_profile`]. The history is cleared, see
+    /// [`Hotkeys::undo`].
+    ///
+    /// # Returns
+    ///
+    /// The IDs that weren't applied, because they are not registered or
+    /// their shortcut couldn't be parsed, so the app can log them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{Hotkeys, Shortcut};
+    ///
+    /// // saved by an older version of the app, which had a "print" action
+    /// let saved = [("save", "Ctrl+Shift+S"), ("print", "Ctrl+P")];
+    ///
+    /// // the new version has no "print", but a new "find" action
+    /// let shortcut = |key| Shortcut::new(Some(KeyboardShortcut::new(Modifiers::CTRL, key)), None);
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", shortcut(Key::S))
+    ///     .register("find", shortcut(Key::F));
+    ///
+    /// let not_applied = hotkeys.load_saved(saved);
+    /// assert_eq!(not_applied, ["print"]);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+    /// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+    ///
+    /// // the unknown action is kept when saving again
+    /// assert!(hotkeys
+    ///     .to_saved()
+    ///     .contains(&("print".to_string(), "Ctrl+P".to_string())));
+    /// ```
+    pub fn load_saved<K, V>(&mut self, saved: impl IntoIterator<Item = (K, V)>) -> Vec<String>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let entries = saved
+            .into_iter()
+            .enumerate()
+            .map(|(index, (id, shortcut))| (index + 1, id.into(), shortcut.into()))
+            .collect();
+        let report = self.apply_saved(entries);
+        let mut not_applied: Vec<(usize, String)> = report
+            .invalid
+            .into_iter()
+            .map(|(index, id, _)| (index, id))
+            .chain(report.unknown)
+            .collect();
+        not_applied.sort_by_key(|(index, _)| *index);
+        not_applied.into_iter().map(|(_, id)| id).collect()
+    }
+}
+
+/// Saves the current shortcuts as a map of action IDs to shortcut strings
+/// (see [`Hotkeys::to_saved`]).
+#[cfg(feature = "serde")]
+impl serde::Serialize for Hotkeys {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.to_saved())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Hotkeys {
+    /// Load a map of action IDs to shortcut strings, as saved by [Hotkeys]'
+    /// [`Serialize`](serde::Serialize) implementation, into the registered
+    /// actions. See [`Hotkeys::load_saved`].
+    ///
+    /// # Returns
+    ///
+    /// The IDs that weren't applied, or an error if the data isn't a map of
+    /// strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, Shortcut};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    ///
+    /// let saved = r#"{ "save": "Ctrl+Shift+S", "print": "Ctrl+P" }"#;
+    /// let not_applied = hotkeys
+    ///     .load(&mut serde_json::Deserializer::from_str(saved))
+    ///     .unwrap();
+    /// assert_eq!(not_applied, ["print"]);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+    ///
+    /// let json = serde_json::to_string(&hotkeys).unwrap();
+    /// assert_eq!(json, r#"{"save":"Ctrl+Shift+S","print":"Ctrl+P"}"#);
+    /// ```
+    ///
+    /// Saved shortcuts of renamed actions are migrated (see [`Hotkeys::migrate_id`]):
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("delete_selection", "Delete".parse().unwrap())
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .migrate_id("del", "delete_selection");
+    ///
+    /// // saved before "del" was renamed
+    /// let saved = r#"{ "del": "Backspace", "save": "F2" }"#;
+    /// let not_applied = hotkeys
+    ///     .load(&mut serde_json::Deserializer::from_str(saved))
+    ///     .unwrap();
+    /// assert!(not_applied.is_empty());
+    /// assert_eq!(hotkeys.current("delete_selection").unwrap().to_string(), "Backspace");
+    ///
+    /// let json = serde_json::to_string(&hotkeys).unwrap();
+    /// assert_eq!(json, r#"{"delete_selection":"Backspace","save":"F2"}"#);
+    /// ```
+    pub fn load<'de, D: serde::Deserializer<'de>>(
+        &mut self,
+        deserializer: D,
+    ) -> Result<Vec<String>, D::Error> {
+        serde::de::DeserializeSeed::deserialize(self, deserializer)
+    }
+}
+
+/// Loads saved shortcuts without any registered actions. They are applied
+/// when the actions are registered with [`Hotkeys::register`]; use
+/// [`Hotkeys::load`] to load into already registered actions instead.
+///
+/// ```
+/// use egui_keybind::Hotkeys;
+///
+/// let mut hotkeys: Hotkeys = serde_json::from_str(r#"{ "save": "Ctrl+Shift+S" }"#).unwrap();
+/// hotkeys
+///     .register("save", "Ctrl+S".parse().unwrap())
+///     .register("find", "Ctrl+F".parse().unwrap());
+///
+/// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+/// assert_eq!(hotkeys.default_shortcut("save").unwrap().to_string(), "Ctrl+S");
+/// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Hotkeys {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut hotkeys = Self::new();
+        serde::de::DeserializeSeed::deserialize(&mut hotkeys, deserializer)?;
+        Ok(hotkeys)
+    }
+}
+
+/// Loads saved shortcuts into existing [Hotkeys], see [`Hotkeys::load`].
+#[cfg(feature = "serde")]
+impl<'de> serde::de::DeserializeSeed<'de> for &mut Hotkeys {
+    type Value = Vec<String>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        struct SavedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SavedVisitor {
+            type Value = Vec<(String, String)>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a map of action IDs to shortcuts")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Self::Value, A::Error> {
+                let mut saved = vec![];
+                while let Some(entry) = map.next_entry()? {
+                    saved.push(entry);
+                }
+                Ok(saved)
+            }
+        }
+
+        let saved = deserializer.deserialize_map(SavedVisitor)?;
+        Ok(self.load_saved(saved))
+    }
+}
+
+impl<'a> IntoIterator for &'a Hotkeys {
+    type Item = &'a Hotkey;
+    type IntoIter = std::slice::Iter<'a, Hotkey>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CommandPolicy, EmacsMeta, ImportError, ImportMode, PointerTrigger, TextKey};
+    use egui::{Key, KeyboardShortcut};
+
+    #[test]
+    fn comma_bindings_round_trip() {
+        let mut hotkeys = Hotkeys::new();
+        hotkeys.register("settings", "Ctrl+,".parse().unwrap());
+        let mut text = Shortcut::NONE;
+        text.set_text(TextKey::new(Modifiers::CTRL, ','), None);
+        hotkeys.add_binding("settings", text);
+        hotkeys.add_binding("settings", "F2".parse().unwrap());
+
+        let exported = hotkeys.export_string();
+        assert_eq!(exported, "settings = \"Ctrl+Comma, Ctrl+\\\\,, F2\"\n");
+        let mut imported = hotkeys.clone();
+        imported.reset_all();
+        assert!(imported.import_string(&exported).unwrap().is_clean());
+        assert_eq!(imported, hotkeys);
+    }
+
+    #[test]
+    fn equivalent_shortcuts_are_not_modified() {
+        let shortcut =
+            |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+        let mut hotkeys = Hotkeys::new();
+        hotkeys
+            .register("save", "CmdOrCtrl+S".parse().unwrap())
+            .register(
+                "find",
+                shortcut(Modifiers::CTRL | Modifiers::MAC_CMD, Key::F),
+            )
+            .register(
+                "quit",
+                shortcut(Modifiers::COMMAND, Key::Q)
+                    .with_command_policy(CommandPolicy::CrossPlatform),
+            );
+
+        // no pointer button to trigger
+        let save = "CmdOrCtrl+S".parse::<Shortcut>().unwrap();
+        hotkeys.set("save", save.with_pointer_trigger(PointerTrigger::Release));
+        // CmdOrCtrl is Ctrl or Cmd, which are both held
+        let find = Modifiers::CTRL | Modifiers::MAC_CMD | Modifiers::COMMAND;
+        hotkeys.set("find", shortcut(find, Key::F));
+        // recorded on Mac, and matched as CmdOrCtrl
+        let quit = shortcut(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::Q);
+        hotkeys.set(
+            "quit",
+            quit.with_command_policy(CommandPolicy::CrossPlatform),
+        );
+        assert!(hotkeys.modified().is_empty());
+
+        // different on Mac
+        hotkeys.set("save", "Ctrl+S".parse().unwrap());
+        assert!(hotkeys.is_modified("save"));
+        hotkeys.set("save", "Cmd+S".parse().unwrap());
+        assert!(hotkeys.is_modified("save"));
+        // and elsewhere
+        hotkeys.set("find", shortcut(Modifiers::MAC_CMD, Key::F));
+        assert!(hotkeys.is_modified("find"));
+    }
+
+    /// Hotkeys with the "Vim" preset active, which moves "left" to H.
+    fn vim_preset() -> Hotkeys {
+        let mut hotkeys = Hotkeys::new();
+        hotkeys
+            .register("left", "ArrowLeft".parse().unwrap())
+            .register("save", "Ctrl+S".parse().unwrap())
+            .add_preset("Vim", [("left", "H")]);
+        hotkeys.switch_profile("Vim").unwrap();
+        hotkeys
+    }
+
+    #[test]
+    fn reset_all_leaves_a_preset_alone() {
+        let mut hotkeys = vim_preset();
+        let saved = hotkeys.to_saved();
+        assert!(!hotkeys.reset_all());
+        assert_eq!(hotkeys.to_saved(), saved);
+        assert_eq!(hotkeys.current("left").unwrap().to_string(), "H");
+    }
+
+    #[test]
+    fn imports_leave_a_preset_alone() {
+        let mut hotkeys = vim_preset();
+        let saved = hotkeys.to_saved();
+        assert_eq!(
+            hotkeys.import_string("left = \"K\""),
+            Err(ImportError::ReadOnlyProfile)
+        );
+        assert_eq!(
+            hotkeys.import_emacs_string("left = \"C-b\"", EmacsMeta::Alt),
+            Err(ImportError::ReadOnlyProfile)
+        );
+
+        let report = hotkeys.apply_from_text("left = \"K\"");
+        assert!(report.rejected);
+        assert!(report.applied.is_empty());
+        assert_eq!(
+            report.errors(),
+            [(0, "the active profile is a read-only preset".to_string())]
+        );
+        let report = hotkeys.apply_from_text_with_mode("left = \"K\"", ImportMode::Partial);
+        assert!(report.rejected);
+        assert_eq!(hotkeys.to_saved(), saved);
+    }
+
+    #[test]
+    fn iter_mut_leaves_a_preset_alone() {
+        let mut hotkeys = vim_preset();
+        let saved = hotkeys.to_saved();
+        for hotkey in hotkeys.iter_mut() {
+            *hotkey.current_mut() = Shortcut::NONE;
+        }
+        assert_eq!(hotkeys.iter_mut().count(), 0);
+        assert_eq!(hotkeys.to_saved(), saved);
+
+        // the preset is applied to every action, and a copy of it can be changed
+        assert_eq!(hotkeys.iter().count(), 2);
+        hotkeys.duplicate_profile("Vim", "Mine").unwrap();
+        hotkeys.switch_profile("Mine").unwrap();
+        assert_eq!(hotkeys.current("left").unwrap().to_string(), "H");
+        for hotkey in hotkeys.iter_mut() {
+            *hotkey.current_mut() = Shortcut::NONE;
+        }
+        assert!(hotkeys.iter().all(|hotkey| !hotkey.current().is_bound()));
+    }
+}
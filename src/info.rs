@@ -0,0 +1,244 @@
+use crate::{Hotkey, Hotkeys};
+use std::sync::Arc;
+
+/// What people see of an action in [Hotkeys] instead of its ID, e.g. in a
+/// [KeybindsPanel](crate::KeybindsPanel), see [`Hotkeys::set_info`].
+///
+/// The name and the description go through the translator of the
+/// [Hotkeys] when they're shown (see [`Hotkeys::set_translator`]), so they
+/// can be translation keys as well. They're never saved with the shortcuts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActionInfo {
+    name: String,
+    description: Option<String>,
+    icon: Option<char>,
+}
+
+impl ActionInfo {
+    /// Create a new [ActionInfo] without a description or icon.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the action, e.g. `"Save file"`.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            icon: None,
+        }
+    }
+
+    /// Set a longer description of the action, e.g. `"Save the open file to
+    /// disk"`. It's shown when hovering the action in a
+    /// [KeybindsPanel](crate::KeybindsPanel).
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set an icon that is shown before the name in a
+    /// [KeybindsPanel](crate::KeybindsPanel) and a
+    /// [CommandPalette](crate::CommandPalette), e.g. `'💾'`.
+    pub fn with_icon(mut self, icon: char) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// The untranslated name of the action.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The untranslated description of the action, if any.
+    #[inline]
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// The icon of the action, if any.
+    #[inline]
+    pub fn icon(&self) -> Option<char> {
+        self.icon
+    }
+}
+
+/// Translates the names of actions, see [`Hotkeys::set_translator`]. Texts
+/// are kept as they are without a translator.
+#[derive(Clone, Default)]
+pub(crate) struct Translator(Option<Arc<TranslateFn>>);
+
+/// Translates a text, see [`Hotkeys::set_translator`].
+type TranslateFn = dyn Fn(&str) -> String + Send + Sync;
+
+impl std::fmt::Debug for Translator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("Translator(..)"),
+            None => f.write_str("Translator(None)"),
+        }
+    }
+}
+
+impl Translator {
+    /// Translate a text.
+    pub(crate) fn translate(&self, text: &str) -> String {
+        match &self.0 {
+            Some(translator) => translator(text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Get the translated name of an action, which is its ID if it has no
+    /// [ActionInfo].
+    pub(crate) fn name(&self, hotkey: &Hotkey) -> String {
+        self.translate(hotkey.info().map_or(hotkey.id(), ActionInfo::name))
+    }
+
+    /// Get the translated description of an action, if it has one.
+    pub(crate) fn description(&self, hotkey: &Hotkey) -> Option<String> {
+        let description = hotkey.info()?.description()?;
+        Some(self.translate(description))
+    }
+}
+
+impl Hotkey {
+    /// The name, description and icon of the action, if they were set with
+    /// [`Hotkeys::set_info`].
+    #[inline]
+    pub fn info(&self) -> Option<&ActionInfo> {
+        self.info.as_ref()
+    }
+}
+
+impl Hotkeys {
+    /// Set the name, description and icon of an action, which are shown
+    /// instead of its ID in a [KeybindsPanel](crate::KeybindsPanel),
+    /// [CheatSheet](crate::CheatSheet), [CommandPalette](crate::CommandPalette)
+    /// and exported tables (see [`Hotkeys::export_markdown`]). Saved
+    /// shortcuts only have the ID, see [`Hotkeys::to_saved`]. Does nothing
+    /// if the action is not registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{ActionInfo, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .set_info(
+    ///         "save",
+    ///         ActionInfo::new("Save file")
+    ///             .with_description("Save the open file to disk")
+    ///             .with_icon('💾'),
+    ///     )
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    ///
+    /// assert_eq!(hotkeys.display_name("save"), "Save file");
+    /// assert_eq!(hotkeys.description("save").as_deref(), Some("Save the open file to disk"));
+    /// assert_eq!(hotkeys.get("save").and_then(|hotkey| hotkey.info()?.icon()), Some('💾'));
+    /// // without info, the ID is shown
+    /// assert_eq!(hotkeys.display_name("find"), "find");
+    /// assert_eq!(hotkeys.description("find"), None);
+    ///
+    /// assert!(hotkeys.export_markdown().contains("| Other | Save file | Ctrl+S |"));
+    /// assert_eq!(
+    ///     hotkeys.to_saved(),
+    ///     [("save".into(), "Ctrl+S".into()), ("find".into(), "Ctrl+F".into())]
+    /// );
+    /// ```
+    pub fn set_info(&mut self, id: &str, info: ActionInfo) -> &mut Self {
+        if let Some(hotkey) = self.get_mut(id) {
+            hotkey.info = Some(info);
+        }
+        self
+    }
+
+    /// Set a function that translates the names and descriptions of actions
+    /// (see [`Hotkeys::set_info`]) and the names of categories, scopes and
+    /// namespaces when they're shown, e.g. to look them up in the catalog of
+    /// the current language. It's called on every frame they're shown, so
+    /// switching the language shows up right away. Actions without a name
+    /// are translated by their ID.
+    ///
+    /// The translations are never saved with the shortcuts, and a
+    /// `with_display_names` function of a widget takes precedence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{ActionInfo, Hotkeys};
+    /// use std::sync::{
+    ///     atomic::{AtomicBool, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// let german = Arc::new(AtomicBool::new(false));
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .set_info("save", ActionInfo::new("action.save"))
+    ///     .set_translator({
+    ///         let german = german.clone();
+    ///         move |key| {
+    ///             let german = german.load(Ordering::Relaxed);
+    ///             match (key, german) {
+    ///                 ("action.save", false) => "Save",
+    ///                 ("action.save", true) => "Speichern",
+    ///                 ("file", false) => "File",
+    ///                 ("file", true) => "Datei",
+    ///                 (key, _) => key,
+    ///             }
+    ///             .to_string()
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(hotkeys.display_name("save"), "Save");
+    /// german.store(true, Ordering::Relaxed);
+    /// assert_eq!(hotkeys.display_name("save"), "Speichern");
+    /// assert_eq!(hotkeys.translate("file"), "Datei");
+    /// assert!(hotkeys.export_markdown().contains("| Datei | Speichern | Ctrl+S |"));
+    ///
+    /// # #[cfg(feature = "serde")] {
+    /// let json = serde_json::to_string(&hotkeys).unwrap();
+    /// assert_eq!(json, r#"{"save":"Ctrl+S"}"#);
+    /// # }
+    /// ```
+    pub fn set_translator(
+        &mut self,
+        translator: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.translator = Translator(Some(Arc::new(translator)));
+        self
+    }
+
+    /// Remove the translator, so texts are shown as they are, see
+    /// [`Hotkeys::set_translator`].
+    pub fn clear_translator(&mut self) -> &mut Self {
+        self.translator = Translator::default();
+        self
+    }
+
+    /// Translate a text with the translator of [`Hotkeys::set_translator`],
+    /// or keep it as it is without one.
+    pub fn translate(&self, text: &str) -> String {
+        self.translator.translate(text)
+    }
+
+    /// Get the translated name of an action to show, see
+    /// [`Hotkeys::set_info`]. This is its translated ID if it has no name
+    /// or isn't registered.
+    pub fn display_name(&self, id: &str) -> String {
+        match self.get(id) {
+            Some(hotkey) => self.translator.name(hotkey),
+            None => self.translate(id),
+        }
+    }
+
+    /// Get the translated description of an action, if it has one, see
+    /// [`Hotkeys::set_info`].
+    pub fn description(&self, id: &str) -> Option<String> {
+        self.translator.description(self.get(id)?)
+    }
+}
@@ -1,19 +1,388 @@
-use crate::Bind;
+use crate::{
+    bind::pointer_separator,
+    capture::{CaptureSettings, CaptureState},
+    format_with_modifier_order, reserved_shortcut, AltGrPolicy, Bind, CaptureStatus, CommandPolicy,
+    GestureDirection, InputKinds, KeyNameMap, ModifierKind, ModifierNamesExt, PointerTrigger,
+    Shortcut, TextKey, WheelDirection,
+};
 use egui::{
-    pos2, vec2, Event, Id, Key, KeyboardShortcut, ModifierNames, PointerButton, RichText, Sense,
-    TextStyle, Ui, Widget, WidgetInfo, WidgetText, WidgetType,
+    os::OperatingSystem, pos2, text::LayoutJob, vec2, Align, Align2, Area, Button, Color32,
+    Context, Event, FontSelection, Galley, Id, Key, KeyboardShortcut, LayerId, Memory,
+    ModifierNames, Modifiers, Order, Painter, PointerButton, PopupCloseBehavior, Rect, Response,
+    RichText, Rounding, Sense, Shape, Stroke, TextEdit, TextStyle, Ui, UiBuilder, Vec2, Widget,
+    WidgetInfo, WidgetText, WidgetType,
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// The modifier names used for screen readers, see [`Keybind::with_spoken_key_names`].
+const SPOKEN_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    is_short: false,
+    alt: "Alt",
+    ctrl: "Control",
+    shift: "Shift",
+    mac_cmd: "Command",
+    mac_alt: "Option",
+    concat: " ",
+};
+
+/// The modifier names of the tooltip that explains symbols, see
+/// [`Keybind::with_symbol_tooltip`].
+const READABLE_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    concat: "+",
+    ..SPOKEN_MODIFIER_NAMES
 };
 
+/// Announced to screen readers when a [Keybind] starts recording.
+const RECORDING_ANNOUNCEMENT: &str = "Recording shortcut, press the desired keys";
+
+/// Announced to screen readers when a [Keybind] that only records pointer
+/// buttons starts recording.
+const POINTER_RECORDING_ANNOUNCEMENT: &str = "Recording shortcut, press the desired mouse button";
+
+/// Announced to screen readers when a [Keybind] stops recording without a
+/// new bind.
+const CANCELLED_ANNOUNCEMENT: &str = "Recording cancelled";
+
+/// What happens to the bind of a [Keybind] that is dragged onto another one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DropPolicy {
+    /// The bind is moved, and the dragged keybind is cleared (see [`Bind::clear`]).
+    #[default]
+    Move,
+    /// The binds of both keybinds are swapped.
+    Swap,
+}
+
+/// Which key press a [Keybind] captures when several land in the same
+/// frame, e.g. from a fast typist or a low frame rate. The same goes for
+/// pointer button presses. See [`Keybind::with_capture_strategy`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaptureStrategy {
+    /// Capture the first press of the frame.
+    First,
+    /// Capture the last press of the frame, the most recent intent. This is
+    /// the default.
+    #[default]
+    Last,
+}
+
+impl CaptureStrategy {
+    /// Pick the press to capture from the presses of a frame, in order.
+    pub(crate) fn pick<T>(self, mut presses: impl Iterator<Item = T>) -> Option<T> {
+        match self {
+            Self::First => presses.next(),
+            Self::Last => presses.last(),
+        }
+    }
+}
+
+/// How a [Keybind] looks when its bind is not bound to anything
+/// (see [`Bind::is_bound`]).
+///
+/// This is only used while the widget is idle; hovering and capturing
+/// use the normal styles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnboundStyle {
+    /// Whether to use italic text.
+    pub italic: bool,
+    /// Whether to use the weak text color ([`egui::Visuals::weak_text_color`]).
+    pub weak_text: bool,
+    /// Whether to paint a dashed stroke around the button.
+    pub dashed_stroke: bool,
+}
+
+impl UnboundStyle {
+    /// Unbound keybinds look the same as bound ones.
+    pub const NONE: Self = Self {
+        italic: false,
+        weak_text: false,
+        dashed_stroke: false,
+    };
+
+    /// Muted, italic text. This is the default.
+    pub const MUTED: Self = Self {
+        italic: true,
+        weak_text: true,
+        dashed_stroke: false,
+    };
+}
+
+impl Default for UnboundStyle {
+    fn default() -> Self {
+        Self::MUTED
+    }
+}
+
+/// Where the text of a [Keybind] (set by [`Keybind::with_text`]) is placed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelLayout {
+    /// Next to the button, on the right. This is the default.
+    #[default]
+    Right,
+    /// On its own line above the button, both left-aligned.
+    Above,
+}
+
 /// A keybind (hotkey) widget for [egui].
+///
+/// While the widget has keyboard focus and isn't waiting for input, the bind
+/// can be copied and pasted as text with the platform's copy and paste
+/// shortcuts (e.g. Ctrl+C and Ctrl+V). While waiting for input, these
+/// shortcuts are captured like any other key instead.
+///
+/// # Held keys
+///
+/// Keys that are held when the recording starts, or that went down during
+/// it, are only captured again after they're released, since some backends
+/// send auto-repeat without marking it as a repeat.
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Keybind, Shortcut};
+///
+/// let ctx = egui::Context::default();
+/// let mut shortcut = Shortcut::NONE;
+/// let mut changes = 0;
+/// // returns the widget's rect and the bind after the frame
+/// let mut frame = |events: Vec<Event>| {
+///     let mut rect = egui::Rect::NOTHING;
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             let response = ui.add(Keybind::new(&mut shortcut, "keybind"));
+///             changes += usize::from(response.changed());
+///             rect = response.rect;
+///         });
+///     });
+///     (rect, shortcut.to_string(), changes)
+/// };
+/// let pos = frame(vec![]).0.center();
+/// let click = |pressed| Event::PointerButton {
+///     pos,
+///     button: PointerButton::Primary,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let key = |pressed| Event::Key {
+///     key: Key::A,
+///     physical_key: None,
+///     pressed,
+///     repeat: false,
+///     modifiers: Modifiers::NONE,
+/// };
+///
+/// // A goes down as the recording starts, and is pressed again without
+/// // being released
+/// frame(vec![Event::PointerMoved(pos), key(true), click(true), click(false)]);
+/// let (_, bind, _) = frame(vec![key(true)]);
+/// assert_eq!(bind, "None");
+///
+/// frame(vec![key(false)]);
+/// let (_, bind, changes) = frame(vec![key(true)]);
+/// assert_eq!(bind, "A");
+/// assert_eq!(changes, 1);
+/// ```
+///
+/// # Memory
+///
+/// A [Keybind] only keeps an entry in egui's memory while it's in use, e.g.
+/// while it's recording, so lists with hundreds of keybinds don't fill it up.
+/// The formatted and laid out texts of binds are kept in a single cache that
+/// all keybinds share, and so are their IDs with the `testing` feature.
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Keybind, Shortcut};
+///
+/// let ctx = egui::Context::default();
+/// let mut shortcuts = vec![Shortcut::NONE; 100];
+/// // returns the rect of the first keybind and the number of entries in egui's memory
+/// let mut frame = |events: Vec<Event>, keybinds: usize| {
+///     let mut rect = egui::Rect::NOTHING;
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             for (index, shortcut) in shortcuts.iter_mut().take(keybinds).enumerate() {
+///                 let response = ui.add(Keybind::new(shortcut, egui::Id::new(index)));
+///                 if index == 0 {
+///                     rect = response.rect;
+///                 }
+///             }
+///         });
+///     });
+///     (rect, ctx.memory(|memory| memory.data.len()))
+/// };
+///
+/// let (_, entries) = frame(vec![], 0);
+/// let (rect, with_keybinds) = frame(vec![], 100);
+/// // only the shared entries were added
+/// let entries = entries + if cfg!(feature = "testing") { 2 } else { 1 };
+/// assert_eq!(with_keybinds, entries);
+///
+/// // recording adds an entry for the keybind, until it's done
+/// let pos = rect.center();
+/// let click = |pressed| Event::PointerButton {
+///     pos,
+///     button: PointerButton::Primary,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let (_, recording) = frame(vec![Event::PointerMoved(pos), click(true), click(false)], 100);
+/// assert_eq!(recording, entries + 1);
+/// let key = Event::Key {
+///     key: Key::A,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::NONE,
+/// };
+/// assert_eq!(frame(vec![key], 100).1, entries);
+/// ```
+///
+/// # Accessibility
+///
+/// Screen readers see a toggle button that is pressed while the widget is
+/// recording, named by its text (see [`Keybind::with_text`]), with the bind
+/// in spoken form as its value, e.g. `"Control S"`, and described by
+/// [`Keybind::with_description`]. Starting and ending the recording is
+/// announced, e.g. `"Shortcut set to Control S"`.
+///
+/// ```
+/// use egui::{output::OutputEvent, Event, Key, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Keybind, Shortcut};
+///
+/// let ctx = egui::Context::default();
+/// # #[cfg(feature = "accesskit")]
+/// ctx.enable_accesskit();
+/// let mut shortcut = Shortcut::NONE;
+/// let mut frame = |events: Vec<Event>| {
+///     let mut response = None;
+///     let output = ctx.run(
+///         RawInput {
+///             events,
+///             ..Default::default()
+///         },
+///         |ctx| {
+///             egui::CentralPanel::default().show(ctx, |ui| {
+///                 let keybind = Keybind::new(&mut shortcut, "save").with_text("Save");
+///                 response = Some(ui.add(keybind));
+///             });
+///         },
+///     );
+///     (response.unwrap(), output)
+/// };
+/// // the last event is what egui's screen reader reads
+/// let announced = |output: &egui::FullOutput| {
+///     output.platform_output.events.iter().rev().find_map(|event| match event {
+///         OutputEvent::ValueChanged(info) => info.label.clone(),
+///         _ => None,
+///     })
+/// };
+///
+/// // clicking the widget starts recording
+/// let (response, _) = frame(vec![]);
+/// let pos = response.rect.center();
+/// let button = |pressed| Event::PointerButton {
+///     pos,
+///     button: PointerButton::Primary,
+///     pressed,
+///     modifiers: Modifiers::NONE,
+/// };
+/// let (_, output) = frame(vec![Event::PointerMoved(pos), button(true), button(false)]);
+/// assert_eq!(
+///     announced(&output).as_deref(),
+///     Some("Recording shortcut, press the desired keys")
+/// );
+///
+/// let key = Event::Key {
+///     key: Key::S,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL,
+/// };
+/// let (_, output) = frame(vec![key]);
+/// assert_eq!(announced(&output).as_deref(), Some("Shortcut set to Control S"));
+///
+/// # #[cfg(feature = "accesskit")]
+/// # {
+/// use egui::accesskit::{Live, Role, Toggled};
+///
+/// let update = output.platform_output.accesskit_update.unwrap();
+/// let node = |id: egui::Id| {
+///     let (_, node) = update
+///         .nodes
+///         .iter()
+///         .find(|(node_id, _)| *node_id == id.value().into())
+///         .unwrap();
+///     node.clone()
+/// };
+/// let button = node(response.id);
+/// assert_eq!(button.role(), Role::Button);
+/// assert_eq!(button.toggled(), Some(Toggled::False));
+/// assert_eq!(button.label(), Some("Save"));
+/// assert_eq!(button.value(), Some("Control S"));
+/// let status = update
+///     .nodes
+///     .iter()
+///     .map(|(_, node)| node)
+///     .find(|node| node.role() == Role::Status)
+///     .unwrap();
+/// assert_eq!(status.live(), Some(Live::Polite));
+/// assert_eq!(status.label(), Some("Shortcut set to Control S"));
+/// # }
+/// ```
 pub struct Keybind<'a, B: Bind> {
     bind: &'a mut B,
-    reset: B,
-    text: &'a str,
+    /// The bind to reset to, only kept if there is a reset key.
+    reset: Option<B>,
+    /// The default bind and how to compare it, see [`Keybind::with_default`].
+    default: Option<(&'a B, BindEq<B>)>,
+    text: WidgetText,
+    text_visible: bool,
+    description: Option<&'a str>,
     id: Id,
     reset_key: Option<Key>,
-    modifier_names: &'a ModifierNames<'a>,
+    clear_keys: &'a [Key],
+    modifier_names: Option<&'a ModifierNames<'a>>,
+    separator: Option<&'a str>,
+    modifier_order: Option<&'a [ModifierKind]>,
+    key_names: Option<&'a KeyNameMap>,
+    spoken_key_names: Option<&'a KeyNameMap>,
+    unbound_style: UnboundStyle,
+    label_layout: LabelLayout,
+    pointer_icons: bool,
+    dim_background: bool,
+    confirm: bool,
+    drop_policy: Option<DropPolicy>,
+    capture_strategy: CaptureStrategy,
+    altgr: Option<AltGrPolicy>,
+    command_policy: CommandPolicy,
+    clipboard_events: bool,
+    text_fallback: bool,
+    hold_measuring: bool,
+    small: bool,
+    touch_padding: Option<Vec2>,
+    popup_editor: bool,
+    suggestions: &'a [Shortcut],
+    text_editing: bool,
+    symbol_tooltip: bool,
+    input_kind_icons: bool,
+    block_reserved: bool,
+    /// Whether the bind is formatted like on Mac, set from [`Context::os`]
+    /// when the widget is shown.
+    is_mac: bool,
 }
 
+/// Compares two binds, see [`Keybind::with_default`].
+type BindEq<B> = fn(&B, &B) -> bool;
+
 impl<'a, B: Bind> Keybind<'a, B> {
     /// Create a new [Keybind] for a given [Bind].
     ///
@@ -22,24 +391,201 @@ impl<'a, B: Bind> Keybind<'a, B> {
     /// * `bind` - The bind to use for the [Keybind].
     /// * `id` - ID for the [Keybind] in [egui]'s memory.
     pub fn new(bind: &'a mut B, id: impl Into<Id>) -> Self {
-        let prev_bind = bind.clone();
         Self {
             bind,
-            reset: prev_bind,
-            text: "",
+            reset: None,
+            default: None,
+            text: WidgetText::default(),
+            text_visible: true,
+            description: None,
             id: id.into(),
             reset_key: None,
-            modifier_names: &ModifierNames::NAMES,
+            clear_keys: &[Key::Delete, Key::Backspace],
+            modifier_names: None,
+            separator: None,
+            modifier_order: None,
+            key_names: None,
+            spoken_key_names: None,
+            unbound_style: UnboundStyle::default(),
+            label_layout: LabelLayout::default(),
+            pointer_icons: false,
+            dim_background: false,
+            confirm: false,
+            drop_policy: None,
+            capture_strategy: CaptureStrategy::default(),
+            altgr: None,
+            command_policy: CommandPolicy::default(),
+            clipboard_events: true,
+            text_fallback: false,
+            hold_measuring: false,
+            small: false,
+            touch_padding: None,
+            popup_editor: false,
+            suggestions: &[],
+            text_editing: false,
+            symbol_tooltip: true,
+            input_kind_icons: true,
+            block_reserved: false,
+            is_mac: false,
         }
     }
 
     /// Set the text of the [Keybind]. This will be displayed next to the
     /// keybind widget (and used for accessibility).
     ///
+    /// This accepts anything that can be turned into [WidgetText], like a
+    /// [`&str`](str), a [RichText] or a [`LayoutJob`](egui::text::LayoutJob).
+    /// The plain text is used for accessibility.
+    ///
     /// You can remove the text by setting it to an empty string.
     /// By default there is no text.
-    pub fn with_text(mut self, text: &'a str) -> Self {
-        self.text = text;
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui::{text::LayoutJob, Align, Color32, FontSelection, RichText};
+    /// use egui_keybind::Keybind;
+    ///
+    /// let mut caption = LayoutJob::default();
+    /// RichText::new("Open ")
+    ///     .color(Color32::LIGHT_BLUE)
+    ///     .append_to(&mut caption, ui.style(), FontSelection::Default, Align::Center);
+    /// RichText::new("the terminal")
+    ///     .strong()
+    ///     .append_to(&mut caption, ui.style(), FontSelection::Default, Align::Center);
+    ///
+    /// ui.add(Keybind::new(&mut shortcut, "open_terminal").with_text(caption));
+    /// # });
+    /// ```
+    pub fn with_text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self.text_visible = true;
+        self
+    }
+
+    /// Set the text of the [Keybind] without displaying it. It is only used
+    /// for accessibility, like the text from [`Keybind::with_text`].
+    ///
+    /// This is useful when the surrounding layout already labels the keybind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{output::OutputEvent, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shortcut: Shortcut = "Ctrl+T".parse().unwrap();
+    /// let mut frame = |events: Vec<egui::Event>, text: Option<&str>| {
+    ///     let mut response = None;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let output = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(&mut shortcut, "terminal");
+    ///             let keybind = match text {
+    ///                 Some(text) => keybind.with_hidden_text(text),
+    ///                 None => keybind,
+    ///             };
+    ///             response = Some(ui.add(keybind));
+    ///         });
+    ///     });
+    ///     (response.unwrap(), output)
+    /// };
+    ///
+    /// // it takes as much space as without the text, and the text isn't painted
+    /// let (plain, _) = frame(vec![], None);
+    /// let (hidden, output) = frame(vec![], Some("Open the terminal"));
+    /// assert_eq!(hidden.rect, plain.rect);
+    /// assert!(!output.shapes.iter().any(|shape| matches!(
+    ///     &shape.shape,
+    ///     egui::Shape::Text(text) if text.galley.text().contains("terminal")
+    /// )));
+    ///
+    /// // but screen readers get it, e.g. when the keybind is clicked
+    /// let pos = hidden.rect.center();
+    /// let click = |pressed| egui::Event::PointerButton {
+    ///     pos,
+    ///     button: egui::PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: egui::Modifiers::NONE,
+    /// };
+    /// let (_, output) = frame(
+    ///     vec![egui::Event::PointerMoved(pos), click(true), click(false)],
+    ///     Some("Open the terminal"),
+    /// );
+    /// let label = output.platform_output.events.iter().find_map(|event| match event {
+    ///     OutputEvent::Clicked(info) => info.label.clone(),
+    ///     _ => None,
+    /// });
+    /// assert_eq!(label.as_deref(), Some("Control T. Open the terminal"));
+    /// ```
+    pub fn with_hidden_text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self.text_visible = false;
+        self
+    }
+
+    /// Set a description of what the action does or how to use the
+    /// [Keybind], e.g. `"Saves the file. Click and press keys to change"`.
+    /// Screen readers get it as the description of the widget, apart from
+    /// its name and value, and it's shown when hovering the widget, after
+    /// any other hover text.
+    ///
+    /// By default there is no description.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Pos2, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// # #[cfg(feature = "accesskit")]
+    /// ctx.enable_accesskit();
+    /// ctx.style_mut(|style| style.interaction.tooltip_delay = 0.0);
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let mut frame = |pointer: Option<Pos2>| {
+    ///     let mut response = None;
+    ///     let mut input = RawInput::default();
+    ///     input.events.extend(pointer.map(egui::Event::PointerMoved));
+    ///     let output = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(&mut shortcut, "save")
+    ///                 .with_text("Save")
+    ///                 .with_description("Saves the file");
+    ///             response = Some(ui.add(keybind));
+    ///         });
+    ///     });
+    ///     (response.unwrap(), output)
+    /// };
+    ///
+    /// // hovering shows the description
+    /// let (response, _) = frame(None);
+    /// let (_, output) = frame(Some(response.rect.center()));
+    /// let (_, output) = frame(Some(response.rect.center()));
+    /// assert!(output.shapes.iter().any(|shape| matches!(
+    ///     &shape.shape,
+    ///     egui::Shape::Text(text) if text.galley.text() == "Saves the file"
+    /// )));
+    ///
+    /// # #[cfg(feature = "accesskit")]
+    /// # {
+    /// let update = output.platform_output.accesskit_update.unwrap();
+    /// let (_, button) = update
+    ///     .nodes
+    ///     .iter()
+    ///     .find(|(id, _)| *id == response.id.value().into())
+    ///     .unwrap();
+    /// assert_eq!(button.label(), Some("Save"));
+    /// assert_eq!(button.value(), Some("Control S"));
+    /// assert_eq!(button.description(), Some("Saves the file"));
+    /// # }
+    /// ```
+    pub fn with_description(mut self, description: &'a str) -> Self {
+        self.description = Some(description);
         self
     }
 
@@ -63,203 +609,3018 @@ impl<'a, B: Bind> Keybind<'a, B> {
     /// never reset to its' previous value.
     ///
     /// By default this is [None].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::Key;
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// // Escape while recording sets the bind back to Ctrl+S
+    /// ui.add(Keybind::new(&mut shortcut, "save").with_reset_key(Some(Key::Escape)));
+    /// # });
+    /// ```
     pub fn with_reset_key(mut self, key: Option<Key>) -> Self {
         self.reset_key = key;
+        // only keep the bind to reset to if it can be reset
+        if key.is_some() && self.reset.is_none() {
+            self.reset = Some(self.bind.clone());
+        }
+        self
+    }
+
+    /// Set the keys that clear the bind (see [`Bind::clear`]) while the
+    /// [Keybind] has keyboard focus and isn't waiting for input. While waiting
+    /// for input, these keys can be bound like any other key.
+    ///
+    /// Use an empty slice to disable clearing.
+    /// By default this is [`Key::Delete`] and [`Key::Backspace`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui::Key;
+    /// use egui_keybind::Keybind;
+    ///
+    /// ui.add(Keybind::new(&mut shortcut, "save").with_clear_keys(&[Key::Delete]));
+    /// # });
+    /// ```
+    pub fn with_clear_keys(mut self, keys: &'a [Key]) -> Self {
+        self.clear_keys = keys;
         self
     }
 
     /// Set the bind that the [Keybind] will reset to after the reset key gets pressed.
     ///
-    /// By default this is the same as the bind passed to `new`.
+    /// By default this is the value the bind had when it was passed to `new`,
+    /// which is only cloned if a reset key is set.
     pub fn with_reset(mut self, prev_bind: B) -> Self {
-        self.reset = prev_bind;
+        self.reset = Some(prev_bind);
+        self
+    }
+
+    /// Set the default bind, e.g. the one the app ships with. While the bind
+    /// is different, a small dot is painted in the top right corner of the
+    /// button, without changing its size. Hovering the dot shows the
+    /// default, and clicking it (or "Reset to default" in the context menu)
+    /// sets the bind to the default and marks the response as changed.
+    ///
+    /// Binds are compared with [PartialEq], so e.g. Ctrl+S and Cmd+S are
+    /// different. By default there is no default bind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let default: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let mut bind: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+    /// let ctx = egui::Context::default();
+    /// let mut frame = |bind: &mut Shortcut, with_default: bool, events: Vec<Event>| {
+    ///     let mut result = None;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let mut keybind = Keybind::new(bind, "save");
+    ///             if with_default {
+    ///                 keybind = keybind.with_default(&default);
+    ///             }
+    ///             let response = ui.add(keybind);
+    ///             result = Some((response.rect, response.changed()));
+    ///         });
+    ///     });
+    ///     result.unwrap()
+    /// };
+    ///
+    /// // the dot doesn't change the size of the keybind
+    /// let (without, _) = frame(&mut bind, false, vec![]);
+    /// let (rect, _) = frame(&mut bind, true, vec![]);
+    /// assert_eq!(rect, without);
+    ///
+    /// // click the dot in the corner
+    /// let pos = rect.right_top() + egui::vec2(-4.0, 4.0);
+    /// let button = |pressed| Event::PointerButton {
+    ///     pos,
+    ///     button: PointerButton::Primary,
+    ///     pressed,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    /// let events = vec![Event::PointerMoved(pos), button(true), button(false)];
+    /// let (_, changed) = frame(&mut bind, true, events);
+    /// assert!(changed);
+    /// assert_eq!(bind, default);
+    ///
+    /// // the click didn't start recording
+    /// let key = Event::Key {
+    ///     key: egui::Key::O,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::CTRL,
+    /// };
+    /// let (_, changed) = frame(&mut bind, true, vec![key]);
+    /// assert!(!changed);
+    /// assert_eq!(bind, default);
+    /// ```
+    pub fn with_default(mut self, default: &'a B) -> Self
+    where
+        B: PartialEq,
+    {
+        self.default = Some((default, B::eq));
         self
     }
 
-    /// Set the modifier names to use for the [Keybind]. By default this is [`ModifierNames::NAMES`].
+    /// Set the modifier names to use for the [Keybind]. By default these are
+    /// the names users of the operating system expect, see
+    /// [`ModifierNamesExt::for_os`].
     pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
-        self.modifier_names = modifier_names;
+        self.modifier_names = Some(modifier_names);
         self
     }
-}
 
-/// Get the widget expecting value from egui's memory.
-fn get_expecting(ui: &Ui, id: Id) -> bool {
-    let expecting = ui.ctx().memory_mut(|memory| {
-        *memory
-            .data
-            .get_temp_mut_or_default::<bool>(ui.make_persistent_id(id))
-    });
-    expecting
-}
+    /// Set the separator that goes between all parts of the displayed bind
+    /// (modifiers, key and pointer button), e.g. `" + "` or `"-"`. If [None],
+    /// the `concat` of the [ModifierNames] is used.
+    ///
+    /// By default this is [None].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{ModifierNames, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let mut shortcut: Shortcut = "Ctrl+S+Mouse Right".parse().unwrap();
+    /// let mut shown = |names: &ModifierNames<'_>, separator: Option<&str>| {
+    ///     let ctx = egui::Context::default();
+    ///     let output = ctx.run(RawInput::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(&mut shortcut, "save")
+    ///                 .with_modifier_names(names)
+    ///                 .with_separator(separator);
+    ///             ui.add(keybind);
+    ///         });
+    ///     });
+    ///     let texts = output.shapes.iter().filter_map(|shape| match &shape.shape {
+    ///         egui::Shape::Text(text) => Some(text.galley.text().to_string()),
+    ///         _ => None,
+    ///     });
+    ///     texts.collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(shown(&ModifierNames::NAMES, None), ["Ctrl+S+Mouse Right"]);
+    /// assert_eq!(shown(&ModifierNames::NAMES, Some(" + ")), ["Ctrl + S + Mouse Right"]);
+    /// assert_eq!(shown(&ModifierNames::NAMES, Some("-")), ["Ctrl-S-Mouse Right"]);
+    /// assert_eq!(shown(&ModifierNames::SYMBOLS, None), ["⌃S+Mouse Right"]);
+    /// assert_eq!(shown(&ModifierNames::SYMBOLS, Some(" ")), ["⌃ S Mouse Right"]);
+    /// ```
+    pub fn with_separator(mut self, separator: Option<&'a str>) -> Self {
+        self.separator = separator;
+        self
+    }
 
-/// Set the widget expecting value in egui's memory.
-fn set_expecting(ui: &Ui, id: Id, expecting: bool) {
-    ui.ctx().memory_mut(|memory| {
-        *memory
-            .data
-            .get_temp_mut_or_default(ui.make_persistent_id(id)) = expecting;
-    });
-}
+    /// Set the order the modifiers of the bind are displayed in, e.g.
+    /// `&[ModifierKind::Shift, ModifierKind::Ctrl]` for "Shift+Ctrl". If [None],
+    /// the fixed order of [`ModifierNames::format`] is used.
+    ///
+    /// By default this is [None].
+    pub fn with_modifier_order(mut self, order: Option<&'a [ModifierKind]>) -> Self {
+        self.modifier_order = order;
+        self
+    }
 
-impl<'a, B: Bind> Widget for Keybind<'a, B> {
-    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        let text = self.bind.format(self.modifier_names, false);
+    /// Set the [KeyNameMap] used to display key and pointer button names, e.g.
+    /// to localize them. If [None], the English names are used.
+    ///
+    /// By default this is [None].
+    pub fn with_key_names(mut self, key_names: Option<&'a KeyNameMap>) -> Self {
+        self.key_names = key_names;
+        self
+    }
 
-        let galley = WidgetText::RichText(RichText::new(text.clone())).into_galley(
-            ui,
-            Some(egui::TextWrapMode::Extend),
-            0.0,
-            TextStyle::Button,
-        );
+    /// Set the [KeyNameMap] used for the accessibility (screen reader) text.
+    /// If [None], the map from [`Keybind::with_key_names`] is used.
+    ///
+    /// By default this is [None].
+    pub fn with_spoken_key_names(mut self, key_names: Option<&'a KeyNameMap>) -> Self {
+        self.spoken_key_names = key_names;
+        self
+    }
 
-        let size = ui.spacing().interact_size.max(galley.size());
-        let button_padding = ui.spacing().button_padding;
-        let mut widget_size = size + button_padding * vec2(2.0, 1.0);
+    /// Set whether hovering the keybind shows its bind spelled out, e.g.
+    /// `"Control+Shift+S"`, when it's displayed with symbols like
+    /// [`ModifierNames::SYMBOLS`] or key names that are symbols, e.g. `"⎋"`.
+    /// The key names come from [`Keybind::with_spoken_key_names`], so they
+    /// can be localized like the ones screen readers get. The tooltip isn't
+    /// shown while recording.
+    ///
+    /// By default this is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{epaint::Shape, Event, ModifierNames, Pos2, RawInput, Rect};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// // get the text of the tooltip after hovering the keybind for a while
+    /// let tooltip = |names: &ModifierNames| {
+    ///     let ctx = egui::Context::default();
+    ///     ctx.style_mut(|style| style.interaction.tooltip_delay = 0.0);
+    ///     let mut shortcut: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+    ///     let mut rect = Rect::NOTHING;
+    ///     let mut texts = vec![];
+    ///     for frame in 0..4 {
+    ///         let mut input = RawInput {
+    ///             time: Some(frame as f64),
+    ///             ..Default::default()
+    ///         };
+    ///         if frame == 1 {
+    ///             input.events.push(Event::PointerMoved(rect.center()));
+    ///         }
+    ///         let output = ctx.run(input, |ctx| {
+    ///             egui::CentralPanel::default().show(ctx, |ui| {
+    ///                 let keybind = Keybind::new(&mut shortcut, "keybind").with_modifier_names(names);
+    ///                 rect = ui.add(keybind).rect;
+    ///             });
+    ///         });
+    ///         texts = output
+    ///             .shapes
+    ///             .iter()
+    ///             .filter_map(|clipped| match &clipped.shape {
+    ///                 Shape::Text(text) => Some(text.galley.text().to_string()),
+    ///                 _ => None,
+    ///             })
+    ///             .collect::<Vec<_>>();
+    ///     }
+    ///     texts.into_iter().find(|text| text.contains("Control"))
+    /// };
+    ///
+    /// assert_eq!(tooltip(&ModifierNames::SYMBOLS).as_deref(), Some("Control+Shift+S"));
+    /// assert_eq!(tooltip(&ModifierNames::NAMES), None);
+    /// ```
+    pub fn with_symbol_tooltip(mut self, symbol_tooltip: bool) -> Self {
+        self.symbol_tooltip = symbol_tooltip;
+        self
+    }
 
-        // compute the text galley next to the widget (set by with_text), expand
-        // widget appropriately
-        let text_galley = if !self.text.is_empty() {
-            let galley = WidgetText::RichText(RichText::new(self.text)).into_galley(
-                ui,
-                None,
-                ui.available_width() - widget_size.x, // not exactly right
-                TextStyle::Button,
-            );
-            Some(galley)
-        } else {
-            None
-        };
+    /// Set whether a small keyboard or mouse icon is shown in the button
+    /// while recording, if the bind only accepts keys or only pointer buttons
+    /// (see [`Bind::input_kinds`]), so users know which kind of input to
+    /// press. Screen readers get it as the description of the widget.
+    ///
+    /// By default this is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, RawInput, Rect};
+    /// use egui_keybind::{Bind, Keybind, Shortcut};
+    ///
+    /// // get the width of a keybind before and while it's recording
+    /// fn widths<B: Bind>(mut bind: B, icons: bool) -> [f32; 2] {
+    ///     let ctx = egui::Context::default();
+    ///     let mut frame = |events: Vec<Event>| {
+    ///         let mut rect = Rect::NOTHING;
+    ///         let input = RawInput {
+    ///             events,
+    ///             ..Default::default()
+    ///         };
+    ///         let _ = ctx.run(input, |ctx| {
+    ///             egui::CentralPanel::default().show(ctx, |ui| {
+    ///                 let keybind = Keybind::new(&mut bind, "keybind").with_input_kind_icons(icons);
+    ///                 rect = ui.add(keybind).rect;
+    ///             });
+    ///         });
+    ///         rect
+    ///     };
+    ///     let idle = frame(vec![]);
+    ///     let pos = idle.center();
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     frame(vec![Event::PointerMoved(pos), button(true), button(false)]);
+    ///     [idle.width(), frame(vec![]).width()]
+    /// }
+    ///
+    /// // a bind that only accepts pointer buttons gets a mouse icon
+    /// let [idle, recording] = widths(Some(PointerButton::Extra1), true);
+    /// assert!(recording > idle);
+    /// let [idle, recording] = widths(Some(PointerButton::Extra1), false);
+    /// assert_eq!(recording, idle);
+    /// // one that accepts both doesn't need one
+    /// let [idle, recording] = widths("Ctrl+Shift+S".parse::<Shortcut>().unwrap(), true);
+    /// assert_eq!(recording, idle);
+    /// ```
+    pub fn with_input_kind_icons(mut self, input_kind_icons: bool) -> Self {
+        self.input_kind_icons = input_kind_icons;
+        self
+    }
 
-        let custom_text_width = text_galley.clone().map_or(0.0, |text_galley| {
-            ui.spacing().icon_spacing + text_galley.size().x
-        });
-        widget_size.x += custom_text_width;
+    /// Set whether shortcuts the operating system reserves (see
+    /// [`reserved_shortcut`](crate::reserved_shortcut)) are ignored while
+    /// recording, like forbidden keys, instead of being recorded with a
+    /// warning.
+    ///
+    /// By default they can be recorded, and the keybind is outlined in the
+    /// warning color until the bind changes again, with what the shortcut
+    /// does on hover, e.g. "Alt+F4 closes windows on Windows — this shortcut
+    /// may not work".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui_keybind::Keybind;
+    ///
+    /// // Alt+F4 can't be recorded on Windows
+    /// ui.add(Keybind::new(&mut shortcut, "close").with_block_reserved(true));
+    /// # });
+    /// ```
+    pub fn with_block_reserved(mut self, block_reserved: bool) -> Self {
+        self.block_reserved = block_reserved;
+        self
+    }
 
-        let (rect, mut response) = ui.allocate_exact_size(widget_size, Sense::click());
+    /// Remember that the bind was just set to a shortcut the operating
+    /// system reserves, and forget it once the bind changes to something
+    /// else, see [`Keybind::with_block_reserved`].
+    fn update_reserved(&self, state: &mut KeybindState, os: OperatingSystem, changed: bool) {
+        let keyboard = self.bind.keyboard();
+        if changed {
+            state.reserved = keyboard.filter(|keyboard| reserved_shortcut(*keyboard, os).is_some());
+        } else if state.reserved.is_some() && state.reserved != keyboard {
+            state.reserved = None;
+        }
+    }
 
-        // calculate size of the widget without the custom text
-        let mut hotkey_rect = rect;
-        *hotkey_rect.right_mut() -= custom_text_width;
+    /// Get the warning for a bind that the operating system reserves, if it
+    /// was just recorded.
+    fn reserved_warning(&self, state: &KeybindState, os: OperatingSystem) -> Option<String> {
+        let action = reserved_shortcut(state.reserved?, os)?;
+        let os_name = crate::reserved::os_name(os)?;
+        let bind = self.format_bind(self.key_names);
+        Some(format!(
+            "{bind} {action} on {os_name} — this shortcut may not work"
+        ))
+    }
 
-        // see if we're currently waiting for any key (pull from egui's memory)
-        let mut expecting = get_expecting(ui, self.id);
-        let prev_expecting = expecting;
-        if response.clicked() {
-            expecting = !expecting;
+    /// Format the bind for screen readers, with the [KeyNameMap] from
+    /// [`Keybind::with_spoken_key_names`] and whole words for the modifiers,
+    /// e.g. `"Control Shift S"`.
+    fn spoken_bind(&self) -> String {
+        self.format_long(&SPOKEN_MODIFIER_NAMES)
+    }
+
+    /// Format the bind like [`Keybind::spoken_bind`], with `names` for the
+    /// modifiers.
+    fn format_long(&self, names: &ModifierNames<'_>) -> String {
+        let key_names = self.spoken_key_names.or(self.key_names);
+        match (self.modifier_order, key_names) {
+            (Some(order), _) => {
+                format_with_modifier_order(self.bind, names, key_names, order, self.is_mac)
+            }
+            (None, Some(key_names)) => {
+                self.bind
+                    .format_with_key_names(names, key_names, self.is_mac)
+            }
+            (None, None) => self.bind.format(names, self.is_mac),
         }
+    }
+
+    /// Get the icon of [`Keybind::with_input_kind_icons`] that is shown,
+    /// if any.
+    fn input_kind_icon(&self, expecting: bool) -> Option<InputKindIcon> {
+        let shown = self.input_kind_icons && expecting;
+        shown
+            .then(|| InputKindIcon::of(self.bind.input_kinds()))
+            .flatten()
+    }
+
+    /// Whether the displayed text of the bind uses symbols, see
+    /// [`Keybind::with_symbol_tooltip`].
+    fn shows_symbols(&self, text: &str) -> bool {
+        self.names().is_short
+            || text
+                .chars()
+                .any(|c| !c.is_alphanumeric() && !c.is_whitespace() && !c.is_ascii_punctuation())
+    }
 
-        // add widget info for accessibility. this generates a string like "Ctrl+T. Open the terminal"
-        // if the keybind was created with `with_text`
+    /// Describe the widget for screen readers, and announce when recording
+    /// starts or ends.
+    ///
+    /// The widget is a toggle button that is pressed while recording, named
+    /// by its text (see [`Keybind::with_text`]), with the bind as its value.
+    /// With the `accesskit` feature the announcements go in a polite live
+    /// region, and they're also sent as [`egui::output::OutputEvent`]s for
+    /// [egui]'s own screen reader.
+    fn accessibility(
+        &self,
+        ui: &Ui,
+        response: &egui::Response,
+        prev_expecting: bool,
+        expecting: bool,
+        label_text: &str,
+    ) {
+        let spoken = self.spoken_bind();
+        let icon = self.input_kind_icon(expecting);
+        // egui's screen reader only reads the label, so it includes the bind,
+        // e.g. "Control T. Open the terminal"
         response.widget_info(|| {
-            WidgetInfo::selected(
-                WidgetType::Button,
-                expecting,
-                expecting,
-                if self.text.is_empty() {
-                    text.clone() // just read out the hotkey
-                } else {
-                    text.clone() + ". " + self.text
-                },
-            )
+            let mut label = match label_text.is_empty() {
+                true => spoken.clone(),
+                false => format!("{spoken}. {label_text}"),
+            };
+            if let Some(icon) = icon {
+                label = format!("{label}. {}", icon.description());
+            }
+            let mut info =
+                WidgetInfo::selected(WidgetType::Button, ui.is_enabled(), expecting, label);
+            info.current_text_value = Some(spoken.clone());
+            info
         });
 
-        if expecting {
-            if response.clicked_elsewhere() {
-                // the user has clicked somewhere else, stop capturing input
-                expecting = false;
-            } else {
-                // everything ok, capture keyboard input
-                let kb = ui.input(|i| {
-                    i.events.iter().find_map(|e| match e {
-                        Event::Key {
-                            key,
-                            pressed: true,
-                            modifiers,
-                            repeat: false,
-                            ..
-                        } => Some((*key, *modifiers)),
-                        _ => None,
-                    })
-                });
+        let announcement = match (prev_expecting, expecting) {
+            (false, true) => match InputKindIcon::of(self.bind.input_kinds()) {
+                Some(InputKindIcon::Mouse) => Some(POINTER_RECORDING_ANNOUNCEMENT.to_string()),
+                _ => Some(RECORDING_ANNOUNCEMENT.to_string()),
+            },
+            (true, false) | (false, false) if response.changed() => {
+                Some(format!("Shortcut set to {spoken}"))
+            }
+            (true, false) => Some(CANCELLED_ANNOUNCEMENT.to_string()),
+            _ => None,
+        };
+        if let Some(announcement) = &announcement {
+            ui.ctx().output_mut(|output| {
+                output.events.push(egui::output::OutputEvent::ValueChanged(
+                    WidgetInfo::labeled(WidgetType::Label, true, announcement),
+                ));
+            });
+        }
 
-                // capture mouse input
-                let pointer = ui.input(|i| {
-                    i.events.iter().find_map(|e| match e {
-                        Event::PointerButton {
-                            button,
-                            pressed: true,
-                            ..
-                        } if *button != PointerButton::Primary
-                            && *button != PointerButton::Secondary =>
-                        {
-                            Some(*button)
-                        }
-                        _ => None,
-                    })
-                });
+        #[cfg(feature = "accesskit")]
+        {
+            use egui::accesskit::{Live, Role, Toggled};
 
-                // set keybind
-                if kb.is_some() || pointer.is_some() {
-                    self.bind
-                        .set(kb.map(|kb| KeyboardShortcut::new(kb.1, kb.0)), pointer);
-                    response.mark_changed();
-                    expecting = false;
+            let enabled = ui.ctx().accesskit_node_builder(response.id, |builder| {
+                builder.set_role(Role::Button);
+                builder.set_toggled(match expecting {
+                    true => Toggled::True,
+                    false => Toggled::False,
+                });
+                match label_text.is_empty() {
+                    true => builder.set_label("Shortcut"),
+                    false => builder.set_label(label_text),
                 }
-            }
-
-            if let Some(reset_key) = self.reset_key {
-                // the reset key was pressed
-                if ui.input(|i| i.key_pressed(reset_key)) {
-                    *self.bind = self.reset;
-                    expecting = false;
-                    response.mark_changed();
+                builder.set_value(spoken.as_str());
+                let description = match (self.description, icon) {
+                    (Some(description), Some(icon)) => {
+                        Some(format!("{description}. {}", icon.description()))
+                    }
+                    (Some(description), None) => Some(description.to_string()),
+                    (None, Some(icon)) => Some(icon.description().to_string()),
+                    (None, None) => None,
+                };
+                if let Some(description) = description {
+                    builder.set_description(description);
                 }
+            });
+            // the live region is kept while accesskit is enabled, so screen
+            // readers notice when its text changes
+            let announcement_id = ui.make_persistent_id(self.id).with("announcement");
+            let last = enabled.and_then(|_| {
+                ui.ctx().memory_mut(|memory| {
+                    if let Some(announcement) = announcement {
+                        memory.data.insert_temp(announcement_id, announcement);
+                    }
+                    memory.data.get_temp::<String>(announcement_id)
+                })
+            });
+            if let Some(last) = last {
+                ui.ctx().accesskit_node_builder(announcement_id, |builder| {
+                    builder.set_role(Role::Status);
+                    builder.set_live(Live::Polite);
+                    builder.set_label(last);
+                });
             }
         }
+    }
 
-        // paint
-        if ui.is_rect_visible(rect) {
-            // paint bg rect
-            let visuals = ui.style().interact_selectable(&response, expecting);
-            ui.painter().rect(
-                hotkey_rect.expand(visuals.expansion),
-                visuals.rounding,
-                visuals.bg_fill,
-                visuals.bg_stroke,
-            );
+    /// Get the configured modifier names, with the separator applied.
+    fn names(&self) -> ModifierNames<'a> {
+        let names = self.modifier_names.unwrap_or(&ModifierNames::NAMES);
+        ModifierNames {
+            concat: self.separator.unwrap_or(names.concat),
+            ..*names
+        }
+    }
 
-            // align text to center in rect that is shrinked to match button padding
-            let mut text_pos = ui
-                .layout()
-                .align_size_within_rect(galley.size(), hotkey_rect.shrink2(button_padding))
-                .min;
+    /// Get the pointer button that is displayed as an icon, in which case
+    /// only the keyboard part is formatted. A bind waiting to be confirmed is
+    /// always formatted as text.
+    fn pointer_icon(&self, pending: bool) -> Option<PointerButton> {
+        match self.pointer_icons && !pending {
+            true => self
+                .bind
+                .pointer()
+                .filter(|button| has_pointer_icon(*button)),
+            false => None,
+        }
+    }
 
-            // align text to center of the button if it doesn't expand the rect
-            if text_pos.x + galley.size().x + button_padding.x < hotkey_rect.right() {
-                text_pos.x += hotkey_rect.size().x / 2.0 - galley.size().x / 2.0 - button_padding.x;
-            }
+    /// Get a hash of everything the formatted text of the bind depends on,
+    /// to keep it between frames, or [None] if the bind has no
+    /// [`Bind::cache_key`].
+    fn text_cache_key(&self, pointer_icon: Option<PointerButton>) -> Option<u64> {
+        self.bind.cache_key().map(|bind| {
+            let names = self.names();
+            egui::util::hash((
+                bind,
+                (names.is_short, names.alt, names.ctrl, names.shift),
+                (names.mac_cmd, names.mac_alt, names.concat),
+                self.is_mac,
+                self.modifier_order,
+                self.key_names,
+                pointer_icon.map(|button| button as usize),
+            ))
+        })
+    }
 
-            // paint text inside button
-            ui.painter().galley(text_pos, galley, visuals.text_color());
+    /// Format the bind with the configured modifier names, separator and key names.
+    fn format_bind(&self, key_names: Option<&KeyNameMap>) -> String {
+        self.format_value(self.bind, key_names)
+    }
 
-            // paint galley for text outside on the left, if any
-            if let Some(text_galley) = text_galley {
-                let text_pos = pos2(
-                    hotkey_rect.right() + ui.spacing().icon_spacing,
-                    hotkey_rect.center().y - 0.5 * text_galley.size().y,
-                );
-                ui.painter().galley(
-                    text_pos,
-                    text_galley,
-                    ui.style().noninteractive().text_color(),
-                );
+    /// Format any value of the bind type like [`Keybind::format_bind`].
+    fn format_value(&self, bind: &B, key_names: Option<&KeyNameMap>) -> String {
+        let names = self.names();
+        match (self.modifier_order, key_names) {
+            (Some(order), _) => {
+                format_with_modifier_order(bind, &names, key_names, order, self.is_mac)
             }
+            (None, Some(key_names)) => bind.format_with_key_names(&names, key_names, self.is_mac),
+            (None, None) => bind.format(&names, self.is_mac),
         }
+    }
 
-        if prev_expecting != expecting {
-            set_expecting(ui, self.id, expecting);
-        }
-        response
+    /// Set how the [Keybind] looks when its bind is not bound to anything.
+    ///
+    /// By default this is [`UnboundStyle::MUTED`]. Use [`UnboundStyle::NONE`]
+    /// to make unbound keybinds look the same as bound ones.
+    pub fn with_unbound_style(mut self, style: UnboundStyle) -> Self {
+        self.unbound_style = style;
+        self
+    }
+
+    /// Set where the text from [`Keybind::with_text`] is placed.
+    ///
+    /// By default this is [`LabelLayout::Right`].
+    pub fn with_label_layout(mut self, layout: LabelLayout) -> Self {
+        self.label_layout = layout;
+        self
+    }
+
+    /// Set whether to display the pointer button part of the bind as a small
+    /// mouse icon instead of text. Buttons that don't have an icon
+    /// ([`PointerButton::Extra1`] and [`PointerButton::Extra2`]) are still
+    /// displayed as text.
+    ///
+    /// By default this is false.
+    pub fn with_pointer_icons(mut self, pointer_icons: bool) -> Self {
+        self.pointer_icons = pointer_icons;
+        self
+    }
+
+    /// Set whether to dim the rest of the ui while the [Keybind] is waiting
+    /// for input. Everything except the [Keybind] is covered by a translucent
+    /// overlay that blocks pointer interaction, and clicking the overlay
+    /// stops waiting for input. The [Keybind] is above the overlay, so it
+    /// can still be clicked.
+    ///
+    /// By default this is false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput, Rect};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shortcut = Shortcut::NONE;
+    /// // run a frame, and get where the keybind and a button are and what was clicked
+    /// let mut frame = |events: Vec<Event>| {
+    ///     let mut shown = (Rect::NOTHING, false, Rect::NOTHING, false);
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(&mut shortcut, "keybind").with_dim_background(true);
+    ///             let keybind = ui.add(keybind);
+    ///             let button = ui.button("Other");
+    ///             shown = (keybind.rect, keybind.clicked(), button.rect, button.clicked());
+    ///         });
+    ///     });
+    ///     shown
+    /// };
+    /// let click = |pos: Pos2| {
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     vec![Event::PointerMoved(pos), button(true), button(false)]
+    /// };
+    ///
+    /// let (keybind, _, other, _) = frame(vec![]);
+    /// // start recording, then click the keybind again above the overlay
+    /// frame(click(keybind.center()));
+    /// frame(vec![]);
+    /// assert!(frame(click(keybind.center())).1);
+    ///
+    /// // other widgets are covered while it's recording
+    /// frame(click(keybind.center()));
+    /// frame(vec![]);
+    /// assert!(!frame(click(other.center())).3);
+    /// ```
+    pub fn with_dim_background(mut self, dim_background: bool) -> Self {
+        self.dim_background = dim_background;
+        self
+    }
+
+    /// Set whether a newly captured bind has to be confirmed before it's set.
+    ///
+    /// If true, the [Keybind] shows the old and the new bind side by side
+    /// after capturing, with apply and cancel buttons. The new bind is only
+    /// set (and the response marked as changed) when it's applied with the
+    /// apply button or Enter. The cancel button, Escape or clicking elsewhere
+    /// keep the old bind. The keybind takes focus when a bind is captured,
+    /// and Enter and Escape only count while the keybind or its buttons have
+    /// focus, so they still reach other widgets.
+    ///
+    /// By default this is false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Id, Key, Modifiers, PointerButton, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let mut frame = |events: Vec<Event>| {
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             rect = ui.add(Keybind::new(&mut shortcut, "save").with_confirm(true)).rect;
+    ///         });
+    ///     });
+    ///     rect
+    /// };
+    /// let key = |key, modifiers| Event::Key {
+    ///     key,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers,
+    /// };
+    /// // click the keybind and press a new bind
+    /// let record = |frame: &mut dyn FnMut(Vec<Event>) -> egui::Rect| {
+    ///     let pos = frame(vec![]).center();
+    ///     let click = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     frame(vec![Event::PointerMoved(pos), click(true), click(false)]);
+    ///     frame(vec![key(Key::O, Modifiers::CTRL)]);
+    /// };
+    ///
+    /// record(&mut frame);
+    /// frame(vec![key(Key::Enter, Modifiers::NONE)]);
+    /// drop(frame);
+    /// assert_eq!(shortcut.to_string(), "Ctrl+O");
+    ///
+    /// // Enter in another widget doesn't apply the new bind, the widget gets it
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let mut enter_reached_other = false;
+    /// let mut frame = |events: Vec<Event>| {
+    ///     let mut rect = egui::Rect::NOTHING;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             rect = ui.add(Keybind::new(&mut shortcut, "save").with_confirm(true)).rect;
+    ///             let other = ui.interact(rect, Id::new("other"), egui::Sense::click());
+    ///             let enter = ui.input(|i| i.key_pressed(Key::Enter));
+    ///             enter_reached_other |= enter && other.has_focus();
+    ///         });
+    ///     });
+    ///     rect
+    /// };
+    /// record(&mut frame);
+    /// ctx.memory_mut(|memory| memory.request_focus(Id::new("other")));
+    /// frame(vec![key(Key::Enter, Modifiers::NONE)]);
+    /// drop(frame);
+    /// assert_eq!(shortcut.to_string(), "Ctrl+S");
+    /// assert!(enter_reached_other);
+    /// ```
+    pub fn with_confirm(mut self, confirm: bool) -> Self {
+        self.confirm = confirm;
+        self
+    }
+
+    /// Enable dragging the bind of this [Keybind] onto other keybinds that
+    /// have drag and drop enabled, and set what happens when a bind is
+    /// dropped onto this one. Both keybinds are marked as changed; with
+    /// [DropPolicy::Move] the dragged one on the frame after the drop, with
+    /// [DropPolicy::Swap] the dragged one on the frame after the drop and the
+    /// other one on the frame after that. Dragging doesn't start while waiting
+    /// for input. If [None], drag and drop is disabled.
+    ///
+    /// Only whole binds are dragged: binds that can't be represented as a
+    /// single [Shortcut] (like a [LongPressBind](crate::LongPressBind) or
+    /// [Shortcuts](crate::Shortcuts) with alternates) can't be dragged, and a
+    /// bind is only dropped onto a keybind that can store all of it.
+    /// [DropPolicy::Move] does nothing if the dragged bind can't be cleared,
+    /// and [DropPolicy::Swap] does nothing if either bind can't store all of
+    /// the other one.
+    ///
+    /// By default this is [None].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+    /// use egui_keybind::{Bind, DropPolicy, Keybind, LongPressBind, Shortcut};
+    ///
+    /// // drag the first keybind onto the second one
+    /// fn drag(source: &mut impl Bind, target: &mut impl Bind, policy: DropPolicy) {
+    ///     let ctx = egui::Context::default();
+    ///     let mut frame = |events: Vec<Event>| {
+    ///         let mut rects = [egui::Rect::NOTHING; 2];
+    ///         let input = RawInput {
+    ///             events,
+    ///             ..Default::default()
+    ///         };
+    ///         let _ = ctx.run(input, |ctx| {
+    ///             egui::CentralPanel::default().show(ctx, |ui| {
+    ///                 let policy = Some(policy);
+    ///                 let source = Keybind::new(source, "a").with_drag_and_drop(policy);
+    ///                 rects[0] = ui.add(source).rect;
+    ///                 let target = Keybind::new(target, "b").with_drag_and_drop(policy);
+    ///                 rects[1] = ui.add(target).rect;
+    ///             });
+    ///         });
+    ///         rects
+    ///     };
+    ///     let [from, to] = frame(vec![]).map(|rect| rect.center());
+    ///     let button = |pos: Pos2, pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     frame(vec![Event::PointerMoved(from), button(from, true)]);
+    ///     frame(vec![Event::PointerMoved(to)]);
+    ///     frame(vec![Event::PointerMoved(to)]);
+    ///     frame(vec![button(to, false)]);
+    ///     // the keybinds pick up their new binds
+    ///     frame(vec![]);
+    ///     frame(vec![]);
+    /// }
+    ///
+    /// let mut source: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let mut target = Shortcut::NONE;
+    /// drag(&mut source, &mut target, DropPolicy::Move);
+    /// assert_eq!((source.to_string(), target.to_string()), ("None".into(), "Ctrl+S".into()));
+    ///
+    /// // a key can't be cleared, so it isn't moved
+    /// let mut source = egui::Key::A;
+    /// let mut target = Shortcut::NONE;
+    /// drag(&mut source, &mut target, DropPolicy::Move);
+    /// assert_eq!((source, target), (egui::Key::A, Shortcut::NONE));
+    /// // and it can't be swapped with a shortcut it can't store
+    /// let mut target: Shortcut = "Ctrl+S".parse().unwrap();
+    /// drag(&mut source, &mut target, DropPolicy::Swap);
+    /// assert_eq!((source, target.to_string()), (egui::Key::A, "Ctrl+S".into()));
+    /// let mut target: Shortcut = "S".parse().unwrap();
+    /// drag(&mut source, &mut target, DropPolicy::Swap);
+    /// assert_eq!((source, target.to_string()), (egui::Key::S, "A".into()));
+    ///
+    /// // the duration of a long press would be lost
+    /// let mut source = LongPressBind::new(egui::PointerButton::Middle, 0.5);
+    /// let mut target = Shortcut::NONE;
+    /// drag(&mut source, &mut target, DropPolicy::Move);
+    /// assert!(source.is_bound() && !target.is_bound());
+    /// ```
+    pub fn with_drag_and_drop(mut self, policy: Option<DropPolicy>) -> Self {
+        self.drop_policy = policy;
+        self
+    }
+
+    /// Set which press is captured when several keys or pointer buttons are
+    /// pressed in the same frame while waiting for input. A key and a pointer
+    /// button pressed in the same frame are always captured together, as one
+    /// bind (e.g. `"Ctrl+S+Mouse 4"`), if the bind can have both.
+    ///
+    /// By default this is [`CaptureStrategy::Last`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui_keybind::{CaptureStrategy, Keybind};
+    ///
+    /// // A then Ctrl+B in one frame records A
+    /// let first = CaptureStrategy::First;
+    /// ui.add(Keybind::new(&mut shortcut, "save").with_capture_strategy(first));
+    /// # });
+    /// ```
+    pub fn with_capture_strategy(mut self, strategy: CaptureStrategy) -> Self {
+        self.capture_strategy = strategy;
+        self
+    }
+
+    /// Set how a key press with both Ctrl and Alt is captured, see
+    /// [AltGrPolicy]. If [None], the policy fits the operating system (see
+    /// [`AltGrPolicy::for_os`]).
+    ///
+    /// By default this is [None].
+    pub fn with_altgr_policy(mut self, policy: Option<AltGrPolicy>) -> Self {
+        self.altgr = policy;
+        self
+    }
+
+    /// Set how Ctrl and Cmd are captured, see [CommandPolicy]. With
+    /// [`CommandPolicy::CrossPlatform`], the primary modifier (Cmd on macOS,
+    /// Ctrl everywhere else) is stored as [`Modifiers::COMMAND`], so the
+    /// shortcut works the same on every platform.
+    ///
+    /// By default the modifiers are stored as they were pressed.
+    pub fn with_command_policy(mut self, policy: CommandPolicy) -> Self {
+        self.command_policy = policy;
+        self
+    }
+
+    /// Set whether copy, cut and paste events are captured as Ctrl+C, Ctrl+X
+    /// and Ctrl+V (Cmd on Mac). Some platforms, like the web, send these
+    /// instead of the key press, so the clipboard shortcuts couldn't be
+    /// captured there otherwise. Key presses are preferred if there are
+    /// both.
+    ///
+    /// By default this is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui_keybind::Keybind;
+    ///
+    /// // only record key presses
+    /// ui.add(Keybind::new(&mut shortcut, "copy").with_clipboard_events(false));
+    /// # });
+    /// ```
+    pub fn with_clipboard_events(mut self, enabled: bool) -> Self {
+        self.clipboard_events = enabled;
+        self
+    }
+
+    /// Set whether keys that [egui] has no [Key] for are captured by the
+    /// character they type, as a [TextKey]. This is only used when a
+    /// keyboard key press doesn't arrive, and only for binds that can store
+    /// text keys (see [`Bind::set_text`]), like [Shortcut].
+    ///
+    /// Text keys depend on the keyboard layout, see [TextKey] for the
+    /// trade-offs. By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut shortcut = egui_keybind::Shortcut::NONE;
+    /// use egui_keybind::Keybind;
+    ///
+    /// // e.g. Alt+§ can be recorded, though egui has no key for §
+    /// ui.add(Keybind::new(&mut shortcut, "console").with_text_fallback(true));
+    /// # });
+    /// ```
+    pub fn with_text_fallback(mut self, enabled: bool) -> Self {
+        self.text_fallback = enabled;
+        self
+    }
+
+    /// Set whether recording measures how long a pointer button is held, to
+    /// record a [LongPressBind](crate::LongPressBind), see
+    /// [`ShortcutCapture::with_hold_measuring`](crate::ShortcutCapture::with_hold_measuring).
+    /// The button is recorded when it's released, with the time it was held
+    /// rounded to a tenth of a second. Short presses are recorded like
+    /// without measuring, and so are holds for binds that can't store long
+    /// presses (see [`Bind::set_long_press`]).
+    ///
+    /// A long press is set right away, even with [`Keybind::with_confirm`].
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::PointerButton;
+    /// use egui_keybind::{Keybind, LongPressBind};
+    ///
+    /// let mut bind = LongPressBind::new(PointerButton::Extra1, 1.0);
+    /// // holding the middle button for 0.7s records "Hold Middle (0.7s)"
+    /// ui.add(Keybind::new(&mut bind, "hold").with_hold_measuring(true));
+    /// # });
+    /// ```
+    pub fn with_hold_measuring(mut self, enabled: bool) -> Self {
+        self.hold_measuring = enabled;
+        self
+    }
+
+    /// Make this a small [Keybind], the same height as [`Ui::small_button`].
+    ///
+    /// This uses the body text style, removes the vertical button padding and
+    /// drops the minimum interact height, so it fits into toolbars and text.
+    pub fn small(mut self) -> Self {
+        self.small = true;
+        self
+    }
+
+    /// Expand the clickable area of the [Keybind] by this much on each side,
+    /// without changing how it is painted. Useful on touch screens, where the
+    /// widget is otherwise hard to tap.
+    ///
+    /// The padding is clamped to half of the item spacing, so the hit area
+    /// never overlaps the hit areas of neighbouring widgets.
+    ///
+    /// By default this is [None], which uses the maximum padding if
+    /// [`InputState::has_touch_screen`](egui::InputState::has_touch_screen)
+    /// is true, and no padding otherwise.
+    pub fn with_touch_padding(mut self, padding: Option<Vec2>) -> Self {
+        self.touch_padding = padding;
+        self
+    }
+
+    /// Record the bind in a popup below the [Keybind] instead of inline. The
+    /// popup has a "Press keys…" area that shows the held modifiers, the
+    /// suggestions set with [`Keybind::with_suggestions`] to pick from, and
+    /// buttons to clear the bind and to cancel.
+    ///
+    /// Keys are captured while the popup is open, like inline. Clicking
+    /// inside the popup keeps capturing, and clicking outside of it or
+    /// pressing Escape closes it without changing the bind. Escape can't be
+    /// recorded, unless it's the reset key (see [`Keybind::with_reset_key`]).
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, PointerButton, Pos2, RawInput, Rect};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let suggestions = ["Ctrl+S".parse().unwrap(), "F2".parse().unwrap()];
+    /// let frame = |shortcut: &mut Shortcut, events: Vec<Event>| {
+    ///     let mut rect = Rect::NOTHING;
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(shortcut, "keybind")
+    ///                 .with_popup_editor(true)
+    ///                 .with_suggestions(&suggestions);
+    ///             rect = ui.add(keybind).rect;
+    ///         });
+    ///     });
+    ///     rect
+    /// };
+    /// let click = |pos: Pos2| {
+    ///     let button = |pressed| Event::PointerButton {
+    ///         pos,
+    ///         button: PointerButton::Primary,
+    ///         pressed,
+    ///         modifiers: Modifiers::NONE,
+    ///     };
+    ///     vec![Event::PointerMoved(pos), button(true), button(false)]
+    /// };
+    /// let popup_open = || ctx.memory(|m| m.any_popup_open());
+    ///
+    /// let rect = frame(&mut shortcut, vec![]);
+    /// frame(&mut shortcut, click(rect.center()));
+    /// assert!(popup_open());
+    /// // Escape closes the popup without being recorded
+    /// let escape = Event::Key {
+    ///     key: Key::Escape,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    /// frame(&mut shortcut, vec![escape]);
+    /// assert!(!popup_open());
+    /// assert_eq!(shortcut.to_string(), "Ctrl+S");
+    ///
+    /// // click along the popup until a suggestion is picked, which closes it
+    /// frame(&mut shortcut, click(rect.center()));
+    /// let popup = ctx.memory(|m| {
+    ///     let layer = m.areas().top_layer_id(egui::Order::Foreground).unwrap();
+    ///     m.area_rect(layer.id).unwrap()
+    /// });
+    /// let mut picked = None;
+    /// 'rows: for y in (0..popup.height() as usize).step_by(2) {
+    ///     for x in (8..popup.width() as usize).step_by(8) {
+    ///         if !popup_open() {
+    ///             frame(&mut shortcut, click(rect.center()));
+    ///         }
+    ///         frame(&mut shortcut, click(popup.min + egui::vec2(x as f32, y as f32)));
+    ///         if shortcut.to_string() == "F2" {
+    ///             picked = Some(y);
+    ///             break 'rows;
+    ///         }
+    ///     }
+    /// }
+    /// // the suggestions are below the "Press keys…" area, and picking one stops recording
+    /// assert!(picked.unwrap() > 10);
+    /// assert!(!popup_open());
+    /// ```
+    pub fn with_popup_editor(mut self, popup_editor: bool) -> Self {
+        self.popup_editor = popup_editor;
+        self
+    }
+
+    /// Set shortcuts that are shown in the popup of
+    /// [`Keybind::with_popup_editor`] to be picked with a click, e.g. the
+    /// default or common alternatives. Picking one sets the bind like
+    /// recording it would, so in confirm mode it waits to be applied (see
+    /// [`Keybind::with_confirm`]). Suggestions the bind can't be set to
+    /// aren't shown.
+    ///
+    /// By default there are no suggestions.
+    pub fn with_suggestions(mut self, suggestions: &'a [Shortcut]) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    /// Set whether the bind can also be typed in as text, e.g.
+    /// `"Ctrl+Shift+F9"`, for keys that are hard to press or don't work.
+    /// Pressing F2 while the keybind has focus, or picking "Edit as text" from
+    /// its context menu, swaps the button for a text field with the current
+    /// bind in it, written like [Shortcut]'s [Display](std::fmt::Display).
+    /// Binds that can't be written that way without losing something, like a
+    /// [LongPressBind](crate::LongPressBind), can't be edited as text.
+    ///
+    /// Enter parses the text like [Shortcut]'s [FromStr](std::str::FromStr),
+    /// and sets the bind if it could have been recorded, skipping
+    /// [`Keybind::with_confirm`]. An empty text clears the bind. If the text
+    /// can't be parsed or bound, the error is shown next to the text field,
+    /// which stays open. Escape or clicking elsewhere closes it without
+    /// changing the bind.
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, PointerButton, RawInput};
+    /// use egui_keybind::{Keybind, LongPressBind, Shortcut};
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+    /// let frame = |shortcut: &mut Shortcut, events: Vec<Event>| {
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut changed = false;
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(shortcut, "keybind").with_text_editing(true);
+    ///             changed = ui.add(keybind).changed();
+    ///         });
+    ///     });
+    ///     changed
+    /// };
+    /// let key = |key| Event::Key {
+    ///     key,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    /// // focus the keybind with Tab, and press F2 to edit it
+    /// let start = |shortcut: &mut Shortcut| {
+    ///     frame(shortcut, vec![key(Key::Tab)]);
+    ///     frame(shortcut, vec![key(Key::F2)]);
+    ///     frame(shortcut, vec![]);
+    /// };
+    /// // select everything in the text field and type over it
+    /// let edit = |text: &str| {
+    ///     let select_all = Event::Key {
+    ///         key: Key::A,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::COMMAND,
+    ///     };
+    ///     vec![select_all, Event::Text(text.to_string())]
+    /// };
+    ///
+    /// frame(&mut shortcut, vec![]);
+    /// start(&mut shortcut);
+    /// assert!(!frame(&mut shortcut, edit("Ctrl+Shift+F9")));
+    /// assert!(frame(&mut shortcut, vec![key(Key::Enter)]));
+    /// assert_eq!(shortcut.to_string(), "Ctrl+Shift+F9");
+    ///
+    /// // a typo stays in the text field, without changing the bind
+    /// start(&mut shortcut);
+    /// frame(&mut shortcut, edit("Ctrl+Shfit+F9"));
+    /// assert!(!frame(&mut shortcut, vec![key(Key::Enter)]));
+    /// assert_eq!(shortcut.to_string(), "Ctrl+Shift+F9");
+    /// // until it's fixed
+    /// frame(&mut shortcut, edit("Ctrl+F9"));
+    /// assert!(frame(&mut shortcut, vec![key(Key::Enter)]));
+    /// assert_eq!(shortcut.to_string(), "Ctrl+F9");
+    ///
+    /// // Escape gives up on the text
+    /// start(&mut shortcut);
+    /// frame(&mut shortcut, edit("F1"));
+    /// assert!(!frame(&mut shortcut, vec![key(Key::Escape)]));
+    /// frame(&mut shortcut, vec![key(Key::Enter)]);
+    /// assert_eq!(shortcut.to_string(), "Ctrl+F9");
+    ///
+    /// // the text field starts with the whole bind, so applying it keeps it
+    /// let mut shortcut: Shortcut = "CmdOrCtrl+F4+Middle Release".parse().unwrap();
+    /// start(&mut shortcut);
+    /// assert!(frame(&mut shortcut, vec![key(Key::Enter)]));
+    /// assert_eq!(shortcut.to_string(), "CmdOrCtrl+F4+Middle Release");
+    ///
+    /// // the duration of a long press can't be typed in, so F2 does nothing
+    /// let ctx = egui::Context::default();
+    /// let mut long_press = LongPressBind::new(PointerButton::Middle, 0.5);
+    /// let mut focused = false;
+    /// for events in [vec![key(Key::Tab)], vec![key(Key::F2)], vec![]] {
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             let keybind = Keybind::new(&mut long_press, "keybind").with_text_editing(true);
+    ///             focused = ui.add(keybind).has_focus();
+    ///         });
+    ///     });
+    /// }
+    /// assert!(focused);
+    /// ```
+    pub fn with_text_editing(mut self, text_editing: bool) -> Self {
+        self.text_editing = text_editing;
+        self
+    }
+
+    /// Get the settings the keybind captures input with.
+    fn capture_settings(&self, os: OperatingSystem) -> CaptureSettings {
+        let kinds = self.bind.input_kinds();
+        CaptureSettings {
+            strategy: self.capture_strategy,
+            altgr: self.altgr,
+            command_policy: self.command_policy,
+            clipboard_events: self.clipboard_events,
+            // binds that can't store text keys keep capturing
+            text_fallback: self.text_fallback && can_store_text(self.bind),
+            keyboard: kinds.keyboard,
+            pointer: kinds.pointer,
+            cancel_key: self.reset_key,
+            measure_hold: self.hold_measuring,
+            wheel: can_store_wheel(self.bind),
+            gestures: can_store_gesture(self.bind),
+            // Escape closes the popup instead
+            forbidden_keys: match self.popup_editor {
+                true => vec![Key::Escape],
+                false => vec![],
+            },
+            reserved_os: self.block_reserved.then_some(os),
+            ..Default::default()
+        }
+    }
+
+    /// Set the bind to a shortcut that was typed in as text, see
+    /// [`Keybind::with_text_editing`].
+    ///
+    /// # Errors
+    /// If the text isn't a shortcut, or one that couldn't have been recorded,
+    /// with the message that is shown to the user.
+    fn set_from_text(&mut self, text: &str, os: OperatingSystem) -> Result<(), String> {
+        let text = text.trim();
+        if text.is_empty() {
+            self.bind.clear();
+            return Ok(());
+        }
+        let shortcut = text
+            .parse::<Shortcut>()
+            .map_err(|error| error.to_string())?;
+        let mut value = self.bind.clone();
+        match self.capture_settings(os).filter(&shortcut) {
+            // the bind has to take all of it, e.g. a `Key` would drop the modifiers
+            Some(shortcut)
+                if set_shortcut(&mut value, &shortcut) && formats_as(&value, &shortcut) =>
+            {
+                *self.bind = value;
+                Ok(())
+            }
+            _ => Err(format!("\"{text}\" can't be bound here")),
+        }
+    }
+
+    /// Show the text field of [`Keybind::with_text_editing`] instead of the
+    /// button.
+    ///
+    /// # Returns
+    /// The response of the text field, and whether editing is over.
+    fn show_text_edit(
+        &mut self,
+        ui: &mut Ui,
+        edit: &mut TextEditState,
+        label: WidgetText,
+    ) -> (Response, bool) {
+        let error_color = ui.visuals().error_fg_color;
+        let edit_id = text_edit_id(ui, self.id);
+        let mut response = ui
+            .horizontal(|ui| {
+                let text_edit = TextEdit::singleline(&mut edit.text)
+                    .id(edit_id)
+                    .desired_width(0.5 * ui.spacing().text_edit_width)
+                    .text_color_opt(edit.error.is_some().then_some(error_color));
+                let response = ui.add(text_edit);
+                if let Some(error) = &edit.error {
+                    ui.colored_label(error_color, error);
+                }
+                if self.text_visible && !label.is_empty() {
+                    ui.label(label);
+                }
+                response
+            })
+            .inner;
+        if response.changed() {
+            edit.error = None;
+        }
+        // typing doesn't change the bind yet
+        response.changed = false;
+
+        let mut done = false;
+        if response.lost_focus() {
+            if ui.input(|i| i.key_pressed(Key::Enter)) {
+                match self.set_from_text(&edit.text, ui.ctx().os()) {
+                    Ok(()) => {
+                        response.mark_changed();
+                        done = true;
+                    }
+                    Err(error) => {
+                        edit.error = Some(error);
+                        response.request_focus();
+                    }
+                }
+            } else {
+                // Escape or a click elsewhere
+                done = true;
+            }
+        }
+        (response, done)
+    }
+
+    /// Show the popup of [`Keybind::with_popup_editor`] below the keybind, if
+    /// it's open.
+    ///
+    /// # Returns
+    ///
+    /// What was clicked in the popup, if anything.
+    fn show_popup(&self, ui: &Ui, popup_id: Id, response: &Response) -> Option<PopupChoice> {
+        let names = self.names();
+        let is_mac = ui.ctx().os() == OperatingSystem::Mac;
+        let kinds = self.bind.input_kinds();
+        let current = shortcut_of(self.bind);
+        // the suggestions are formatted like the bind of the keybind
+        let suggestions: Vec<(Shortcut, String)> = self
+            .suggestions
+            .iter()
+            .filter(|suggestion| kinds.accepts(suggestion.keyboard(), suggestion.pointer()))
+            .filter_map(|suggestion| {
+                let mut value = self.bind.clone();
+                set_shortcut(&mut value, suggestion)
+                    .then(|| (*suggestion, self.format_value(&value, self.key_names)))
+            })
+            .collect();
+
+        egui::popup_below_widget(
+            ui,
+            popup_id,
+            response,
+            PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                ui.set_min_width(160.0);
+                let mut choice = None;
+                let held = ui.input(|i| i.modifiers);
+                let prompt = match held.any() && kinds.keyboard {
+                    true => format!("{}…", names.format(&held, is_mac)),
+                    false => match InputKindIcon::of(kinds) {
+                        Some(InputKindIcon::Mouse) => "Press a mouse button…".to_string(),
+                        _ => "Press keys…".to_string(),
+                    },
+                };
+                ui.add_space(ui.spacing().item_spacing.y);
+                ui.vertical_centered(|ui| ui.strong(prompt));
+                ui.add_space(ui.spacing().item_spacing.y);
+
+                if !suggestions.is_empty() {
+                    ui.separator();
+                    ui.horizontal_wrapped(|ui| {
+                        for (suggestion, text) in suggestions {
+                            if ui.selectable_label(suggestion == current, text).clicked() {
+                                choice = Some(PopupChoice::Suggestion(suggestion));
+                            }
+                        }
+                    });
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let clear = Button::new("Clear");
+                    if ui.add_enabled(self.bind.is_bound(), clear).clicked() {
+                        choice = Some(PopupChoice::Clear);
+                    }
+                    if ui.button("Cancel").clicked() {
+                        choice = Some(PopupChoice::Cancel);
+                    }
+                });
+                choice
+            },
+        )
+        .flatten()
+    }
+}
+
+/// What was clicked in the popup of [`Keybind::with_popup_editor`].
+enum PopupChoice {
+    /// A suggestion, which is set like a recorded bind.
+    Suggestion(Shortcut),
+    /// The button to clear the bind.
+    Clear,
+    /// The button to stop recording without changing the bind.
+    Cancel,
+}
+
+/// What a [Keybind] remembers between frames. It's only kept in egui's memory
+/// while the keybind isn't idle, so keybinds that were never used don't add
+/// any entries, and it's removed again when the keybind returns to idle.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct KeybindState {
+    /// What the keybind remembers while it's capturing, [None] when it isn't.
+    capture: Option<CaptureState>,
+    /// The bind that is waiting to be confirmed, in confirm mode.
+    pending: Option<Shortcut>,
+    /// The value that was left for this keybind by the keybind its bind was
+    /// dropped onto. [None] inside means the bind was moved away.
+    dropped: Option<Option<Shortcut>>,
+    /// The keybind this keybind's bind was dropped onto with [DropPolicy::Swap]
+    /// and its bind. It's only swapped if this keybind can store all of it.
+    swap: Option<(Id, Shortcut)>,
+    /// The time of the last failed paste, while its error outline is shown.
+    paste_error: Option<f64>,
+    /// The text field the bind is edited in, see [`Keybind::with_text_editing`].
+    text_edit: Option<TextEditState>,
+    /// The shortcut the bind was set to if the operating system reserves
+    /// it, until the bind changes, see [`Keybind::with_block_reserved`].
+    reserved: Option<KeyboardShortcut>,
+}
+
+/// What a keybind remembers while its bind is edited as text.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct TextEditState {
+    /// The text in the text field.
+    text: String,
+    /// Why the text couldn't be set as the bind the last time Enter was
+    /// pressed, until it's edited again.
+    error: Option<String>,
+}
+
+/// Get the ID of the text field a keybind is edited in.
+fn text_edit_id(ui: &Ui, id: Id) -> Id {
+    ui.make_persistent_id(id).with("text_edit")
+}
+
+impl KeybindState {
+    /// Whether there is nothing to remember.
+    fn is_idle(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Get the state of a keybind from egui's memory, without adding an entry
+/// if there is none, and the formatted text of its bind, at once. `key` gets
+/// the state and returns the cache key of the text.
+fn load(
+    ui: &Ui,
+    id: Id,
+    key: impl FnOnce(&KeybindState) -> Option<u64>,
+) -> (KeybindState, Option<(u64, CachedText)>) {
+    let id = ui.make_persistent_id(id);
+    let frame = ui.ctx().cumulative_pass_nr();
+    ui.ctx().memory_mut(|memory| {
+        let state = memory.data.get_temp::<KeybindState>(id).unwrap_or_default();
+        let text = key(&state).and_then(|key| {
+            let text = TextCache::get_mut(memory, frame).get(key)?;
+            Some((key, text))
+        });
+        (state, text)
+    })
+}
+
+/// Write what a keybind remembers back to egui's memory at once: its state
+/// if it changed, removing it if it's idle, and the text of its bind with its
+/// cache key if it was formatted or laid out again.
+fn store(ui: &Ui, id: Id, state: Option<KeybindState>, text: Option<(u64, CachedText)>) {
+    if state.is_none() && text.is_none() {
+        return;
+    }
+    let id = ui.make_persistent_id(id);
+    let frame = ui.ctx().cumulative_pass_nr();
+    ui.ctx().memory_mut(|memory| {
+        match state {
+            Some(state) if state.is_idle() => memory.data.remove::<KeybindState>(id),
+            Some(state) => memory.data.insert_temp(id, state),
+            None => {}
+        }
+        if let Some((key, text)) = text {
+            TextCache::get_mut(memory, frame).current.insert(key, text);
+        }
+    });
+}
+
+/// Make a keybind start capturing on the next time it's shown.
+pub(crate) fn start_capture(ui: &Ui, id: Id) {
+    start_capture_with_persistent_id(ui.ctx(), ui.make_persistent_id(id));
+}
+
+/// Make the keybind with the persistent `id` start capturing on the next
+/// time it's shown, with the keys that are held right now.
+pub(crate) fn start_capture_with_persistent_id(ctx: &Context, id: Id) {
+    let capture = CaptureState::new(ctx);
+    ctx.memory_mut(|memory| {
+        memory
+            .data
+            .get_temp_mut_or_default::<KeybindState>(id)
+            .capture = Some(capture);
+    });
+}
+
+/// Whether the keybind with the persistent `id` is capturing.
+#[cfg(feature = "testing")]
+pub(crate) fn is_capturing_with_persistent_id(ctx: &Context, id: Id) -> bool {
+    ctx.memory(|memory| {
+        memory
+            .data
+            .get_temp::<KeybindState>(id)
+            .is_some_and(|state| state.capture.is_some())
+    })
+}
+
+#[cfg(test)]
+thread_local! {
+    /// How many times [Keybind]s on this thread formatted their bind or laid
+    /// out its text, to check that they don't do it every frame.
+    static FORMAT_COUNT: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Get how many times [Keybind]s on this thread formatted their bind or laid
+/// out its text.
+#[cfg(test)]
+pub(crate) fn format_count() -> usize {
+    FORMAT_COUNT.with(std::cell::Cell::get)
+}
+
+/// Count a formatted or laid out text of a bind, see `format_count`.
+fn count_format() {
+    #[cfg(test)]
+    FORMAT_COUNT.with(|count| count.set(count.get() + 1));
+}
+
+/// The formatted text of a bind in the [TextCache].
+#[derive(Debug, Clone)]
+struct CachedText {
+    text: Arc<str>,
+    /// The text laid out, with a hash of everything the layout depends on
+    /// besides the text, see [layout_key].
+    galley: Option<(u64, Arc<Galley>)>,
+}
+
+impl CachedText {
+    /// A text that wasn't laid out yet.
+    fn new(text: Arc<str>) -> Self {
+        Self { text, galley: None }
+    }
+}
+
+/// The formatted texts of binds by their cache key, shared by all
+/// [Keybind]s. Like egui's own caches, texts that weren't used in the last
+/// frame are dropped.
+#[derive(Debug, Clone, Default)]
+struct TextCache {
+    /// The frame the texts in `current` were used in.
+    frame: u64,
+    /// The texts that were used in `frame`.
+    current: HashMap<u64, CachedText>,
+    /// The texts that were used in the frame before.
+    previous: HashMap<u64, CachedText>,
+}
+
+impl TextCache {
+    /// Where the cache is kept in egui's memory.
+    fn id() -> Id {
+        Id::new("egui_keybind_text_cache")
+    }
+
+    /// Drop the texts that weren't used since the frame before `frame`.
+    fn start_frame(&mut self, frame: u64) {
+        if frame != self.frame {
+            self.frame = frame;
+            self.previous = std::mem::take(&mut self.current);
+        }
+    }
+
+    /// Get the cache from egui's memory, for the current frame.
+    fn get_mut(memory: &mut Memory, frame: u64) -> &mut Self {
+        let cache = memory.data.get_temp_mut_or_default::<Self>(Self::id());
+        cache.start_frame(frame);
+        cache
+    }
+
+    /// Get the formatted text of a bind, if one was formatted with the same
+    /// cache key, and keep it for this frame.
+    fn get(&mut self, key: u64) -> Option<CachedText> {
+        if let Some(text) = self.previous.remove(&key) {
+            self.current.insert(key, text);
+        }
+        self.current.get(&key).cloned()
+    }
+}
+
+/// Hash everything the galley of a bind's text depends on besides the text,
+/// so it's laid out again when any of it changes.
+fn layout_key(ui: &Ui, text_style: &TextStyle, italic: bool) -> u64 {
+    egui::util::hash((
+        text_style.resolve(ui.style()),
+        italic,
+        ui.visuals().override_text_color,
+        ui.ctx().pixels_per_point().to_bits(),
+    ))
+}
+
+/// The drag and drop payload of a [Keybind].
+#[derive(Clone, Copy)]
+struct DragPayload {
+    /// Persistent ID of the keybind that is being dragged.
+    source: Id,
+    /// The whole bind of the keybind that is being dragged, see [exact_shortcut].
+    shortcut: Shortcut,
+    /// Whether the dragged keybind can be cleared, so its bind can be moved.
+    clearable: bool,
+}
+
+/// Get the keyboard shortcut, pointer button and text key of a bind as a [Shortcut].
+fn shortcut_of<B: Bind>(bind: &B) -> Shortcut {
+    let mut shortcut = Shortcut::new(bind.keyboard(), bind.pointer());
+    if let Some(text) = bind.text() {
+        shortcut.set_text(text, bind.pointer());
+    }
+    shortcut
+}
+
+/// Get the whole bind as a [Shortcut], if nothing of it is lost that way,
+/// e.g. the duration of a [LongPressBind](crate::LongPressBind) or the
+/// alternates of [Shortcuts](crate::Shortcuts). A pointer button's trigger
+/// (see [`Shortcut::with_pointer_trigger`]) is kept. Binds are compared by
+/// how they're formatted, since that is all that is known about custom binds.
+fn exact_shortcut<B: Bind>(bind: &B) -> Option<Shortcut> {
+    if !bind.alternates().is_empty() {
+        return None;
+    }
+    let shortcut = shortcut_of(bind);
+    [
+        PointerTrigger::Press,
+        PointerTrigger::Click,
+        PointerTrigger::Release,
+    ]
+    .into_iter()
+    .map(|trigger| shortcut.with_pointer_trigger(trigger))
+    .find(|shortcut| formats_as(bind, shortcut))
+}
+
+/// Whether a bind is formatted like a [Shortcut], on all platforms.
+fn formats_as<B: Bind>(bind: &B, shortcut: &Shortcut) -> bool {
+    [false, true].into_iter().all(|is_mac| {
+        bind.format(&ModifierNames::NAMES, is_mac)
+            == Bind::format(shortcut, &ModifierNames::NAMES, is_mac)
+    })
+}
+
+/// Whether a bind can be cleared (see [`Bind::clear`]), so it's unbound
+/// afterwards.
+fn can_clear<B: Bind>(bind: &B) -> bool {
+    let mut cleared = bind.clone();
+    cleared.clear();
+    !cleared.is_bound()
+}
+
+/// Whether a bind can store text keys, see [`Bind::set_text`].
+fn can_store_text<B: Bind>(bind: &B) -> bool {
+    bind.clone()
+        .set_text(TextKey::new(Modifiers::NONE, 'a'), None)
+}
+
+/// Whether a bind can store wheel turns, see [`Bind::set_wheel`].
+fn can_store_wheel<B: Bind>(bind: &B) -> bool {
+    bind.clone().set_wheel(Modifiers::NONE, WheelDirection::Up)
+}
+
+/// Whether a bind can store mouse gestures, see [`Bind::set_gesture`].
+fn can_store_gesture<B: Bind>(bind: &B) -> bool {
+    bind.clone()
+        .set_gesture(PointerButton::Secondary, GestureDirection::Left)
+}
+
+/// Set a bind to the keyboard shortcut, pointer button and text key of a
+/// [Shortcut].
+///
+/// # Returns
+/// Whether the bind was set. Binds that can't store text keys are left as
+/// they are if the shortcut has one.
+fn set_shortcut<B: Bind>(bind: &mut B, shortcut: &Shortcut) -> bool {
+    match shortcut.text() {
+        Some(text) => bind.set_text(text, shortcut.pointer()),
+        None => {
+            bind.set(shortcut.keyboard(), shortcut.pointer());
+            true
+        }
+    }
+}
+
+/// Leave a value for a dragged keybind in egui's memory, it picks it up
+/// the next time it's shown.
+fn set_drop_result(ui: &Ui, source: Id, value: Option<Shortcut>) {
+    ui.ctx().memory_mut(|memory| {
+        memory
+            .data
+            .get_temp_mut_or_default::<KeybindState>(source)
+            .dropped = Some(value);
+    });
+    ui.ctx().request_repaint();
+}
+
+/// How long the error outline is shown after pasting text that isn't a valid shortcut.
+const PASTE_ERROR_DURATION: f64 = 0.6;
+
+/// Show a translucent overlay over the whole screen that blocks interaction
+/// with everything below it, and add the widget with `widget_id` at
+/// `widget_rect` above it.
+///
+/// Returns whether the overlay was clicked, and the response of the widget.
+fn show_dim_overlay(
+    ctx: &Context,
+    id: Id,
+    widget_rect: Rect,
+    widget_id: Id,
+    sense: Sense,
+) -> (bool, Response) {
+    let screen_rect = ctx.screen_rect();
+    // the area itself covers the screen and senses the clicks, its contents are disabled on the
+    // first frame while egui lays them out
+    let overlay = Area::new(id.with("dim_background"))
+        .order(Order::Foreground)
+        .fixed_pos(screen_rect.min)
+        .default_size(screen_rect.size())
+        .show(ctx, |ui| ui.set_min_size(screen_rect.size()))
+        .response;
+    ctx.layer_painter(overlay.layer_id).rect_filled(
+        screen_rect,
+        0.0,
+        Color32::from_black_alpha(128),
+    );
+    let above = Ui::new(
+        ctx.clone(),
+        id.with("above_dim_background"),
+        UiBuilder::new()
+            .layer_id(overlay.layer_id)
+            .max_rect(screen_rect),
+    );
+    let widget = above.interact(widget_rect, widget_id, sense);
+    let clicked = overlay.clicked() || overlay.secondary_clicked() || overlay.middle_clicked();
+    (clicked, widget)
+}
+
+/// Whether [paint_pointer_icon] can paint an icon for this button.
+fn has_pointer_icon(button: PointerButton) -> bool {
+    matches!(
+        button,
+        PointerButton::Primary | PointerButton::Secondary | PointerButton::Middle
+    )
+}
+
+/// Paint a small mouse icon with the given button highlighted.
+fn paint_pointer_icon(painter: &Painter, rect: Rect, button: PointerButton, color: Color32) {
+    let stroke = Stroke::new((rect.width() * 0.1).max(1.0), color);
+    let body = rect.shrink(stroke.width * 0.5);
+    let rounding = body.width() * 0.45;
+    let split_y = body.top() + body.height() * 0.45;
+
+    // highlight the bound part of the mouse
+    let top_rounding = |left: bool| Rounding {
+        nw: if left { rounding } else { 0.0 },
+        ne: if left { 0.0 } else { rounding },
+        sw: 0.0,
+        se: 0.0,
+    };
+    let wheel = Rect::from_center_size(
+        pos2(body.center().x, body.top() + body.height() * 0.25),
+        vec2(body.width() * 0.2, body.height() * 0.22),
+    );
+    let highlight = match button {
+        PointerButton::Primary => Some((
+            Rect::from_x_y_ranges(body.left()..=body.center().x, body.top()..=split_y),
+            top_rounding(true),
+        )),
+        PointerButton::Secondary => Some((
+            Rect::from_x_y_ranges(body.center().x..=body.right(), body.top()..=split_y),
+            top_rounding(false),
+        )),
+        _ => None,
+    };
+    if let Some((highlight, rounding)) = highlight {
+        painter.rect_filled(highlight, rounding, color);
+    }
+
+    // outline, button split and wheel
+    painter.rect_stroke(body, rounding, stroke);
+    painter.line_segment(
+        [pos2(body.left(), split_y), pos2(body.right(), split_y)],
+        stroke,
+    );
+    painter.line_segment(
+        [
+            pos2(body.center().x, body.top()),
+            pos2(body.center().x, wheel.top()),
+        ],
+        stroke,
+    );
+    painter.line_segment(
+        [
+            pos2(body.center().x, wheel.bottom()),
+            pos2(body.center().x, split_y),
+        ],
+        stroke,
+    );
+    if button == PointerButton::Middle {
+        painter.rect_filled(wheel, wheel.width() * 0.5, color);
+    } else {
+        painter.rect_stroke(wheel, wheel.width() * 0.5, stroke);
+    }
+}
+
+/// The icon of [`Keybind::with_input_kind_icons`], for the only kind of
+/// input a bind accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InputKindIcon {
+    /// Only keys are recorded.
+    Keyboard,
+    /// Only pointer buttons are recorded.
+    Mouse,
+}
+
+impl InputKindIcon {
+    /// Get the icon for the input kinds of a bind, if it accepts only one.
+    fn of(kinds: InputKinds) -> Option<Self> {
+        match (kinds.keyboard, kinds.pointer) {
+            (true, false) => Some(Self::Keyboard),
+            (false, true) => Some(Self::Mouse),
+            _ => None,
+        }
+    }
+
+    /// The description screen readers get for the icon.
+    fn description(self) -> &'static str {
+        match self {
+            Self::Keyboard => "Only keys can be recorded",
+            Self::Mouse => "Only mouse buttons can be recorded",
+        }
+    }
+
+    /// The size of the icon next to text that is `height` high.
+    fn size(self, height: f32) -> Vec2 {
+        match self {
+            Self::Keyboard => vec2(height * 1.2, height * 0.75),
+            Self::Mouse => vec2(height * 0.55, height * 0.8),
+        }
+    }
+
+    /// Paint the icon in `rect`.
+    fn paint(self, painter: &Painter, rect: Rect, color: Color32) {
+        match self {
+            Self::Keyboard => paint_keyboard_icon(painter, rect, color),
+            // a mouse without a highlighted button
+            Self::Mouse => paint_pointer_icon(painter, rect, PointerButton::Extra1, color),
+        }
+    }
+}
+
+/// Paint a keyboard in `rect`: two rows of keys and a space bar.
+fn paint_keyboard_icon(painter: &Painter, rect: Rect, color: Color32) {
+    let stroke = Stroke::new((rect.height() * 0.1).max(1.0), color);
+    let body = rect.shrink(stroke.width * 0.5);
+    painter.rect_stroke(body, body.height() * 0.15, stroke);
+
+    let key_size = Vec2::splat(body.height() * 0.14);
+    for row in 0..2 {
+        for column in 0..4 {
+            let center = pos2(
+                body.left() + body.width() * (column + 1) as f32 / 5.0,
+                body.top() + body.height() * (0.3 + 0.22 * row as f32),
+            );
+            painter.rect_filled(Rect::from_center_size(center, key_size), 0.0, color);
+        }
+    }
+    let space_y = body.top() + body.height() * 0.75;
+    painter.line_segment(
+        [
+            pos2(body.left() + body.width() * 0.3, space_y),
+            pos2(body.right() - body.width() * 0.3, space_y),
+        ],
+        stroke,
+    );
+}
+
+impl<'a, B: Bind> Widget for Keybind<'a, B> {
+    fn ui(mut self, ui: &mut egui::Ui) -> egui::Response {
+        let os = ui.ctx().os();
+        self.modifier_names
+            .get_or_insert_with(|| ModifierNames::for_os(os));
+        self.is_mac = os == OperatingSystem::Mac;
+        let label = std::mem::take(&mut self.text);
+        let label_text = label.text().to_owned();
+        // small keybinds follow `Button::small`: body text, no vertical padding
+        let text_style = if self.small {
+            TextStyle::Body
+        } else {
+            TextStyle::Button
+        };
+
+        // everything the keybind remembers and the text of its bind are read at once here, and
+        // only written back at the end if they changed
+        let (mut state, cached) = load(ui, self.id, |state| {
+            let pending = self.confirm && state.pending.is_some();
+            self.text_cache_key(self.pointer_icon(pending))
+        });
+        let prev_state = state.clone();
+        #[cfg(feature = "testing")]
+        crate::testing::register(ui, self.id);
+
+        // another keybind took our bind by drag and drop, pick up what it left us
+        let dropped_elsewhere = match state.dropped.take() {
+            Some(Some(value)) => {
+                set_shortcut(self.bind, &value);
+                true
+            }
+            Some(None) => {
+                self.bind.clear();
+                true
+            }
+            None => false,
+        };
+        // our bind was dropped onto another keybind to swap them, which only
+        // takes our bind once we've taken its one
+        let dropped_elsewhere = dropped_elsewhere
+            || state.swap.take().is_some_and(|(target, value)| {
+                let Some(ours) = exact_shortcut(self.bind) else {
+                    return false;
+                };
+                let mut swapped = self.bind.clone();
+                if !set_shortcut(&mut swapped, &value) || !formats_as(&swapped, &value) {
+                    return false;
+                }
+                *self.bind = swapped;
+                set_drop_result(ui, target, Some(ours));
+                true
+            });
+
+        // the bind is typed in instead, see `with_text_editing`
+        if let Some(mut edit) = state.text_edit.take() {
+            let (mut response, done) = self.show_text_edit(ui, &mut edit, label);
+            if dropped_elsewhere {
+                response.mark_changed();
+            }
+            if !done {
+                state.text_edit = Some(edit);
+            }
+            self.update_reserved(&mut state, os, response.changed());
+            store(ui, self.id, (state != prev_state).then_some(state), None);
+            return response;
+        }
+
+        // in confirm mode, a captured bind waits in memory until it's applied or cancelled
+        let mut pending = if self.confirm {
+            state.pending.take()
+        } else {
+            None
+        };
+
+        // if the pointer button is displayed as an icon, only format the keyboard part
+        let pointer_icon = self.pointer_icon(pending.is_some());
+        // the text is kept between frames, until the bind or how it's formatted changes. The
+        // bind may have been dropped onto since it was read, so the key is checked again
+        let cache_key = self.text_cache_key(pointer_icon);
+        let cached = cached.filter(|(key, _)| Some(*key) == cache_key);
+        // the text to keep in memory at the end, if it was formatted or laid out again
+        let mut uncached: Option<CachedText> = None;
+        let (text, cached_galley) = if let Some((_, cached)) = cached {
+            (cached.text, cached.galley)
+        } else if pointer_icon.is_some() {
+            let names = self.names();
+            let mut text = self.bind.keyboard().map_or_else(String::new, |kb| {
+                match (self.modifier_order, self.key_names) {
+                    (Some(order), key_names) => {
+                        format_with_modifier_order(&kb, &names, key_names, order, self.is_mac)
+                    }
+                    (None, Some(key_names)) => {
+                        key_names.format_keyboard_shortcut(&kb, &names, self.is_mac)
+                    }
+                    (None, None) => {
+                        KeyNameMap::default().format_keyboard_shortcut(&kb, &names, self.is_mac)
+                    }
+                }
+            });
+            if !text.is_empty() {
+                text.push_str(pointer_separator(&names));
+            }
+            let text: Arc<str> = text.into();
+            count_format();
+            uncached = Some(CachedText::new(text.clone()));
+            (text, None)
+        } else {
+            let text: Arc<str> = self.format_bind(self.key_names).into();
+            count_format();
+            uncached = Some(CachedText::new(text.clone()));
+            (text, None)
+        };
+        let pointer_icon_size = pointer_icon.map_or(Vec2::ZERO, |_| {
+            let height = ui.text_style_height(&text_style);
+            vec2(height * 0.7, height)
+        });
+        // an icon for the only kind of input that can be recorded, before the text
+        let kind_icon = self.input_kind_icon(state.capture.is_some());
+        let kind_icon_size = kind_icon.map_or(Vec2::ZERO, |icon| {
+            icon.size(ui.text_style_height(&text_style))
+        });
+        let kind_icon_width = match kind_icon {
+            Some(_) => kind_icon_size.x + 0.5 * ui.spacing().item_spacing.x,
+            None => 0.0,
+        };
+
+        let symbols = self.symbol_tooltip && self.bind.is_bound() && self.shows_symbols(&text);
+        let unbound = !self.bind.is_bound();
+        let italic = unbound && self.unbound_style.italic;
+        let rich_text = || {
+            let rich_text = RichText::new(&*text);
+            match italic {
+                true => rich_text.italics(),
+                false => rich_text,
+            }
+        };
+        let layout = |widget_text: WidgetText| {
+            widget_text.into_galley(
+                ui,
+                Some(egui::TextWrapMode::Extend),
+                0.0,
+                text_style.clone(),
+            )
+        };
+
+        let galley = match pending {
+            Some(pending) => {
+                // show the old and the new bind side by side, e.g. "Ctrl+S → Ctrl+Shift+S"
+                let mut preview = self.bind.clone();
+                set_shortcut(&mut preview, &pending);
+                let preview_text = format!(" → {}", self.format_value(&preview, self.key_names));
+
+                let font = || FontSelection::Style(text_style.clone());
+                let mut job = LayoutJob::default();
+                rich_text()
+                    .strikethrough()
+                    .color(ui.visuals().weak_text_color())
+                    .append_to(&mut job, ui.style(), font(), Align::Center);
+                RichText::new(preview_text).append_to(&mut job, ui.style(), font(), Align::Center);
+                layout(WidgetText::LayoutJob(job))
+            }
+            None => {
+                // the galley is kept with the text, until something it depends on changes
+                let layout_key = layout_key(ui, &text_style, italic);
+                match cached_galley.filter(|(key, _)| *key == layout_key) {
+                    Some((_, galley)) => galley,
+                    None => {
+                        let galley = layout(WidgetText::RichText(rich_text()));
+                        count_format();
+                        let mut cached = CachedText::new(text.clone());
+                        cached.galley = Some((layout_key, galley.clone()));
+                        uncached = Some(cached);
+                        galley
+                    }
+                }
+            }
+        };
+
+        // size of one of the apply/cancel buttons in confirm mode
+        let confirm_button_size = Vec2::splat(ui.text_style_height(&text_style));
+        let confirm_buttons_width = if pending.is_some() {
+            2.0 * (ui.spacing().item_spacing.x + confirm_button_size.x)
+        } else {
+            0.0
+        };
+
+        // a badge with the number of alternate binds, e.g. "+1"
+        let badge_galley = if self.bind.alternates().is_empty() || pending.is_some() {
+            None
+        } else {
+            let badge = RichText::new(format!("+{}", self.bind.alternates().len()));
+            Some(WidgetText::RichText(badge).into_galley(
+                ui,
+                Some(egui::TextWrapMode::Extend),
+                0.0,
+                TextStyle::Small,
+            ))
+        };
+        let badge_gap = 0.5 * ui.spacing().item_spacing.x;
+        let badge_width = badge_galley
+            .as_ref()
+            .map_or(0.0, |badge| badge_gap + badge.size().x);
+
+        let mut min_size = ui.spacing().interact_size;
+        let mut button_padding = ui.spacing().button_padding;
+        if self.small {
+            min_size.y = 0.0;
+            button_padding.y = 0.0;
+        }
+
+        let content_size = vec2(
+            kind_icon_width
+                + galley.size().x
+                + pointer_icon_size.x
+                + badge_width
+                + confirm_buttons_width,
+            galley
+                .size()
+                .y
+                .max(pointer_icon_size.y)
+                .max(kind_icon_size.y),
+        );
+        let size = min_size.max(content_size);
+        let button_size = size + button_padding * vec2(2.0, 1.0);
+
+        // compute the text galley next to the widget (set by with_text), expand
+        // widget appropriately
+        let text_galley = if self.text_visible && !label.is_empty() {
+            let wrap_width = match self.label_layout {
+                LabelLayout::Right => ui.available_width() - button_size.x, // not exactly right
+                LabelLayout::Above => ui.available_width(),
+            };
+            let galley = label.into_galley(ui, None, wrap_width, text_style.clone());
+            Some(galley)
+        } else {
+            None
+        };
+
+        let widget_size = match (self.label_layout, &text_galley) {
+            (_, None) => button_size,
+            (LabelLayout::Right, Some(text_galley)) => {
+                button_size + vec2(ui.spacing().icon_spacing + text_galley.size().x, 0.0)
+            }
+            (LabelLayout::Above, Some(text_galley)) => vec2(
+                button_size.x.max(text_galley.size().x),
+                text_galley.size().y + ui.spacing().item_spacing.y + button_size.y,
+            ),
+        };
+
+        // allocate the visual rect, but sense clicks in a (possibly) larger
+        // area, clamped so it doesn't reach into the neighbouring widgets
+        let (id, space) = ui.allocate_space(widget_size);
+        // Enter and Escape apply and cancel the pending bind, but only while the keybind or
+        // its buttons have focus. They're taken before the button sees Enter as a click
+        let focused =
+            ui.memory(|m| [id, id.with("apply"), id.with("cancel")].map(|id| m.has_focus(id)));
+        let confirm_keys = match pending.is_some() && focused.contains(&true) {
+            true => ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::NONE, Key::Enter),
+                    i.consume_key(Modifiers::NONE, Key::Escape),
+                )
+            }),
+            false => (false, false),
+        };
+        let rect = ui.layout().align_size_within_rect(widget_size, space);
+        let max_touch_padding = ui.spacing().item_spacing * 0.5;
+        let touch_padding = self
+            .touch_padding
+            .or_else(|| {
+                ui.input(|i| i.has_touch_screen())
+                    .then_some(max_touch_padding)
+            })
+            .map_or(Vec2::ZERO, |padding| {
+                padding.max(Vec2::ZERO).min(max_touch_padding)
+            });
+
+        // calculate the rect of the button, without the custom text
+        let hotkey_rect = match self.label_layout {
+            LabelLayout::Right => Rect::from_min_size(
+                pos2(rect.left(), rect.center().y - 0.5 * button_size.y),
+                vec2(
+                    rect.width() - (widget_size.x - button_size.x),
+                    button_size.y,
+                ),
+            ),
+            LabelLayout::Above => Rect::from_min_size(
+                pos2(rect.left(), rect.bottom() - button_size.y),
+                button_size,
+            ),
+        };
+
+        // see if we're currently waiting for any key
+        let mut expecting = state.capture.is_some();
+        let mut start_text_edit = false;
+        // only binds that are written as text without losing anything, see `with_text_editing`
+        let text_editing = self.text_editing && exact_shortcut(self.bind).is_some();
+        let prev_expecting = expecting;
+
+        // capture mode takes precedence over dragging
+        let sense = if self.drop_policy.is_some() && !expecting {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        // only the button is clickable when the label is above it, but hovering covers the whole stack
+        let interact_rect = match self.label_layout {
+            LabelLayout::Right => space.expand2(touch_padding),
+            LabelLayout::Above => hotkey_rect.expand2(touch_padding),
+        };
+        // dim the rest of the ui while capturing, the keybind is moved above it
+        let dimmed = expecting && self.dim_background;
+        let (overlay_clicked, mut response) = if dimmed {
+            show_dim_overlay(ui.ctx(), self.id, interact_rect, id, sense)
+        } else {
+            let mut response = ui.interact(interact_rect, id, sense);
+            if let LabelLayout::Above = self.label_layout {
+                response = response.union(ui.interact(space, id.with("label"), Sense::hover()));
+            }
+            (false, response)
+        };
+        response.intrinsic_size = Some(widget_size);
+        if dropped_elsewhere {
+            response.mark_changed();
+        }
+
+        // apply and cancel buttons at the right of the button, in confirm mode
+        let confirm_buttons = pending.map(|_| {
+            let inner = hotkey_rect.shrink2(button_padding);
+            let cancel_rect = Rect::from_center_size(
+                pos2(
+                    inner.right() - 0.5 * confirm_button_size.x,
+                    inner.center().y,
+                ),
+                confirm_button_size,
+            );
+            let apply_rect = cancel_rect.translate(vec2(
+                -(confirm_button_size.x + ui.spacing().item_spacing.x),
+                0.0,
+            ));
+            let apply = ui.interact(apply_rect, id.with("apply"), Sense::click());
+            apply.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, "Apply"));
+            let cancel = ui.interact(cancel_rect, id.with("cancel"), Sense::click());
+            cancel.widget_info(|| WidgetInfo::labeled(WidgetType::Button, true, "Cancel"));
+            (apply, cancel)
+        });
+
+        if response.clicked() {
+            expecting = !expecting;
+        }
+
+        if let Some((apply, cancel)) = &confirm_buttons {
+            if expecting {
+                // the button was clicked again, capture a new bind instead
+                pending = None;
+            } else if apply.clicked() || confirm_keys.0 {
+                if let Some(pending) = pending.take() {
+                    set_shortcut(self.bind, &pending);
+                    response.mark_changed();
+                }
+            } else if cancel.clicked() || response.clicked_elsewhere() || confirm_keys.1 {
+                pending = None;
+            }
+        }
+
+        // drag and drop the bind between keybinds
+        let mut drop_hovered = false;
+        if let Some(policy) = self.drop_policy {
+            let source = ui.make_persistent_id(self.id);
+            // binds that aren't a whole shortcut can't be dragged, they'd lose the rest
+            if let Some(shortcut) = exact_shortcut(self.bind).filter(|_| !expecting) {
+                response.dnd_set_drag_payload(DragPayload {
+                    source,
+                    shortcut,
+                    clearable: can_clear(self.bind),
+                });
+            }
+            // what this keybind is set to, if the dragged bind can be dropped here
+            let drop = |bind: &B, payload: &DragPayload| {
+                if payload.source == source {
+                    return None;
+                }
+                let mut value = bind.clone();
+                if !set_shortcut(&mut value, &payload.shortcut)
+                    || !formats_as(&value, &payload.shortcut)
+                {
+                    return None;
+                }
+                match policy {
+                    // a bind that can't be cleared would be duplicated
+                    DropPolicy::Move if !payload.clearable => None,
+                    DropPolicy::Move => Some(value),
+                    DropPolicy::Swap => exact_shortcut(bind).map(|_| value),
+                }
+            };
+            drop_hovered = response
+                .dnd_hover_payload::<DragPayload>()
+                .is_some_and(|payload| drop(self.bind, &payload).is_some());
+            if let Some(payload) = response.dnd_release_payload::<DragPayload>() {
+                match (policy, drop(self.bind, &payload)) {
+                    (_, None) => {}
+                    (DropPolicy::Move, Some(value)) => {
+                        // the source keybind is cleared on the next frame
+                        set_drop_result(ui, payload.source, None);
+                        *self.bind = value;
+                        response.mark_changed();
+                        expecting = false;
+                    }
+                    (DropPolicy::Swap, Some(_)) => {
+                        // only the source keybind knows if it can store our bind, it
+                        // leaves its bind to us once it did
+                        let ours = exact_shortcut(self.bind).unwrap_or_default();
+                        ui.ctx().memory_mut(|memory| {
+                            memory
+                                .data
+                                .get_temp_mut_or_default::<KeybindState>(payload.source)
+                                .swap = Some((source, ours));
+                        });
+                        ui.ctx().request_repaint();
+                        expecting = false;
+                    }
+                }
+            }
+        }
+
+        let popup_id = ui.make_persistent_id(self.id).with("popup");
+        if expecting && self.popup_editor && !ui.memory(|m| m.is_popup_open(popup_id)) {
+            ui.memory_mut(|m| m.open_popup(popup_id));
+        }
+
+        if expecting {
+            if response.clicked_elsewhere() && !self.popup_editor {
+                // the user has clicked somewhere else, stop capturing input
+                expecting = false;
+            } else {
+                // everything ok, capture keyboard and mouse input
+                let settings = self.capture_settings(os);
+                let capture = state
+                    .capture
+                    .get_or_insert_with(|| CaptureState::new(ui.ctx()));
+                let mut status = capture.poll(ui.ctx(), &settings);
+                if self.popup_editor && status == CaptureStatus::Pending {
+                    match self.show_popup(ui, popup_id, &response) {
+                        Some(PopupChoice::Suggestion(shortcut)) => {
+                            status = CaptureStatus::Done(shortcut);
+                        }
+                        Some(PopupChoice::Clear) => {
+                            self.bind.clear();
+                            pending = None;
+                            response.mark_changed();
+                            expecting = false;
+                        }
+                        Some(PopupChoice::Cancel) => expecting = false,
+                        // closed by a click outside of it or Escape
+                        None if !ui.memory(|m| m.is_popup_open(popup_id)) => expecting = false,
+                        None => {}
+                    }
+                }
+                match status {
+                    CaptureStatus::Pending => {}
+                    // set keybind, or wait for it to be confirmed
+                    CaptureStatus::Done(shortcut) => {
+                        if self.confirm {
+                            pending = Some(shortcut);
+                            // so Enter and Escape apply or cancel it
+                            response.request_focus();
+                        } else {
+                            set_shortcut(self.bind, &shortcut);
+                            response.mark_changed();
+                        }
+                        expecting = false;
+                    }
+                    // held pointer button, binds that can't store it get the button
+                    CaptureStatus::LongPress(long_press) => {
+                        if !self
+                            .bind
+                            .set_long_press(long_press.button, long_press.duration)
+                        {
+                            self.bind.set(None, Some(long_press.button));
+                        }
+                        response.mark_changed();
+                        expecting = false;
+                    }
+                    // only captured for binds that can store it
+                    CaptureStatus::Wheel(wheel) => {
+                        self.bind.set_wheel(wheel.modifiers, wheel.direction);
+                        response.mark_changed();
+                        expecting = false;
+                    }
+                    CaptureStatus::Gesture(gesture) => {
+                        self.bind.set_gesture(gesture.button, gesture.direction);
+                        response.mark_changed();
+                        expecting = false;
+                    }
+                    // the reset key was pressed
+                    CaptureStatus::Cancelled => {
+                        if let Some(reset) = self.reset.take() {
+                            *self.bind = reset;
+                        }
+                        expecting = false;
+                        response.mark_changed();
+                    }
+                }
+            }
+        } else if response.has_focus() {
+            // clear the bind, consuming the key so it doesn't leak to other handlers
+            let clear = ui.input_mut(|i| {
+                self.clear_keys
+                    .iter()
+                    .any(|key| i.consume_key(Modifiers::NONE, *key))
+            });
+            if clear && self.bind.is_bound() {
+                self.bind.clear();
+                response.mark_changed();
+            }
+
+            if text_editing && ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::F2)) {
+                start_text_edit = true;
+            }
+
+            // copy and paste the bind. this only works outside of capture mode,
+            // where the copy and paste shortcuts are captured like any other key
+            let (copy, paste) = ui.input(|i| {
+                (
+                    i.events.iter().any(|e| matches!(e, Event::Copy)),
+                    i.events.iter().find_map(|e| match e {
+                        Event::Paste(text) => Some(text.clone()),
+                        _ => None,
+                    }),
+                )
+            });
+            if copy {
+                ui.ctx()
+                    .copy_text(self.bind.format(&ModifierNames::NAMES, self.is_mac));
+            }
+            if let Some(text) = paste {
+                let kinds = self.bind.input_kinds();
+                match text.parse::<Shortcut>() {
+                    Ok(shortcut)
+                        if kinds.accepts(shortcut.keyboard(), shortcut.pointer())
+                            && set_shortcut(self.bind, &shortcut) =>
+                    {
+                        response.mark_changed();
+                    }
+                    _ => state.paste_error = Some(ui.input(|i| i.time)),
+                }
+            }
+        }
+
+        if self.popup_editor && !expecting && ui.memory(|m| m.is_popup_open(popup_id)) {
+            ui.memory_mut(|m| m.close_popup());
+        }
+
+        // a dot in the corner while the bind isn't the default, which resets it when clicked
+        let modified = match self.default {
+            Some((default, eq)) if !expecting && pending.is_none() && !eq(self.bind, default) => {
+                Some(default)
+            }
+            _ => None,
+        };
+        let mut reset_to_default = false;
+        let default_dot = modified.map(|default| {
+            let radius = 0.15 * ui.text_style_height(&text_style);
+            let center = hotkey_rect.right_top() + vec2(-2.0 * radius, 2.0 * radius);
+            let dot = ui.interact(
+                Rect::from_center_size(center, Vec2::splat(4.0 * radius)),
+                id.with("default_dot"),
+                Sense::click(),
+            );
+            dot.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Reset to default")
+            });
+            let default = self.format_value(default, self.key_names);
+            let dot = dot.on_hover_text(format!("Modified, the default is {default}"));
+            reset_to_default |= dot.clicked();
+            (center, radius, dot.hovered())
+        });
+
+        if (text_editing || modified.is_some()) && !expecting {
+            response.context_menu(|ui| {
+                if text_editing && ui.button("Edit as text").clicked() {
+                    start_text_edit = true;
+                    ui.close_menu();
+                }
+                if modified.is_some() && ui.button("Reset to default").clicked() {
+                    reset_to_default = true;
+                    ui.close_menu();
+                }
+            });
+        }
+        if let Some(default) = modified.filter(|_| reset_to_default) {
+            *self.bind = default.clone();
+            response.mark_changed();
+            ui.ctx().request_repaint();
+        }
+        // the bind could have changed since, e.g. by resetting it
+        if let Some(shortcut) = exact_shortcut(self.bind).filter(|_| start_text_edit) {
+            let text = match self.bind.is_bound() {
+                true => shortcut.to_string(),
+                false => String::new(),
+            };
+            state.text_edit = Some(TextEditState { text, error: None });
+            ui.memory_mut(|m| m.request_focus(text_edit_id(ui, self.id)));
+            ui.ctx().request_repaint();
+        }
+
+        self.update_reserved(&mut state, os, response.changed());
+        let reserved_warning = self.reserved_warning(&state, os).filter(|_| !expecting);
+
+        // clicking the dimmed area cancels the capture
+        if overlay_clicked {
+            expecting = false;
+        }
+        if dimmed && !expecting {
+            // the overlay was shown before the capture ended, so it's gone on the next frame
+            ui.ctx().request_repaint();
+        }
+
+        // paint
+        if ui.is_rect_visible(rect) {
+            // paint on top of the dim overlay, if there is one
+            let painter = if dimmed {
+                ui.ctx()
+                    .layer_painter(LayerId::new(Order::Tooltip, self.id.with("dimmed")))
+                    .with_clip_rect(ui.clip_rect())
+            } else {
+                ui.painter().clone()
+            };
+
+            // paint bg rect
+            let visuals = ui.style().interact_selectable(&response, expecting);
+
+            // the unbound style only applies while idle, hover and capture take precedence
+            let idle_unbound =
+                unbound && !expecting && !response.hovered() && !response.has_focus();
+            let dashed = idle_unbound && self.unbound_style.dashed_stroke;
+
+            let bg_rect = hotkey_rect.expand(visuals.expansion);
+            painter.rect(
+                bg_rect,
+                visuals.rounding,
+                visuals.bg_fill,
+                if dashed {
+                    Stroke::NONE
+                } else {
+                    visuals.bg_stroke
+                },
+            );
+            if dashed {
+                let stroke = Stroke::new(
+                    visuals.bg_stroke.width.max(1.0),
+                    ui.visuals().weak_text_color(),
+                );
+                painter.add(Shape::dashed_line(
+                    &[
+                        bg_rect.left_top(),
+                        bg_rect.right_top(),
+                        bg_rect.right_bottom(),
+                        bg_rect.left_bottom(),
+                        bg_rect.left_top(),
+                    ],
+                    stroke,
+                    3.0,
+                    2.0,
+                ));
+            }
+
+            // paint a focus ring around the button for keyboard users, unless
+            // we're capturing (which already has its own style)
+            if response.has_focus() && !expecting {
+                let stroke = ui.visuals().selection.stroke;
+                painter.rect_stroke(
+                    hotkey_rect.expand(visuals.expansion + stroke.width + 1.0),
+                    visuals.rounding,
+                    stroke,
+                );
+            }
+
+            // highlight the keybind if something can be dropped onto it
+            if drop_hovered {
+                let stroke = ui.visuals().selection.stroke;
+                painter.rect_stroke(bg_rect, visuals.rounding, stroke);
+            }
+
+            // flash an error outline if the pasted text wasn't a valid shortcut
+            if let Some(error_time) = state.paste_error {
+                let elapsed = ui.input(|i| i.time) - error_time;
+                if (0.0..PASTE_ERROR_DURATION).contains(&elapsed) {
+                    painter.rect_stroke(
+                        bg_rect,
+                        visuals.rounding,
+                        Stroke::new(
+                            visuals.bg_stroke.width.max(1.0),
+                            ui.visuals().error_fg_color,
+                        ),
+                    );
+                    ui.ctx().request_repaint();
+                } else {
+                    state.paste_error = None;
+                }
+            }
+
+            // tint the keybind if it was set to a shortcut the operating system reserves
+            if reserved_warning.is_some() {
+                let color = ui.visuals().warn_fg_color;
+                painter.rect(
+                    bg_rect,
+                    visuals.rounding,
+                    color.gamma_multiply(0.15),
+                    Stroke::new(visuals.bg_stroke.width.max(1.0), color),
+                );
+            }
+
+            // align text to center in rect that is shrinked to match button padding
+            let mut text_pos = ui
+                .layout()
+                .align_size_within_rect(content_size, hotkey_rect.shrink2(button_padding))
+                .min;
+
+            // align text to center of the button if it doesn't expand the rect
+            if text_pos.x + content_size.x + button_padding.x < hotkey_rect.right() {
+                text_pos.x += hotkey_rect.size().x / 2.0 - content_size.x / 2.0 - button_padding.x;
+            }
+
+            // paint text inside button
+            let text_color = if idle_unbound && self.unbound_style.weak_text {
+                ui.visuals().weak_text_color()
+            } else {
+                visuals.text_color()
+            };
+            if let Some(icon) = kind_icon {
+                let icon_pos = text_pos + vec2(0.0, 0.5 * (content_size.y - kind_icon_size.y));
+                let color = ui.visuals().weak_text_color();
+                icon.paint(
+                    &painter,
+                    Rect::from_min_size(icon_pos, kind_icon_size),
+                    color,
+                );
+                text_pos.x += kind_icon_width;
+            }
+            let icon_pos = text_pos
+                + vec2(
+                    galley.size().x,
+                    0.5 * (content_size.y - pointer_icon_size.y),
+                );
+            let badge_pos = icon_pos + vec2(pointer_icon_size.x + badge_gap, 0.0);
+            text_pos.y += 0.5 * (content_size.y - galley.size().y);
+            painter.galley(text_pos, galley, text_color);
+
+            // paint the alternate binds badge after the text and icon, if any
+            if let Some(badge) = badge_galley {
+                let badge_pos = pos2(badge_pos.x, hotkey_rect.center().y - 0.5 * badge.size().y);
+                painter.galley(badge_pos, badge, ui.visuals().weak_text_color());
+            }
+
+            // paint the modified dot, if any
+            if let Some((center, radius, hovered)) = default_dot {
+                let radius = if hovered { 1.25 * radius } else { radius };
+                painter.circle_filled(center, radius, ui.visuals().selection.stroke.color);
+            }
+
+            // paint the apply and cancel buttons, if any
+            if let Some((apply, cancel)) = &confirm_buttons {
+                for (response, glyph) in [(apply, "✔"), (cancel, "✖")] {
+                    let visuals = ui.style().interact(response);
+                    if response.hovered() || response.has_focus() {
+                        painter.rect_filled(
+                            response.rect.expand(visuals.expansion),
+                            visuals.rounding,
+                            visuals.weak_bg_fill,
+                        );
+                    }
+                    painter.text(
+                        response.rect.center(),
+                        Align2::CENTER_CENTER,
+                        glyph,
+                        text_style.resolve(ui.style()),
+                        visuals.text_color(),
+                    );
+                }
+            }
+
+            // paint the pointer button icon after the text, if any
+            if let Some(button) = pointer_icon {
+                paint_pointer_icon(
+                    &painter,
+                    Rect::from_min_size(icon_pos, pointer_icon_size),
+                    button,
+                    text_color,
+                );
+            }
+
+            // paint galley for the custom text, if any
+            if let Some(text_galley) = text_galley {
+                let text_pos = match self.label_layout {
+                    LabelLayout::Right => pos2(
+                        hotkey_rect.right() + ui.spacing().icon_spacing,
+                        hotkey_rect.center().y - 0.5 * text_galley.size().y,
+                    ),
+                    LabelLayout::Above => rect.left_top(),
+                };
+                painter.galley(
+                    text_pos,
+                    text_galley,
+                    ui.style().noninteractive().text_color(),
+                );
+            }
+        }
+
+        if !expecting {
+            state.capture = None;
+        }
+        state.pending = pending;
+        let text = cache_key.zip(uncached);
+        store(ui, self.id, (state != prev_state).then_some(state), text);
+        self.accessibility(ui, &response, prev_expecting, expecting, &label_text);
+        // the icon only changes the size on the next frame
+        if expecting != prev_expecting && InputKindIcon::of(self.bind.input_kinds()).is_some() {
+            ui.ctx().request_repaint();
+        }
+
+        // spell out the bind if it's shown with symbols, and list all binds
+        // when hovering a keybind that has alternates
+        let mut lines: Vec<String> = reserved_warning.into_iter().collect();
+        if symbols {
+            lines.push(self.format_long(&READABLE_MODIFIER_NAMES));
+        }
+        if !self.bind.alternates().is_empty() {
+            let names = self.names();
+            lines.push(self.format_bind(self.key_names));
+            lines.extend(
+                self.bind
+                    .alternates()
+                    .iter()
+                    .map(|alternate| match self.key_names {
+                        Some(key_names) => {
+                            alternate.format_with_key_names(&names, key_names, self.is_mac)
+                        }
+                        None => Bind::format(alternate, &names, self.is_mac),
+                    }),
+            );
+        }
+        lines.extend(self.description.map(str::to_string));
+        if !expecting && !lines.is_empty() {
+            response = response.on_hover_text(lines.join("\n"));
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui_kittest::{kittest::Queryable, Harness};
+
+    /// Show a keybind labelled "Keybind" for the bind in the state of the
+    /// harness, set up by `configure`.
+    fn keybind_harness<'a, B: Bind + 'a>(
+        bind: B,
+        configure: impl Fn(Keybind<'_, B>) -> Keybind<'_, B> + 'a,
+    ) -> Harness<'a, B> {
+        Harness::new_ui_state(
+            move |ui, bind| {
+                ui.add(configure(
+                    Keybind::new(bind, "keybind").with_text("Keybind"),
+                ));
+            },
+            bind,
+        )
+    }
+
+    /// Click the keybind with the mouse, e.g. to start recording.
+    fn click<State>(harness: &mut Harness<'_, State>) {
+        harness.get_by_label_contains("Keybind").simulate_click();
+        harness.run();
+    }
+
+    /// Run a frame with some events while `modifiers` are held.
+    fn frame<State>(harness: &mut Harness<'_, State>, events: Vec<Event>, modifiers: Modifiers) {
+        let input = harness.input_mut();
+        input.events = events;
+        input.modifiers = modifiers;
+        harness.step();
+        harness.input_mut().modifiers = Modifiers::NONE;
+        harness.run();
+    }
+
+    fn key(key: Key, modifiers: Modifiers) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    /// Press or release a pointer button. Over the keybind, it doesn't stop
+    /// the recording.
+    fn button<State>(harness: &Harness<'_, State>, button: PointerButton, pressed: bool) -> Event {
+        let rect = harness
+            .get_by_label_contains("Keybind")
+            .bounding_box()
+            .unwrap();
+        let center = (rect.x0 + rect.x1) / 2.0;
+        let middle = (rect.y0 + rect.y1) / 2.0;
+        Event::PointerButton {
+            pos: pos2(center as f32, middle as f32),
+            button,
+            pressed,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn reset_key_sets_the_bind_back() {
+        // start recording, then press Escape
+        fn record(configure: impl Fn(Keybind<'_, Shortcut>) -> Keybind<'_, Shortcut>) -> String {
+            let mut harness = keybind_harness("Ctrl+S".parse::<Shortcut>().unwrap(), configure);
+            click(&mut harness);
+            frame(
+                &mut harness,
+                vec![key(Key::Escape, Modifiers::NONE)],
+                Modifiers::NONE,
+            );
+            harness.state().to_string()
+        }
+
+        let default: Shortcut = "Ctrl+O".parse().unwrap();
+        // without a reset key, Escape is recorded
+        assert_eq!(record(|keybind| keybind), "Escape");
+        // the value the keybind was created with
+        assert_eq!(
+            record(|keybind| keybind.with_reset_key(Some(Key::Escape))),
+            "Ctrl+S"
+        );
+        // the value set with `with_reset`, in either order
+        assert_eq!(
+            record(|keybind| keybind
+                .with_reset_key(Some(Key::Escape))
+                .with_reset(default)),
+            "Ctrl+O"
+        );
+        assert_eq!(
+            record(|keybind| keybind
+                .with_reset(default)
+                .with_reset_key(Some(Key::Escape))),
+            "Ctrl+O"
+        );
+    }
+
+    /// The bind, whether clear keys are set, whether the last frame changed
+    /// the bind, and whether key presses were left for the app.
+    type ClearState = (Shortcut, bool, bool, bool);
+
+    #[test]
+    fn clear_keys_clear_the_focused_keybind() {
+        fn press(harness: &mut Harness<'_, ClearState>, pressed: Key) -> (String, bool, bool) {
+            harness.input_mut().events = vec![key(pressed, Modifiers::NONE)];
+            harness.step();
+            let (shortcut, _, changed, left) = *harness.state();
+            (shortcut.to_string(), changed, left)
+        }
+
+        let clear_keys = [Key::Delete, Key::Backspace];
+        let mut harness = Harness::new_ui_state(
+            |ui, (shortcut, clear, changed, left): &mut ClearState| {
+                let keys = if *clear { &clear_keys[..] } else { &[] };
+                let keybind = Keybind::new(shortcut, "keybind")
+                    .with_text("Keybind")
+                    .with_clear_keys(keys);
+                *changed = ui.add(keybind).changed();
+                *left = ui.input(|i| i.events.iter().any(|e| matches!(e, Event::Key { .. })));
+            },
+            ("Ctrl+S".parse().unwrap(), true, false, false),
+        );
+
+        // without focus, the keys are left alone
+        assert_eq!(
+            press(&mut harness, Key::Delete),
+            ("Ctrl+S".into(), false, true)
+        );
+        // with focus, they clear the bind and are consumed
+        harness.get_by_label_contains("Keybind").focus();
+        harness.run();
+        assert_eq!(
+            press(&mut harness, Key::Delete),
+            ("None".into(), true, false)
+        );
+
+        // while recording, they are bound like any other key
+        click(&mut harness);
+        let (bind, changed, _) = press(&mut harness, Key::Backspace);
+        assert_eq!((bind.as_str(), changed), ("Backspace", true));
+        // and with no clear keys, they do nothing
+        harness.state_mut().1 = false;
+        harness.run();
+        assert!(harness.get_by_label_contains("Keybind").is_focused());
+        assert_eq!(
+            press(&mut harness, Key::Backspace),
+            ("Backspace".into(), false, true)
+        );
+    }
+
+    #[test]
+    fn capture_strategy_picks_a_press() {
+        /// Makes the events of a frame, from where the keybind is.
+        type Events = fn(&Harness<'_, Shortcut>) -> Vec<Event>;
+
+        // all events of `events` in one frame
+        fn capture(
+            strategy: CaptureStrategy,
+            events: fn(&Harness<'_, Shortcut>) -> Vec<Event>,
+        ) -> String {
+            let mut harness = keybind_harness(Shortcut::NONE, move |keybind| {
+                keybind.with_capture_strategy(strategy)
+            });
+            click(&mut harness);
+            let events = events(&harness);
+            frame(&mut harness, events, Modifiers::NONE);
+            harness.state().to_string()
+        }
+
+        let keys: Events = |_| vec![key(Key::A, Modifiers::NONE), key(Key::B, Modifiers::CTRL)];
+        assert_eq!(capture(CaptureStrategy::First, keys), "A");
+        assert_eq!(capture(CaptureStrategy::Last, keys), "Ctrl+B");
+
+        let buttons: Events = |harness| {
+            vec![
+                button(harness, PointerButton::Extra1, true),
+                button(harness, PointerButton::Extra2, true),
+            ]
+        };
+        assert_eq!(capture(CaptureStrategy::First, buttons), "Mouse 4");
+        assert_eq!(capture(CaptureStrategy::Last, buttons), "Mouse 5");
+
+        // a key and a pointer button in the same frame make one bind
+        let both: Events = |harness| {
+            vec![
+                key(Key::S, Modifiers::CTRL),
+                button(harness, PointerButton::Middle, true),
+            ]
+        };
+        assert_eq!(capture(CaptureStrategy::Last, both), "Ctrl+S+Middle");
+    }
+
+    #[test]
+    fn clipboard_events_are_captured() {
+        let capture = |enabled| {
+            let mut harness = keybind_harness(Shortcut::NONE, move |keybind| {
+                keybind.with_clipboard_events(enabled)
+            });
+            click(&mut harness);
+            // only a copy event, like on the web
+            frame(
+                &mut harness,
+                vec![Event::Copy],
+                Modifiers::CTRL | Modifiers::COMMAND,
+            );
+            *harness.state()
+        };
+
+        let shortcut = capture(true);
+        assert_eq!(shortcut.to_string(), "CmdOrCtrl+Ctrl+C");
+        // the bound shortcut is pressed by the same event
+        let input = egui::RawInput {
+            events: vec![Event::Copy],
+            modifiers: Modifiers::CTRL | Modifiers::COMMAND,
+            ..Default::default()
+        };
+        let _ = Context::default().run(input, |ctx| {
+            assert!(ctx.input_mut(|input| shortcut.pressed(input)));
+        });
+
+        assert_eq!(capture(false), Shortcut::NONE);
+    }
+
+    #[test]
+    fn text_fallback_captures_text_keys() {
+        let mut harness =
+            keybind_harness(Shortcut::NONE, |keybind| keybind.with_text_fallback(true));
+        click(&mut harness);
+        // a key that only sends text
+        frame(&mut harness, vec![Event::Text("§".into())], Modifiers::ALT);
+        let shortcut = harness.state();
+        assert_eq!(shortcut.text(), Some(TextKey::new(Modifiers::ALT, '§')));
+        assert_eq!(shortcut.keyboard(), None);
+        assert_eq!(shortcut.to_string(), "Alt+§");
+    }
+
+    #[test]
+    fn hold_measuring_records_long_presses() {
+        fn frame_at<State>(harness: &mut Harness<'_, State>, time: f64, events: Vec<Event>) {
+            harness.input_mut().time = Some(time);
+            frame(harness, events, Modifiers::NONE);
+        }
+
+        let bind = crate::LongPressBind::new(PointerButton::Extra1, 1.0);
+        let mut harness = keybind_harness(bind, |keybind| keybind.with_hold_measuring(true));
+        click(&mut harness);
+        let press = button(&harness, PointerButton::Middle, true);
+        frame_at(&mut harness, 1.0, vec![press]);
+        frame_at(&mut harness, 1.4, vec![]);
+        let release = button(&harness, PointerButton::Middle, false);
+        frame_at(&mut harness, 1.71, vec![release]);
+        let measured = crate::LongPressBind::new(PointerButton::Middle, 0.7);
+        assert_eq!(*harness.state(), measured);
+        assert_eq!(harness.state().to_string(), "Hold Middle (0.7s)");
+    }
+
+    #[test]
+    fn text_is_only_formatted_when_it_changes() {
+        let ctx = Context::default();
+        let mut shortcut: Shortcut = "Ctrl+S".parse().unwrap();
+        let frame = |shortcut: &mut Shortcut| {
+            let _ = ctx.run(Default::default(), |ctx| {
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    ui.add(Keybind::new(shortcut, "save"));
+                });
+            });
+        };
+
+        frame(&mut shortcut);
+        let count = format_count();
+        for _ in 0..10 {
+            frame(&mut shortcut);
+        }
+        assert_eq!(format_count(), count);
+
+        // changing the bind formats it and lays it out again
+        shortcut = "Ctrl+O".parse().unwrap();
+        frame(&mut shortcut);
+        assert_eq!(format_count(), count + 2);
+
+        // and so does changing how it looks
+        ctx.set_pixels_per_point(2.0);
+        frame(&mut shortcut);
+        assert_eq!(format_count(), count + 3);
     }
 }
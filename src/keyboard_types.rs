@@ -0,0 +1,740 @@
+use crate::{Bind, Shortcut, TextKey};
+use egui::{Key, KeyboardShortcut, Modifiers};
+use keyboard_types::{Code, Key as Named, KeyState, KeyboardEvent, Modifiers as KtModifiers};
+use std::fmt;
+
+/// Why a value couldn't be converted to or from a
+/// [keyboard-types](https://docs.rs/keyboard-types) value, see
+/// [FromKeyboardTypes] and [ToKeyboardTypes].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyboardTypesError {
+    /// The logical key has no egui [Key], e.g. `CapsLock` or a character
+    /// like `"§"`.
+    UnsupportedKey(Named),
+    /// The physical key code has no egui [Key], e.g. `IntlBackslash`.
+    UnsupportedCode(Code),
+    /// The egui [Key] has no physical key code, e.g. [`Key::Plus`], which is
+    /// typed with different keys on different keyboard layouts.
+    NoCode(Key),
+    /// The modifiers contain `ALT_GRAPH`, `HYPER` or `SYMBOL`, which egui
+    /// has no modifier for.
+    UnsupportedModifiers(KtModifiers),
+    /// [`Modifiers::COMMAND`] is held without Ctrl or Cmd, so it's unknown
+    /// whether it's `CONTROL` or `META`. Replace it with [`Modifiers::CTRL`]
+    /// or [`Modifiers::MAC_CMD`] first.
+    Command,
+    /// The shortcut has a pointer button, which isn't a key.
+    PointerButton,
+    /// The shortcut has no key.
+    Unbound,
+}
+
+impl fmt::Display for KeyboardTypesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedKey(key) => write!(f, "the key \"{key}\" has no egui key"),
+            Self::UnsupportedCode(code) => write!(f, "the key code \"{code}\" has no egui key"),
+            Self::NoCode(key) => {
+                write!(f, "the key \"{}\" has no physical key code", key.name())
+            }
+            Self::UnsupportedModifiers(modifiers) => {
+                write!(f, "the modifiers {modifiers:?} have no egui equivalent")
+            }
+            Self::Command => write!(f, "the Command modifier can be either Ctrl or Meta"),
+            Self::PointerButton => write!(f, "pointer buttons aren't keys"),
+            Self::Unbound => write!(f, "the shortcut has no key"),
+        }
+    }
+}
+
+impl std::error::Error for KeyboardTypesError {}
+
+/// Conversion from a [keyboard-types](https://docs.rs/keyboard-types) value,
+/// like [TryFrom]. The conversions are a trait since neither side belongs to
+/// this crate, so they can't implement [TryFrom] for each other.
+///
+/// | keyboard-types | egui |
+/// | --- | --- |
+/// | `Key::Character`, case-insensitive | the [Key] that types the character, e.g. `"a"` and `"A"` are [`Key::A`], `" "` is [`Key::Space`] |
+/// | `Key::Help` | [`Key::Insert`], which is in its place on Mac keyboards |
+/// | other named keys, e.g. `Key::Enter` | the [Key] with the same name |
+/// | `Code::Numpad0` to `Code::Numpad9` | [`Key::Num0`] to [`Key::Num9`] |
+/// | `Code::NumpadAdd`, `NumpadSubtract`, `NumpadDecimal`, `NumpadDivide`, `NumpadEnter`, `NumpadEqual`, `NumpadComma` | [`Key::Plus`], [`Key::Minus`], [`Key::Period`], [`Key::Slash`], [`Key::Enter`], [`Key::Equals`], [`Key::Comma`] |
+/// | `CONTROL`, `ALT`, `SHIFT` | [`Modifiers::CTRL`], [`Modifiers::ALT`], [`Modifiers::SHIFT`] |
+/// | `META` or `SUPER` | [`Modifiers::MAC_CMD`] |
+/// | lock modifiers, `FN` | ignored |
+///
+/// Everything else has no egui equivalent and is an error: modifier and
+/// lock keys, media and browser keys, characters that no [Key] types (e.g.
+/// `"!"` or `"§"`), the other numpad and international key codes, and the
+/// `ALT_GRAPH`, `HYPER` and `SYMBOL` modifiers.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, Modifiers};
+/// use egui_keybind::{FromKeyboardTypes, KeyboardTypesError};
+/// use keyboard_types::{Code, Key as Named, Modifiers as KtModifiers};
+///
+/// let character = |c: &str| Named::Character(c.into());
+/// assert_eq!(Key::from_keyboard_types(&character("a")), Ok(Key::A));
+/// assert_eq!(Key::from_keyboard_types(&character("A")), Ok(Key::A));
+/// assert_eq!(Key::from_keyboard_types(&character(" ")), Ok(Key::Space));
+/// assert_eq!(Key::from_keyboard_types(&character("+")), Ok(Key::Plus));
+/// assert_eq!(Key::from_keyboard_types(&Named::Help), Ok(Key::Insert));
+/// assert_eq!(Key::from_keyboard_types(Code::Numpad7), Ok(Key::Num7));
+/// assert_eq!(Key::from_keyboard_types(Code::NumpadAdd), Ok(Key::Plus));
+/// assert_eq!(Key::from_keyboard_types(Code::BracketLeft), Ok(Key::OpenBracket));
+///
+/// // keys that exist in keyboard-types only
+/// for key in [
+///     Named::CapsLock,
+///     Named::Control,
+///     Named::PrintScreen,
+///     Named::MediaPlayPause,
+///     Named::Unidentified,
+///     character("!"),
+///     character("§"),
+///     character("ab"),
+/// ] {
+///     let error = KeyboardTypesError::UnsupportedKey(key.clone());
+///     assert_eq!(Key::from_keyboard_types(&key), Err(error));
+/// }
+/// for code in [
+///     Code::IntlBackslash,
+///     Code::NumpadMultiply,
+///     Code::ShiftLeft,
+///     Code::Unidentified,
+/// ] {
+///     let error = KeyboardTypesError::UnsupportedCode(code);
+///     assert_eq!(Key::from_keyboard_types(code), Err(error));
+/// }
+///
+/// let modifiers = KtModifiers::CONTROL | KtModifiers::SHIFT | KtModifiers::NUM_LOCK;
+/// assert_eq!(
+///     Modifiers::from_keyboard_types(modifiers),
+///     Ok(Modifiers::CTRL | Modifiers::SHIFT)
+/// );
+/// assert_eq!(
+///     Modifiers::from_keyboard_types(KtModifiers::SUPER),
+///     Ok(Modifiers::MAC_CMD)
+/// );
+/// assert_eq!(
+///     Modifiers::from_keyboard_types(KtModifiers::ALT_GRAPH),
+///     Err(KeyboardTypesError::UnsupportedModifiers(KtModifiers::ALT_GRAPH))
+/// );
+/// ```
+pub trait FromKeyboardTypes<T>: Sized {
+    /// Convert a keyboard-types value.
+    ///
+    /// # Errors
+    ///
+    /// A [KeyboardTypesError] if the value has no equivalent in egui.
+    fn from_keyboard_types(value: T) -> Result<Self, KeyboardTypesError>;
+}
+
+/// Conversion to a [keyboard-types](https://docs.rs/keyboard-types) value,
+/// like [TryInto]. See [FromKeyboardTypes] for the conversions back.
+///
+/// Every [Key] has a logical keyboard-types key: letters are their lowercase
+/// character, e.g. `"a"`, [`Key::Space`] is `" "`, and punctuation is the
+/// character it types. [`Key::Colon`], [`Key::Pipe`], [`Key::Questionmark`]
+/// and [`Key::Plus`] have no physical key code, since they are typed with
+/// Shift on a US keyboard and with different keys elsewhere.
+/// [`Modifiers::MAC_CMD`] is `META`.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// use egui_keybind::{FromKeyboardTypes, KeyboardTypesError, ToKeyboardTypes};
+/// use keyboard_types::{Code, Key as Named, Modifiers as KtModifiers};
+///
+/// // every key survives a round trip, and only these have no key code
+/// let mut without_code = Vec::new();
+/// for &key in Key::ALL {
+///     let named: Named = key.to_keyboard_types().unwrap();
+///     assert_eq!(Key::from_keyboard_types(&named), Ok(key));
+///     match key.to_keyboard_types() {
+///         Ok(code) => assert_eq!(<Key as FromKeyboardTypes<Code>>::from_keyboard_types(code), Ok(key)),
+///         Err(error) => {
+///             assert_eq!(error, KeyboardTypesError::NoCode(key));
+///             without_code.push(key);
+///         }
+///     }
+/// }
+/// assert_eq!(
+///     without_code,
+///     [Key::Colon, Key::Plus, Key::Pipe, Key::Questionmark]
+/// );
+///
+/// let named: Named = Key::Space.to_keyboard_types().unwrap();
+/// assert_eq!(named, Named::Character(" ".into()));
+/// let code: Code = Key::Num1.to_keyboard_types().unwrap();
+/// assert_eq!(code, Code::Digit1);
+///
+/// let save = KeyboardShortcut::new(Modifiers::CTRL | Modifiers::SHIFT, Key::S);
+/// let (modifiers, key): (KtModifiers, Named) = save.to_keyboard_types().unwrap();
+/// assert_eq!(modifiers, KtModifiers::CONTROL | KtModifiers::SHIFT);
+/// assert_eq!(key, Named::Character("s".into()));
+/// assert_eq!(
+///     KeyboardShortcut::from_keyboard_types((modifiers, &key)),
+///     Ok(save)
+/// );
+///
+/// let mac: KtModifiers = Modifiers::MAC_CMD.to_keyboard_types().unwrap();
+/// assert_eq!(mac, KtModifiers::META);
+/// let error = ToKeyboardTypes::<KtModifiers>::to_keyboard_types(&Modifiers::COMMAND);
+/// assert_eq!(error, Err(KeyboardTypesError::Command));
+/// ```
+pub trait ToKeyboardTypes<T> {
+    /// Convert to a keyboard-types value.
+    ///
+    /// # Errors
+    ///
+    /// A [KeyboardTypesError] if the value has no equivalent in
+    /// keyboard-types.
+    fn to_keyboard_types(&self) -> Result<T, KeyboardTypesError>;
+}
+
+/// Get the logical keyboard-types key of a key.
+fn named_key(key: Key) -> Named {
+    let character = match key {
+        Key::ArrowDown => return Named::ArrowDown,
+        Key::ArrowLeft => return Named::ArrowLeft,
+        Key::ArrowRight => return Named::ArrowRight,
+        Key::ArrowUp => return Named::ArrowUp,
+        Key::Escape => return Named::Escape,
+        Key::Tab => return Named::Tab,
+        Key::Backspace => return Named::Backspace,
+        Key::Enter => return Named::Enter,
+        Key::Insert => return Named::Insert,
+        Key::Delete => return Named::Delete,
+        Key::Home => return Named::Home,
+        Key::End => return Named::End,
+        Key::PageUp => return Named::PageUp,
+        Key::PageDown => return Named::PageDown,
+        Key::Copy => return Named::Copy,
+        Key::Cut => return Named::Cut,
+        Key::Paste => return Named::Paste,
+        Key::F1 => return Named::F1,
+        Key::F2 => return Named::F2,
+        Key::F3 => return Named::F3,
+        Key::F4 => return Named::F4,
+        Key::F5 => return Named::F5,
+        Key::F6 => return Named::F6,
+        Key::F7 => return Named::F7,
+        Key::F8 => return Named::F8,
+        Key::F9 => return Named::F9,
+        Key::F10 => return Named::F10,
+        Key::F11 => return Named::F11,
+        Key::F12 => return Named::F12,
+        Key::F13 => return Named::F13,
+        Key::F14 => return Named::F14,
+        Key::F15 => return Named::F15,
+        Key::F16 => return Named::F16,
+        Key::F17 => return Named::F17,
+        Key::F18 => return Named::F18,
+        Key::F19 => return Named::F19,
+        Key::F20 => return Named::F20,
+        Key::F21 => return Named::F21,
+        Key::F22 => return Named::F22,
+        Key::F23 => return Named::F23,
+        Key::F24 => return Named::F24,
+        Key::F25 => return Named::F25,
+        Key::F26 => return Named::F26,
+        Key::F27 => return Named::F27,
+        Key::F28 => return Named::F28,
+        Key::F29 => return Named::F29,
+        Key::F30 => return Named::F30,
+        Key::F31 => return Named::F31,
+        Key::F32 => return Named::F32,
+        Key::F33 => return Named::F33,
+        Key::F34 => return Named::F34,
+        Key::F35 => return Named::F35,
+        Key::Space => ' ',
+        Key::Colon => ':',
+        Key::Comma => ',',
+        Key::Minus => '-',
+        Key::Period => '.',
+        Key::Plus => '+',
+        Key::Equals => '=',
+        Key::Semicolon => ';',
+        Key::OpenBracket => '[',
+        Key::CloseBracket => ']',
+        Key::Backtick => '`',
+        Key::Backslash => '\\',
+        Key::Slash => '/',
+        Key::Pipe => '|',
+        Key::Questionmark => '?',
+        Key::Quote => '\'',
+        Key::A => 'a',
+        Key::B => 'b',
+        Key::C => 'c',
+        Key::D => 'd',
+        Key::E => 'e',
+        Key::F => 'f',
+        Key::G => 'g',
+        Key::H => 'h',
+        Key::I => 'i',
+        Key::J => 'j',
+        Key::K => 'k',
+        Key::L => 'l',
+        Key::M => 'm',
+        Key::N => 'n',
+        Key::O => 'o',
+        Key::P => 'p',
+        Key::Q => 'q',
+        Key::R => 'r',
+        Key::S => 's',
+        Key::T => 't',
+        Key::U => 'u',
+        Key::V => 'v',
+        Key::W => 'w',
+        Key::X => 'x',
+        Key::Y => 'y',
+        Key::Z => 'z',
+        Key::Num0 => '0',
+        Key::Num1 => '1',
+        Key::Num2 => '2',
+        Key::Num3 => '3',
+        Key::Num4 => '4',
+        Key::Num5 => '5',
+        Key::Num6 => '6',
+        Key::Num7 => '7',
+        Key::Num8 => '8',
+        Key::Num9 => '9',
+    };
+    Named::Character(character.to_string())
+}
+
+/// Get the physical key code of a key on a US keyboard, if it has one.
+fn key_code(key: Key) -> Option<Code> {
+    Some(match key {
+        Key::ArrowDown => Code::ArrowDown,
+        Key::ArrowLeft => Code::ArrowLeft,
+        Key::ArrowRight => Code::ArrowRight,
+        Key::ArrowUp => Code::ArrowUp,
+        Key::Escape => Code::Escape,
+        Key::Tab => Code::Tab,
+        Key::Backspace => Code::Backspace,
+        Key::Enter => Code::Enter,
+        Key::Insert => Code::Insert,
+        Key::Delete => Code::Delete,
+        Key::Home => Code::Home,
+        Key::End => Code::End,
+        Key::PageUp => Code::PageUp,
+        Key::PageDown => Code::PageDown,
+        Key::Copy => Code::Copy,
+        Key::Cut => Code::Cut,
+        Key::Paste => Code::Paste,
+        Key::Space => Code::Space,
+        Key::Comma => Code::Comma,
+        Key::Minus => Code::Minus,
+        Key::Period => Code::Period,
+        Key::Equals => Code::Equal,
+        Key::Semicolon => Code::Semicolon,
+        Key::OpenBracket => Code::BracketLeft,
+        Key::CloseBracket => Code::BracketRight,
+        Key::Backtick => Code::Backquote,
+        Key::Backslash => Code::Backslash,
+        Key::Slash => Code::Slash,
+        Key::Quote => Code::Quote,
+        // typed with Shift on a US keyboard, and different keys elsewhere
+        Key::Colon | Key::Pipe | Key::Questionmark | Key::Plus => return None,
+        Key::A => Code::KeyA,
+        Key::B => Code::KeyB,
+        Key::C => Code::KeyC,
+        Key::D => Code::KeyD,
+        Key::E => Code::KeyE,
+        Key::F => Code::KeyF,
+        Key::G => Code::KeyG,
+        Key::H => Code::KeyH,
+        Key::I => Code::KeyI,
+        Key::J => Code::KeyJ,
+        Key::K => Code::KeyK,
+        Key::L => Code::KeyL,
+        Key::M => Code::KeyM,
+        Key::N => Code::KeyN,
+        Key::O => Code::KeyO,
+        Key::P => Code::KeyP,
+        Key::Q => Code::KeyQ,
+        Key::R => Code::KeyR,
+        Key::S => Code::KeyS,
+        Key::T => Code::KeyT,
+        Key::U => Code::KeyU,
+        Key::V => Code::KeyV,
+        Key::W => Code::KeyW,
+        Key::X => Code::KeyX,
+        Key::Y => Code::KeyY,
+        Key::Z => Code::KeyZ,
+        Key::Num0 => Code::Digit0,
+        Key::Num1 => Code::Digit1,
+        Key::Num2 => Code::Digit2,
+        Key::Num3 => Code::Digit3,
+        Key::Num4 => Code::Digit4,
+        Key::Num5 => Code::Digit5,
+        Key::Num6 => Code::Digit6,
+        Key::Num7 => Code::Digit7,
+        Key::Num8 => Code::Digit8,
+        Key::Num9 => Code::Digit9,
+        Key::F1 => Code::F1,
+        Key::F2 => Code::F2,
+        Key::F3 => Code::F3,
+        Key::F4 => Code::F4,
+        Key::F5 => Code::F5,
+        Key::F6 => Code::F6,
+        Key::F7 => Code::F7,
+        Key::F8 => Code::F8,
+        Key::F9 => Code::F9,
+        Key::F10 => Code::F10,
+        Key::F11 => Code::F11,
+        Key::F12 => Code::F12,
+        Key::F13 => Code::F13,
+        Key::F14 => Code::F14,
+        Key::F15 => Code::F15,
+        Key::F16 => Code::F16,
+        Key::F17 => Code::F17,
+        Key::F18 => Code::F18,
+        Key::F19 => Code::F19,
+        Key::F20 => Code::F20,
+        Key::F21 => Code::F21,
+        Key::F22 => Code::F22,
+        Key::F23 => Code::F23,
+        Key::F24 => Code::F24,
+        Key::F25 => Code::F25,
+        Key::F26 => Code::F26,
+        Key::F27 => Code::F27,
+        Key::F28 => Code::F28,
+        Key::F29 => Code::F29,
+        Key::F30 => Code::F30,
+        Key::F31 => Code::F31,
+        Key::F32 => Code::F32,
+        Key::F33 => Code::F33,
+        Key::F34 => Code::F34,
+        Key::F35 => Code::F35,
+    })
+}
+
+impl FromKeyboardTypes<&Named> for Key {
+    fn from_keyboard_types(key: &Named) -> Result<Self, KeyboardTypesError> {
+        let wanted = match key {
+            Named::Help => return Ok(Key::Insert),
+            Named::Character(text) => Named::Character(text.to_lowercase()),
+            key => key.clone(),
+        };
+        Key::ALL
+            .iter()
+            .copied()
+            .find(|&key| named_key(key) == wanted)
+            .ok_or_else(|| KeyboardTypesError::UnsupportedKey(key.clone()))
+    }
+}
+
+impl FromKeyboardTypes<Code> for Key {
+    fn from_keyboard_types(code: Code) -> Result<Self, KeyboardTypesError> {
+        let wanted = match code {
+            Code::Numpad0 => Code::Digit0,
+            Code::Numpad1 => Code::Digit1,
+            Code::Numpad2 => Code::Digit2,
+            Code::Numpad3 => Code::Digit3,
+            Code::Numpad4 => Code::Digit4,
+            Code::Numpad5 => Code::Digit5,
+            Code::Numpad6 => Code::Digit6,
+            Code::Numpad7 => Code::Digit7,
+            Code::Numpad8 => Code::Digit8,
+            Code::Numpad9 => Code::Digit9,
+            Code::NumpadAdd => return Ok(Key::Plus),
+            Code::NumpadSubtract => Code::Minus,
+            Code::NumpadDecimal => Code::Period,
+            Code::NumpadDivide => Code::Slash,
+            Code::NumpadEnter => Code::Enter,
+            Code::NumpadEqual => Code::Equal,
+            Code::NumpadComma => Code::Comma,
+            code => code,
+        };
+        Key::ALL
+            .iter()
+            .copied()
+            .find(|&key| key_code(key) == Some(wanted))
+            .ok_or(KeyboardTypesError::UnsupportedCode(code))
+    }
+}
+
+impl FromKeyboardTypes<KtModifiers> for Modifiers {
+    fn from_keyboard_types(modifiers: KtModifiers) -> Result<Self, KeyboardTypesError> {
+        let unsupported =
+            modifiers & (KtModifiers::ALT_GRAPH | KtModifiers::HYPER | KtModifiers::SYMBOL);
+        if !unsupported.is_empty() {
+            return Err(KeyboardTypesError::UnsupportedModifiers(unsupported));
+        }
+        Ok(Modifiers {
+            alt: modifiers.alt(),
+            ctrl: modifiers.ctrl(),
+            shift: modifiers.shift(),
+            mac_cmd: modifiers.intersects(KtModifiers::META | KtModifiers::SUPER),
+            command: false,
+        })
+    }
+}
+
+impl FromKeyboardTypes<(KtModifiers, &Named)> for KeyboardShortcut {
+    fn from_keyboard_types(
+        (modifiers, key): (KtModifiers, &Named),
+    ) -> Result<Self, KeyboardTypesError> {
+        Ok(KeyboardShortcut::new(
+            Modifiers::from_keyboard_types(modifiers)?,
+            Key::from_keyboard_types(key)?,
+        ))
+    }
+}
+
+impl ToKeyboardTypes<Named> for Key {
+    fn to_keyboard_types(&self) -> Result<Named, KeyboardTypesError> {
+        Ok(named_key(*self))
+    }
+}
+
+impl ToKeyboardTypes<Code> for Key {
+    fn to_keyboard_types(&self) -> Result<Code, KeyboardTypesError> {
+        key_code(*self).ok_or(KeyboardTypesError::NoCode(*self))
+    }
+}
+
+impl ToKeyboardTypes<KtModifiers> for Modifiers {
+    fn to_keyboard_types(&self) -> Result<KtModifiers, KeyboardTypesError> {
+        if self.command && !self.ctrl && !self.mac_cmd {
+            return Err(KeyboardTypesError::Command);
+        }
+        let mut modifiers = KtModifiers::empty();
+        modifiers.set(KtModifiers::ALT, self.alt);
+        modifiers.set(KtModifiers::CONTROL, self.ctrl);
+        modifiers.set(KtModifiers::SHIFT, self.shift);
+        modifiers.set(KtModifiers::META, self.mac_cmd);
+        Ok(modifiers)
+    }
+}
+
+impl ToKeyboardTypes<(KtModifiers, Named)> for KeyboardShortcut {
+    fn to_keyboard_types(&self) -> Result<(KtModifiers, Named), KeyboardTypesError> {
+        Ok((
+            self.modifiers.to_keyboard_types()?,
+            named_key(self.logical_key),
+        ))
+    }
+}
+
+/// Get the character of a logical key that types exactly one character.
+fn single_character(key: &Named) -> Option<char> {
+    let Named::Character(text) = key else {
+        return None;
+    };
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(character), None) => Some(character),
+        _ => None,
+    }
+}
+
+/// Convert a logical key with modifiers to a shortcut. Characters that no
+/// [Key] types become a [TextKey].
+impl TryFrom<(KtModifiers, Named)> for Shortcut {
+    type Error = KeyboardTypesError;
+
+    fn try_from((modifiers, key): (KtModifiers, Named)) -> Result<Self, Self::Error> {
+        let modifiers = Modifiers::from_keyboard_types(modifiers)?;
+        if let Ok(key) = Key::from_keyboard_types(&key) {
+            return Ok(Shortcut::new(
+                Some(KeyboardShortcut::new(modifiers, key)),
+                None,
+            ));
+        }
+        let character =
+            single_character(&key).ok_or(KeyboardTypesError::UnsupportedKey(key.clone()))?;
+        let mut shortcut = Shortcut::NONE;
+        shortcut.set_text(TextKey::new(modifiers, character), None);
+        Ok(shortcut)
+    }
+}
+
+/// Convert the key of a keyboard event to a shortcut, like egui does: the
+/// logical key if egui has it, otherwise the physical key code, otherwise a
+/// [TextKey] for the character it types.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// use egui_keybind::{Bind, KeyboardTypesError, Shortcut, TextKey};
+/// use keyboard_types::{Code, Key as Named, KeyboardEvent, Modifiers as KtModifiers};
+///
+/// let event = |modifiers, key, code| KeyboardEvent {
+///     modifiers,
+///     key,
+///     code,
+///     ..Default::default()
+/// };
+/// let character = |c: &str| Named::Character(c.into());
+///
+/// let ctrl_s = event(KtModifiers::CONTROL, character("s"), Code::KeyS);
+/// assert_eq!(Shortcut::try_from(&ctrl_s).unwrap().to_string(), "Ctrl+S");
+///
+/// // "!" has no `Key`, so the key code is used
+/// let bang = event(KtModifiers::SHIFT, character("!"), Code::Digit1);
+/// assert_eq!(Shortcut::try_from(&bang).unwrap().to_string(), "Shift+1");
+///
+/// let section = event(KtModifiers::ALT, character("§"), Code::IntlBackslash);
+/// let shortcut = Shortcut::try_from(&section).unwrap();
+/// assert_eq!(shortcut.text(), Some(TextKey::new(Modifiers::ALT, '§')));
+///
+/// let caps = event(KtModifiers::CAPS_LOCK, Named::CapsLock, Code::CapsLock);
+/// assert_eq!(
+///     Shortcut::try_from(&caps),
+///     Err(KeyboardTypesError::UnsupportedKey(Named::CapsLock))
+/// );
+/// ```
+impl TryFrom<&KeyboardEvent> for Shortcut {
+    type Error = KeyboardTypesError;
+
+    fn try_from(event: &KeyboardEvent) -> Result<Self, Self::Error> {
+        let key =
+            Key::from_keyboard_types(&event.key).or_else(|_| Key::from_keyboard_types(event.code));
+        let Ok(key) = key else {
+            // a text key, if the logical key types a character
+            return Shortcut::try_from((event.modifiers, event.key.clone()));
+        };
+        let modifiers = Modifiers::from_keyboard_types(event.modifiers)?;
+        Ok(Shortcut::new(
+            Some(KeyboardShortcut::new(modifiers, key)),
+            None,
+        ))
+    }
+}
+
+/// Convert a shortcut to a logical key with modifiers. A [TextKey] is its
+/// character.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+/// use egui_keybind::{KeyboardTypesError, Shortcut};
+/// use keyboard_types::{Key as Named, Modifiers as KtModifiers};
+///
+/// let shortcut: Shortcut = "Cmd+Alt+F5".parse().unwrap();
+/// let (modifiers, key) = <(KtModifiers, Named)>::try_from(shortcut).unwrap();
+/// assert_eq!(modifiers, KtModifiers::META | KtModifiers::ALT);
+/// assert_eq!(key, Named::F5);
+/// assert_eq!(Shortcut::try_from((modifiers, key)), Ok(shortcut));
+///
+/// let text: Shortcut = "Alt+§".parse().unwrap();
+/// let converted = <(KtModifiers, Named)>::try_from(text).unwrap();
+/// assert_eq!(converted, (KtModifiers::ALT, Named::Character("§".into())));
+/// assert_eq!(Shortcut::try_from(converted), Ok(text));
+///
+/// let click = Shortcut::new(None, Some(PointerButton::Primary));
+/// let error = <(KtModifiers, Named)>::try_from(click).unwrap_err();
+/// assert_eq!(error, KeyboardTypesError::PointerButton);
+/// let error = <(KtModifiers, Named)>::try_from(Shortcut::NONE).unwrap_err();
+/// assert_eq!(error, KeyboardTypesError::Unbound);
+///
+/// let command = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+/// let error = <(KtModifiers, Named)>::try_from(Shortcut::new(Some(command), None));
+/// assert_eq!(error, Err(KeyboardTypesError::Command));
+/// ```
+impl TryFrom<Shortcut> for (KtModifiers, Named) {
+    type Error = KeyboardTypesError;
+
+    fn try_from(shortcut: Shortcut) -> Result<Self, Self::Error> {
+        if shortcut.pointer().is_some() {
+            return Err(KeyboardTypesError::PointerButton);
+        }
+        if let Some(text) = shortcut.text() {
+            let modifiers = text.modifiers.to_keyboard_types()?;
+            return Ok((modifiers, Named::Character(text.character.to_string())));
+        }
+        shortcut
+            .keyboard()
+            .ok_or(KeyboardTypesError::Unbound)?
+            .to_keyboard_types()
+    }
+}
+
+impl Shortcut {
+    /// Check if a keyboard-types key press presses the shortcut, with its
+    /// [MatchPolicy](crate::MatchPolicy) and
+    /// [CommandPolicy](crate::CommandPolicy). The key is looked up like in
+    /// [`Shortcut::try_from`], and [`Modifiers::COMMAND`] matches both
+    /// Ctrl and Meta, since the platform is unknown.
+    ///
+    /// Key releases, events during text composition and shortcuts with a
+    /// pointer button never match.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The keyboard event.
+    ///
+    /// # Returns
+    ///
+    /// Whether the event presses the shortcut.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::Shortcut;
+    /// use keyboard_types::{Code, Key as Named, KeyState, KeyboardEvent, Modifiers as KtModifiers};
+    ///
+    /// let press = |modifiers, c: &str, code| KeyboardEvent {
+    ///     modifiers,
+    ///     key: Named::Character(c.into()),
+    ///     code,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let save: Shortcut = "Ctrl+S".parse().unwrap();
+    /// assert!(save.matches_keyboard_types(&press(KtModifiers::CONTROL, "s", Code::KeyS)));
+    /// // extra Shift is allowed by the default match policy
+    /// let shifted = press(KtModifiers::CONTROL | KtModifiers::SHIFT, "S", Code::KeyS);
+    /// assert!(save.matches_keyboard_types(&shifted));
+    /// assert!(!save.matches_keyboard_types(&press(KtModifiers::META, "s", Code::KeyS)));
+    /// assert!(!save.matches_keyboard_types(&press(KtModifiers::CONTROL, "d", Code::KeyD)));
+    ///
+    /// // the logical key counts, so this is "Ctrl+A" on an AZERTY keyboard
+    /// assert!(!save.matches_keyboard_types(&press(KtModifiers::CONTROL, "a", Code::KeyS)));
+    ///
+    /// let mut release = press(KtModifiers::CONTROL, "s", Code::KeyS);
+    /// release.state = KeyState::Up;
+    /// assert!(!save.matches_keyboard_types(&release));
+    ///
+    /// // Command matches Ctrl and Meta
+    /// let command = Shortcut::new(Some(KeyboardShortcut::new(Modifiers::COMMAND, Key::S)), None);
+    /// assert!(command.matches_keyboard_types(&press(KtModifiers::CONTROL, "s", Code::KeyS)));
+    /// assert!(command.matches_keyboard_types(&press(KtModifiers::META, "s", Code::KeyS)));
+    ///
+    /// let text: Shortcut = "Alt+§".parse().unwrap();
+    /// assert!(text.matches_keyboard_types(&press(KtModifiers::ALT, "§", Code::IntlBackslash)));
+    /// assert!(!text.matches_keyboard_types(&press(KtModifiers::empty(), "§", Code::IntlBackslash)));
+    /// ```
+    pub fn matches_keyboard_types(&self, event: &KeyboardEvent) -> bool {
+        if event.state != KeyState::Down || event.is_composing || self.pointer().is_some() {
+            return false;
+        }
+        let Ok(mut pressed) = Modifiers::from_keyboard_types(event.modifiers) else {
+            return false;
+        };
+        pressed.command = pressed.ctrl || pressed.mac_cmd;
+        let policy = self.match_policy();
+
+        if let Some(text) = self.text() {
+            return single_character(&event.key) == Some(text.character)
+                && policy.matches(pressed, text.modifiers);
+        }
+        let Some(keyboard) = self.matched_keyboard() else {
+            return false;
+        };
+        let key =
+            Key::from_keyboard_types(&event.key).or_else(|_| Key::from_keyboard_types(event.code));
+        key == Ok(keyboard.logical_key) && policy.matches(pressed, keyboard.modifiers)
+    }
+}
@@ -0,0 +1,893 @@
+use crate::{EmacsMeta, Hotkeys, ParseShortcutError, Shortcut};
+use egui::{Button, Context, Id, TextEdit, Ui, Window};
+use std::fmt::{self, Write};
+
+/// An error that stops [`Hotkeys::import_string`], because a line is not in
+/// the `action_id = "shortcut"` format or the active profile is a preset.
+/// Line numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportError {
+    /// The line has no `=` after the action ID.
+    MissingEquals {
+        /// The line number.
+        line: usize,
+    },
+    /// The action ID is empty, or is a quoted string that isn't closed.
+    InvalidId {
+        /// The line number.
+        line: usize,
+    },
+    /// The shortcut is not a quoted string, or is followed by something
+    /// other than a comment.
+    InvalidValue {
+        /// The line number.
+        line: usize,
+    },
+    /// The active profile is a preset, which can't be changed (see
+    /// [`Hotkeys::is_profile_read_only`]).
+    ReadOnlyProfile,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEquals { line } => write!(f, "line {line}: expected `=` after the action"),
+            Self::InvalidId { line } => write!(f, "line {line}: invalid action ID"),
+            Self::InvalidValue { line } => {
+                write!(f, "line {line}: expected a quoted shortcut after `=`")
+            }
+            Self::ReadOnlyProfile => write!(f, "the active profile is a read-only preset"),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+/// What [`Hotkeys::import_string`], [`Hotkeys::import_emacs_string`] or
+/// [`Hotkeys::apply_from_text`] did with each line. Line numbers start at 1.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    /// The IDs of the actions whose shortcut was set.
+    pub applied: Vec<String>,
+    /// The line numbers and IDs of actions that are not registered. Their
+    /// shortcuts are kept like with [`Hotkeys::load_saved`].
+    pub unknown: Vec<(usize, String)>,
+    /// The line numbers and IDs of shortcuts that couldn't be parsed, and why.
+    pub invalid: Vec<(usize, String, ParseShortcutError)>,
+    /// The line numbers and IDs of actions that were already set by an
+    /// earlier line. Only the first line of each action is used.
+    pub duplicates: Vec<(usize, String)>,
+    /// The line numbers, saved IDs and new IDs of the actions that were
+    /// applied after migrating their ID or shortcut (see
+    /// [`Hotkeys::migrate_id`]). The IDs are the same if only the shortcut
+    /// was migrated.
+    pub migrated: Vec<(usize, String, String)>,
+    /// The lines that are not in the `action_id = "shortcut"` format, or
+    /// [`ImportError::ReadOnlyProfile`]. Only [`Hotkeys::apply_from_text`]
+    /// reports them, the other imports stop at the first one.
+    pub malformed: Vec<ImportError>,
+    /// Whether nothing was applied because a line was malformed or had an
+    /// invalid shortcut, see [`ImportMode::AllOrNothing`], or because the
+    /// active profile is a preset. The other lists still say what was wrong
+    /// with each line, and `applied` and `migrated` are empty.
+    pub rejected: bool,
+}
+
+impl ImportReport {
+    /// Whether every line was applied, without any unknown actions, invalid
+    /// shortcuts, duplicates or malformed lines. Migrated lines were applied.
+    pub fn is_clean(&self) -> bool {
+        self.unknown.is_empty()
+            && self.invalid.is_empty()
+            && self.duplicates.is_empty()
+            && self.malformed.is_empty()
+            && !self.rejected
+    }
+
+    /// Describe the problem with each line that wasn't applied, sorted by
+    /// line number, e.g. to show them below the text the user pasted.
+    ///
+    /// # Returns
+    ///
+    /// The line numbers and descriptions, e.g.
+    /// `(4, "find: unknown key or button \"Nope\"")`. The line number is 0
+    /// for [`ImportError::ReadOnlyProfile`].
+    pub fn errors(&self) -> Vec<(usize, String)> {
+        let mut errors: Vec<(usize, String)> = self
+            .malformed
+            .iter()
+            .map(|err| {
+                let line = err.line();
+                let message = err.to_string();
+                let message = message
+                    .strip_prefix(&format!("line {line}: "))
+                    .unwrap_or(&message);
+                (line, message.to_string())
+            })
+            .collect();
+        errors.extend(
+            self.invalid
+                .iter()
+                .map(|(line, id, err)| (*line, format!("{id}: {err}"))),
+        );
+        errors.extend(
+            self.unknown
+                .iter()
+                .map(|(line, id)| (*line, format!("{id}: unknown action"))),
+        );
+        errors.extend(
+            self.duplicates
+                .iter()
+                .map(|(line, id)| (*line, format!("{id}: already set by an earlier line"))),
+        );
+        errors.sort_by_key(|(line, _)| *line);
+        errors
+    }
+}
+
+impl ImportError {
+    /// The line number of the line, starting at 1, or 0 for
+    /// [`ImportError::ReadOnlyProfile`].
+    pub fn line(&self) -> usize {
+        match self {
+            Self::MissingEquals { line }
+            | Self::InvalidId { line }
+            | Self::InvalidValue { line } => *line,
+            Self::ReadOnlyProfile => 0,
+        }
+    }
+}
+
+/// Whether [`Hotkeys::apply_from_text_with_mode`] applies the lines that are
+/// fine when others aren't.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ImportMode {
+    /// Apply nothing if any line is malformed or has an invalid shortcut,
+    /// so a keymap is never half applied. Unknown actions and duplicates
+    /// don't stop it. This is the default.
+    #[default]
+    AllOrNothing,
+    /// Apply every line that is fine, and skip the others.
+    Partial,
+}
+
+impl Hotkeys {
+    /// Export the current shortcuts as a hand-editable document, with one
+    /// `action_id = "Ctrl+Shift+S"` line per action. Alternate shortcuts
+    /// follow the current one, separated by commas (see [`Hotkeys::to_saved`]).
+    /// Actions are grouped by their category (see [`Hotkeys::grouped`]), with
+    /// the category as a comment above them. Saved shortcuts of actions that
+    /// aren't registered come last. Load it back with [`Hotkeys::import_string`]. Only the
+    /// active profile is exported (see [`Hotkeys::switch_profile`]), and
+    /// importing loads into the active profile.
+    ///
+    /// Shortcuts are written in their text form, which keeps every modifier,
+    /// so shortcuts recorded on macOS come back with Cmd.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{Hotkeys, Shortcut};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap());
+    /// // Cmd+S, recorded on macOS
+    /// let cmd_s = KeyboardShortcut::new(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::S);
+    /// hotkeys.set("save", Shortcut::new(Some(cmd_s), None));
+    /// hotkeys.add_binding("quit", "Cmd+Q".parse().unwrap());
+    ///
+    /// let exported = hotkeys.export_string();
+    /// assert_eq!(
+    ///     exported,
+    ///     "# file\nsave = \"CmdOrCtrl+Cmd+S\"\n\nquit = \"Ctrl+Q, Cmd+Q\"\n"
+    /// );
+    ///
+    /// // round trip
+    /// let mut imported = hotkeys.clone();
+    /// imported.reset_all();
+    /// assert!(imported.import_string(&exported).unwrap().is_clean());
+    /// assert_eq!(imported, hotkeys);
+    /// assert_eq!(imported.current("save").unwrap().keyboard(), Some(cmd_s));
+    /// ```
+    pub fn export_string(&self) -> String {
+        self.export(None, false)
+    }
+
+    /// Export only the actions whose shortcuts differ from their defaults
+    /// (see [`Hotkeys::modified`]), like [`Hotkeys::export_string`], e.g. as
+    /// a minimal config with the user's overrides. Saved shortcuts of actions
+    /// that aren't registered are kept, since their defaults are unknown.
+    /// Importing it with [`Hotkeys::import_string`] leaves the other actions
+    /// as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register_in("file", "open", "Ctrl+O".parse().unwrap())
+    ///     .register_in("edit", "undo", "Ctrl+Z".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap());
+    /// hotkeys.set("open", "F3".parse().unwrap());
+    /// hotkeys.add_binding("quit", "Alt+F4".parse().unwrap());
+    ///
+    /// let diff = hotkeys.export_diff_string();
+    /// assert_eq!(diff, "# file\nopen = \"F3\"\n\nquit = \"Ctrl+Q, Alt+F4\"\n");
+    ///
+    /// // round trip
+    /// let mut imported = hotkeys.clone();
+    /// imported.reset_all();
+    /// assert!(imported.import_string(&diff).unwrap().is_clean());
+    /// assert_eq!(imported, hotkeys);
+    ///
+    /// // reset actions are left out
+    /// hotkeys.reset("open");
+    /// assert_eq!(hotkeys.export_diff_string(), "quit = \"Ctrl+Q, Alt+F4\"\n");
+    /// hotkeys.reset("quit");
+    /// assert!(hotkeys.export_diff_string().is_empty());
+    /// ```
+    pub fn export_diff_string(&self) -> String {
+        self.export(None, true)
+    }
+
+    /// Export the current shortcuts like [`Hotkeys::export_string`], with the
+    /// name of each action from `names` as a comment after it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register_in("file", "save as", "Ctrl+Shift+S".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap());
+    /// hotkeys.set("save as", "Alt+S".parse().unwrap());
+    ///
+    /// let exported = hotkeys.export_string_with_names(|id| id.to_uppercase());
+    /// assert_eq!(
+    ///     exported,
+    ///     r#"# file
+    /// save = "Ctrl+S" # SAVE
+    /// "save as" = "Alt+S" # SAVE AS
+    ///
+    /// quit = "Ctrl+Q" # QUIT
+    /// "#
+    /// );
+    ///
+    /// // round trip
+    /// let mut imported = hotkeys.clone();
+    /// imported.reset_all();
+    /// assert!(imported.import_string(&exported).unwrap().is_clean());
+    /// assert_eq!(imported, hotkeys);
+    /// ```
+    pub fn export_string_with_names(&self, names: impl Fn(&str) -> String) -> String {
+        self.export(Some(&names), false)
+    }
+
+    fn export(&self, names: Option<&dyn Fn(&str) -> String>, only_modified: bool) -> String {
+        let mut out = String::new();
+        let line = |out: &mut String, id: &str, shortcut: &str, name: Option<String>| {
+            let _ = write!(out, "{} = {}", quote_id(id), quote(shortcut));
+            if let Some(name) = name {
+                let _ = write!(out, " # {}", name.replace('\n', " "));
+            }
+            out.push('\n');
+        };
+
+        let groups = self
+            .grouped()
+            .into_iter()
+            .filter_map(|(category, hotkeys)| {
+                let hotkeys: Vec<_> = hotkeys
+                    .into_iter()
+                    .filter(|hotkey| !only_modified || hotkey.is_modified())
+                    .collect();
+                (!hotkeys.is_empty()).then_some((category, hotkeys))
+            });
+        let mut exported = 0;
+        for (index, (category, hotkeys)) in groups.enumerate() {
+            exported += hotkeys.len();
+            if index > 0 {
+                out.push('\n');
+            }
+            if let Some(category) = category {
+                let _ = writeln!(out, "# {}", category.replace('\n', " "));
+            }
+            for hotkey in hotkeys {
+                let name = names.map(|names| names(hotkey.id()));
+                line(&mut out, hotkey.id(), &hotkey.format_bindings(), name);
+            }
+        }
+
+        let unknown = &self.unknown;
+        if !unknown.is_empty() {
+            if exported > 0 {
+                out.push('\n');
+            }
+            out.push_str("# not registered\n");
+            for (id, shortcut) in unknown {
+                line(&mut out, id, shortcut, None);
+            }
+        }
+        out
+    }
+
+    /// Import shortcuts from a document written by [`Hotkeys::export_string`]
+    /// or by hand. Each line is either empty, a `#` comment, or an
+    /// `action_id = "shortcut"` pair, where the ID may be quoted and the
+    /// shortcut may be followed by a comment.
+    ///
+    /// Shortcuts of unknown actions, shortcuts that can't be parsed and
+    /// duplicate actions don't stop the import, they are listed in the
+    /// returned [ImportReport] instead. Actions that are missing from the
+    /// document keep their current shortcut. Renamed actions and changed
+    /// shortcut formats are migrated, see [`Hotkeys::migrate_id`]. The
+    /// history is cleared, see [`Hotkeys::undo`].
+    ///
+    /// # Errors
+    ///
+    /// An [ImportError] if a line is not in the format above, or
+    /// [`ImportError::ReadOnlyProfile`] if the active profile is a preset.
+    /// Nothing is imported in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, ImportError};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap());
+    ///
+    /// let report = hotkeys
+    ///     .import_string(
+    ///         r#"
+    /// ## edited by hand
+    /// save = "Ctrl+Shift+S"
+    /// find = "Ctrl+Nope"
+    /// print = "Ctrl+P"
+    /// save = "F2" # oops
+    /// "#,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(report.applied, ["save"]);
+    /// assert_eq!(report.invalid[0].0, 4);
+    /// assert_eq!(report.unknown, [(5, "print".to_string())]);
+    /// assert_eq!(report.duplicates, [(6, "save".to_string())]);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+    /// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+    ///
+    /// // the unknown action is kept when exporting again
+    /// assert!(hotkeys.export_string().ends_with("# not registered\nprint = \"Ctrl+P\"\n"));
+    ///
+    /// // a malformed line stops the import
+    /// assert_eq!(
+    ///     hotkeys.import_string("save = Ctrl+S"),
+    ///     Err(ImportError::InvalidValue { line: 1 })
+    /// );
+    /// assert_eq!(
+    ///     hotkeys.import_string("find = \"F3\"\nsave"),
+    ///     Err(ImportError::MissingEquals { line: 2 })
+    /// );
+    /// assert_eq!(
+    ///     hotkeys.import_string(r#"= "F3""#),
+    ///     Err(ImportError::InvalidId { line: 1 })
+    /// );
+    /// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+    /// ```
+    pub fn import_string(&mut self, document: &str) -> Result<ImportReport, ImportError> {
+        self.import(document, |_, _, value| Ok(Saved::Text(value.to_string())))
+    }
+
+    /// Import shortcuts written in Emacs notation (see
+    /// [`Shortcut::parse_emacs`]), e.g. `save = "C-x C-s"`, like
+    /// [`Hotkeys::import_string`]. Each action has one shortcut, so there
+    /// are no alternates.
+    ///
+    /// Key sequences set the first step of a chord (see
+    /// [`Hotkeys::register_chord`]). The second step can't be changed, so it
+    /// has to be the one the action was registered with, or the line is
+    /// listed in [`ImportReport::invalid`] with
+    /// [`ParseShortcutError::MismatchedStep`]. Sequences for actions that
+    /// aren't chords are [`ParseShortcutError::TooManySteps`].
+    ///
+    /// # Arguments
+    ///
+    /// * `document` - The document, with one `action_id = "shortcut"` line
+    ///   per action.
+    /// * `meta` - Which modifier `M-` is.
+    ///
+    /// # Errors
+    ///
+    /// An [ImportError] if a line is not in the format of
+    /// [`Hotkeys::import_string`] or the active profile is a preset. Nothing
+    /// is imported in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{EmacsMeta, Hotkeys, ParseShortcutError};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("find", "Ctrl+F".parse().unwrap())
+    ///     .register("command", "Alt+X".parse().unwrap())
+    ///     .register("copy", "Ctrl+C".parse().unwrap())
+    ///     .register("quit", "Ctrl+Q".parse().unwrap())
+    ///     .register_chord("save", "Ctrl+X".parse().unwrap(), "Ctrl+S".parse().unwrap())
+    ///     .register_chord("open", "Ctrl+X".parse().unwrap(), "Ctrl+F".parse().unwrap());
+    ///
+    /// let report = hotkeys
+    ///     .import_emacs_string(
+    ///         r#"
+    /// find = "C-s"
+    /// command = "s-x"
+    /// copy = "M-w"
+    /// save = "C-c C-s"
+    /// open = "C-c C-o"
+    /// quit = "C-Q"
+    /// "#,
+    ///         EmacsMeta::Cmd,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(report.applied, ["find", "command", "copy", "save"]);
+    /// assert_eq!(
+    ///     report.invalid,
+    ///     [
+    ///         (6, "open".to_string(), ParseShortcutError::MismatchedStep("C-o".into())),
+    ///         (7, "quit".to_string(), ParseShortcutError::Ambiguous("C-Q".into())),
+    ///     ]
+    /// );
+    /// let keyboard = |id| hotkeys.current(id).unwrap().keyboard().unwrap();
+    /// assert_eq!(keyboard("find"), KeyboardShortcut::new(Modifiers::CTRL, Key::S));
+    /// // with Meta as Cmd, Super is Alt
+    /// assert_eq!(keyboard("command"), KeyboardShortcut::new(Modifiers::ALT, Key::X));
+    /// assert_eq!(keyboard("copy"), KeyboardShortcut::new(Modifiers::MAC_CMD, Key::W));
+    ///
+    /// // with Meta as Alt, Super is Cmd
+    /// hotkeys.import_emacs_string(r#"copy = "s-c""#, EmacsMeta::Alt).unwrap();
+    /// let keyboard = |id| hotkeys.current(id).unwrap().keyboard().unwrap();
+    /// assert_eq!(keyboard("copy"), KeyboardShortcut::new(Modifiers::MAC_CMD, Key::C));
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+C");
+    /// assert_eq!(hotkeys.current("quit").unwrap().to_string(), "Ctrl+Q");
+    /// ```
+    pub fn import_emacs_string(
+        &mut self,
+        document: &str,
+        meta: EmacsMeta,
+    ) -> Result<ImportReport, ImportError> {
+        self.import(document, |hotkeys, id, value| {
+            let (first, second) = Shortcut::parse_emacs_chord(value, meta)?;
+            let registered = hotkeys.get(id).map(|hotkey| hotkey.second_step());
+            match (second, registered) {
+                (Some(_), Some(None)) => return Err(ParseShortcutError::TooManySteps),
+                (Some(second), Some(Some(registered))) if second != *registered => {
+                    let step = value.split_whitespace().nth(1).unwrap_or_default();
+                    return Err(ParseShortcutError::MismatchedStep(step.to_string()));
+                }
+                _ => {}
+            }
+            Ok(Saved::Shortcut(first))
+        })
+    }
+
+    /// Copy the current shortcuts to the clipboard, in the format of
+    /// [`Hotkeys::export_string`], e.g. for a "Copy keybindings" button.
+    /// Paste them back with [`Hotkeys::apply_from_text`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The [Context] whose clipboard to copy to, see [`Context::copy_text`].
+    pub fn copy_to_clipboard(&self, ctx: &Context) {
+        ctx.copy_text(self.export_string());
+    }
+
+    /// Apply shortcuts that were pasted by the user, e.g. from
+    /// [`Hotkeys::copy_to_clipboard`], with [`ImportMode::AllOrNothing`].
+    /// Unlike [`Hotkeys::import_string`], malformed lines don't stop it but
+    /// are listed in [`ImportReport::malformed`], so the user can be told
+    /// about every line at once (see [`ImportReport::errors`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, ImportError};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    ///
+    /// let report = hotkeys.apply_from_text("save = \"F2\"\nfind = Ctrl+G\nfind = \"Ctrl+Nope\"");
+    /// assert!(report.rejected);
+    /// assert!(report.applied.is_empty());
+    /// assert_eq!(report.malformed, [ImportError::InvalidValue { line: 2 }]);
+    /// assert_eq!(
+    ///     report.errors(),
+    ///     [
+    ///         (2, "expected a quoted shortcut after `=`".to_string()),
+    ///         (3, "find: unknown key or button \"Nope\"".to_string()),
+    ///     ]
+    /// );
+    /// // nothing was applied
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+S");
+    ///
+    /// let report = hotkeys.apply_from_text("save = \"F2\"\nfind = \"Ctrl+G\"");
+    /// assert!(report.is_clean());
+    /// assert_eq!(report.applied, ["save", "find"]);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "F2");
+    /// ```
+    pub fn apply_from_text(&mut self, text: &str) -> ImportReport {
+        self.apply_from_text_with_mode(text, ImportMode::AllOrNothing)
+    }
+
+    /// Apply shortcuts that were pasted by the user like
+    /// [`Hotkeys::apply_from_text`], choosing whether the lines that are
+    /// fine are applied when others aren't.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The pasted text, with one `action_id = "shortcut"` line
+    ///   per action like [`Hotkeys::import_string`].
+    /// * `mode` - Whether a malformed line or invalid shortcut stops the
+    ///   whole import.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, ImportMode};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap());
+    ///
+    /// let report = hotkeys
+    ///     .apply_from_text_with_mode("save = \"F2\"\nfind = \"Ctrl+Nope\"", ImportMode::Partial);
+    /// assert!(!report.rejected);
+    /// assert_eq!(report.applied, ["save"]);
+    /// assert_eq!(report.invalid[0].0, 2);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "F2");
+    /// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+    /// ```
+    pub fn apply_from_text_with_mode(&mut self, text: &str, mode: ImportMode) -> ImportReport {
+        if self.is_profile_read_only(self.active_profile()) {
+            return ImportReport {
+                malformed: vec![ImportError::ReadOnlyProfile],
+                rejected: true,
+                ..Default::default()
+            };
+        }
+        let (entries, malformed) = parse_document(text);
+        let plain = |_: &Self, _: &str, value: &str| Ok(Saved::Text(value.to_string()));
+        if mode == ImportMode::Partial {
+            let report = self.import_entries(entries, plain);
+            return ImportReport {
+                malformed,
+                ..report
+            };
+        }
+
+        // imported into a copy, so nothing changes if a line is wrong
+        let mut imported = self.clone();
+        let mut report = imported.import_entries(entries, plain);
+        report.malformed = malformed;
+        if report.malformed.is_empty() && report.invalid.is_empty() {
+            *self = imported;
+        } else {
+            report.applied.clear();
+            report.migrated.clear();
+            report.rejected = true;
+        }
+        report
+    }
+
+    /// Import a document like [`Hotkeys::import_string`], with each shortcut
+    /// converted by `convert`, which gets the action ID and the shortcut as
+    /// written.
+    fn import(
+        &mut self,
+        document: &str,
+        convert: impl Fn(&Self, &str, &str) -> Result<Saved, ParseShortcutError>,
+    ) -> Result<ImportReport, ImportError> {
+        if self.is_profile_read_only(self.active_profile()) {
+            return Err(ImportError::ReadOnlyProfile);
+        }
+        let (entries, malformed) = parse_document(document);
+        if let Some(err) = malformed.into_iter().next() {
+            return Err(err);
+        }
+        Ok(self.import_entries(entries, convert))
+    }
+
+    /// Apply the parsed lines of a document, with each shortcut converted by
+    /// `convert` like in `import`.
+    fn import_entries(
+        &mut self,
+        entries: Vec<(usize, String, String)>,
+        convert: impl Fn(&Self, &str, &str) -> Result<Saved, ParseShortcutError>,
+    ) -> ImportReport {
+        let mut duplicates = vec![];
+        let mut invalid = vec![];
+        let mut unique: Vec<(usize, String, Saved)> = vec![];
+        for (line, id, value) in entries {
+            if unique.iter().any(|(_, other, _)| *other == id)
+                || invalid.iter().any(|(_, other, _)| *other == id)
+            {
+                duplicates.push((line, id));
+                continue;
+            }
+            match convert(self, &id, &value) {
+                Ok(value) => unique.push((line, id, value)),
+                Err(err) => invalid.push((line, id, err)),
+            }
+        }
+        let mut report = self.apply_entries(unique);
+        report.invalid.extend(invalid);
+        report.invalid.sort_by_key(|(line, _, _)| *line);
+        ImportReport {
+            duplicates,
+            ..report
+        }
+    }
+
+    /// Apply saved shortcuts with their line numbers, migrating them if
+    /// needed (see [`Hotkeys::migrate_id`]), and clear the history. Later
+    /// entries for the same action replace earlier ones.
+    pub(crate) fn apply_saved(&mut self, entries: Vec<(usize, String, String)>) -> ImportReport {
+        let entries = entries
+            .into_iter()
+            .map(|(line, id, value)| (line, id, Saved::Text(value)))
+            .collect();
+        self.apply_entries(entries)
+    }
+
+    /// Apply saved or already parsed shortcuts with their line numbers, like
+    /// [`Hotkeys::apply_saved`]. Only saved text can be a profile entry (see
+    /// `Hotkeys::take_profiles`) or be migrated to a new format.
+    fn apply_entries(&mut self, entries: Vec<(usize, String, Saved)>) -> ImportReport {
+        let (text, mut entries): (Vec<_>, Vec<_>) = entries
+            .into_iter()
+            .partition(|(_, _, value)| matches!(value, Saved::Text(_)));
+        let text = text
+            .into_iter()
+            .filter_map(|(line, id, value)| match value {
+                Saved::Text(value) => Some((line, id, value)),
+                Saved::Shortcut(_) => None,
+            });
+        let text = self.take_profiles(text.collect());
+        entries.extend(
+            text.into_iter()
+                .map(|(line, id, value)| (line, id, Saved::Text(value))),
+        );
+        entries.sort_by_key(|(line, _, _)| *line);
+
+        let mut report = ImportReport::default();
+        for (line, id, saved) in &entries {
+            let mut target = id.clone();
+            if !self.contains(id) {
+                // an action that was renamed, unless its new ID is saved as well
+                let renamed = self.migrations.id(id).filter(|new| {
+                    self.contains(new) && !entries.iter().any(|(_, other, _)| other == new)
+                });
+                target = renamed.unwrap_or(target);
+            }
+            let migrated_value = match saved {
+                Saved::Text(value) => self.migrations.value(&target, value),
+                Saved::Shortcut(_) => None,
+            };
+            let migrated = target != *id || migrated_value.is_some();
+
+            let Some(hotkey) = self.get_mut(&target) else {
+                // kept as it was saved, so it can be migrated when it's registered
+                let value = match (migrated_value, saved) {
+                    (Some(value), _) => value,
+                    (None, Saved::Text(value)) => value.clone(),
+                    (None, Saved::Shortcut(shortcut)) => shortcut.to_string(),
+                };
+                self.unknown.retain(|(unknown, _)| unknown != id);
+                self.unknown.push((id.clone(), value));
+                self.note_change();
+                report.unknown.push((*line, id.clone()));
+                continue;
+            };
+            let applied = match (migrated_value, saved) {
+                (Some(value), _) => hotkey.parse_bindings(&value),
+                (None, Saved::Text(value)) => hotkey.parse_bindings(value),
+                (None, Saved::Shortcut(shortcut)) => {
+                    hotkey.set_imported(*shortcut);
+                    Ok(())
+                }
+            };
+            match applied {
+                Ok(()) => {
+                    if migrated {
+                        report.migrated.push((*line, id.clone(), target.clone()));
+                    }
+                    report.applied.push(target);
+                }
+                Err(err) => report.invalid.push((*line, id.clone(), err)),
+            }
+        }
+        self.clear_history();
+        report
+    }
+}
+
+/// A shortcut of an imported line, see `Hotkeys::import`.
+pub(crate) enum Saved {
+    /// Text in the format of [`Hotkeys::export_string`], which is parsed
+    /// when it's applied.
+    Text(String),
+    /// A shortcut that was parsed from another format, e.g. Emacs notation.
+    Shortcut(Shortcut),
+}
+
+/// The state of the dialog of [clipboard_bar] that shortcuts are pasted
+/// into, kept in [egui]'s memory while it's open.
+#[derive(Clone, Default)]
+struct PasteDialog {
+    text: String,
+    /// The problems with the lines of the last text that was rejected.
+    errors: Vec<(usize, String)>,
+}
+
+/// Show buttons to copy the shortcuts to the clipboard and to paste them
+/// into a dialog, for the [KeybindsPanel](crate::KeybindsPanel). Pasted
+/// shortcuts are applied with [`Hotkeys::apply_from_text`], and the dialog
+/// stays open with the errors of each line if they're rejected.
+///
+/// # Returns
+///
+/// What was imported, if shortcuts were applied on this frame.
+pub(crate) fn clipboard_bar(ui: &mut Ui, hotkeys: &mut Hotkeys, id: Id) -> Option<ImportReport> {
+    let dialog_id = id.with("paste");
+    let mut dialog = ui
+        .ctx()
+        .memory(|memory| memory.data.get_temp::<PasteDialog>(dialog_id));
+    ui.horizontal(|ui| {
+        if ui.button("📋 Copy keybindings").clicked() {
+            hotkeys.copy_to_clipboard(ui.ctx());
+        }
+        let read_only = hotkeys.is_profile_read_only(hotkeys.active_profile());
+        if ui
+            .add_enabled(!read_only, Button::new("Paste keybindings…"))
+            .clicked()
+        {
+            dialog.get_or_insert_with(PasteDialog::default);
+        }
+    });
+
+    let mut report = None;
+    if let Some(state) = &mut dialog {
+        let mut open = true;
+        let mut apply = false;
+        Window::new("Paste keybindings")
+            .id(dialog_id)
+            .open(&mut open)
+            .collapsible(false)
+            .show(ui.ctx(), |ui| {
+                ui.add(
+                    TextEdit::multiline(&mut state.text)
+                        .hint_text("Paste the copied keybindings here")
+                        .code_editor()
+                        .desired_rows(8),
+                );
+                for (line, error) in &state.errors {
+                    let error = match line {
+                        0 => error.clone(),
+                        line => format!("Line {line}: {error}"),
+                    };
+                    ui.colored_label(ui.visuals().error_fg_color, error);
+                }
+                let enabled = !state.text.trim().is_empty();
+                apply = ui.add_enabled(enabled, Button::new("Apply")).clicked();
+            });
+        if apply {
+            let imported = hotkeys.apply_from_text(&state.text);
+            if imported.rejected {
+                state.errors = imported.errors();
+            } else {
+                open = false;
+                report = Some(imported);
+            }
+        }
+        if !open {
+            dialog = None;
+        }
+    }
+    ui.ctx().memory_mut(|memory| match dialog {
+        Some(dialog) => memory.data.insert_temp(dialog_id, dialog),
+        None => memory.data.remove::<PasteDialog>(dialog_id),
+    });
+    report
+}
+
+/// Quote a string, escaping quotes and backslashes.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Quote an action ID, unless it only has characters that are safe to leave unquoted.
+fn quote_id(id: &str) -> String {
+    let bare = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/'));
+    if bare {
+        id.to_string()
+    } else {
+        quote(id)
+    }
+}
+
+/// Parse a quoted string at the start of `s`, and get it along with the rest of `s`.
+fn parse_quoted(s: &str) -> Option<(String, &str)> {
+    let mut chars = s.strip_prefix('"')?.char_indices();
+    let mut out = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &s[index + 2..])),
+            '\\' => out.push(chars.next()?.1),
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// Parse the lines of an imported document into their line numbers, action
+/// IDs and shortcut strings, along with the lines that are malformed.
+fn parse_document(document: &str) -> (Vec<(usize, String, String)>, Vec<ImportError>) {
+    let mut entries = vec![];
+    let mut malformed = vec![];
+    for (index, text) in document.lines().enumerate() {
+        match parse_line(text, index + 1) {
+            Ok(Some((id, value))) => entries.push((index + 1, id, value)),
+            Ok(None) => {}
+            Err(err) => malformed.push(err),
+        }
+    }
+    (entries, malformed)
+}
+
+/// Parse a line of an imported document into an action ID and a shortcut
+/// string. Empty lines and comments are [None].
+fn parse_line(text: &str, line: usize) -> Result<Option<(String, String)>, ImportError> {
+    let text = text.trim();
+    if text.is_empty() || text.starts_with('#') {
+        return Ok(None);
+    }
+
+    let (id, rest) = if text.starts_with('"') {
+        parse_quoted(text).ok_or(ImportError::InvalidId { line })?
+    } else {
+        let end = text.find(['=', '#']).unwrap_or(text.len());
+        (text[..end].trim_end().to_string(), &text[end..])
+    };
+    if id.is_empty() {
+        return Err(ImportError::InvalidId { line });
+    }
+    let rest = rest
+        .trim_start()
+        .strip_prefix('=')
+        .ok_or(ImportError::MissingEquals { line })?;
+
+    let (value, rest) =
+        parse_quoted(rest.trim_start()).ok_or(ImportError::InvalidValue { line })?;
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(ImportError::InvalidValue { line });
+    }
+    Ok(Some((id, value)))
+}
@@ -4,7 +4,52 @@
 //!
 //! # Serde
 //!
-//! For serde support, enable the `serde` feature.
+//! For serde support, enable the `serde` feature. Shortcuts are saved in this
+//! crate's own format, so it doesn't enable egui's `serde` feature.
+//! It also lets [Hotkeys] save themselves in egui's persisted memory with
+//! `Hotkeys::load_or_default`. Binary formats like postcard or bincode
+//! store a [Shortcut] in a compact, versioned encoding that doesn't depend on
+//! egui's types, see [`Shortcut::to_compact_bytes`].
+//!
+//! # eframe
+//!
+//! The `eframe` feature saves [Hotkeys] or a single [Shortcut] in an eframe
+//! `Storage`, e.g. in `App::save`, with `Hotkeys::save_to_storage`, and loads
+//! them with `Hotkeys::load_from_storage`. They're saved as text, so they
+//! survive egui upgrades, and renamed actions are migrated.
+//!
+//! # Global hotkeys
+//!
+//! The `global` feature adds `GlobalSync`, which registers the shortcuts of
+//! [Hotkeys] as OS-global hotkeys with the
+//! [global-hotkey](https://docs.rs/global-hotkey) crate.
+//!
+//! # Gamepads
+//!
+//! The `gamepad` feature lets actions in [Hotkeys] have a gamepad button
+//! besides their shortcuts, see `Hotkeys::register_gamepad`. The app passes
+//! the button presses of a gamepad library to `Hotkeys::press_gamepad`.
+//!
+//! # keyboard-types
+//!
+//! The `keyboard-types` feature converts keys, modifiers and shortcuts to and
+//! from the [keyboard-types](https://docs.rs/keyboard-types) crate, see
+//! `FromKeyboardTypes` and `ToKeyboardTypes`, and checks its key events with
+//! `Shortcut::matches_keyboard_types`.
+//!
+//! # Accessibility
+//!
+//! [Keybind] describes itself to screen readers as a toggle button that is
+//! pressed while it's recording. The `accesskit` feature enables [egui]'s
+//! AccessKit support, and announces when recording starts and ends in a live
+//! region.
+//!
+//! # Testing
+//!
+//! The `testing` feature adds helpers for tests of an app's settings with
+//! `egui_kittest`. The `KeybindHarnessExt` trait adds `simulate_capture` to
+//! its `Harness`, to make a [Keybind] record a shortcut without
+//! hand-crafting its input, and `is_capturing`. See the `testing` example.
 //!
 //! # License
 //!
@@ -12,7 +57,116 @@
 
 #![warn(missing_docs)]
 
+mod altgr;
 mod bind;
+mod button;
+mod capture;
+mod cheat_sheet;
+mod chord;
+mod click;
+mod command;
+mod compact;
+mod conflicts;
+mod debounce;
+mod debug;
+mod emacs;
+mod focus;
+#[cfg(feature = "gamepad")]
+mod gamepad;
+mod gesture;
+#[cfg(feature = "global")]
+mod global;
+mod history;
+mod hotkeys;
+mod info;
 mod keybind;
+#[cfg(feature = "keyboard-types")]
+mod keyboard_types;
+mod keymap;
+mod list;
+mod long_press;
+mod macros;
+mod menu;
+mod migration;
+mod modifier_editor;
+mod names;
+mod namespace;
+mod pair;
+mod palette;
+mod panel;
+mod parse;
+#[cfg(feature = "serde")]
+mod persistence;
+mod picker;
+mod profile;
+mod reserved;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shortcuts;
+mod stats;
+#[cfg(feature = "eframe")]
+mod storage;
+mod table;
+#[cfg(feature = "testing")]
+mod testing;
+mod text_key;
+mod toggle;
+mod tooltip;
+mod vim;
+mod vscode;
+mod wheel;
+pub use altgr::*;
 pub use bind::*;
+pub use button::*;
+pub use capture::*;
+pub use cheat_sheet::*;
+pub use chord::*;
+pub use click::*;
+pub use command::*;
+pub use compact::*;
+pub use conflicts::*;
+pub use debounce::*;
+pub use debug::*;
+pub use emacs::*;
+#[cfg(feature = "gamepad")]
+pub use gamepad::*;
+pub use gesture::*;
+#[cfg(feature = "global")]
+pub use global::*;
+pub use history::*;
+pub use hotkeys::*;
+pub use info::*;
 pub use keybind::*;
+#[cfg(feature = "keyboard-types")]
+pub use keyboard_types::*;
+pub use keymap::*;
+pub use list::*;
+pub use long_press::*;
+pub use menu::*;
+pub use modifier_editor::*;
+pub use names::*;
+pub use namespace::*;
+pub use pair::*;
+pub use palette::*;
+pub use panel::*;
+pub use parse::*;
+pub use picker::*;
+pub use profile::*;
+pub use reserved::*;
+pub use shortcuts::*;
+pub use stats::*;
+pub use table::*;
+#[cfg(feature = "testing")]
+pub use testing::*;
+pub use text_key::*;
+pub use toggle::*;
+pub use tooltip::*;
+pub use vim::*;
+pub use wheel::*;
+
+// used by the `keybinds!` macro
+#[doc(hidden)]
+pub use egui as __egui;
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as __serde;
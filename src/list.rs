@@ -0,0 +1,228 @@
+use crate::{keybind::start_capture, Bind, Keybind, Shortcut, Shortcuts};
+use egui::{Id, ModifierNames, Response, Ui, Widget};
+
+/// A widget for editing a list of alternate shortcuts ([Shortcuts]).
+///
+/// Each shortcut is shown as a [Keybind] with a button to remove it, followed
+/// by a button that adds a new shortcut and starts capturing it right away.
+/// Shortcuts that are the same as an earlier one are flagged as duplicates.
+///
+/// The response is marked as changed if any shortcut was changed, added or removed.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, PointerButton, Pos2, RawInput};
+/// use egui_keybind::{KeybindList, Shortcuts};
+///
+/// let ctx = egui::Context::default();
+/// let mut shortcuts: Shortcuts = vec!["Ctrl+Y".parse().unwrap()].into();
+/// // returns whether the list changed, its shortcuts, and the texts it shows with their positions
+/// let mut frame = |events: Vec<Event>| {
+///     let mut changed = false;
+///     let mut list = vec![];
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let output = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             changed = ui.add(KeybindList::new(&mut shortcuts, "redo")).changed();
+///             list = shortcuts.iter().map(ToString::to_string).collect::<Vec<_>>();
+///         });
+///     });
+///     let texts = output.shapes.iter().filter_map(|shape| match &shape.shape {
+///         egui::Shape::Text(text) => {
+///             let pos = text.pos + text.galley.rect.center().to_vec2();
+///             Some((text.galley.text().to_string(), pos))
+///         }
+///         _ => None,
+///     });
+///     (changed, list, texts.collect::<Vec<_>>())
+/// };
+/// let find = |texts: &[(String, Pos2)], text: &str| {
+///     let found = texts.iter().filter(|(shown, _)| shown == text);
+///     found.map(|(_, pos)| *pos).collect::<Vec<_>>()
+/// };
+/// let click = |pos| {
+///     let button = |pressed| Event::PointerButton {
+///         pos,
+///         button: PointerButton::Primary,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     };
+///     vec![Event::PointerMoved(pos), button(true), button(false)]
+/// };
+/// let press = |key, modifiers| Event::Key {
+///     key,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers,
+/// };
+///
+/// // adding a binding records it right away
+/// let (_, _, texts) = frame(vec![]);
+/// let add = find(&texts, "Add binding")[0];
+/// let (changed, list, _) = frame(click(add));
+/// assert!(changed);
+/// assert_eq!(list, ["Ctrl+Y", "None"]);
+/// frame(vec![]);
+/// let (changed, list, texts) = frame(vec![press(Key::Z, Modifiers::CTRL | Modifiers::SHIFT)]);
+/// assert!(changed);
+/// assert_eq!(list, ["Ctrl+Y", "Ctrl+Shift+Z"]);
+///
+/// // the same shortcut twice is flagged
+/// frame(click(find(&texts, "Add binding")[0]));
+/// frame(vec![]);
+/// let (_, list, _) = frame(vec![press(Key::Y, Modifiers::CTRL)]);
+/// assert_eq!(list, ["Ctrl+Y", "Ctrl+Shift+Z", "Ctrl+Y"]);
+/// let (_, _, texts) = frame(vec![]);
+/// assert_eq!(find(&texts, "⚠").len(), 1);
+///
+/// // removing a row doesn't start recording another one
+/// let remove = find(&texts, "✖")[0];
+/// let (changed, list, _) = frame(click(remove));
+/// assert!(changed);
+/// assert_eq!(list, ["Ctrl+Shift+Z", "Ctrl+Y"]);
+/// let (changed, list, texts) = frame(vec![press(Key::A, Modifiers::NONE)]);
+/// assert!(!changed);
+/// assert_eq!(list, ["Ctrl+Shift+Z", "Ctrl+Y"]);
+/// assert!(find(&texts, "⚠").is_empty());
+/// drop(frame);
+///
+/// // the whole list can be saved and loaded
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let saved = serde_json::to_string(&shortcuts).unwrap();
+/// let loaded: Shortcuts = serde_json::from_str(&saved).unwrap();
+/// assert_eq!(loaded, shortcuts);
+/// # }
+/// ```
+pub struct KeybindList<'a> {
+    shortcuts: &'a mut Shortcuts,
+    id: Id,
+    modifier_names: &'a ModifierNames<'a>,
+}
+
+impl<'a> KeybindList<'a> {
+    /// Create a new [KeybindList] for the given [Shortcuts].
+    ///
+    /// # Arguments
+    ///
+    /// * `shortcuts` - The shortcuts to edit.
+    /// * `id` - ID for the [KeybindList] in [egui]'s memory.
+    pub fn new(shortcuts: &'a mut Shortcuts, id: impl Into<Id>) -> Self {
+        Self {
+            shortcuts,
+            id: id.into(),
+            modifier_names: &ModifierNames::NAMES,
+        }
+    }
+
+    /// Set the modifier names to use for the keybinds. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+}
+
+/// State of a [KeybindList] in egui's memory.
+#[derive(Clone, Default)]
+struct ListState {
+    /// A stable ID for each row, so capture state doesn't move to another
+    /// row when rows are removed.
+    row_ids: Vec<u64>,
+    /// The next row ID to hand out.
+    next_row_id: u64,
+    /// The row that should start capturing on this frame.
+    arm: Option<u64>,
+}
+
+impl ListState {
+    /// Make sure there is a row ID for every shortcut. If the shortcuts were
+    /// changed from outside of the widget, the IDs are handed out again.
+    fn sync(&mut self, len: usize) {
+        if self.row_ids.len() != len {
+            self.row_ids = (self.next_row_id..self.next_row_id + len as u64).collect();
+            self.next_row_id += len as u64;
+        }
+    }
+
+    /// Add an ID for a new row.
+    fn push(&mut self) -> u64 {
+        let id = self.next_row_id;
+        self.next_row_id += 1;
+        self.row_ids.push(id);
+        id
+    }
+}
+
+impl<'a> Widget for KeybindList<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let state_id = ui.make_persistent_id(self.id);
+        let mut state = ui
+            .ctx()
+            .memory(|memory| memory.data.get_temp::<ListState>(state_id))
+            .unwrap_or_default();
+        state.sync(self.shortcuts.len());
+        let arm = state.arm.take();
+
+        let mut changed = false;
+        let mut remove = None;
+        let mut response = ui
+            .vertical(|ui| {
+                for (index, row_id) in state.row_ids.iter().enumerate() {
+                    let shortcuts = self.shortcuts.as_mut_vec();
+                    let duplicate = shortcuts[index].is_bound()
+                        && shortcuts[..index].contains(&shortcuts[index]);
+
+                    ui.horizontal(|ui| {
+                        let keybind_id = self.id.with(row_id);
+                        if arm == Some(*row_id) {
+                            start_capture(ui, keybind_id);
+                        }
+                        changed |= ui
+                            .add(
+                                Keybind::new(&mut shortcuts[index], keybind_id)
+                                    .with_modifier_names(self.modifier_names),
+                            )
+                            .changed();
+                        if ui
+                            .small_button("✖")
+                            .on_hover_text("Remove binding")
+                            .clicked()
+                        {
+                            remove = Some(index);
+                        }
+                        if duplicate {
+                            ui.colored_label(ui.visuals().warn_fg_color, "⚠")
+                                .on_hover_text("Duplicate binding");
+                        }
+                    });
+                }
+
+                if ui.button("Add binding").clicked() {
+                    // the new row starts capturing on the next frame, when it's shown
+                    self.shortcuts.push(Shortcut::NONE);
+                    state.arm = Some(state.push());
+                    ui.ctx().request_repaint();
+                    changed = true;
+                }
+            })
+            .response;
+
+        if let Some(index) = remove {
+            self.shortcuts.remove(index);
+            state.row_ids.remove(index);
+            changed = true;
+        }
+
+        ui.ctx()
+            .memory_mut(|memory| memory.data.insert_temp(state_id, state));
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{pointer_button_name, Bind, InputKinds, KeyNameMap};
+use egui::{InputState, KeyboardShortcut, ModifierNames, PointerButton};
+
+/// A pointer button that has to be held for a while, e.g. holding the middle
+/// mouse button for half a second to open a radial menu.
+///
+/// [Bind::pressed] fires once, on the frame where the button has been held
+/// for [`LongPressBind::duration`] without being released, and not again
+/// until it's released and held again. Releasing it earlier doesn't fire.
+/// egui only runs a frame when there's input or a repaint was requested, so
+/// request repaints while [`LongPressBind::down`] is [Some] for it to fire
+/// on time.
+///
+/// The [Keybind](crate::Keybind) widget records it with
+/// [`Keybind::with_hold_measuring`](crate::Keybind::with_hold_measuring),
+/// which sets the button and how long it was held.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Bind, LongPressBind};
+///
+/// let bind = LongPressBind::new(PointerButton::Middle, 0.5);
+/// assert_eq!(bind.to_string(), "Hold Middle (0.5s)");
+///
+/// let ctx = egui::Context::default();
+/// let frame = |time: f64, pressed: Option<bool>| {
+///     let mut input = RawInput {
+///         time: Some(time),
+///         ..Default::default()
+///     };
+///     input.events.extend(pressed.map(|pressed| Event::PointerButton {
+///         pos: egui::Pos2::ZERO,
+///         button: PointerButton::Middle,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     }));
+///     let mut result = (false, None);
+///     let _ = ctx.run(input, |ctx| {
+///         result = ctx.input_mut(|input| (bind.pressed(input), bind.down(input)));
+///     });
+///     result
+/// };
+///
+/// // released before the threshold, so it doesn't fire
+/// assert_eq!(frame(0.0, Some(true)), (false, Some(0.0)));
+/// assert_eq!(frame(0.25, None), (false, Some(0.5)));
+/// assert_eq!(frame(0.4, Some(false)), (false, None));
+/// assert_eq!(frame(1.0, None), (false, None));
+///
+/// // held long enough, it fires once and not again while it's still held
+/// assert_eq!(frame(2.0, Some(true)), (false, Some(0.0)));
+/// assert_eq!(frame(2.3, None), (false, Some(0.6)));
+/// assert_eq!(frame(2.6, None), (true, None));
+/// assert_eq!(frame(2.8, None), (false, None));
+/// assert_eq!(frame(4.0, None), (false, None));
+/// assert_eq!(frame(4.1, Some(false)), (false, None));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LongPressBind {
+    /// The button to hold.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::button_by_name"))]
+    pub button: PointerButton,
+    /// How long the button has to be held, in seconds.
+    pub duration: f32,
+}
+
+impl LongPressBind {
+    /// Create a new [LongPressBind].
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - The button to hold.
+    /// * `duration` - How long the button has to be held, in seconds.
+    pub const fn new(button: PointerButton, duration: f32) -> Self {
+        Self { button, duration }
+    }
+
+    /// Get how long the button has been held, in seconds, or [None] if it's
+    /// not down.
+    fn held_for(&self, input: &InputState) -> Option<f64> {
+        if !input.pointer.button_down(self.button) {
+            return None;
+        }
+        let since = input.pointer.press_start_time()?;
+        Some(input.time - since)
+    }
+
+    /// Check whether the button is being held and hasn't been held long
+    /// enough yet, e.g. to show a progress ring around the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The [InputState] to check with.
+    ///
+    /// # Returns
+    ///
+    /// How far the hold is, from `0.0` when the button went down to just
+    /// below `1.0`, or [None] if the button isn't held or the bind already
+    /// fired.
+    pub fn down(&self, input: &InputState) -> Option<f32> {
+        let held = self.held_for(input)? as f32;
+        (held < self.duration).then(|| held / self.duration)
+    }
+}
+
+impl std::fmt::Display for LongPressBind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Hold {} ({}s)",
+            pointer_button_name(self.button),
+            self.duration
+        )
+    }
+}
+
+/// Ignores keyboard shortcuts. Setting a pointer button keeps the duration.
+impl Bind for LongPressBind {
+    fn set(&mut self, _keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        if let Some(pointer) = pointer {
+            self.button = pointer;
+        }
+    }
+
+    fn format(&self, _names: &ModifierNames<'_>, _is_mac: bool) -> String {
+        self.to_string()
+    }
+
+    fn format_with_key_names(
+        &self,
+        _names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        _is_mac: bool,
+    ) -> String {
+        format!(
+            "Hold {} ({}s)",
+            key_names.pointer_button_name(self.button),
+            self.duration
+        )
+    }
+
+    /// Triggered on the frame where the hold reaches the duration.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        let Some(held) = self.held_for(input) else {
+            return false;
+        };
+        let duration = self.duration as f64;
+        let before = held - input.unstable_dt as f64;
+        held >= duration && before < duration
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        Some(self.button)
+    }
+
+    fn set_long_press(&mut self, button: PointerButton, duration: f32) -> bool {
+        *self = Self::new(button, duration);
+        true
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds::POINTER
+    }
+}
@@ -0,0 +1,179 @@
+/// Declare a struct with a [Shortcut](crate::Shortcut) field for each action,
+/// so actions are checked by the compiler instead of being looked up by a
+/// string ID like in [Hotkeys](crate::Hotkeys).
+///
+/// Each action is written as `field: "Label" = default`. The struct gets:
+///
+/// * A public [Shortcut](crate::Shortcut) field for each action.
+/// * `defaults()`, which creates it with the default shortcuts. This is also
+///   its [Default].
+/// * `ui(&mut self, ui)`, which shows a grid with a [Keybind](crate::Keybind)
+///   for each action next to its label, and returns whether any of them was
+///   changed. Widget IDs are made from the struct and field names, so they
+///   stay the same between frames.
+/// * With the `serde` feature, `Serialize` and
+///   `Deserialize` as a map of field names to shortcuts.
+///   Actions that are missing when deserializing get their default shortcut,
+///   and unknown ones are ignored.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+/// use egui_keybind::{keybinds, Bind, Shortcut};
+///
+/// fn ctrl(key: Key) -> Shortcut {
+///     Shortcut::new(Some(KeyboardShortcut::new(Modifiers::COMMAND, key)), None)
+/// }
+///
+/// keybinds! {
+///     /// The shortcuts of the app.
+///     pub struct AppBinds {
+///         save: "Save" = ctrl(Key::S),
+///         quit: "Quit" = ctrl(Key::Q),
+///         back: "Go back" = Shortcut::new(None, Some(PointerButton::Extra1)),
+///     }
+/// }
+///
+/// let mut binds = AppBinds::defaults();
+/// assert_eq!(binds.save, ctrl(Key::S));
+/// assert_eq!(binds, AppBinds::default());
+///
+/// if binds.ui(ui) {
+///     // save the binds
+/// }
+/// if ui.input_mut(|input| binds.quit.pressed(input)) {
+///     // quit the app
+/// }
+/// # });
+/// ```
+///
+/// With the `serde` feature:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use egui_keybind::keybinds;
+///
+/// keybinds! {
+///     struct AppBinds {
+///         save: "Save" = "Ctrl+S".parse().unwrap(),
+///         open: "Open" = "Ctrl+O".parse().unwrap(),
+///     }
+/// }
+///
+/// let mut binds = AppBinds::defaults();
+/// binds.save = "Ctrl+Shift+S".parse().unwrap();
+/// let json = serde_json::to_string(&binds).unwrap();
+///
+/// // "open" is missing from the old config, so it gets its default
+/// let saved = json.replace(r#","open""#, r#","removed""#);
+/// let loaded: AppBinds = serde_json::from_str(&saved).unwrap();
+/// assert_eq!(loaded, binds);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! keybinds {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident : $label:literal = $default:expr
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        $vis struct $name {
+            $(
+                $(#[$field_meta])*
+                #[doc = $label]
+                pub $field: $crate::Shortcut,
+            )*
+        }
+
+        impl $name {
+            /// Create the binds with their default shortcuts.
+            pub fn defaults() -> Self {
+                Self {
+                    $($field: $default,)*
+                }
+            }
+
+            /// Show a [Keybind]($crate::Keybind) for each bind next to its label.
+            ///
+            /// # Returns
+            ///
+            /// Whether any bind was changed.
+            pub fn ui(&mut self, ui: &mut $crate::__egui::Ui) -> bool {
+                let mut any_changed = false;
+                $crate::__egui::Grid::new(stringify!($name))
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        $(
+                            ui.label($label);
+                            any_changed |= ui
+                                .add($crate::Keybind::new(
+                                    &mut self.$field,
+                                    $crate::__egui::Id::new((stringify!($name), stringify!($field))),
+                                ))
+                                .changed();
+                            ui.end_row();
+                        )*
+                    });
+                any_changed
+            }
+        }
+
+        impl ::std::default::Default for $name {
+            fn default() -> Self {
+                Self::defaults()
+            }
+        }
+
+        $crate::__keybinds_serde!($name { $($field),* });
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __keybinds_serde {
+    ($name:ident { $($field:ident),* }) => {
+        impl $crate::__serde::Serialize for $name {
+            fn serialize<S: $crate::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::std::result::Result<S::Ok, S::Error> {
+                use $crate::__serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(None)?;
+                $(map.serialize_entry(stringify!($field), &self.$field)?;)*
+                map.end()
+            }
+        }
+
+        impl<'de> $crate::__serde::Deserialize<'de> for $name {
+            fn deserialize<D: $crate::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::std::result::Result<Self, D::Error> {
+                let saved: ::std::collections::HashMap<::std::string::String, $crate::Shortcut> =
+                    $crate::__serde::Deserialize::deserialize(deserializer)?;
+                #[allow(unused_mut)]
+                let mut binds = Self::defaults();
+                $(
+                    if let Some(shortcut) = saved.get(stringify!($field)) {
+                        binds.$field = *shortcut;
+                    }
+                )*
+                Ok(binds)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __keybinds_serde {
+    ($($tt:tt)*) => {};
+}
@@ -0,0 +1,154 @@
+use crate::{AltGrPolicy, Bind, TextInputPolicy};
+use egui::{os::OperatingSystem, Button, Context, Id, ModifierNames, Response, Ui};
+
+/// The response of [menu_shortcut_item].
+pub struct MenuItemResponse {
+    /// The response of the menu item.
+    pub response: Response,
+    /// Whether the bind of the item was pressed on this frame, see
+    /// [menu_shortcut_pressed].
+    pub pressed: bool,
+}
+
+impl MenuItemResponse {
+    /// Whether the item was clicked or its bind was pressed on this frame.
+    #[inline]
+    pub fn activated(&self) -> bool {
+        self.response.clicked() || self.pressed
+    }
+}
+
+/// Check whether a bind was pressed on this frame (see [`Bind::pressed`]),
+/// for the shortcut of a [menu_shortcut_item].
+///
+/// The input is only checked by the first call for the bind in each pass,
+/// later calls return the same result. Call this outside of the menu, so the
+/// shortcut works while the menu is closed, and combine it with
+/// [`MenuItemResponse::activated`] to run the action once. Like
+/// [ShortcutButton](crate::ShortcutButton), a bind without a modifier other
+/// than Shift is not checked while typing into a text field.
+///
+/// # Example
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_keybind::{menu_shortcut_item, menu_shortcut_pressed, Shortcut};
+///
+/// let copy: Shortcut = "Ctrl+C".parse().unwrap();
+/// let paste: Shortcut = "Ctrl+V".parse().unwrap();
+///
+/// // checked every frame, even when the menu is closed
+/// let mut do_copy = menu_shortcut_pressed(ui.ctx(), &copy);
+/// let mut do_paste = menu_shortcut_pressed(ui.ctx(), &paste);
+/// ui.menu_button("Edit", |ui| {
+///     do_copy |= menu_shortcut_item(ui, "Copy", &copy).activated();
+///     do_paste |= menu_shortcut_item(ui, "Paste", &paste).activated();
+/// });
+/// if do_copy {
+///     // copy
+/// }
+/// # });
+/// ```
+///
+/// Checking the same bind again in the same frame gives the same result:
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{menu_shortcut_pressed, Shortcut};
+///
+/// let copy: Shortcut = "Ctrl+C".parse().unwrap();
+/// let mut input = RawInput::default();
+/// input.events.push(Event::Key {
+///     key: Key::C,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL,
+/// });
+///
+/// let ctx = egui::Context::default();
+/// let _ = ctx.run(input, |ctx| {
+///     assert!(menu_shortcut_pressed(ctx, &copy));
+///     assert!(menu_shortcut_pressed(ctx, &copy));
+/// });
+/// let _ = ctx.run(RawInput::default(), |ctx| {
+///     assert!(!menu_shortcut_pressed(ctx, &copy));
+/// });
+/// ```
+///
+/// Binds that only differ in something else than their keyboard shortcut and
+/// pointer button, like when a pointer button triggers, are checked apart:
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{menu_shortcut_pressed, PointerTrigger, Shortcut};
+///
+/// let press = Shortcut::new(None, Some(PointerButton::Middle));
+/// let release = press.with_pointer_trigger(PointerTrigger::Release);
+/// let mut input = RawInput::default();
+/// input.events.push(Event::PointerButton {
+///     pos: egui::pos2(10.0, 10.0),
+///     button: PointerButton::Middle,
+///     pressed: true,
+///     modifiers: Modifiers::NONE,
+/// });
+///
+/// let ctx = egui::Context::default();
+/// let _ = ctx.run(input, |ctx| {
+///     assert!(menu_shortcut_pressed(ctx, &press));
+///     assert!(!menu_shortcut_pressed(ctx, &release));
+/// });
+/// ```
+pub fn menu_shortcut_pressed<B: Bind>(ctx: &Context, bind: &B) -> bool {
+    if !bind.is_bound() {
+        return false;
+    }
+    // binds without a cache key are told apart by how they're formatted
+    let key = bind.cache_key().unwrap_or_else(|| {
+        egui::util::hash([false, true].map(|is_mac| bind.format(&ModifierNames::NAMES, is_mac)))
+    });
+    let id = Id::new(("menu_shortcut_pressed", key));
+    let pass = ctx.cumulative_pass_nr();
+    let checked = ctx.data(|data| data.get_temp::<(u64, bool)>(id));
+    if let Some((_, pressed)) = checked.filter(|(checked, _)| *checked == pass) {
+        return pressed;
+    }
+
+    let typing = ctx.wants_keyboard_input();
+    let altgr = AltGrPolicy::for_os(ctx.os());
+    let pressed = (!typing || TextInputPolicy::WithModifiers.allows(bind))
+        && ctx.input_mut(|input| altgr.pressed(bind, input));
+    ctx.data_mut(|data| data.insert_temp(id, (pass, pressed)));
+    pressed
+}
+
+/// Show a menu item with its text on the left and the formatted bind dimmed
+/// on the right, like a native menu entry. The shortcuts of all items in a
+/// menu line up at its right edge, since the menu is as wide as its widest
+/// item. The whole row is highlighted when hovered.
+///
+/// The item is [activated](MenuItemResponse::activated) when it's clicked or
+/// its bind is pressed (see [menu_shortcut_pressed]), and the menu is closed
+/// then.
+///
+/// # Arguments
+///
+/// * `ui` - The [Ui] of the menu, e.g. in [`Ui::menu_button`].
+/// * `text` - The text of the item, e.g. `"Copy"`.
+/// * `bind` - The bind that activates the item.
+pub fn menu_shortcut_item<B: Bind>(ui: &mut Ui, text: &str, bind: &B) -> MenuItemResponse {
+    let is_mac = ui.ctx().os() == OperatingSystem::Mac;
+    let mut button = Button::new(text);
+    if bind.is_bound() {
+        button = button.shortcut_text(bind.format(&ModifierNames::NAMES, is_mac));
+    }
+    let response = ui.add(button);
+    let item = MenuItemResponse {
+        pressed: menu_shortcut_pressed(ui.ctx(), bind),
+        response,
+    };
+    if item.activated() {
+        ui.close_menu();
+    }
+    item
+}
@@ -0,0 +1,149 @@
+use crate::Hotkeys;
+use std::{fmt, sync::Arc};
+
+type IdMigration = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+type ValueMigration = Arc<dyn Fn(&str, &str) -> Option<String> + Send + Sync>;
+
+/// How [Hotkeys] migrates saved shortcuts from older versions of the app,
+/// see [`Hotkeys::migrate_id`].
+#[derive(Clone, Default)]
+pub(crate) struct Migrations {
+    /// Old action IDs and the IDs they were renamed to.
+    renamed: Vec<(String, String)>,
+    ids: Option<IdMigration>,
+    values: Option<ValueMigration>,
+}
+
+impl fmt::Debug for Migrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrations")
+            .field("renamed", &self.renamed)
+            .field("ids", &self.ids.is_some())
+            .field("values", &self.values.is_some())
+            .finish()
+    }
+}
+
+impl Migrations {
+    /// Get the ID an old action ID was renamed to, if any.
+    pub(crate) fn id(&self, old: &str) -> Option<String> {
+        let renamed = self.renamed.iter().find(|(from, _)| from == old);
+        match renamed {
+            Some((_, to)) => Some(to.clone()),
+            None => self.ids.as_ref().and_then(|ids| ids(old)),
+        }
+    }
+
+    /// Get the migrated shortcut string of an action, if it changed.
+    pub(crate) fn value(&self, id: &str, value: &str) -> Option<String> {
+        let values = self.values.as_ref()?;
+        values(id, value).filter(|migrated| migrated != value)
+    }
+}
+
+impl Hotkeys {
+    /// Load the saved shortcuts of an action that was renamed into the action
+    /// with its new ID, e.g. after renaming `"del"` to `"delete_selection"`.
+    ///
+    /// Old IDs are only migrated if no action is registered with them, the
+    /// new ID is registered and its shortcuts aren't saved as well. This
+    /// applies to [`Hotkeys::load_saved`], [`Hotkeys::import_string`] and
+    /// deserializing with the `serde` feature, so set the migrations up
+    /// before loading. The new ID is saved from then on.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The ID the action was saved with, e.g. `"del"`.
+    /// * `new` - The ID of the action now, e.g. `"delete_selection"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("delete_selection", "Delete".parse().unwrap())
+    ///     .register("duplicate_selection", "Ctrl+D".parse().unwrap())
+    ///     .migrate_id("del", "delete_selection")
+    ///     .set_id_migration(|old| Some(format!("{}_selection", old.strip_prefix("sel_")?)));
+    ///
+    /// // saved by an older version of the app
+    /// let report = hotkeys
+    ///     .import_string(
+    ///         r#"
+    /// del = "Backspace"
+    /// sel_duplicate = "Ctrl+Shift+D"
+    /// sel_flip = "F"
+    /// "#,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     report.migrated,
+    ///     [
+    ///         (2, "del".to_string(), "delete_selection".to_string()),
+    ///         (3, "sel_duplicate".to_string(), "duplicate_selection".to_string()),
+    ///     ]
+    /// );
+    /// // "flip_selection" isn't registered, so the old ID is kept
+    /// assert_eq!(report.unknown, [(4, "sel_flip".to_string())]);
+    /// assert_eq!(hotkeys.current("delete_selection").unwrap().to_string(), "Backspace");
+    /// assert_eq!(hotkeys.current("duplicate_selection").unwrap().to_string(), "Ctrl+Shift+D");
+    ///
+    /// // and is migrated when it's registered
+    /// hotkeys.register("flip_selection", "H".parse().unwrap());
+    /// assert_eq!(hotkeys.current("flip_selection").unwrap().to_string(), "F");
+    /// assert!(!hotkeys.export_string().contains("del ="));
+    /// ```
+    pub fn migrate_id(&mut self, old: impl Into<String>, new: impl Into<String>) -> &mut Self {
+        let old = old.into();
+        self.migrations.renamed.retain(|(from, _)| *from != old);
+        self.migrations.renamed.push((old, new.into()));
+        self
+    }
+
+    /// Set a function that gets the new ID of a saved action that is not
+    /// registered, or [None] if it wasn't renamed, like
+    /// [`Hotkeys::migrate_id`] for a whole scheme of renames. IDs set with
+    /// [`Hotkeys::migrate_id`] take precedence.
+    pub fn set_id_migration(
+        &mut self,
+        migration: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.migrations.ids = Some(Arc::new(migration));
+        self
+    }
+
+    /// Set a function that rewrites saved shortcut strings before they are
+    /// parsed, e.g. after the format of a key name changed. It gets the
+    /// (migrated) action ID and the saved string, and returns the new string
+    /// or [None] to keep it. Applies to the same loading functions as
+    /// [`Hotkeys::migrate_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("delete", "Delete".parse().unwrap())
+    ///     .register("find", "Ctrl+F".parse().unwrap())
+    ///     // an older version saved "Control-Delete"
+    ///     .set_value_migration(|_, value| Some(value.replace('-', "+").replace("Control", "Ctrl")));
+    ///
+    /// let report = hotkeys
+    ///     .import_string("delete = \"Control-Delete\"\nfind = \"Ctrl+G\"")
+    ///     .unwrap();
+    /// assert_eq!(report.migrated, [(1, "delete".to_string(), "delete".to_string())]);
+    /// assert_eq!(report.applied, ["delete", "find"]);
+    /// assert_eq!(hotkeys.current("delete").unwrap().to_string(), "Ctrl+Delete");
+    /// ```
+    pub fn set_value_migration(
+        &mut self,
+        migration: impl Fn(&str, &str) -> Option<String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.migrations.values = Some(Arc::new(migration));
+        self
+    }
+}
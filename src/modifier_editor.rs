@@ -0,0 +1,70 @@
+use crate::{picker::capture_modifiers, Bind};
+use egui::{os::OperatingSystem, KeyboardShortcut, ModifierNames, Response, Ui, Widget};
+
+/// A compact editor for only the modifiers of a bind's keyboard shortcut,
+/// e.g. to turn Ctrl+S into Ctrl+Alt+S without pressing the whole chord again.
+///
+/// Shows a toggle for Ctrl, Shift and Alt, and for Cmd on Mac. The key and
+/// the pointer button of the bind are kept as they are. The editor is
+/// disabled if the bind has no keyboard shortcut.
+pub struct ModifierEditor<'a, B: Bind> {
+    bind: &'a mut B,
+    modifier_names: &'a ModifierNames<'a>,
+}
+
+impl<'a, B: Bind> ModifierEditor<'a, B> {
+    /// Create a new [ModifierEditor].
+    ///
+    /// # Arguments
+    ///
+    /// * `bind` - The bind to edit the modifiers of.
+    pub fn new(bind: &'a mut B) -> Self {
+        Self {
+            bind,
+            modifier_names: &ModifierNames::NAMES,
+        }
+    }
+
+    /// Set the modifier names to show on the toggles. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+}
+
+impl<'a, B: Bind> Widget for ModifierEditor<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let is_mac = ui.ctx().os() == OperatingSystem::Mac;
+        let names = self.modifier_names;
+        let keyboard = self.bind.keyboard();
+        let mut modifiers = keyboard.map(|kb| kb.modifiers).unwrap_or_default();
+        let prev_modifiers = modifiers;
+
+        let mut response = ui
+            .add_enabled_ui(keyboard.is_some(), |ui| {
+                ui.horizontal(|ui| {
+                    ui.toggle_value(&mut modifiers.ctrl, names.ctrl);
+                    ui.toggle_value(&mut modifiers.shift, names.shift);
+                    ui.toggle_value(
+                        &mut modifiers.alt,
+                        if is_mac { names.mac_alt } else { names.alt },
+                    );
+                    if is_mac {
+                        ui.toggle_value(&mut modifiers.mac_cmd, names.mac_cmd);
+                    }
+                });
+            })
+            .response;
+
+        if let Some(keyboard) = keyboard.filter(|_| modifiers != prev_modifiers) {
+            // keep `command` in sync with Ctrl (or Cmd on Mac), like egui does
+            let modifiers = capture_modifiers(modifiers, is_mac);
+            self.bind.set(
+                Some(KeyboardShortcut::new(modifiers, keyboard.logical_key)),
+                self.bind.pointer(),
+            );
+            response.mark_changed();
+        }
+        response
+    }
+}
@@ -0,0 +1,531 @@
+use crate::{bind::pointer_separator, Bind};
+use egui::{
+    os::OperatingSystem, Key, KeyboardShortcut, ModifierNames, Modifiers, PointerButton,
+    NUM_POINTER_BUTTONS,
+};
+use std::collections::HashMap;
+
+/// Get the English name of a [PointerButton]: `"Mouse Left"`, `"Mouse Right"`,
+/// `"Middle"`, `"Mouse 4"` or `"Mouse 5"`. The left and right buttons aren't
+/// just `"Left"` and `"Right"`, since those are the arrow keys in the text form
+/// of a [Shortcut](crate::Shortcut).
+pub const fn pointer_button_name(button: PointerButton) -> &'static str {
+    match button {
+        PointerButton::Primary => "Mouse Left",
+        PointerButton::Secondary => "Mouse Right",
+        PointerButton::Middle => "Middle",
+        PointerButton::Extra1 => "Mouse 4",
+        PointerButton::Extra2 => "Mouse 5",
+    }
+}
+
+/// Get the usual name of the logo key (the one [`Modifiers::mac_cmd`] is
+/// for) on an operating system: `"Cmd"` on Mac and iOS, `"Win"` on Windows,
+/// `"Super"` on Linux and other Unix-like systems, and `"Meta"` elsewhere.
+pub const fn logo_key_name(os: OperatingSystem) -> &'static str {
+    match os {
+        OperatingSystem::Mac | OperatingSystem::IOS => "Cmd",
+        OperatingSystem::Windows => "Win",
+        OperatingSystem::Nix => "Super",
+        OperatingSystem::Android | OperatingSystem::Unknown => "Meta",
+    }
+}
+
+/// [`ModifierNames::NAMES`] with the logo key named `"Win"`.
+pub const WINDOWS_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    mac_cmd: logo_key_name(OperatingSystem::Windows),
+    ..ModifierNames::NAMES
+};
+
+/// [`ModifierNames::NAMES`] with the logo key named `"Super"`.
+pub const LINUX_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    mac_cmd: logo_key_name(OperatingSystem::Nix),
+    ..ModifierNames::NAMES
+};
+
+/// [`ModifierNames::NAMES`] with the logo key named `"Meta"`.
+pub const META_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    mac_cmd: logo_key_name(OperatingSystem::Unknown),
+    ..ModifierNames::NAMES
+};
+
+/// German modifier names, as printed on German keyboards and in German
+/// versions of Windows and macOS, e.g. `"Strg+Umschalt+S"`.
+pub const GERMAN_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    is_short: false,
+    alt: "Alt",
+    ctrl: "Strg",
+    shift: "Umschalt",
+    mac_cmd: "Befehl",
+    mac_alt: "Wahl",
+    concat: "+",
+};
+
+/// French modifier names, e.g. `"Ctrl+Maj+S"`.
+pub const FRENCH_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    is_short: false,
+    alt: "Alt",
+    ctrl: "Ctrl",
+    shift: "Maj",
+    mac_cmd: "Cmd",
+    mac_alt: "Option",
+    concat: "+",
+};
+
+/// Spanish modifier names, e.g. `"Ctrl+Mayús+S"`.
+pub const SPANISH_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    is_short: false,
+    alt: "Alt",
+    ctrl: "Ctrl",
+    shift: "Mayús",
+    mac_cmd: "Cmd",
+    mac_alt: "Opción",
+    concat: "+",
+};
+
+/// Japanese modifier names. Japanese keyboards and manuals use the English
+/// key names, so these are [`ModifierNames::NAMES`] with the spaced plus
+/// of Japanese manuals, e.g. `"Ctrl + Shift + S"`.
+pub const JAPANESE_MODIFIER_NAMES: ModifierNames<'static> = ModifierNames {
+    concat: " + ",
+    ..ModifierNames::NAMES
+};
+
+/// Extra constructors for [ModifierNames].
+pub trait ModifierNamesExt {
+    /// Get the modifier names that users of an operating system expect:
+    /// [`ModifierNames::SYMBOLS`] on Mac and iOS, [WINDOWS_MODIFIER_NAMES] on
+    /// Windows, [LINUX_MODIFIER_NAMES] on Linux and other Unix-like systems
+    /// and [META_MODIFIER_NAMES] elsewhere. These only differ from
+    /// [`ModifierNames::NAMES`] in the name of the logo key (see
+    /// [logo_key_name]), except on Mac and iOS.
+    ///
+    /// This is what [Keybind](crate::Keybind) uses unless
+    /// [`Keybind::with_modifier_names`](crate::Keybind::with_modifier_names)
+    /// was called.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{os::OperatingSystem, ModifierNames};
+    /// use egui_keybind::ModifierNamesExt;
+    ///
+    /// let logo_key = |os| ModifierNames::for_os(os).mac_cmd;
+    /// assert_eq!(logo_key(OperatingSystem::Mac), "⌘");
+    /// assert_eq!(logo_key(OperatingSystem::Windows), "Win");
+    /// assert_eq!(logo_key(OperatingSystem::Nix), "Super");
+    /// assert_eq!(logo_key(OperatingSystem::Unknown), "Meta");
+    ///
+    /// // the other modifiers keep their usual names
+    /// assert_eq!(ModifierNames::for_os(OperatingSystem::Windows).ctrl, "Ctrl");
+    /// assert_eq!(ModifierNames::for_os(OperatingSystem::Mac).ctrl, "⌃");
+    /// ```
+    ///
+    /// A [Keybind](crate::Keybind) shows its bind with the names of the
+    /// operating system of its [Context](egui::Context) (see
+    /// [`Context::os`](egui::Context::os)):
+    ///
+    /// ```
+    /// use egui::{epaint::Shape, os::OperatingSystem, RawInput};
+    /// use egui_keybind::{Keybind, Shortcut};
+    ///
+    /// let rendered = |os, shortcut: &str| {
+    ///     let ctx = egui::Context::default();
+    ///     ctx.set_os(os);
+    ///     let mut shortcut: Shortcut = shortcut.parse().unwrap();
+    ///     let output = ctx.run(RawInput::default(), |ctx| {
+    ///         egui::CentralPanel::default().show(ctx, |ui| {
+    ///             ui.add(Keybind::new(&mut shortcut, "keybind"));
+    ///         });
+    ///     });
+    ///     let mut texts = output.shapes.iter().filter_map(|clipped| match &clipped.shape {
+    ///         Shape::Text(text) => Some(text.galley.text().to_string()),
+    ///         _ => None,
+    ///     });
+    ///     texts.next().unwrap()
+    /// };
+    ///
+    /// // Cmd+S and Ctrl+S recorded on macOS
+    /// assert_eq!(rendered(OperatingSystem::Mac, "CmdOrCtrl+Cmd+S"), "⌘S");
+    /// assert_eq!(rendered(OperatingSystem::Mac, "Ctrl+S"), "⌃S");
+    /// assert_eq!(rendered(OperatingSystem::Mac, "CmdOrCtrl+S"), "⌘S");
+    /// // the logo key is shown everywhere
+    /// assert_eq!(rendered(OperatingSystem::Nix, "Super+S"), "Super+S");
+    /// assert_eq!(rendered(OperatingSystem::Windows, "Ctrl+Shift+Win+S"), "Ctrl+Shift+Win+S");
+    /// assert_eq!(rendered(OperatingSystem::Windows, "CmdOrCtrl+S"), "Ctrl+S");
+    /// ```
+    fn for_os(os: OperatingSystem) -> &'static ModifierNames<'static>;
+
+    /// Get the modifier names of a language, from a locale like `"de"`,
+    /// `"de-AT"` or `"fr_CA"`: [GERMAN_MODIFIER_NAMES],
+    /// [FRENCH_MODIFIER_NAMES], [SPANISH_MODIFIER_NAMES] or
+    /// [JAPANESE_MODIFIER_NAMES]. Only the language is used, and case is
+    /// ignored. Other languages fall back to the English
+    /// [`ModifierNames::NAMES`].
+    ///
+    /// The names can be used with
+    /// [`Keybind::with_modifier_names`](crate::Keybind::with_modifier_names).
+    /// Key names are not translated, see [KeyNameMap] for that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, ModifierNames, Modifiers};
+    /// use egui_keybind::{Keybind, ModifierNamesExt};
+    ///
+    /// let save = KeyboardShortcut::new(Modifiers::CTRL | Modifiers::SHIFT, Key::S);
+    /// let format = |locale| save.format(ModifierNames::for_locale(locale), false);
+    /// assert_eq!(format("de"), "Strg+Umschalt+S");
+    /// assert_eq!(format("de-AT"), "Strg+Umschalt+S");
+    /// assert_eq!(format("fr_CA"), "Ctrl+Maj+S");
+    /// assert_eq!(format("ES"), "Ctrl+Mayús+S");
+    /// assert_eq!(format("ja-JP"), "Ctrl + Shift + S");
+    /// // English for everything else
+    /// assert_eq!(format("en-US"), "Ctrl+Shift+S");
+    /// assert_eq!(format("xx"), "Ctrl+Shift+S");
+    /// assert_eq!(format(""), "Ctrl+Shift+S");
+    ///
+    /// // on Mac
+    /// let alt_cmd = KeyboardShortcut::new(Modifiers::ALT | Modifiers::MAC_CMD, Key::S);
+    /// assert_eq!(alt_cmd.format(ModifierNames::for_locale("de"), true), "Wahl+Befehl+S");
+    /// assert_eq!(alt_cmd.format(ModifierNames::for_locale("es"), true), "Opción+Cmd+S");
+    ///
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut shortcut = save;
+    /// let names = ModifierNames::for_locale("de");
+    /// ui.add(Keybind::new(&mut shortcut, "save").with_modifier_names(names));
+    /// # });
+    /// ```
+    fn for_locale(locale: &str) -> &'static ModifierNames<'static>;
+}
+
+impl ModifierNamesExt for ModifierNames<'_> {
+    fn for_os(os: OperatingSystem) -> &'static ModifierNames<'static> {
+        match os {
+            OperatingSystem::Mac | OperatingSystem::IOS => &ModifierNames::SYMBOLS,
+            OperatingSystem::Windows => &WINDOWS_MODIFIER_NAMES,
+            OperatingSystem::Nix => &LINUX_MODIFIER_NAMES,
+            _ => &META_MODIFIER_NAMES,
+        }
+    }
+
+    fn for_locale(locale: &str) -> &'static ModifierNames<'static> {
+        let language = locale.split(['-', '_']).next().unwrap_or_default();
+        match language.to_ascii_lowercase().as_str() {
+            "de" => &GERMAN_MODIFIER_NAMES,
+            "fr" => &FRENCH_MODIFIER_NAMES,
+            "es" => &SPANISH_MODIFIER_NAMES,
+            "ja" => &JAPANESE_MODIFIER_NAMES,
+            _ => &ModifierNames::NAMES,
+        }
+    }
+}
+
+/// A table of display names for keys and pointer buttons, used to localize
+/// the formatted keybinds.
+///
+/// Keys and buttons that are not in the table fall back to their English names
+/// ([`Key::name`] and [pointer_button_name]).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct KeyNameMap {
+    keys: HashMap<Key, String>,
+    pointer_buttons: [Option<String>; NUM_POINTER_BUTTONS],
+    /// A hash of the names, so a [Keybind](crate::Keybind) doesn't hash all
+    /// of them every frame, see [`KeyNameMap::update_hash`].
+    hash: u64,
+}
+
+/// Only the hash of the names is hashed, which is updated when they change.
+impl std::hash::Hash for KeyNameMap {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
+impl KeyNameMap {
+    /// Create a new, empty [KeyNameMap].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of a [Key].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to rename.
+    /// * `name` - The name to display for the key, e.g. `"Échap"` for [`Key::Escape`].
+    pub fn with_key(mut self, key: Key, name: impl Into<String>) -> Self {
+        self.keys.insert(key, name.into());
+        self.update_hash();
+        self
+    }
+
+    /// Set the name of a [PointerButton].
+    ///
+    /// # Arguments
+    ///
+    /// * `button` - The pointer button to rename.
+    /// * `name` - The name to display for the button, e.g. `"Molette"` for [`PointerButton::Middle`].
+    pub fn with_pointer_button(mut self, button: PointerButton, name: impl Into<String>) -> Self {
+        self.pointer_buttons[button as usize] = Some(name.into());
+        self.update_hash();
+        self
+    }
+
+    /// Hash the names again after they changed. They are hashed in no
+    /// particular order, so equal tables hash the same.
+    fn update_hash(&mut self) {
+        let keys = self
+            .keys
+            .iter()
+            .map(egui::util::hash)
+            .fold(0, u64::wrapping_add);
+        self.hash = egui::util::hash((keys, &self.pointer_buttons));
+    }
+
+    /// Get the display name of a [Key].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to get the name of.
+    /// * `is_short` - Whether to fall back to [`Key::symbol_or_name`] instead of [`Key::name`].
+    pub fn key_name(&self, key: Key, is_short: bool) -> &str {
+        match self.keys.get(&key) {
+            Some(name) => name,
+            None if is_short => key.symbol_or_name(),
+            None => key.name(),
+        }
+    }
+
+    /// Get the display name of a [PointerButton].
+    pub fn pointer_button_name(&self, button: PointerButton) -> &str {
+        self.pointer_buttons[button as usize]
+            .as_deref()
+            .unwrap_or_else(|| pointer_button_name(button))
+    }
+
+    /// Format a [KeyboardShortcut] with the names from this table.
+    ///
+    /// This is the same as [`KeyboardShortcut::format`], except for the key name.
+    pub fn format_keyboard_shortcut(
+        &self,
+        shortcut: &KeyboardShortcut,
+        names: &ModifierNames<'_>,
+        is_mac: bool,
+    ) -> String {
+        let mut string = String::new();
+        self.format_keyboard_shortcut_into(&mut string, shortcut, names, is_mac);
+        string
+    }
+
+    /// Append a [KeyboardShortcut] to `out`, like [`KeyNameMap::format_keyboard_shortcut`].
+    pub(crate) fn format_keyboard_shortcut_into(
+        &self,
+        out: &mut String,
+        shortcut: &KeyboardShortcut,
+        names: &ModifierNames<'_>,
+        is_mac: bool,
+    ) {
+        let key_name = self.key_name(shortcut.logical_key, names.is_short);
+        push_keyboard_shortcut(out, shortcut, names, key_name, is_mac);
+    }
+}
+
+/// Append [Modifiers] to `out`, in the same order as [`ModifierNames::format`].
+/// Unlike there, [`Modifiers::mac_cmd`] is shown outside of Mac as well, as
+/// the logo key (e.g. `"Win"` or `"Super"`, see [logo_key_name]).
+pub(crate) fn push_modifiers(
+    out: &mut String,
+    modifiers: &Modifiers,
+    names: &ModifierNames<'_>,
+    is_mac: bool,
+) {
+    let start = out.len();
+    let mut push_if = |is_active: bool, name: &str| {
+        if is_active {
+            if out.len() > start {
+                out.push_str(names.concat);
+            }
+            out.push_str(name);
+        }
+    };
+    if is_mac {
+        push_if(modifiers.ctrl, names.ctrl);
+        push_if(modifiers.shift, names.shift);
+        push_if(modifiers.alt, names.mac_alt);
+        push_if(modifiers.mac_cmd || modifiers.command, names.mac_cmd);
+    } else {
+        push_if(modifiers.ctrl || modifiers.command, names.ctrl);
+        push_if(modifiers.alt, names.alt);
+        push_if(modifiers.shift, names.shift);
+        push_if(modifiers.mac_cmd, names.mac_cmd);
+    }
+}
+
+/// Append the modifiers of the text form of a [Shortcut](crate::Shortcut)
+/// to `out`, each followed by a `+`. Every flag of [Modifiers] has its own
+/// name, so nothing is lost: `"CmdOrCtrl"` is [`Modifiers::command`] and
+/// `"Cmd"` is [`Modifiers::mac_cmd`].
+pub(crate) fn push_canonical_modifiers(out: &mut String, modifiers: &Modifiers) {
+    for (is_active, name) in [
+        (modifiers.command, "CmdOrCtrl"),
+        (modifiers.ctrl, "Ctrl"),
+        (modifiers.alt, "Alt"),
+        (modifiers.shift, "Shift"),
+        (modifiers.mac_cmd, "Cmd"),
+    ] {
+        if is_active {
+            out.push_str(name);
+            out.push('+');
+        }
+    }
+}
+
+/// Append a [KeyboardShortcut] to `out` like [`KeyboardShortcut::format`],
+/// with the given name for its key.
+pub(crate) fn push_keyboard_shortcut(
+    out: &mut String,
+    shortcut: &KeyboardShortcut,
+    names: &ModifierNames<'_>,
+    key_name: &str,
+    is_mac: bool,
+) {
+    let start = out.len();
+    push_modifiers(out, &shortcut.modifiers, names, is_mac);
+    if out.len() > start {
+        out.push_str(names.concat);
+    }
+    out.push_str(key_name);
+}
+
+/// A kind of modifier key, used to choose the order modifiers are displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModifierKind {
+    /// Ctrl, or ⌃ on Mac.
+    Ctrl,
+    /// Alt, or Option (⌥) on Mac.
+    Alt,
+    /// Shift (⇧).
+    Shift,
+    /// Cmd (⌘) on Mac, the logo key elsewhere (see [logo_key_name]).
+    Cmd,
+}
+
+impl ModifierKind {
+    /// All modifiers in the conventional order: Ctrl+Alt+Shift on Windows and
+    /// Linux, ⌃⌥⇧⌘ on Mac.
+    pub const ALL: [Self; 4] = [Self::Ctrl, Self::Alt, Self::Shift, Self::Cmd];
+
+    /// Whether this modifier is held in `modifiers`.
+    ///
+    /// Like [`ModifierNames::format`], `command` counts as Ctrl, or as Cmd on Mac.
+    pub fn is_active(self, modifiers: &Modifiers, is_mac: bool) -> bool {
+        match self {
+            Self::Ctrl => modifiers.ctrl || (!is_mac && modifiers.command),
+            Self::Alt => modifiers.alt,
+            Self::Shift => modifiers.shift,
+            Self::Cmd => modifiers.mac_cmd || (is_mac && modifiers.command),
+        }
+    }
+
+    /// Get the name of this modifier from a [ModifierNames].
+    pub fn name<'a>(self, names: &ModifierNames<'a>, is_mac: bool) -> &'a str {
+        match self {
+            Self::Ctrl => names.ctrl,
+            Self::Alt if is_mac => names.mac_alt,
+            Self::Alt => names.alt,
+            Self::Shift => names.shift,
+            Self::Cmd => names.mac_cmd,
+        }
+    }
+}
+
+/// Format [Modifiers] in the given order, instead of the fixed order of
+/// [`ModifierNames::format`].
+///
+/// Modifiers that are missing from `order` are added at the end, in the
+/// order of [`ModifierKind::ALL`].
+///
+/// # Arguments
+///
+/// * `modifiers` - The modifiers to format.
+/// * `names` - The [ModifierNames] to use.
+/// * `order` - The order to display the modifiers in.
+/// * `is_mac` - Whether to use MacOS symbols.
+pub fn format_modifiers(
+    modifiers: &Modifiers,
+    names: &ModifierNames<'_>,
+    order: &[ModifierKind],
+    is_mac: bool,
+) -> String {
+    let mut string = String::new();
+    let missing = ModifierKind::ALL
+        .into_iter()
+        .filter(|kind| !order.contains(kind));
+    for kind in order.iter().copied().chain(missing) {
+        if kind.is_active(modifiers, is_mac) {
+            if !string.is_empty() {
+                string.push_str(names.concat);
+            }
+            string.push_str(kind.name(names, is_mac));
+        }
+    }
+    string
+}
+
+/// Format a bind like [`Bind::format_with_key_names`], but with its modifiers
+/// in the given order (see [format_modifiers]).
+///
+/// Only the [`Bind::keyboard`] and [`Bind::pointer`] parts of the bind are
+/// ordered. Binds without modifiers are formatted as usual.
+///
+/// # Arguments
+///
+/// * `bind` - The bind to format.
+/// * `names` - The [ModifierNames] to use.
+/// * `key_names` - The [KeyNameMap] to use, or [None] for the English names.
+/// * `order` - The order to display the modifiers in.
+/// * `is_mac` - Whether to use MacOS symbols.
+///
+/// # Example
+///
+/// ```
+/// use egui::ModifierNames;
+/// use egui_keybind::{format_with_modifier_order, ModifierKind, Shortcut};
+///
+/// let shortcut: Shortcut = "Ctrl+Shift+S".parse().unwrap();
+/// let names = &ModifierNames::NAMES;
+/// assert_eq!(
+///     format_with_modifier_order(&shortcut, names, None, &ModifierKind::ALL, false),
+///     "Ctrl+Shift+S"
+/// );
+/// assert_eq!(
+///     format_with_modifier_order(&shortcut, names, None, &[ModifierKind::Shift], false),
+///     "Shift+Ctrl+S"
+/// );
+/// ```
+pub fn format_with_modifier_order<B: Bind>(
+    bind: &B,
+    names: &ModifierNames<'_>,
+    key_names: Option<&KeyNameMap>,
+    order: &[ModifierKind],
+    is_mac: bool,
+) -> String {
+    let default_key_names = KeyNameMap::default();
+    let key_names = key_names.unwrap_or(&default_key_names);
+    let Some(keyboard) = bind.keyboard().filter(|kb| !kb.modifiers.is_none()) else {
+        return bind.format_with_key_names(names, key_names, is_mac);
+    };
+
+    let mut string = format_modifiers(&keyboard.modifiers, names, order, is_mac);
+    if !string.is_empty() {
+        string.push_str(names.concat);
+    }
+    string.push_str(key_names.key_name(keyboard.logical_key, names.is_short));
+    if let Some(pointer) = bind.pointer() {
+        string.push_str(pointer_separator(names));
+        string.push_str(key_names.pointer_button_name(pointer));
+    }
+    string
+}
@@ -0,0 +1,307 @@
+use crate::{Hotkey, Hotkeys, Shortcut};
+use egui::{Context, InputState};
+use std::fmt;
+
+/// The separator between the namespace and the short ID of an action
+/// registered through a [HotkeysRegistrar], e.g. `"plugin.foo/save"`.
+/// Neither the namespace nor the short ID can contain it. An action that is
+/// registered with [`Hotkeys::register`] has no namespace, even if its ID
+/// contains the separator.
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// An error of registering an action through a [HotkeysRegistrar].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegisterError {
+    /// An action with the same ID is already registered in the namespace.
+    Duplicate {
+        /// The fully qualified ID, e.g. `"core/save"`.
+        id: String,
+    },
+    /// The short ID is empty or contains [NAMESPACE_SEPARATOR].
+    InvalidId {
+        /// The short ID.
+        id: String,
+    },
+    /// The namespace is empty or contains [NAMESPACE_SEPARATOR].
+    InvalidNamespace {
+        /// The namespace.
+        namespace: String,
+    },
+}
+
+impl fmt::Display for RegisterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Duplicate { id } => write!(f, "action \"{id}\" is already registered"),
+            Self::InvalidId { id } => write!(f, "invalid action ID \"{id}\""),
+            Self::InvalidNamespace { namespace } => {
+                write!(f, "invalid namespace \"{namespace}\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegisterError {}
+
+impl Hotkey {
+    /// Get the namespace of the action, if it was registered through a
+    /// [HotkeysRegistrar], e.g. `"plugin.foo"` for `"plugin.foo/save"`.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Get the ID of the action without its namespace, e.g. `"save"` for
+    /// `"plugin.foo/save"`. This is the whole ID if it has no namespace.
+    pub fn short_id(&self) -> &str {
+        match &self.namespace {
+            Some(namespace) => &self.id()[namespace.len() + NAMESPACE_SEPARATOR.len_utf8()..],
+            None => self.id(),
+        }
+    }
+}
+
+impl Hotkeys {
+    /// Get a handle to register and look up actions in a namespace, so
+    /// separate parts of an app (e.g. plugins) can share one [Hotkeys]
+    /// without their IDs colliding. See [HotkeysRegistrar].
+    ///
+    /// # Arguments
+    ///
+    /// * `namespace` - The namespace, e.g. `"core"` or `"plugin.foo"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, RegisterError};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .namespace("core")
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .unwrap();
+    /// let mut plugin = hotkeys.namespace("plugin.foo");
+    /// plugin.register("save", "Ctrl+Alt+S".parse().unwrap()).unwrap();
+    ///
+    /// // short IDs are looked up in the namespace, full IDs anywhere
+    /// assert_eq!(plugin.current("save").unwrap().to_string(), "Ctrl+Alt+S");
+    /// assert_eq!(plugin.current("core/save").unwrap().to_string(), "Ctrl+S");
+    /// assert_eq!(
+    ///     plugin.register("save", "Ctrl+Shift+S".parse().unwrap()).unwrap_err(),
+    ///     RegisterError::Duplicate {
+    ///         id: "plugin.foo/save".to_string()
+    ///     }
+    /// );
+    ///
+    /// // the same short ID in two namespaces are two actions
+    /// let ids: Vec<_> = hotkeys.iter().map(|hotkey| hotkey.id()).collect();
+    /// assert_eq!(ids, ["core/save", "plugin.foo/save"]);
+    /// assert_eq!(hotkeys.get("plugin.foo/save").unwrap().namespace(), Some("plugin.foo"));
+    /// assert!(hotkeys.export_string().contains("core/save = \"Ctrl+S\""));
+    ///
+    /// // only actions registered through the handle have a namespace
+    /// hotkeys.register("file/open", "Ctrl+O".parse().unwrap());
+    /// let open = hotkeys.get("file/open").unwrap();
+    /// assert_eq!((open.namespace(), open.short_id()), (None, "file/open"));
+    /// assert_eq!(hotkeys.namespaces(), ["core", "plugin.foo"]);
+    ///
+    /// // and namespaces can't be empty or contain the separator
+    /// for namespace in ["", "plugin/foo"] {
+    ///     let error = hotkeys
+    ///         .namespace(namespace)
+    ///         .register("open", "Ctrl+O".parse().unwrap())
+    ///         .unwrap_err();
+    ///     assert!(matches!(error, RegisterError::InvalidNamespace { .. }));
+    /// }
+    /// ```
+    pub fn namespace(&mut self, namespace: impl Into<String>) -> HotkeysRegistrar<'_> {
+        HotkeysRegistrar {
+            hotkeys: self,
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Get the namespaces of the registered actions, in the order they were
+    /// first registered in (see [`Hotkeys::namespace`]). Actions without a
+    /// namespace are not included.
+    pub fn namespaces(&self) -> Vec<&str> {
+        let mut namespaces = vec![];
+        for namespace in self.iter().filter_map(Hotkey::namespace) {
+            if !namespaces.contains(&namespace) {
+                namespaces.push(namespace);
+            }
+        }
+        namespaces
+    }
+}
+
+/// A handle to register and look up the actions of a namespace in [Hotkeys],
+/// see [`Hotkeys::namespace`].
+///
+/// Actions are registered with fully qualified IDs, e.g. `"plugin.foo/save"`
+/// for `"save"` in `"plugin.foo"`, which are also the IDs they are saved and
+/// [triggered](Hotkeys::triggered) with. Unlike [`Hotkeys::register`],
+/// registering an ID that is already registered in the namespace is an error
+/// instead of changing the action.
+///
+/// Lookups accept the short ID of an action in the namespace, or the fully
+/// qualified ID of any action.
+#[derive(Debug)]
+pub struct HotkeysRegistrar<'a> {
+    hotkeys: &'a mut Hotkeys,
+    namespace: String,
+}
+
+impl HotkeysRegistrar<'_> {
+    /// Get the namespace of the handle.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.namespace
+    }
+
+    /// Get the fully qualified ID of an action in the namespace, e.g.
+    /// `"plugin.foo/save"` for `"save"`.
+    pub fn qualify(&self, id: &str) -> String {
+        format!("{}{NAMESPACE_SEPARATOR}{id}", self.namespace)
+    }
+
+    /// Get the fully qualified ID of a registered action from its short ID
+    /// in the namespace, or from its full ID.
+    pub fn resolve(&self, id: &str) -> Option<String> {
+        let qualified = self.qualify(id);
+        if self.hotkeys.contains(&qualified) {
+            Some(qualified)
+        } else {
+            self.hotkeys.contains(id).then(|| id.to_string())
+        }
+    }
+
+    /// Register an action in the namespace, like [`Hotkeys::register`].
+    ///
+    /// # Errors
+    ///
+    /// A [RegisterError] if the action is already registered in the
+    /// namespace, or `id` is not a valid short ID, or the namespace is not
+    /// valid. Nothing is registered then.
+    pub fn register(
+        &mut self,
+        id: impl AsRef<str>,
+        default: Shortcut,
+    ) -> Result<&mut Self, RegisterError> {
+        let id = self.new_id(id.as_ref())?;
+        self.hotkeys.register(id.clone(), default);
+        self.claim(&id);
+        Ok(self)
+    }
+
+    /// Register an action in the namespace and a category, like
+    /// [`Hotkeys::register_in`]. Fails like [`HotkeysRegistrar::register`].
+    pub fn register_in(
+        &mut self,
+        category: impl Into<String>,
+        id: impl AsRef<str>,
+        default: Shortcut,
+    ) -> Result<&mut Self, RegisterError> {
+        let id = self.new_id(id.as_ref())?;
+        self.hotkeys.register_in(category, id.clone(), default);
+        self.claim(&id);
+        Ok(self)
+    }
+
+    /// Register an action in the namespace and a scope, like
+    /// [`Hotkeys::register_scoped`]. Fails like [`HotkeysRegistrar::register`].
+    pub fn register_scoped(
+        &mut self,
+        scope: impl Into<String>,
+        id: impl AsRef<str>,
+        default: Shortcut,
+    ) -> Result<&mut Self, RegisterError> {
+        let id = self.new_id(id.as_ref())?;
+        self.hotkeys.register_scoped(scope, id.clone(), default);
+        self.claim(&id);
+        Ok(self)
+    }
+
+    /// Register a chord in the namespace, like [`Hotkeys::register_chord`].
+    /// Fails like [`HotkeysRegistrar::register`].
+    pub fn register_chord(
+        &mut self,
+        id: impl AsRef<str>,
+        first: Shortcut,
+        second: Shortcut,
+    ) -> Result<&mut Self, RegisterError> {
+        let id = self.new_id(id.as_ref())?;
+        self.hotkeys.register_chord(id.clone(), first, second);
+        self.claim(&id);
+        Ok(self)
+    }
+
+    /// Get the fully qualified ID for a new action, if it and the namespace
+    /// are valid and it's not registered yet.
+    fn new_id(&self, id: &str) -> Result<String, RegisterError> {
+        if self.namespace.is_empty() || self.namespace.contains(NAMESPACE_SEPARATOR) {
+            return Err(RegisterError::InvalidNamespace {
+                namespace: self.namespace.clone(),
+            });
+        }
+        if id.is_empty() || id.contains(NAMESPACE_SEPARATOR) {
+            return Err(RegisterError::InvalidId { id: id.to_string() });
+        }
+        let id = self.qualify(id);
+        match self.hotkeys.contains(&id) {
+            true => Err(RegisterError::Duplicate { id }),
+            false => Ok(id),
+        }
+    }
+
+    /// Mark a newly registered action as part of the namespace.
+    fn claim(&mut self, id: &str) {
+        if let Some(hotkey) = self.hotkeys.get_mut(id) {
+            hotkey.namespace = Some(self.namespace.clone());
+        }
+    }
+
+    /// Get a registered action by its short or full ID.
+    pub fn get(&self, id: &str) -> Option<&Hotkey> {
+        self.hotkeys.get(&self.resolve(id)?)
+    }
+
+    /// Whether an action is registered, by its short or full ID.
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Get the current shortcut of an action by its short or full ID, if
+    /// it's registered.
+    pub fn current(&self, id: &str) -> Option<&Shortcut> {
+        self.get(id).map(Hotkey::current)
+    }
+
+    /// Check whether an action was pressed by its short or full ID, like
+    /// [`Hotkeys::pressed`].
+    pub fn pressed(&self, input: &mut InputState, id: &str, typing: bool) -> bool {
+        self.resolve(id)
+            .is_some_and(|id| self.hotkeys.pressed(input, &id, typing))
+    }
+
+    /// Check whether an action was pressed by its short or full ID, like
+    /// [`Hotkeys::pressed_ctx`].
+    pub fn pressed_ctx(&self, ctx: &Context, id: &str) -> bool {
+        self.resolve(id)
+            .is_some_and(|id| self.hotkeys.pressed_ctx(ctx, &id))
+    }
+
+    /// Iterate over the actions in the namespace, in the order they were
+    /// registered.
+    pub fn iter(&self) -> impl Iterator<Item = &Hotkey> {
+        self.hotkeys
+            .iter()
+            .filter(|hotkey| hotkey.namespace() == Some(&self.namespace))
+    }
+
+    /// Get the [Hotkeys] the handle registers into, e.g. to set the scope
+    /// of an action with its full ID.
+    #[inline]
+    pub fn hotkeys(&mut self) -> &mut Hotkeys {
+        self.hotkeys
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{Bind, Keybind};
+use egui::{Id, Key, Response, Ui, Widget, WidgetText};
+
+/// Two keybinds of an action pair next to one label, e.g. zoom in and zoom
+/// out: `Zoom   [Ctrl+=] [Ctrl+-]`.
+///
+/// Each half is a [Keybind] that is clicked and recorded on its own, with
+/// its own ID derived from the pair's ID. The returned [Response] is
+/// [changed](Response::changed) if either half changed.
+///
+/// In a [Grid](egui::Grid), the label and the keybinds are two cells, so
+/// pairs line up with other rows that have a label and a keybind. In other
+/// vertical layouts they're shown in one horizontal row.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{Bind, BindPair, Shortcut};
+///
+/// let ctx = egui::Context::default();
+/// let mut zoom_in: Shortcut = "Ctrl+Plus".parse().unwrap();
+/// let mut zoom_out: Shortcut = "Ctrl+Minus".parse().unwrap();
+/// let mut zoom = 0;
+/// let mut frame = |events: Vec<Event>| {
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         if ctx.input_mut(|i| zoom_in.pressed(i)) {
+///             zoom += 1;
+///         }
+///         if ctx.input_mut(|i| zoom_out.pressed(i)) {
+///             zoom -= 1;
+///         }
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             egui::Grid::new("binds").num_columns(2).show(ui, |ui| {
+///                 ui.add(BindPair::new("Zoom", &mut zoom_in, &mut zoom_out, "zoom"));
+///                 ui.end_row();
+///             });
+///         });
+///     });
+/// };
+/// let key = |key| Event::Key {
+///     key,
+///     physical_key: None,
+///     pressed: true,
+///     repeat: false,
+///     modifiers: Modifiers::CTRL,
+/// };
+///
+/// frame(vec![key(Key::Plus)]);
+/// frame(vec![key(Key::Plus)]);
+/// frame(vec![key(Key::Minus)]);
+/// drop(frame);
+/// assert_eq!(zoom, 1);
+/// ```
+pub struct BindPair<'a, B: Bind> {
+    text: WidgetText,
+    first: &'a mut B,
+    second: &'a mut B,
+    id: Id,
+    reset: Option<(B, B)>,
+    reset_key: Option<Key>,
+}
+
+impl<'a, B: Bind> BindPair<'a, B> {
+    /// Create a new [BindPair].
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The label of the pair, e.g. `"Zoom"`.
+    /// * `first` - The first bind, e.g. zoom in.
+    /// * `second` - The second bind, e.g. zoom out.
+    /// * `id` - ID for the pair in [egui]'s memory. The halves get their own
+    ///   IDs from it, see [`BindPair::half_ids`].
+    pub fn new(
+        text: impl Into<WidgetText>,
+        first: &'a mut B,
+        second: &'a mut B,
+        id: impl Into<Id>,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            first,
+            second,
+            id: id.into(),
+            reset: None,
+            reset_key: None,
+        }
+    }
+
+    /// Set the binds the halves reset to when the reset key is pressed while
+    /// one of them is recording, see [`Keybind::with_reset`]. Only the half
+    /// that is recording is reset.
+    pub fn with_reset(mut self, first: B, second: B) -> Self {
+        self.reset = Some((first, second));
+        self
+    }
+
+    /// Set the key that resets the half that is recording, see
+    /// [`Keybind::with_reset_key`]. By default there is none.
+    pub fn with_reset_key(mut self, key: Option<Key>) -> Self {
+        self.reset_key = key;
+        self
+    }
+
+    /// Get the IDs the two halves are created with, e.g. to simulate
+    /// recording one of them in tests.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the pair.
+    pub fn half_ids(id: impl Into<Id>) -> [Id; 2] {
+        let id = id.into();
+        [id.with("first"), id.with("second")]
+    }
+
+    /// Show the two keybinds next to each other.
+    fn show_halves(self, ui: &mut Ui) -> Response {
+        let [first_id, second_id] = Self::half_ids(self.id);
+        let (first_reset, second_reset) = match self.reset {
+            Some((first, second)) => (Some(first), Some(second)),
+            None => (None, None),
+        };
+        let half = |bind, id, reset: Option<B>| {
+            let keybind = Keybind::new(bind, id).with_reset_key(self.reset_key);
+            match reset {
+                Some(reset) => keybind.with_reset(reset),
+                None => keybind,
+            }
+        };
+        ui.horizontal(|ui| {
+            let first = ui.add(half(self.first, first_id, first_reset));
+            let second = ui.add(half(self.second, second_id, second_reset));
+            first | second
+        })
+        .inner
+    }
+}
+
+impl<'a, B: Bind> Widget for BindPair<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let text = self.text.clone();
+        let show = move |ui: &mut Ui| {
+            let label = ui.label(text);
+            let halves = self.show_halves(ui);
+            label | halves
+        };
+        // in a grid, the label and the keybinds are cells of the row
+        if ui.layout().main_dir().is_horizontal() {
+            show(ui)
+        } else {
+            ui.horizontal(show).inner
+        }
+    }
+}
@@ -0,0 +1,338 @@
+use crate::{fold, ActionInfo, Bind, DisplayNames, Hotkey, Hotkeys, Shortcut};
+use egui::{
+    os::OperatingSystem, Align, Align2, Context, Id, Key, KeyboardShortcut, Layout, ModifierNames,
+    Modifiers, ScrollArea, TextEdit, Window,
+};
+
+/// A searchable list of all actions in [Hotkeys], for running them without
+/// remembering their shortcuts.
+///
+/// The palette is opened and closed with a shortcut (see
+/// [`CommandPalette::with_open_bind`]) or with [`CommandPalette::open`]. It
+/// shows a search field and the actions whose name fuzzy-matches it, e.g.
+/// `"sva"` finds "Save as", each with its current shortcut. Arrow keys move
+/// the selection, Enter or a click chooses an action and Escape closes the
+/// palette. Only actions in an active scope are listed (see
+/// [`Hotkeys::is_active`]).
+///
+/// The chosen action is not run, its ID is returned to the app instead.
+/// Whether the palette is open, the search text and the selection are kept
+/// in [egui]'s memory.
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// # let _ = ctx.run(Default::default(), |ctx| {
+/// use egui_keybind::{CommandPalette, Hotkeys};
+///
+/// let mut hotkeys = Hotkeys::new();
+/// hotkeys
+///     .register("save", "Ctrl+S".parse().unwrap())
+///     .register("find", "Ctrl+F".parse().unwrap());
+///
+/// let chosen = CommandPalette::new(&hotkeys, "palette")
+///     .with_display_names(|id| id.to_uppercase())
+///     .show(ctx);
+/// for id in hotkeys.triggered(ctx).map(|action| action.id().to_string()).chain(chosen) {
+///     // run the action
+/// }
+/// # });
+/// ```
+pub struct CommandPalette<'a> {
+    hotkeys: &'a Hotkeys,
+    id: Id,
+    open_bind: Shortcut,
+    modifier_names: &'a ModifierNames<'a>,
+    display_names: Option<DisplayNames<'a>>,
+}
+
+/// State of a [CommandPalette] in egui's memory.
+#[derive(Clone, Default)]
+struct PaletteState {
+    open: bool,
+    /// Whether the palette was opened on this frame, so the search field
+    /// gets focus.
+    opened: bool,
+    query: String,
+    selected: usize,
+}
+
+impl<'a> CommandPalette<'a> {
+    /// Create a new [CommandPalette] for the given [Hotkeys].
+    ///
+    /// # Arguments
+    ///
+    /// * `hotkeys` - The actions to choose from.
+    /// * `id` - ID for the [CommandPalette] in [egui]'s memory.
+    pub fn new(hotkeys: &'a Hotkeys, id: impl Into<Id>) -> Self {
+        Self {
+            hotkeys,
+            id: id.into(),
+            open_bind: Shortcut::new(
+                Some(KeyboardShortcut::new(
+                    Modifiers::COMMAND | Modifiers::SHIFT,
+                    Key::P,
+                )),
+                None,
+            ),
+            modifier_names: &ModifierNames::NAMES,
+            display_names: None,
+        }
+    }
+
+    /// Set the shortcut that opens and closes the palette. By default this
+    /// is Ctrl+Shift+P (Cmd+Shift+P on Mac). [`Shortcut::NONE`] leaves
+    /// opening it to [`CommandPalette::open`].
+    pub fn with_open_bind(mut self, open_bind: Shortcut) -> Self {
+        self.open_bind = open_bind;
+        self
+    }
+
+    /// Set the modifier names to use for the shortcuts. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+
+    /// Set a function that gets the name to show and search for an action
+    /// from its ID, e.g. to translate it. By default the name of
+    /// [`Hotkeys::display_name`] is used.
+    pub fn with_display_names(mut self, display_names: impl Fn(&str) -> String + 'a) -> Self {
+        self.display_names = Some(Box::new(display_names));
+        self
+    }
+
+    /// Open the palette with the given ID, e.g. from a menu, with an empty
+    /// search field.
+    pub fn open(ctx: &Context, id: impl Into<Id>) {
+        let state = PaletteState {
+            open: true,
+            opened: true,
+            ..Default::default()
+        };
+        ctx.data_mut(|data| data.insert_temp(id.into(), state));
+    }
+
+    /// Close the palette with the given ID.
+    pub fn close(ctx: &Context, id: impl Into<Id>) {
+        ctx.data_mut(|data| data.remove::<PaletteState>(id.into()));
+    }
+
+    /// Whether the palette with the given ID is open.
+    pub fn is_open(ctx: &Context, id: impl Into<Id>) -> bool {
+        ctx.data(|data| data.get_temp::<PaletteState>(id.into()))
+            .is_some_and(|state| state.open)
+    }
+
+    /// Get the name to show for an action.
+    fn action_name(&self, id: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(id),
+            None => self.hotkeys.display_name(id),
+        }
+    }
+
+    /// Show the palette if it's open, and open or close it if its shortcut
+    /// was pressed.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the action that was chosen on this frame, if any.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{CommandPalette, Hotkeys};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("Save", "Ctrl+S".parse().unwrap())
+    ///     .register("Find", "Ctrl+F".parse().unwrap())
+    ///     .register("Save as", "Ctrl+Shift+S".parse().unwrap());
+    ///
+    /// let key = |key| Event::Key {
+    ///     key,
+    ///     physical_key: None,
+    ///     pressed: true,
+    ///     repeat: false,
+    ///     modifiers: Modifiers::NONE,
+    /// };
+    /// let ctx = egui::Context::default();
+    /// let mut run = |events: Vec<Event>| {
+    ///     let input = RawInput {
+    ///         events,
+    ///         ..Default::default()
+    ///     };
+    ///     let mut chosen = None;
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         chosen = CommandPalette::new(&hotkeys, "palette").show(ctx);
+    ///     });
+    ///     chosen
+    /// };
+    ///
+    /// CommandPalette::open(&ctx, "palette");
+    /// assert_eq!(run(vec![]), None);
+    /// // "Find" doesn't match, so down selects "Save as"
+    /// assert_eq!(run(vec![Event::Text("sa".to_string())]), None);
+    /// let chosen = run(vec![key(Key::ArrowDown), key(Key::Enter)]);
+    /// assert_eq!(chosen.as_deref(), Some("Save as"));
+    /// assert!(!CommandPalette::is_open(&ctx, "palette"));
+    ///
+    /// // escape closes the palette without choosing anything
+    /// CommandPalette::open(&ctx, "palette");
+    /// assert_eq!(run(vec![key(Key::Escape)]), None);
+    /// assert!(!CommandPalette::is_open(&ctx, "palette"));
+    /// ```
+    pub fn show(self, ctx: &Context) -> Option<String> {
+        let toggled = self.open_bind.is_bound() && ctx.input_mut(|i| self.open_bind.pressed(i));
+        if toggled {
+            match Self::is_open(ctx, self.id) {
+                true => Self::close(ctx, self.id),
+                false => Self::open(ctx, self.id),
+            }
+        }
+        let mut state = ctx
+            .data(|data| data.get_temp::<PaletteState>(self.id))
+            .unwrap_or_default();
+        if !state.open {
+            return None;
+        }
+
+        // handle the keys before the search field, which would use them otherwise
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        if escape {
+            Self::close(ctx, self.id);
+            return None;
+        }
+
+        let query: Vec<char> = state
+            .query
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(fold)
+            .collect();
+        let mut matches: Vec<(i32, &Hotkey, String)> = self
+            .hotkeys
+            .iter()
+            .filter(|hotkey| self.hotkeys.is_active(hotkey.id()))
+            .filter_map(|hotkey| {
+                let name = self.action_name(hotkey.id());
+                Some((fuzzy_score(&name, &query)?, hotkey, name))
+            })
+            .collect();
+        // best matches first, in the order the actions were registered
+        matches.sort_by_key(|(score, _, _)| -score);
+
+        let last = matches.len().saturating_sub(1);
+        let previous = state.selected;
+        if up {
+            state.selected = state.selected.checked_sub(1).unwrap_or(last);
+        }
+        if down {
+            state.selected = if state.selected >= last {
+                0
+            } else {
+                state.selected + 1
+            };
+        }
+        state.selected = state.selected.min(last);
+        let mut chosen = enter
+            .then(|| matches.get(state.selected))
+            .flatten()
+            .map(|(_, hotkey, _)| hotkey.id().to_string());
+
+        let is_mac = ctx.os() == OperatingSystem::Mac;
+        Window::new("Command palette")
+            .id(self.id.with("window"))
+            .title_bar(false)
+            .resizable(false)
+            .collapsible(false)
+            .anchor(Align2::CENTER_TOP, [0.0, 64.0])
+            .fixed_size([360.0, 0.0])
+            .show(ctx, |ui| {
+                let search = ui.add(
+                    TextEdit::singleline(&mut state.query)
+                        .hint_text("Search actions")
+                        .desired_width(f32::INFINITY),
+                );
+                if state.opened {
+                    // typing searches right away
+                    search.request_focus();
+                    state.opened = false;
+                }
+                if search.changed() {
+                    state.selected = 0;
+                }
+
+                if matches.is_empty() {
+                    ui.weak("No actions match");
+                    return;
+                }
+                ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    for (index, (_, hotkey, name)) in matches.iter().enumerate() {
+                        let selected = index == state.selected;
+                        let row = ui.horizontal(|ui| {
+                            let label = match hotkey.info().and_then(ActionInfo::icon) {
+                                Some(icon) => {
+                                    ui.selectable_label(selected, format!("{icon} {name}"))
+                                }
+                                None => ui.selectable_label(selected, name),
+                            };
+                            if hotkey.current().is_bound() {
+                                ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                                    ui.weak(hotkey.current().format(self.modifier_names, is_mac));
+                                });
+                            }
+                            label
+                        });
+                        if row.inner.clicked() {
+                            chosen = Some(hotkey.id().to_string());
+                        }
+                        if selected && state.selected != previous {
+                            row.response.scroll_to_me(None);
+                        }
+                    }
+                });
+            });
+
+        match chosen {
+            Some(_) => Self::close(ctx, self.id),
+            None => ctx.data_mut(|data| data.insert_temp(self.id, state)),
+        }
+        chosen
+    }
+}
+
+/// Match the folded `query` against a name as a subsequence (see [fold]),
+/// e.g. `"sva"` matches "Save as". Matches at the start of words and
+/// consecutive matches score higher.
+///
+/// # Returns
+///
+/// The score of the match, or [None] if the name doesn't match.
+fn fuzzy_score(name: &str, query: &[char]) -> Option<i32> {
+    let chars: Vec<char> = name.chars().map(fold).collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+    for c in query {
+        let index = next + chars[next..].iter().position(|other| other == c)?;
+        score += 1;
+        if previous.is_some_and(|previous| previous + 1 == index) {
+            score += 4;
+        }
+        if index == 0 || !chars[index - 1].is_alphanumeric() {
+            score += 2;
+        }
+        previous = Some(index);
+        next = index + 1;
+    }
+    Some(score)
+}
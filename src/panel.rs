@@ -0,0 +1,760 @@
+use crate::{
+    info::Translator, ActionInfo, Assignment, Bind, Conflict, ConflictKind, ConflictReport, Hotkey,
+    Hotkeys, ImportReport, Keybind, KeybindList, Shortcut, Shortcuts, Snapshot, GLOBAL_SCOPE,
+};
+use egui::{
+    os::OperatingSystem, text::LayoutJob, vec2, Align, Button, CollapsingHeader, FontSelection,
+    Grid, Id, ModifierNames, Response, RichText, ScrollArea, TextEdit, Ui, WidgetText,
+};
+use std::{collections::HashMap, ops::Range, sync::Arc};
+
+/// A ready-made settings page for rebinding all actions in [Hotkeys].
+///
+/// Shows a striped, scrollable grid with a row for each action: its name
+/// and icon, with its description on hover (see [`Hotkeys::set_info`]),
+/// with a dot if it's modified (see [`Hotkeys::modified`]), a [Keybind] for
+/// its current shortcut, a [KeybindList] for its alternate shortcuts, with
+/// the `gamepad` feature a picker for its gamepad button, a button to reset
+/// it that is only shown if it's modified, and a warning if it conflicts
+/// with another action (see [`Hotkeys::conflicts`]). How often it was used
+/// can be shown as well, see [`KeybindsPanel::with_usage`].
+///
+/// If any actions have a category (see [`Hotkeys::register_in`]), each
+/// category gets a collapsible section, and the actions without a category
+/// are shown under "Other".
+///
+/// Shortcuts recorded with the [Keybind] or the [KeybindList] are set with
+/// [`Hotkeys::assign_binding`], so conflicts are resolved with the
+/// [ConflictPolicy](crate::ConflictPolicy) of the [Hotkeys]. The result is in
+/// [`KeybindsPanelOutput::assignment`], e.g. to show it to the user. All
+/// changes are recorded in the history of the [Hotkeys], see
+/// [`Hotkeys::undo`].
+///
+/// A search field above the grid narrows the rows down to the actions whose
+/// name, ID or shortcut contain the search text, e.g. `"ctrl+k"` finds all
+/// actions bound to Ctrl+K. Case and the diacritics of common Latin letters
+/// are ignored. The search text is kept in [egui]'s memory.
+///
+/// Only the rows that are scrolled into view are built, so the panel stays
+/// fast with hundreds of actions. The other rows take the space they took
+/// when they were last shown. A [Keybind] that is recording keeps recording
+/// while its row is scrolled out of view, and records the keys that are
+/// pressed after it's scrolled back. The conflicts are only found again
+/// when a shortcut changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_keybind::{Hotkeys, KeybindsPanel};
+///
+/// let mut hotkeys = Hotkeys::new();
+/// hotkeys
+///     .register("save", "Ctrl+S".parse().unwrap())
+///     .register("find", "Ctrl+F".parse().unwrap());
+///
+/// let output = KeybindsPanel::new(&mut hotkeys, "keybinds")
+///     .with_display_names(|id| id.to_uppercase())
+///     .show(ui);
+/// if let Some(id) = output.changed {
+///     // save the hotkeys
+/// }
+/// if let Some(assignment) = output.assignment {
+///     // e.g. show "Moved Ctrl+S from save to find"
+/// }
+/// # });
+/// ```
+///
+/// Searching:
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, RawInput};
+/// use egui_keybind::{Hotkeys, KeybindsPanel};
+///
+/// // type into the search field, and get the texts the panel shows on the frame after that
+/// let search = |text: &str| {
+///     let mut hotkeys = Hotkeys::new();
+///     hotkeys
+///         .register("save", "Ctrl+S".parse().unwrap())
+///         .register("coffee", "Ctrl+K".parse().unwrap())
+///         .register("find", "Ctrl+F".parse().unwrap());
+///     let ctx = egui::Context::default();
+///     let mut frame = |events: Vec<Event>| {
+///         let input = RawInput {
+///             events,
+///             ..Default::default()
+///         };
+///         let output = ctx.run(input, |ctx| {
+///             egui::CentralPanel::default().show(ctx, |ui| {
+///                 KeybindsPanel::new(&mut hotkeys, "keybinds")
+///                     .with_display_names(|id| match id {
+///                         "coffee" => "Make a café".to_string(),
+///                         id => id.to_uppercase(),
+///                     })
+///                     .show(ui);
+///             });
+///         });
+///         let texts = output.shapes.into_iter().filter_map(|shape| match shape.shape {
+///             egui::Shape::Text(text) => Some((text.galley.text().to_string(), text.pos)),
+///             _ => None,
+///         });
+///         texts.collect::<Vec<_>>()
+///     };
+///     let texts = frame(vec![]);
+///     let (_, pos) = texts.iter().find(|(text, _)| text.starts_with('🔍')).unwrap();
+///     let click = |pressed| Event::PointerButton {
+///         pos: *pos,
+///         button: PointerButton::Primary,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     };
+///     frame(vec![Event::PointerMoved(*pos), click(true), click(false)]);
+///     frame(vec![Event::Text(text.to_string())]);
+///     // the search text is kept from frame to frame
+///     let texts = frame(vec![]);
+///     texts.into_iter().map(|(text, _)| text).collect::<Vec<_>>()
+/// };
+/// let shows = |texts: &[String], text: &str| texts.iter().any(|shown| shown == text);
+///
+/// // case and diacritics are ignored
+/// let texts = search("CAFE");
+/// assert!(shows(&texts, "Make a café"));
+/// assert!(!shows(&texts, "SAVE") && !shows(&texts, "FIND"));
+///
+/// // shortcuts are searched as well
+/// let texts = search("ctrl+f");
+/// assert!(shows(&texts, "FIND"));
+/// assert!(!shows(&texts, "SAVE") && !shows(&texts, "Make a café"));
+///
+/// let texts = search("print");
+/// assert!(shows(&texts, "No actions match \"print\""));
+/// assert!(!shows(&texts, "SAVE"));
+/// ```
+pub struct KeybindsPanel<'a> {
+    hotkeys: &'a mut Hotkeys,
+    id: Id,
+    modifier_names: &'a ModifierNames<'a>,
+    display_names: Option<DisplayNames<'a>>,
+    show_scopes: bool,
+    show_namespaces: bool,
+    show_profiles: bool,
+    show_usage: bool,
+    show_clipboard: bool,
+}
+
+/// Gets the name to show for an action from its ID.
+pub(crate) type DisplayNames<'a> = Box<dyn Fn(&str) -> String + 'a>;
+
+/// The output of [`KeybindsPanel::show`].
+pub struct KeybindsPanelOutput {
+    /// The response of the whole panel. Marked as changed if any action was
+    /// changed or reset.
+    pub response: Response,
+    /// The ID of the action that was changed or reset on this frame, if any.
+    pub changed: Option<String>,
+    /// What happened when a shortcut was recorded on this frame, if any,
+    /// see [`Hotkeys::assign`].
+    pub assignment: Option<Assignment>,
+    /// The profile that was switched to on this frame, if any, see
+    /// [`KeybindsPanel::with_profiles`].
+    pub profile: Option<String>,
+    /// What was imported when shortcuts were pasted on this frame, if any,
+    /// see [`KeybindsPanel::with_clipboard`].
+    pub imported: Option<ImportReport>,
+}
+
+impl<'a> KeybindsPanel<'a> {
+    /// Create a new [KeybindsPanel] for the given [Hotkeys].
+    ///
+    /// # Arguments
+    ///
+    /// * `hotkeys` - The actions to rebind.
+    /// * `id` - ID for the [KeybindsPanel] in [egui]'s memory.
+    pub fn new(hotkeys: &'a mut Hotkeys, id: impl Into<Id>) -> Self {
+        Self {
+            hotkeys,
+            id: id.into(),
+            modifier_names: &ModifierNames::NAMES,
+            display_names: None,
+            show_scopes: false,
+            show_namespaces: false,
+            show_profiles: false,
+            show_usage: false,
+            show_clipboard: false,
+        }
+    }
+
+    /// Set the modifier names to use for the keybinds. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+
+    /// Set a function that gets the name to show for an action or category
+    /// from its ID, e.g. to translate it. By default the name of
+    /// [`Hotkeys::display_name`] is shown for actions, and the category
+    /// itself, translated with [`Hotkeys::translate`], for categories.
+    pub fn with_display_names(mut self, display_names: impl Fn(&str) -> String + 'a) -> Self {
+        self.display_names = Some(Box::new(display_names));
+        self
+    }
+
+    /// Show the scope of each action next to its name, unless it's
+    /// [GLOBAL_SCOPE] (see [`Hotkeys::register_scoped`]). Scope names are
+    /// also passed through [`KeybindsPanel::with_display_names`]. By default
+    /// scopes are not shown.
+    pub fn with_scopes(mut self, show_scopes: bool) -> Self {
+        self.show_scopes = show_scopes;
+        self
+    }
+
+    /// Show the namespace of each action next to its name, if it has one
+    /// (see [`Hotkeys::namespace`]), and name the action by its short ID
+    /// instead of its full ID if there are no display names. Namespaces are
+    /// also passed through [`KeybindsPanel::with_display_names`]. By default
+    /// namespaces are not shown.
+    pub fn with_namespaces(mut self, show_namespaces: bool) -> Self {
+        self.show_namespaces = show_namespaces;
+        self
+    }
+
+    /// Show a dropdown above the actions to switch the profile (see
+    /// [`Hotkeys::switch_profile`]), with buttons to duplicate the active
+    /// profile and to delete it. The actions can't be changed while a preset
+    /// is active. By default the profiles are not shown.
+    pub fn with_profiles(mut self, show_profiles: bool) -> Self {
+        self.show_profiles = show_profiles;
+        self
+    }
+
+    /// Show how many times each action was used in an extra column, if the
+    /// actions are counted (see [`Hotkeys::set_stats_enabled`]). By default
+    /// the counts are not shown.
+    pub fn with_usage(mut self, show_usage: bool) -> Self {
+        self.show_usage = show_usage;
+        self
+    }
+
+    /// Show buttons above the actions to copy all shortcuts to the
+    /// clipboard (see [`Hotkeys::copy_to_clipboard`]) and to paste shortcuts
+    /// into a dialog, e.g. ones a colleague copied. Pasted shortcuts are
+    /// only applied if every line is fine (see [`Hotkeys::apply_from_text`]),
+    /// otherwise the dialog shows what's wrong with each line. By default the
+    /// buttons are not shown.
+    pub fn with_clipboard(mut self, show_clipboard: bool) -> Self {
+        self.show_clipboard = show_clipboard;
+        self
+    }
+
+    /// Show the panel.
+    pub fn show(self, ui: &mut Ui) -> KeybindsPanelOutput {
+        let Self {
+            hotkeys,
+            id: panel_id,
+            modifier_names,
+            display_names,
+            show_scopes,
+            show_namespaces,
+            show_profiles,
+            show_usage,
+            show_clipboard,
+        } = self;
+        let usage = (show_usage && hotkeys.is_stats_enabled()).then(|| {
+            hotkeys
+                .stats()
+                .into_iter()
+                .map(|(id, stats)| (id.to_string(), stats.count()))
+                .collect()
+        });
+        let profile = show_profiles
+            .then(|| crate::profile::profile_bar(ui, hotkeys, panel_id.with("profile")))
+            .flatten();
+        let imported = show_clipboard
+            .then(|| crate::keymap::clipboard_bar(ui, hotkeys, panel_id.with("clipboard")))
+            .flatten();
+        let read_only = hotkeys.is_profile_read_only(hotkeys.active_profile());
+        let conflicts = cached_conflicts(ui, panel_id, hotkeys);
+        let conflict_names = conflicts
+            .iter()
+            .flat_map(Conflict::ids)
+            .map(|id| (id.clone(), hotkeys.display_name(id)))
+            .collect();
+        let rows = Rows {
+            panel_id,
+            modifier_names,
+            display_names,
+            translator: hotkeys.translator.clone(),
+            conflict_names,
+            show_scopes,
+            show_namespaces,
+            usage,
+            is_mac: ui.ctx().os() == OperatingSystem::Mac,
+        };
+        let categories: Vec<Option<String>> = hotkeys
+            .categories()
+            .into_iter()
+            .map(|category| category.map(str::to_string))
+            .collect();
+        let mut changed = None;
+        let mut recorded: Option<(String, usize, Shortcut)> = None;
+        let mut edited: Option<(String, Snapshot)> = None;
+
+        let filter_id = panel_id.with("filter");
+        let mut filter = ui
+            .ctx()
+            .memory(|memory| memory.data.get_temp::<String>(filter_id))
+            .unwrap_or_default();
+        // the height of each row when it was last shown, to skip the rows that aren't visible.
+        // it's taken out of memory instead of cloned, and put back after the rows
+        let heights_id = panel_id.with("row_heights");
+        let mut heights = ui
+            .ctx()
+            .memory_mut(|memory| memory.data.remove_temp::<RowHeights>(heights_id))
+            .unwrap_or_default();
+
+        let mut response = ui
+            .scope(|ui| {
+                ui.add(
+                    TextEdit::singleline(&mut filter)
+                        .hint_text("🔍 Search actions or shortcuts")
+                        .desired_width(f32::INFINITY),
+                );
+                let folded: Vec<char> = filter.trim().chars().map(fold).collect();
+                if read_only {
+                    ui.disable();
+                }
+
+                let mut shown: Vec<&mut Hotkey> = hotkeys
+                    .iter_all_mut()
+                    .filter(|hotkey| rows.matches(hotkey, &folded))
+                    .collect();
+                if shown.is_empty() {
+                    ui.weak(format!("No actions match \"{}\"", filter.trim()));
+                    return;
+                }
+
+                let mut grid = |ui: &mut Ui, grid_id: Id, group: Vec<&mut Hotkey>| {
+                    Grid::new(grid_id)
+                        .num_columns(
+                            5 + usize::from(cfg!(feature = "gamepad"))
+                                + usize::from(rows.usage.is_some()),
+                        )
+                        .striped(true)
+                        .show(ui, |ui| {
+                            let default_height =
+                                ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+                            let clip = ui.clip_rect().y_range();
+                            // the height and number of the rows that aren't visible, which
+                            // take their space at once before the next visible row
+                            let mut skipped = (0.0, 0);
+                            for hotkey in group {
+                                let top = ui.cursor().top() + skipped.0;
+                                let height = heights.get(hotkey.id()).copied();
+                                let bottom = top + height.unwrap_or(default_height);
+                                if !clip.intersects((top..=bottom).into()) {
+                                    skipped = (skipped.0 + bottom - top, skipped.1 + 1);
+                                    continue;
+                                }
+                                skip_rows(ui, std::mem::take(&mut skipped));
+                                let change = rows.show(ui, hotkey, &conflicts, &folded);
+                                ui.end_row();
+                                let measured = ui.cursor().top() - top;
+                                if height != Some(measured) {
+                                    heights.insert(hotkey.id().to_string(), measured);
+                                }
+                                match change {
+                                    Some(RowChange::Edited(old)) => {
+                                        changed = Some(hotkey.id().to_string());
+                                        edited = Some((hotkey.id().to_string(), old));
+                                    }
+                                    Some(RowChange::Recorded(index, shortcut)) => {
+                                        let id = hotkey.id().to_string();
+                                        recorded = Some((id, index, shortcut));
+                                    }
+                                    None => {}
+                                }
+                            }
+                            skip_rows(ui, skipped);
+                        });
+                };
+                ScrollArea::vertical().id_salt(panel_id).show(ui, |ui| {
+                    if categories.iter().all(Option::is_none) {
+                        grid(ui, panel_id.with("grid"), shown);
+                        return;
+                    }
+                    for category in &categories {
+                        let (group, rest) = shown
+                            .into_iter()
+                            .partition(|hotkey| hotkey.category() == category.as_deref());
+                        shown = rest;
+                        if group.is_empty() {
+                            continue;
+                        }
+                        let category_id = panel_id.with(("category", category));
+                        let name = category
+                            .as_deref()
+                            .map_or_else(|| "Other".to_string(), |category| rows.name(category));
+                        CollapsingHeader::new(name)
+                            .id_salt(category_id)
+                            .default_open(true)
+                            .show(ui, |ui| grid(ui, category_id.with("grid"), group));
+                    }
+                });
+            })
+            .response;
+
+        ui.ctx().memory_mut(|memory| {
+            memory.data.insert_temp(filter_id, filter);
+            memory.data.insert_temp(heights_id, heights);
+        });
+        if let Some((id, old)) = edited {
+            hotkeys.record_edit(&id, old);
+        }
+        let assignment =
+            recorded.and_then(|(id, index, shortcut)| hotkeys.assign_binding(&id, index, shortcut));
+        if let Some(assignment) = assignment
+            .as_ref()
+            .filter(|assignment| assignment.is_changed())
+        {
+            changed = Some(assignment.id().to_string());
+        }
+        if changed.is_some() || profile.is_some() || imported.is_some() {
+            response.mark_changed();
+        }
+        #[cfg(feature = "serde")]
+        hotkeys.auto_store(ui.ctx());
+        KeybindsPanelOutput {
+            response,
+            changed,
+            assignment,
+            profile,
+            imported,
+        }
+    }
+}
+
+/// The height of the row of each action of a [KeybindsPanel] when it was last
+/// shown, including the spacing below it.
+type RowHeights = HashMap<String, f32>;
+
+/// Take the space of `rows` rows of a grid with a total `height` without
+/// building them. They're one row, or two if there's an even number of them,
+/// so the stripes of the rows after them stay the same.
+fn skip_rows(ui: &mut Ui, (height, rows): (f32, usize)) {
+    if rows == 0 {
+        return;
+    }
+    let parts = 2 - rows % 2;
+    let spacing = ui.spacing().item_spacing.y;
+    for _ in 0..parts {
+        ui.allocate_space(vec2(0.0, height / parts as f32 - spacing));
+        ui.end_row();
+    }
+}
+
+/// Get the conflicts of the actions, from egui's memory if no shortcut
+/// changed since they were last found.
+fn cached_conflicts(ui: &Ui, panel_id: Id, hotkeys: &Hotkeys) -> Arc<ConflictReport> {
+    let id = panel_id.with("conflicts");
+    let key = hotkeys.conflicts_key();
+    let cached = ui
+        .ctx()
+        .memory(|memory| memory.data.get_temp::<(u64, Arc<ConflictReport>)>(id));
+    match cached {
+        Some((cached_key, conflicts)) if cached_key == key => conflicts,
+        _ => {
+            let conflicts = Arc::new(hotkeys.conflicts());
+            ui.ctx()
+                .memory_mut(|memory| memory.data.insert_temp(id, (key, conflicts.clone())));
+            conflicts
+        }
+    }
+}
+
+/// How a row of a [KeybindsPanel] was changed.
+enum RowChange {
+    /// An alternate shortcut was added or removed, or the action was reset.
+    /// Holds the shortcuts from before, to record the change in the history.
+    Edited(Snapshot),
+    /// A shortcut was recorded with a [Keybind], to be assigned with
+    /// [`Hotkeys::assign_binding`] at its index once the rows are shown.
+    Recorded(usize, Shortcut),
+}
+
+/// How the rows of a [KeybindsPanel] are shown.
+struct Rows<'a> {
+    panel_id: Id,
+    modifier_names: &'a ModifierNames<'a>,
+    display_names: Option<DisplayNames<'a>>,
+    translator: Translator,
+    /// The names of the actions that conflict with others.
+    conflict_names: HashMap<String, String>,
+    show_scopes: bool,
+    show_namespaces: bool,
+    /// How many times each action was triggered, if shown.
+    usage: Option<HashMap<String, u64>>,
+    is_mac: bool,
+}
+
+impl Rows<'_> {
+    /// Get the name to show for a category, scope or namespace.
+    fn name(&self, id: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(id),
+            None => self.translator.translate(id),
+        }
+    }
+
+    /// Get the name to show for the row of an action.
+    fn action_name(&self, hotkey: &Hotkey) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(hotkey.id()),
+            None if self.show_namespaces && hotkey.info().is_none() => {
+                hotkey.short_id().to_string()
+            }
+            None => self.translator.name(hotkey),
+        }
+    }
+
+    /// Get the name to show for an action that conflicts with another.
+    fn conflict_name(&self, id: &str) -> String {
+        match (&self.display_names, self.conflict_names.get(id)) {
+            (Some(display_names), _) => display_names(id),
+            (None, Some(name)) => name.clone(),
+            (None, None) => id.to_string(),
+        }
+    }
+
+    /// Whether the name, ID or shortcut of an action contain the folded
+    /// filter. Everything matches an empty filter.
+    fn matches(&self, hotkey: &Hotkey, filter: &[char]) -> bool {
+        if filter.is_empty() {
+            return true;
+        }
+        let names = [self.action_name(hotkey), hotkey.id().to_string()];
+        let shortcuts = hotkey.bindings().flat_map(|shortcut| {
+            [
+                shortcut.to_string(),
+                Bind::format(shortcut, self.modifier_names, self.is_mac),
+            ]
+        });
+        names
+            .into_iter()
+            .chain(shortcuts)
+            .any(|text| find_folded(&text, filter).is_some())
+    }
+
+    /// Show the row of an action, with the part of its name that matches the
+    /// filter highlighted. Returns how the action was changed, if at all.
+    fn show(
+        &self,
+        ui: &mut Ui,
+        hotkey: &mut Hotkey,
+        conflicts: &ConflictReport,
+        filter: &[char],
+    ) -> Option<RowChange> {
+        let id = hotkey.id().to_string();
+        let name = self.action_name(hotkey);
+        ui.horizontal(|ui| {
+            if let Some(icon) = hotkey.info().and_then(ActionInfo::icon) {
+                ui.label(icon.to_string());
+            }
+            let label = ui.label(highlight(ui, &name, filter));
+            if let Some(description) = self.translator.description(hotkey) {
+                label.on_hover_text(description);
+            }
+            if hotkey.is_modified() {
+                let default = Bind::format(hotkey.default_bind(), self.modifier_names, self.is_mac);
+                ui.label(RichText::new("•").color(ui.visuals().selection.stroke.color))
+                    .on_hover_text(format!("Modified, the default is {default}"));
+            }
+            if self.show_scopes && hotkey.scope() != GLOBAL_SCOPE {
+                ui.label(RichText::new(self.name(hotkey.scope())).small().weak());
+            }
+            if let Some(namespace) = hotkey.namespace().filter(|_| self.show_namespaces) {
+                ui.label(RichText::new(self.name(namespace)).small().weak());
+            }
+        });
+
+        let old = hotkey.snapshot();
+        let mut current = *hotkey.current();
+        let keybind = Keybind::new(&mut current, self.panel_id.with(&id))
+            .with_modifier_names(self.modifier_names);
+        let mut recorded = ui.add(keybind).changed().then_some((0, current));
+        let mut changed = false;
+        // recorded alternates are assigned like the current shortcut, other edits are kept
+        let mut alternates = hotkey.alternates().clone();
+        let list = KeybindList::new(&mut alternates, self.panel_id.with((&id, "alternates")))
+            .with_modifier_names(self.modifier_names);
+        if ui.add(list).changed() {
+            match recorded_index(hotkey.alternates(), &alternates) {
+                Some(index) => recorded = Some((index + 1, alternates.as_slice()[index])),
+                None => {
+                    *hotkey.alternates_mut() = alternates;
+                    changed = true;
+                }
+            }
+        }
+        #[cfg(feature = "gamepad")]
+        {
+            let gamepad_id = self.panel_id.with((&id, "gamepad"));
+            changed |= crate::gamepad::gamepad_combo(ui, gamepad_id, &mut hotkey.gamepad);
+        }
+        changed |= ui
+            .add_visible(hotkey.is_modified(), Button::new("⟲"))
+            .on_hover_text("Reset to default")
+            .clicked()
+            && hotkey.reset();
+
+        match conflicts.kind_of(&id) {
+            Some(kind) => {
+                let (color, text) = match kind {
+                    ConflictKind::Duplicate => (ui.visuals().error_fg_color, "Same shortcut as"),
+                    ConflictKind::Shadowed => (ui.visuals().warn_fg_color, "Overlaps with"),
+                };
+                let others: Vec<String> = conflicts
+                    .conflicts_of(&id)
+                    .flat_map(Conflict::ids)
+                    .filter(|other| **other != id)
+                    .map(|other| self.conflict_name(other))
+                    .collect();
+                ui.colored_label(color, "⚠")
+                    .on_hover_text(format!("{text} {}", others.join(", ")));
+            }
+            None => {
+                ui.label("");
+            }
+        }
+        if let Some(usage) = &self.usage {
+            let text = match usage.get(&id).copied().unwrap_or_default() {
+                0 => "unused".to_string(),
+                1 => "used once".to_string(),
+                count => format!("used {count} times"),
+            };
+            ui.weak(text);
+        }
+        match recorded {
+            Some((index, shortcut)) => Some(RowChange::Recorded(index, shortcut)),
+            None => changed.then_some(RowChange::Edited(old)),
+        }
+    }
+}
+
+/// Get the index of the shortcut that was recorded in a [KeybindList], if
+/// the only change from `before` to `after` is a shortcut that was bound.
+fn recorded_index(before: &Shortcuts, after: &Shortcuts) -> Option<usize> {
+    if before.len() != after.len() {
+        return None;
+    }
+    let mut changed = before
+        .iter()
+        .zip(after)
+        .enumerate()
+        .filter(|(_, (before, after))| before != after);
+    match (changed.next(), changed.next()) {
+        (Some((index, (_, after))), None) if after.is_bound() => Some(index),
+        _ => None,
+    }
+}
+
+/// Highlight the part of `text` that matches the folded `filter`, if any.
+fn highlight(ui: &Ui, text: &str, filter: &[char]) -> WidgetText {
+    let Some(range) = find_folded(text, filter).filter(|range| !range.is_empty()) else {
+        return text.into();
+    };
+    let mut job = LayoutJob::default();
+    for (part, highlighted) in [
+        (&text[..range.start], false),
+        (&text[range.clone()], true),
+        (&text[range.end..], false),
+    ] {
+        let mut part = RichText::new(part);
+        if highlighted {
+            part = part.background_color(ui.visuals().selection.bg_fill);
+        }
+        part.append_to(&mut job, ui.style(), FontSelection::Default, Align::Center);
+    }
+    job.into()
+}
+
+/// Fold a character for case-insensitive matching that ignores the
+/// diacritics of common Latin letters, e.g. `'É'` becomes `'e'`.
+pub(crate) fn fold(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'à'..='å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' => 'i',
+        'ł' | 'ľ' | 'ĺ' | 'ļ' => 'l',
+        'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+        'ŕ' | 'ŗ' | 'ř' => 'r',
+        'ś' | 'ŝ' | 'ş' | 'š' => 's',
+        'ţ' | 'ť' => 't',
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        c => c,
+    }
+}
+
+/// Find the folded `filter` in `text` (see [fold]), and get the byte range
+/// of the match in `text`.
+fn find_folded(text: &str, filter: &[char]) -> Option<Range<usize>> {
+    let chars: Vec<(usize, char)> = text
+        .char_indices()
+        .map(|(index, c)| (index, fold(c)))
+        .collect();
+    if filter.is_empty() {
+        return Some(0..0);
+    }
+    let start = chars
+        .windows(filter.len())
+        .position(|window| window.iter().map(|(_, c)| c).eq(filter))?;
+    let end = chars
+        .get(start + filter.len())
+        .map_or(text.len(), |(index, _)| *index);
+    Some(chars[start].0..end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format_count;
+    use egui::{Key, KeyboardShortcut, Modifiers, Pos2, RawInput, Rect};
+
+    #[test]
+    fn only_visible_rows_are_formatted() {
+        let mut hotkeys = Hotkeys::new();
+        let modifiers = [
+            Modifiers::NONE,
+            Modifiers::CTRL,
+            Modifiers::ALT,
+            Modifiers::SHIFT,
+        ];
+        let shortcuts = modifiers.into_iter().flat_map(|modifiers| {
+            Key::ALL
+                .iter()
+                .map(move |key| KeyboardShortcut::new(modifiers, *key))
+        });
+        for (index, keyboard) in shortcuts.take(500).enumerate() {
+            hotkeys.register(
+                format!("action {index}"),
+                Shortcut::new(Some(keyboard), None),
+            );
+        }
+
+        let ctx = egui::Context::default();
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0))),
+            ..Default::default()
+        };
+        let count = format_count();
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                KeybindsPanel::new(&mut hotkeys, "keybinds").show(ui);
+            });
+        });
+        assert!(format_count() - count < 100);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::{Bind, PointerTrigger, Shortcut, TextKey};
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use std::{fmt, str::FromStr};
+
+/// An error that can occur when parsing a [Shortcut] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseShortcutError {
+    /// The string is empty.
+    Empty,
+    /// A part of the string is not a known modifier, key or pointer button.
+    UnknownName(String),
+    /// The string contains more than one key.
+    MultipleKeys,
+    /// The string contains more than one pointer button.
+    MultiplePointerButtons,
+    /// The string contains modifiers, but no key to go with them.
+    MissingKey,
+    /// A part of the string is a known key or modifier that egui can't tell
+    /// apart from others, e.g. `"capslock"`.
+    Unsupported(String),
+    /// The string has more chord steps than can be parsed, e.g. a chord
+    /// where a single shortcut is expected.
+    TooManySteps,
+    /// A part of the string could mean more than one shortcut, e.g. `"C-X"`
+    /// in Emacs notation, which could be with or without Shift.
+    Ambiguous(String),
+    /// The second step of a chord is not the one the action was registered
+    /// with, see [`Hotkeys::import_emacs_string`](crate::Hotkeys::import_emacs_string).
+    MismatchedStep(String),
+}
+
+impl fmt::Display for ParseShortcutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the shortcut is empty"),
+            Self::UnknownName(name) => write!(f, "unknown key or button \"{name}\""),
+            Self::MultipleKeys => write!(f, "the shortcut has more than one key"),
+            Self::MultiplePointerButtons => {
+                write!(f, "the shortcut has more than one pointer button")
+            }
+            Self::MissingKey => write!(f, "the shortcut has modifiers, but no key"),
+            Self::Unsupported(name) => write!(f, "\"{name}\" can't be bound in egui"),
+            Self::TooManySteps => write!(f, "the shortcut has too many chord steps"),
+            Self::Ambiguous(name) => write!(f, "\"{name}\" is ambiguous"),
+            Self::MismatchedStep(step) => {
+                write!(f, "the chord step \"{step}\" doesn't match the action")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseShortcutError {}
+
+/// Parse a modifier name, e.g. `"Ctrl"` or `"Shift"`. Case-insensitive.
+pub(crate) fn parse_modifier(name: &str) -> Option<Modifiers> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Modifiers::CTRL,
+        "shift" => Modifiers::SHIFT,
+        "alt" | "option" | "opt" => Modifiers::ALT,
+        "cmd" | "command" | "super" | "win" | "meta" => Modifiers::MAC_CMD,
+        "cmdorctrl" | "commandorcontrol" => Modifiers::COMMAND,
+        _ => return None,
+    })
+}
+
+/// Split a modifier symbol (e.g. `"⌃"`) off the start of a name.
+fn split_symbol_modifier(name: &str) -> Option<(Modifiers, &str)> {
+    let mut chars = name.chars();
+    let modifier = match chars.next()? {
+        '⌃' => Modifiers::CTRL,
+        '⇧' => Modifiers::SHIFT,
+        '⌥' => Modifiers::ALT,
+        '⌘' => Modifiers::MAC_CMD,
+        _ => return None,
+    };
+    Some((modifier, chars.as_str()))
+}
+
+/// How a comma is written as a [TextKey] in the text form of a [Shortcut]. A
+/// bare `","` separates the shortcuts of a list, e.g. `"Ctrl+Y, Ctrl+Shift+Z"`,
+/// and the comma key is written by its name, `"Comma"`.
+pub(crate) const ESCAPED_COMMA: &str = "\\,";
+
+/// Split a list of shortcuts in their text form at the commas between them,
+/// i.e. the ones that aren't escaped like [ESCAPED_COMMA].
+pub(crate) fn split_shortcut_list(list: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, c) in list.char_indices() {
+        if c == ',' && !escaped {
+            parts.push(&list[start..index]);
+            start = index + 1;
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    parts.push(&list[start..]);
+    parts
+}
+
+/// Get the character of a name that is a single printable character, e.g.
+/// `"§"`, for a [TextKey].
+fn single_char(name: &str) -> Option<char> {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if !c.is_control() && !c.is_whitespace() => Some(c),
+        _ => None,
+    }
+}
+
+/// Parse a key name, e.g. `"Escape"` or `"a"`. Case-insensitive.
+pub(crate) fn parse_key(name: &str) -> Option<Key> {
+    Key::from_name(name).or_else(|| {
+        Key::ALL
+            .iter()
+            .copied()
+            .find(|key| key.name().eq_ignore_ascii_case(name))
+    })
+}
+
+/// Parse a pointer button name, e.g. `"Middle"`, `"Mouse 4"` or
+/// `"Mouse Left"`. The names egui uses, e.g. `"Primary"` or `"Extra1"`, are
+/// accepted as well, since they were the names before. Case-insensitive.
+pub(crate) fn parse_pointer_button(name: &str) -> Option<PointerButton> {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    Some(match words.join(" ").to_ascii_lowercase().as_str() {
+        "left" | "mouse left" | "primary" => PointerButton::Primary,
+        "right" | "mouse right" | "secondary" => PointerButton::Secondary,
+        "middle" => PointerButton::Middle,
+        "mouse 4" | "mouse4" | "extra1" => PointerButton::Extra1,
+        "mouse 5" | "mouse5" | "extra2" => PointerButton::Extra2,
+        _ => return None,
+    })
+}
+
+/// Parse a pointer button name with an optional [PointerTrigger] after it,
+/// e.g. `"Middle"`, `"Right Release"` or `"Mouse 4 Release"`.
+/// Case-insensitive.
+fn parse_pointer(name: &str) -> Option<(PointerButton, PointerTrigger)> {
+    if let Some(button) = parse_pointer_button(name) {
+        return Some((button, PointerTrigger::Press));
+    }
+    // button names can have spaces too, so try every split
+    name.match_indices(char::is_whitespace)
+        .find_map(|(index, _)| {
+            let button = parse_pointer_button(&name[..index])?;
+            Some((button, PointerTrigger::from_name(name[index..].trim())?))
+        })
+}
+
+/// Parses shortcuts like `"Ctrl+Shift+S"`, `"Middle"`, `"Alt+F4+Mouse 4"`,
+/// `"Mouse Right Release"`, `"⌃⇧S"` or `"None"`, as formatted by
+/// [`Bind::format`](crate::Bind::format) and [Shortcut]'s
+/// [Display](std::fmt::Display) implementation.
+///
+/// Names are case-insensitive and modifiers can be in any order. `"Cmd"`,
+/// `"Super"`, `"Win"` and `"Meta"` are all [`Modifiers::MAC_CMD`], and
+/// `"CmdOrCtrl"` is [`Modifiers::COMMAND`]. The pointer buttons are
+/// `"Mouse Left"`, `"Mouse Right"`, `"Middle"`, `"Mouse 4"` and `"Mouse 5"`,
+/// and egui's names for them (e.g. `"Primary"` or `"Extra1"`) are read as
+/// well. `"Left"` and `"Right"` on their own, as earlier versions wrote the
+/// buttons, are the arrow keys unless they come after a key.
+///
+/// Other characters are read as a [TextKey]. A comma is written as `\\,`
+/// there, so that it doesn't end the shortcut in a list, while `"Comma"` and a
+/// bare `","` are the comma key.
+impl FromStr for Shortcut {
+    type Err = ParseShortcutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseShortcutError::Empty);
+        }
+        if s.eq_ignore_ascii_case("none") {
+            return Ok(Self::NONE);
+        }
+
+        let mut modifiers = Modifiers::NONE;
+        let mut key = None;
+        let mut pointer = None;
+        let mut trigger = PointerTrigger::Press;
+        let mut text = None;
+
+        // "Ctrl++" binds the plus key
+        let (s, plus) = match s.strip_suffix("++") {
+            Some(rest) => (rest, true),
+            None => (s, s == "+"),
+        };
+        let mut names: Vec<&str> = if s == "+" {
+            vec![]
+        } else {
+            s.split('+').map(str::trim).collect()
+        };
+        if plus {
+            names.push("Plus");
+        }
+
+        for mut name in names {
+            // symbol modifiers are not separated, e.g. "⌃⇧S"
+            let has_symbols = split_symbol_modifier(name).is_some();
+            while let Some((modifier, rest)) = split_symbol_modifier(name) {
+                modifiers = modifiers | modifier;
+                name = rest;
+            }
+
+            if has_symbols && name.is_empty() {
+                continue;
+            } else if let Some(modifier) = parse_modifier(name) {
+                modifiers = modifiers | modifier;
+            } else if let Some(parsed) =
+                parse_key(name).filter(|_| key.is_none() || parse_pointer(name).is_none())
+            {
+                if key.replace(parsed).is_some() || text.is_some() {
+                    return Err(ParseShortcutError::MultipleKeys);
+                }
+            } else if let Some((parsed, parsed_trigger)) = parse_pointer(name) {
+                if pointer.replace(parsed).is_some() {
+                    return Err(ParseShortcutError::MultiplePointerButtons);
+                }
+                trigger = parsed_trigger;
+            } else if let Some(character) =
+                single_char(name).or((name == ESCAPED_COMMA).then_some(','))
+            {
+                // keys without a `Key` are bound by the character they type
+                if text.replace(character).is_some() || key.is_some() {
+                    return Err(ParseShortcutError::MultipleKeys);
+                }
+            } else if name.is_empty() {
+                return Err(ParseShortcutError::Empty);
+            } else {
+                return Err(ParseShortcutError::UnknownName(name.to_string()));
+            }
+        }
+
+        let keyboard = match (key, text) {
+            (Some(key), _) => Some(KeyboardShortcut::new(modifiers, key)),
+            (None, Some(_)) => None,
+            (None, None) if modifiers.is_none() => None,
+            (None, None) => return Err(ParseShortcutError::MissingKey),
+        };
+        let mut shortcut = Self::new(keyboard, pointer).with_pointer_trigger(trigger);
+        if let Some(character) = text {
+            shortcut.set_text(TextKey::new(modifiers, character), pointer);
+        }
+        Ok(shortcut)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comma_round_trips() {
+        let key: Shortcut = "Ctrl+,".parse().unwrap();
+        let comma = KeyboardShortcut::new(Modifiers::CTRL, Key::Comma);
+        assert_eq!(key, Shortcut::new(Some(comma), None));
+        assert_eq!(key.to_string(), "Ctrl+Comma");
+        assert_eq!(key.to_string().parse(), Ok(key));
+
+        let mut text = Shortcut::NONE;
+        text.set_text(TextKey::new(Modifiers::CTRL, ','), None);
+        assert_eq!(text.to_string(), "Ctrl+\\,");
+        assert_eq!(text.to_string().parse(), Ok(text));
+    }
+
+    #[test]
+    fn lists_split_at_unescaped_commas() {
+        let list = "Ctrl+Comma, Ctrl+\\,, \\,";
+        assert_eq!(
+            split_shortcut_list(list),
+            ["Ctrl+Comma", " Ctrl+\\,", " \\,"]
+        );
+        assert_eq!(split_shortcut_list("A"), ["A"]);
+        assert_eq!(split_shortcut_list(""), [""]);
+    }
+}
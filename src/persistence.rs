@@ -0,0 +1,160 @@
+use crate::Hotkeys;
+use egui::{Context, Id};
+
+/// Where [Hotkeys] are stored in [egui]'s memory, see [`Hotkeys::load_or_default`].
+#[derive(Debug, Clone)]
+pub(crate) struct Persistence {
+    id: Id,
+    auto_store: bool,
+    /// The shortcuts that were stored last, so they are only stored again
+    /// when they change.
+    stored: Vec<(String, String)>,
+    /// The [`Hotkeys::generation`] when the shortcuts were last stored or
+    /// compared, so they aren't compared again until something changed.
+    generation: u64,
+}
+
+impl Hotkeys {
+    /// Create [Hotkeys] that are saved in [egui]'s persisted memory, so
+    /// with eframe's `persistence` feature they survive restarts of the app.
+    ///
+    /// The actions are registered by `build_defaults`, then the shortcuts
+    /// stored under `id` are loaded into them like with
+    /// [`Hotkeys::load_saved`]: actions that were added since keep their
+    /// default, and stored shortcuts of actions that were removed are kept.
+    ///
+    /// The shortcuts are stored again whenever they changed and
+    /// [`Hotkeys::triggered`] or [`KeybindsPanel::show`](crate::KeybindsPanel::show)
+    /// is called, unless that is turned off with [`Hotkeys::set_auto_store`].
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The [Context] to load the shortcuts from.
+    /// * `id` - ID for the shortcuts in [egui]'s memory.
+    /// * `build_defaults` - Creates the [Hotkeys] with all actions registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let defaults = || {
+    ///     let mut hotkeys = Hotkeys::new();
+    ///     hotkeys
+    ///         .register("save", "Ctrl+S".parse().unwrap())
+    ///         .register("find", "Ctrl+F".parse().unwrap());
+    ///     hotkeys
+    /// };
+    ///
+    /// let ctx = egui::Context::default();
+    /// let mut hotkeys = Hotkeys::load_or_default(&ctx, "hotkeys", defaults);
+    /// let mut frame = |hotkeys: &mut Hotkeys| {
+    ///     let _ = ctx.run(Default::default(), |ctx| {
+    ///         hotkeys.triggered(ctx);
+    ///     });
+    /// };
+    /// let stored = || {
+    ///     let id = egui::Id::new("hotkeys");
+    ///     ctx.data_mut(|data| data.get_persisted::<Vec<(String, String)>>(id))
+    /// };
+    ///
+    /// // nothing is stored while nothing changes
+    /// frame(&mut hotkeys);
+    /// assert_eq!(stored(), None);
+    /// hotkeys.set("save", "Ctrl+Shift+S".parse().unwrap());
+    /// frame(&mut hotkeys);
+    /// assert!(stored().is_some());
+    ///
+    /// // e.g. after restarting the app
+    /// let hotkeys = Hotkeys::load_or_default(&ctx, "hotkeys", defaults);
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+    /// assert_eq!(hotkeys.current("find").unwrap().to_string(), "Ctrl+F");
+    /// ```
+    pub fn load_or_default(
+        ctx: &Context,
+        id: impl Into<Id>,
+        build_defaults: impl FnOnce() -> Self,
+    ) -> Self {
+        let id = id.into();
+        let mut hotkeys = build_defaults();
+        let saved = ctx.data_mut(|data| data.get_persisted::<Vec<(String, String)>>(id));
+        if let Some(saved) = saved {
+            hotkeys.load_saved(saved);
+        }
+        hotkeys.persistence = Some(Persistence {
+            id,
+            auto_store: true,
+            stored: hotkeys.to_saved(),
+            generation: hotkeys.generation,
+        });
+        hotkeys
+    }
+
+    /// Set whether the shortcuts are stored automatically when they change,
+    /// see [`Hotkeys::load_or_default`]. When this is off, call
+    /// [`Hotkeys::store`] to store them. By default this is on.
+    pub fn set_auto_store(&mut self, auto_store: bool) {
+        if let Some(persistence) = &mut self.persistence {
+            persistence.auto_store = auto_store;
+        }
+    }
+
+    /// Store the shortcuts in [egui]'s persisted memory, e.g. when the user
+    /// clicks "Apply". Does nothing if the [Hotkeys] weren't created with
+    /// [`Hotkeys::load_or_default`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let ctx = egui::Context::default();
+    /// let defaults = || {
+    ///     let mut hotkeys = Hotkeys::new();
+    ///     hotkeys.register("save", "Ctrl+S".parse().unwrap());
+    ///     hotkeys
+    /// };
+    /// let mut hotkeys = Hotkeys::load_or_default(&ctx, "hotkeys", defaults);
+    /// hotkeys.set_auto_store(false);
+    ///
+    /// hotkeys.set("save", "F2".parse().unwrap());
+    /// let _ = ctx.run(Default::default(), |ctx| {
+    ///     hotkeys.triggered(ctx);
+    /// });
+    /// let loaded = Hotkeys::load_or_default(&ctx, "hotkeys", defaults);
+    /// assert_eq!(loaded.current("save").unwrap().to_string(), "Ctrl+S");
+    ///
+    /// hotkeys.store(&ctx);
+    /// let loaded = Hotkeys::load_or_default(&ctx, "hotkeys", defaults);
+    /// assert_eq!(loaded.current("save").unwrap().to_string(), "F2");
+    /// ```
+    pub fn store(&mut self, ctx: &Context) {
+        let saved = self.to_saved();
+        if let Some(persistence) = &mut self.persistence {
+            ctx.data_mut(|data| data.insert_persisted(persistence.id, saved.clone()));
+            persistence.stored = saved;
+            persistence.generation = self.generation;
+        }
+    }
+
+    /// Store the shortcuts if they changed and are stored automatically.
+    /// Nothing is compared on frames where nothing was changed.
+    pub(crate) fn auto_store(&mut self, ctx: &Context) {
+        let generation = self.generation;
+        let Some(persistence) = &mut self.persistence else {
+            return;
+        };
+        if !persistence.auto_store || persistence.generation == generation {
+            return;
+        }
+        // something may have changed, but it may also have been changed back
+        persistence.generation = generation;
+        let saved = self.to_saved();
+        if let Some(persistence) = &mut self.persistence {
+            if persistence.stored != saved {
+                ctx.data_mut(|data| data.insert_persisted(persistence.id, saved.clone()));
+                persistence.stored = saved;
+            }
+        }
+    }
+}
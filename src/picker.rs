@@ -0,0 +1,358 @@
+use crate::{Bind, KeyNameMap};
+use egui::{
+    os::OperatingSystem, popup::PopupCloseBehavior, Button, ComboBox, Id, Key, KeyboardShortcut,
+    ModifierNames, Modifiers, PointerButton, Response, RichText, ScrollArea, TextEdit, Ui, Widget,
+};
+
+/// A named group of keys in a [KeybindPicker], and a function that tells
+/// whether a key belongs to it.
+type KeyGroup = (&'static str, fn(Key) -> bool);
+
+/// The groups of keys shown in a [KeybindPicker], in order.
+const KEY_GROUPS: [KeyGroup; 6] = [
+    ("Letters", is_letter),
+    ("Digits", is_digit),
+    ("Function keys", is_function_key),
+    ("Navigation", is_navigation_key),
+    ("Editing", is_editing_key),
+    ("Punctuation", is_punctuation),
+];
+
+fn is_letter(key: Key) -> bool {
+    let name = key.name();
+    name.len() == 1 && name.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_digit(key: Key) -> bool {
+    let name = key.name();
+    name.len() == 1 && name.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_function_key(key: Key) -> bool {
+    key.name()
+        .strip_prefix('F')
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_navigation_key(key: Key) -> bool {
+    matches!(
+        key,
+        Key::ArrowDown
+            | Key::ArrowLeft
+            | Key::ArrowRight
+            | Key::ArrowUp
+            | Key::Home
+            | Key::End
+            | Key::PageUp
+            | Key::PageDown
+    )
+}
+
+fn is_editing_key(key: Key) -> bool {
+    matches!(
+        key,
+        Key::Escape
+            | Key::Tab
+            | Key::Backspace
+            | Key::Enter
+            | Key::Space
+            | Key::Insert
+            | Key::Delete
+            | Key::Copy
+            | Key::Cut
+            | Key::Paste
+    )
+}
+
+fn is_punctuation(key: Key) -> bool {
+    !(is_letter(key)
+        || is_digit(key)
+        || is_function_key(key)
+        || is_navigation_key(key)
+        || is_editing_key(key))
+}
+
+/// A widget for choosing a keyboard shortcut from a list, as an alternative
+/// to pressing it. Useful on the web and on touch devices, where pressing
+/// the key you want is often impossible.
+///
+/// Clicking the button opens a popup with modifier checkboxes and a
+/// searchable list of all keys. Choosing a key writes the combination into
+/// the bind with [`Bind::set`], exactly like pressing it in a
+/// [Keybind](crate::Keybind) would, and closes the popup. Escape closes the
+/// popup without changing the bind.
+pub struct KeybindPicker<'a, B: Bind> {
+    bind: &'a mut B,
+    id: Id,
+    modifier_names: &'a ModifierNames<'a>,
+    key_names: Option<&'a KeyNameMap>,
+}
+
+impl<'a, B: Bind> KeybindPicker<'a, B> {
+    /// Create a new [KeybindPicker].
+    ///
+    /// # Arguments
+    ///
+    /// * `bind` - The bind to write the chosen shortcut into.
+    /// * `id` - ID for the [KeybindPicker] in [egui]'s memory.
+    pub fn new(bind: &'a mut B, id: impl Into<Id>) -> Self {
+        Self {
+            bind,
+            id: id.into(),
+            modifier_names: &ModifierNames::NAMES,
+            key_names: None,
+        }
+    }
+
+    /// Set the modifier names to use. By default this is [`ModifierNames::NAMES`].
+    pub fn with_modifier_names(mut self, modifier_names: &'a ModifierNames<'a>) -> Self {
+        self.modifier_names = modifier_names;
+        self
+    }
+
+    /// Set the table of key and pointer button names to display. Keys are
+    /// searchable by these names as well as their English names. By default
+    /// this is [None], which uses the English names.
+    pub fn with_key_names(mut self, key_names: Option<&'a KeyNameMap>) -> Self {
+        self.key_names = key_names;
+        self
+    }
+
+    fn key_name(&self, key: Key) -> &str {
+        match self.key_names {
+            Some(key_names) => key_names.key_name(key, true),
+            None => key.symbol_or_name(),
+        }
+    }
+}
+
+/// State of an open [KeybindPicker] popup in egui's memory.
+#[derive(Clone, Default)]
+struct PickerState {
+    modifiers: Modifiers,
+    search: String,
+}
+
+/// Make the modifiers the same as egui reports for a physical key press,
+/// where `command` mirrors Ctrl, or Cmd on Mac.
+pub(crate) fn capture_modifiers(mut modifiers: Modifiers, is_mac: bool) -> Modifiers {
+    modifiers.command = if is_mac {
+        modifiers.mac_cmd
+    } else {
+        modifiers.ctrl
+    };
+    modifiers
+}
+
+impl<'a, B: Bind> Widget for KeybindPicker<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let popup_id = ui.make_persistent_id(self.id);
+        let state_id = popup_id.with("state");
+        let is_mac = ui.ctx().os() == OperatingSystem::Mac;
+
+        let text = match self.key_names {
+            Some(key_names) => {
+                self.bind
+                    .format_with_key_names(self.modifier_names, key_names, false)
+            }
+            None => self.bind.format(self.modifier_names, false),
+        };
+        let mut response =
+            ui.add(Button::new(format!("{text} ⏷")).min_size(ui.spacing().interact_size));
+
+        let mut opened = false;
+        if response.clicked() {
+            if !ui.memory(|memory| memory.is_popup_open(popup_id)) {
+                opened = true;
+                // start from the modifiers of the current bind
+                let state = PickerState {
+                    modifiers: self
+                        .bind
+                        .keyboard()
+                        .map_or(Modifiers::NONE, |kb| kb.modifiers),
+                    search: String::new(),
+                };
+                ui.memory_mut(|memory| memory.data.insert_temp(state_id, state));
+            }
+            ui.memory_mut(|memory| memory.toggle_popup(popup_id));
+        }
+
+        let mut chosen = None;
+        let mut close = false;
+        egui::popup_below_widget(
+            ui,
+            popup_id,
+            &response,
+            PopupCloseBehavior::CloseOnClickOutside,
+            |ui| {
+                ui.set_min_width(240.0);
+                let mut state = ui
+                    .memory(|memory| memory.data.get_temp::<PickerState>(state_id))
+                    .unwrap_or_default();
+
+                let names = self.modifier_names;
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut state.modifiers.ctrl, names.ctrl);
+                    ui.checkbox(&mut state.modifiers.shift, names.shift);
+                    ui.checkbox(
+                        &mut state.modifiers.alt,
+                        if is_mac { names.mac_alt } else { names.alt },
+                    );
+                    if is_mac {
+                        ui.checkbox(&mut state.modifiers.mac_cmd, names.mac_cmd);
+                    }
+                });
+
+                let search = ui.add(
+                    TextEdit::singleline(&mut state.search)
+                        .hint_text("Search keys")
+                        .desired_width(f32::INFINITY),
+                );
+                if opened {
+                    // typing searches right away
+                    search.request_focus();
+                }
+
+                let query = state.search.trim().to_lowercase();
+                let matches = |key: Key| {
+                    query.is_empty()
+                        || key.name().to_lowercase().contains(&query)
+                        || self.key_name(key).to_lowercase().contains(&query)
+                };
+                let current = self.bind.keyboard().map(|kb| kb.logical_key);
+
+                // enter picks the first key that matches the search
+                if search.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter)) {
+                    chosen = Key::ALL.iter().copied().find(|key| matches(*key));
+                }
+
+                ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                    for (group, in_group) in KEY_GROUPS {
+                        let keys: Vec<Key> = Key::ALL
+                            .iter()
+                            .copied()
+                            .filter(|key| in_group(*key) && matches(*key))
+                            .collect();
+                        if keys.is_empty() {
+                            continue;
+                        }
+
+                        ui.label(RichText::new(group).weak());
+                        ui.horizontal_wrapped(|ui| {
+                            for key in keys {
+                                if ui
+                                    .selectable_label(current == Some(key), self.key_name(key))
+                                    .on_hover_text(key.name())
+                                    .clicked()
+                                {
+                                    chosen = Some(key);
+                                }
+                            }
+                        });
+                    }
+                });
+
+                if ui.input(|i| i.key_pressed(Key::Escape)) {
+                    close = true;
+                }
+                ui.memory_mut(|memory| memory.data.insert_temp(state_id, state));
+            },
+        );
+
+        if let Some(key) = chosen {
+            let modifiers = ui
+                .memory(|memory| memory.data.get_temp::<PickerState>(state_id))
+                .map_or(Modifiers::NONE, |state| state.modifiers);
+            let shortcut = KeyboardShortcut::new(capture_modifiers(modifiers, is_mac), key);
+            self.bind.set(Some(shortcut), None);
+            response.mark_changed();
+            close = true;
+        }
+        if close {
+            ui.memory_mut(|memory| {
+                memory.close_popup();
+                memory.data.remove::<PickerState>(state_id);
+            });
+        }
+
+        response
+    }
+}
+
+/// All pointer buttons, in the order they are listed in a [PointerButtonPicker].
+const POINTER_BUTTONS: [PointerButton; 5] = [
+    PointerButton::Primary,
+    PointerButton::Secondary,
+    PointerButton::Middle,
+    PointerButton::Extra1,
+    PointerButton::Extra2,
+];
+
+/// A dropdown for choosing the pointer button of a bind from a list, without
+/// having to press it. Useful for configuring buttons your mouse doesn't have.
+///
+/// Only the pointer half of the bind is changed, the keyboard half is kept.
+/// Choosing "None" removes the pointer button.
+pub struct PointerButtonPicker<'a, B: Bind> {
+    bind: &'a mut B,
+    id: Id,
+    key_names: Option<&'a KeyNameMap>,
+}
+
+impl<'a, B: Bind> PointerButtonPicker<'a, B> {
+    /// Create a new [PointerButtonPicker].
+    ///
+    /// # Arguments
+    ///
+    /// * `bind` - The bind to write the chosen pointer button into.
+    /// * `id` - ID for the [PointerButtonPicker] in [egui]'s memory.
+    pub fn new(bind: &'a mut B, id: impl Into<Id>) -> Self {
+        Self {
+            bind,
+            id: id.into(),
+            key_names: None,
+        }
+    }
+
+    /// Set the table of pointer button names to display. By default this is
+    /// [None], which uses the English names.
+    pub fn with_key_names(mut self, key_names: Option<&'a KeyNameMap>) -> Self {
+        self.key_names = key_names;
+        self
+    }
+
+    fn button_name(&self, button: Option<PointerButton>) -> &str {
+        match (button, self.key_names) {
+            (None, _) => "None",
+            (Some(button), Some(key_names)) => key_names.pointer_button_name(button),
+            (Some(button), None) => crate::pointer_button_name(button),
+        }
+    }
+}
+
+impl<'a, B: Bind> Widget for PointerButtonPicker<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let current = self.bind.pointer();
+        let mut selected = current;
+
+        let mut response = ComboBox::from_id_salt(self.id)
+            .selected_text(self.button_name(current))
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut selected, None, self.button_name(None));
+                for button in POINTER_BUTTONS {
+                    ui.selectable_value(
+                        &mut selected,
+                        Some(button),
+                        self.button_name(Some(button)),
+                    );
+                }
+            })
+            .response;
+
+        if selected != current {
+            self.bind.set(self.bind.keyboard(), selected);
+            response.mark_changed();
+        }
+        response
+    }
+}
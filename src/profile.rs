@@ -0,0 +1,477 @@
+use crate::Hotkeys;
+use egui::{Button, ComboBox, Id, Ui};
+use std::fmt;
+
+/// The name of the profile [Hotkeys] start with, see [`Hotkeys::switch_profile`].
+pub const DEFAULT_PROFILE: &str = "Default";
+
+/// The prefix of the saved entries of profiles, see [`Hotkeys::to_saved`].
+const PROFILE_KEY: &str = "@profile";
+
+/// An error of managing the profiles of [Hotkeys], see [`Hotkeys::switch_profile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileError {
+    /// There is no profile with the name.
+    Unknown(String),
+    /// A profile with the name already exists.
+    Exists(String),
+    /// The name is empty or contains a `/`.
+    InvalidName(String),
+    /// The profile is [DEFAULT_PROFILE] or a preset, which can't be deleted.
+    Protected(String),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unknown(name) => write!(f, "there is no profile \"{name}\""),
+            Self::Exists(name) => write!(f, "profile \"{name}\" already exists"),
+            Self::InvalidName(name) => write!(f, "invalid profile name \"{name}\""),
+            Self::Protected(name) => write!(f, "profile \"{name}\" can't be deleted"),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+/// The profiles of [Hotkeys] and which one is active.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Profiles {
+    active: String,
+    list: Vec<Profile>,
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_PROFILE.to_string(),
+            list: vec![Profile {
+                name: DEFAULT_PROFILE.to_string(),
+                read_only: false,
+                bindings: vec![],
+            }],
+        }
+    }
+}
+
+impl Profiles {
+    fn get(&self, name: &str) -> Option<&Profile> {
+        self.list.iter().find(|profile| profile.name == name)
+    }
+
+    fn get_mut(&mut self, name: &str) -> Option<&mut Profile> {
+        self.list.iter_mut().find(|profile| profile.name == name)
+    }
+
+    /// Add a profile, or replace the one with the same name.
+    fn insert(&mut self, profile: Profile) {
+        match self.get_mut(&profile.name) {
+            Some(existing) => *existing = profile,
+            None => self.list.push(profile),
+        }
+    }
+}
+
+/// A named set of shortcuts for the registered actions.
+#[derive(Debug, Clone, PartialEq)]
+struct Profile {
+    name: String,
+    /// Whether it's a preset, see [`Hotkeys::add_preset`].
+    read_only: bool,
+    /// The saved shortcuts of the actions that differ from their defaults.
+    /// Out of date while the profile is active, unless it's read-only.
+    bindings: Vec<(String, String)>,
+}
+
+/// Check that a name can be used for a new profile.
+fn check_name(profiles: &Profiles, name: &str) -> Result<(), ProfileError> {
+    if name.is_empty() || name.contains('/') {
+        return Err(ProfileError::InvalidName(name.to_string()));
+    }
+    match profiles.get(name) {
+        Some(_) => Err(ProfileError::Exists(name.to_string())),
+        None => Ok(()),
+    }
+}
+
+impl Hotkeys {
+    /// Add a read-only profile with shortcuts that differ from the defaults
+    /// of the actions, e.g. a "Vim-style" preset of the app. Users can switch
+    /// to it with [`Hotkeys::switch_profile`], and duplicate it with
+    /// [`Hotkeys::duplicate_profile`] to change it. Changes to the shortcuts
+    /// while it's active are discarded when switching to another profile.
+    ///
+    /// Presets are not saved, so add them every time the app starts, before
+    /// loading the saved shortcuts. Adding a preset again replaces it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the preset, e.g. `"Vim-style"`.
+    /// * `bindings` - Action IDs and shortcut strings like in
+    ///   [`Hotkeys::load_saved`], for the actions that differ from their
+    ///   defaults.
+    pub fn add_preset<K, V>(
+        &mut self,
+        name: impl Into<String>,
+        bindings: impl IntoIterator<Item = (K, V)>,
+    ) -> &mut Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        let profile = Profile {
+            name: name.into(),
+            read_only: true,
+            bindings: bindings
+                .into_iter()
+                .map(|(id, shortcut)| (id.into(), shortcut.into()))
+                .collect(),
+        };
+        if profile.name == DEFAULT_PROFILE {
+            return self;
+        }
+        let active = profile.name == self.profiles.active;
+        let bindings = profile.bindings.clone();
+        self.note_change();
+        self.profiles.insert(profile);
+        if active {
+            self.apply_profile(&bindings);
+        }
+        self
+    }
+
+    /// Get the name of the active profile, whose shortcuts are the current
+    /// shortcuts of the actions. This is [DEFAULT_PROFILE] unless another
+    /// profile was switched to.
+    #[inline]
+    pub fn active_profile(&self) -> &str {
+        &self.profiles.active
+    }
+
+    /// Get the names of all profiles, starting with [DEFAULT_PROFILE], in the
+    /// order they were added.
+    pub fn profiles(&self) -> Vec<&str> {
+        self.profiles
+            .list
+            .iter()
+            .map(|profile| profile.name.as_str())
+            .collect()
+    }
+
+    /// Whether a profile is a preset (see [`Hotkeys::add_preset`]).
+    pub fn is_profile_read_only(&self, name: &str) -> bool {
+        self.profiles
+            .get(name)
+            .is_some_and(|profile| profile.read_only)
+    }
+
+    /// Switch to another profile, e.g. from a dropdown. The shortcuts of the
+    /// active profile are kept in it, and the shortcuts of the actions are
+    /// set to the ones of the other profile, so [`Hotkeys::triggered`] and
+    /// the [KeybindsPanel](crate::KeybindsPanel) use them from then on. All
+    /// profiles share the registered actions and their defaults. The history
+    /// is cleared, see [`Hotkeys::undo`].
+    ///
+    /// All profiles except presets are saved by [`Hotkeys::to_saved`] and
+    /// restored with the active one by [`Hotkeys::load_saved`].
+    ///
+    /// # Errors
+    ///
+    /// [`ProfileError::Unknown`] if there's no profile with the name.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::{Hotkeys, DEFAULT_PROFILE};
+    ///
+    /// let build = || {
+    ///     let mut hotkeys = Hotkeys::new();
+    ///     hotkeys
+    ///         .register("save", "Ctrl+S".parse().unwrap())
+    ///         .register("left", "ArrowLeft".parse().unwrap())
+    ///         .add_preset("Vim-style", [("left", "H")]);
+    ///     hotkeys
+    /// };
+    /// let mut hotkeys = build();
+    ///
+    /// let ctx = egui::Context::default();
+    /// let press = |hotkeys: &mut Hotkeys, key| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::NONE,
+    ///     });
+    ///     let mut ids = vec![];
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         ids = hotkeys.triggered(ctx).map(|action| action.id().to_string()).collect();
+    ///     });
+    ///     ids
+    /// };
+    ///
+    /// assert_eq!(press(&mut hotkeys, Key::ArrowLeft), ["left"]);
+    /// hotkeys.switch_profile("Vim-style").unwrap();
+    /// assert!(press(&mut hotkeys, Key::ArrowLeft).is_empty());
+    /// assert_eq!(press(&mut hotkeys, Key::H), ["left"]);
+    ///
+    /// // presets are cloned to change them
+    /// assert!(hotkeys.is_profile_read_only("Vim-style"));
+    /// hotkeys.duplicate_profile("Vim-style", "Mine").unwrap();
+    /// hotkeys.switch_profile("Mine").unwrap();
+    /// hotkeys.set("save", "Ctrl+W".parse().unwrap());
+    /// assert_eq!(hotkeys.profiles(), [DEFAULT_PROFILE, "Vim-style", "Mine"]);
+    ///
+    /// // the default profile kept its shortcuts
+    /// hotkeys.switch_profile(DEFAULT_PROFILE).unwrap();
+    /// assert_eq!(hotkeys.current("left").unwrap().to_string(), "Left");
+    /// hotkeys.switch_profile("Mine").unwrap();
+    ///
+    /// // e.g. after restarting the app
+    /// let mut loaded = build();
+    /// loaded.load_saved(hotkeys.to_saved());
+    /// assert_eq!(loaded.active_profile(), "Mine");
+    /// assert_eq!(loaded.current("left").unwrap().to_string(), "H");
+    /// assert_eq!(loaded.current("save").unwrap().to_string(), "Ctrl+W");
+    /// loaded.switch_profile(DEFAULT_PROFILE).unwrap();
+    /// assert_eq!(loaded.current("save").unwrap().to_string(), "Ctrl+S");
+    /// loaded.switch_profile("Mine").unwrap();
+    /// assert_eq!(loaded, hotkeys);
+    /// ```
+    pub fn switch_profile(&mut self, name: &str) -> Result<(), ProfileError> {
+        let Some(profile) = self.profiles.get(name) else {
+            return Err(ProfileError::Unknown(name.to_string()));
+        };
+        if name == self.profiles.active {
+            return Ok(());
+        }
+        let bindings = profile.bindings.clone();
+        self.store_active_profile();
+        self.profiles.active = name.to_string();
+        self.apply_profile(&bindings);
+        Ok(())
+    }
+
+    /// Add a copy of a profile under a new name, e.g. of a preset to change
+    /// it. The active profile doesn't change.
+    ///
+    /// # Errors
+    ///
+    /// [`ProfileError::Unknown`] if there's no profile named `from`,
+    /// [`ProfileError::Exists`] if there is one named `to` already, and
+    /// [`ProfileError::InvalidName`] if `to` is empty or contains a `/`.
+    pub fn duplicate_profile(&mut self, from: &str, to: &str) -> Result<(), ProfileError> {
+        check_name(&self.profiles, to)?;
+        let Some(profile) = self.profiles.get(from) else {
+            return Err(ProfileError::Unknown(from.to_string()));
+        };
+        let bindings = match from == self.profiles.active {
+            true => self.capture_profile(&profile.bindings),
+            false => profile.bindings.clone(),
+        };
+        self.note_change();
+        self.profiles.list.push(Profile {
+            name: to.to_string(),
+            read_only: false,
+            bindings,
+        });
+        Ok(())
+    }
+
+    /// Delete a profile. If it's active, [DEFAULT_PROFILE] is switched to.
+    ///
+    /// # Errors
+    ///
+    /// [`ProfileError::Unknown`] if there's no profile with the name, and
+    /// [`ProfileError::Protected`] if it's [DEFAULT_PROFILE] or a preset.
+    pub fn delete_profile(&mut self, name: &str) -> Result<(), ProfileError> {
+        match self.profiles.get(name) {
+            None => return Err(ProfileError::Unknown(name.to_string())),
+            Some(profile) if profile.read_only || profile.name == DEFAULT_PROFILE => {
+                return Err(ProfileError::Protected(name.to_string()));
+            }
+            Some(_) => {}
+        }
+        if name == self.profiles.active {
+            // nothing to keep, it's deleted
+            self.profiles.active = DEFAULT_PROFILE.to_string();
+            let bindings = self.profiles.get(DEFAULT_PROFILE).unwrap().bindings.clone();
+            self.apply_profile(&bindings);
+        }
+        self.note_change();
+        self.profiles.list.retain(|profile| profile.name != name);
+        Ok(())
+    }
+
+    /// Get the shortcuts of the actions that differ from their defaults, and
+    /// the ones of `old` for actions that aren't registered.
+    fn capture_profile(&self, old: &[(String, String)]) -> Vec<(String, String)> {
+        self.iter()
+            .filter(|hotkey| hotkey.is_modified())
+            .map(|hotkey| (hotkey.id().to_string(), hotkey.format_bindings()))
+            .chain(old.iter().filter(|(id, _)| !self.contains(id)).cloned())
+            .collect()
+    }
+
+    /// Keep the current shortcuts in the active profile, unless it's read-only.
+    fn store_active_profile(&mut self) {
+        let active = self.profiles.active.clone();
+        let Some(profile) = self
+            .profiles
+            .get(&active)
+            .filter(|profile| !profile.read_only)
+        else {
+            return;
+        };
+        let bindings = self.capture_profile(&profile.bindings);
+        if let Some(profile) = self.profiles.get_mut(&active) {
+            profile.bindings = bindings;
+        }
+    }
+
+    /// Set the shortcuts of the actions to their defaults and the shortcuts
+    /// of a profile.
+    fn apply_profile(&mut self, bindings: &[(String, String)]) {
+        for hotkey in self.iter_all_mut() {
+            hotkey.reset();
+        }
+        for (id, value) in bindings {
+            if let Some(hotkey) = self.get_mut(id) {
+                let _ = hotkey.parse_bindings(value);
+            }
+        }
+        self.clear_history();
+    }
+
+    /// Get the saved entries of the profiles for [`Hotkeys::to_saved`]: the
+    /// name of the active profile, and the shortcuts of the inactive ones
+    /// that aren't presets. Nothing if only [DEFAULT_PROFILE] is used.
+    pub(crate) fn saved_profiles(&self) -> Vec<(String, String)> {
+        let profiles = &self.profiles;
+        if *profiles == Profiles::default() {
+            return vec![];
+        }
+        let mut saved = vec![(PROFILE_KEY.to_string(), profiles.active.clone())];
+        for profile in &profiles.list {
+            if profile.read_only || profile.name == profiles.active {
+                continue;
+            }
+            let key = format!("{PROFILE_KEY}/{}", profile.name);
+            saved.push((key.clone(), String::new()));
+            for (id, value) in &profile.bindings {
+                saved.push((format!("{key}/{id}"), value.clone()));
+            }
+        }
+        saved
+    }
+
+    /// Restore the profiles from the saved entries made by
+    /// [`Hotkeys::saved_profiles`], and get the other entries, which are the
+    /// shortcuts of the active profile. The profiles are kept if there are
+    /// no saved ones, e.g. in shortcuts saved before they were used.
+    pub(crate) fn take_profiles(
+        &mut self,
+        entries: Vec<(usize, String, String)>,
+    ) -> Vec<(usize, String, String)> {
+        let (saved, entries): (Vec<_>, Vec<_>) = entries.into_iter().partition(|(_, id, _)| {
+            id == PROFILE_KEY || id.starts_with(&format!("{PROFILE_KEY}/"))
+        });
+        let mut active = None;
+        let mut loaded: Vec<Profile> = vec![];
+        for (_, id, value) in saved {
+            let Some(rest) = id.strip_prefix(&format!("{PROFILE_KEY}/")) else {
+                active = Some(value);
+                continue;
+            };
+            let (name, binding) = match rest.split_once('/') {
+                Some((name, id)) => (name, Some((id.to_string(), value))),
+                None => (rest, None),
+            };
+            let index = match loaded.iter().position(|profile| profile.name == name) {
+                Some(index) => index,
+                None => {
+                    loaded.push(Profile {
+                        name: name.to_string(),
+                        read_only: false,
+                        bindings: vec![],
+                    });
+                    loaded.len() - 1
+                }
+            };
+            loaded[index].bindings.extend(binding);
+        }
+        let Some(active) = active else {
+            return entries;
+        };
+
+        for profile in loaded {
+            // presets come from the app, not from the saved shortcuts
+            if !self.is_profile_read_only(&profile.name) && profile.name != active {
+                self.profiles.insert(profile);
+            }
+        }
+        if self.profiles.get(&active).is_none() {
+            self.profiles.list.push(Profile {
+                name: active.clone(),
+                read_only: false,
+                bindings: vec![],
+            });
+        }
+        // the entries are applied over the shortcuts of the profile
+        let bindings = self.profiles.get(&active).unwrap().bindings.clone();
+        self.profiles.active = active;
+        self.apply_profile(&bindings);
+        entries
+    }
+}
+
+/// Show a dropdown to switch the profile of [Hotkeys] and buttons to
+/// duplicate and delete it, for the [KeybindsPanel](crate::KeybindsPanel).
+///
+/// # Returns
+///
+/// The profile that was switched to, if any.
+pub(crate) fn profile_bar(ui: &mut Ui, hotkeys: &mut Hotkeys, id: Id) -> Option<String> {
+    let mut switched = None;
+    ui.horizontal(|ui| {
+        ui.label("Profile");
+        let mut selected = hotkeys.active_profile().to_string();
+        ComboBox::from_id_salt(id)
+            .selected_text(&selected)
+            .show_ui(ui, |ui| {
+                for name in hotkeys.profiles() {
+                    ui.selectable_value(&mut selected, name.to_string(), name);
+                }
+            });
+        if selected != hotkeys.active_profile() && hotkeys.switch_profile(&selected).is_ok() {
+            switched = Some(selected);
+        }
+
+        let active = hotkeys.active_profile().to_string();
+        if ui.button("Duplicate").clicked() {
+            let copy = (1..)
+                .map(|n| match n {
+                    1 => format!("{active} copy"),
+                    n => format!("{active} copy {n}"),
+                })
+                .find(|name| check_name(&hotkeys.profiles, name).is_ok());
+            if let Some(copy) = copy {
+                let _ = hotkeys.duplicate_profile(&active, &copy);
+                let _ = hotkeys.switch_profile(&copy);
+                switched = Some(copy);
+            }
+        }
+        let protected = hotkeys.is_profile_read_only(&active) || active == DEFAULT_PROFILE;
+        if ui.add_enabled(!protected, Button::new("Delete")).clicked()
+            && hotkeys.delete_profile(&active).is_ok()
+        {
+            switched = Some(DEFAULT_PROFILE.to_string());
+        }
+    });
+    if hotkeys.is_profile_read_only(hotkeys.active_profile()) {
+        ui.weak("This profile is a preset, duplicate it to change it.");
+    }
+    switched
+}
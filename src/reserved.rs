@@ -0,0 +1,140 @@
+use egui::{os::OperatingSystem, Key, KeyboardShortcut, Modifiers};
+
+/// A shortcut the operating system or desktop uses, and what it does.
+type Reserved = (Modifiers, Key, &'static str);
+
+/// Shortcuts that Windows handles before apps get them.
+const WINDOWS: &[Reserved] = &[
+    (Modifiers::ALT, Key::F4, "closes windows"),
+    (Modifiers::ALT, Key::Tab, "switches windows"),
+    (Modifiers::ALT, Key::Escape, "switches windows"),
+    (Modifiers::ALT, Key::Space, "opens the window menu"),
+    (Modifiers::CTRL, Key::Escape, "opens the Start menu"),
+    (
+        Modifiers::CTRL.plus(Modifiers::SHIFT),
+        Key::Escape,
+        "opens the Task Manager",
+    ),
+    (
+        Modifiers::CTRL.plus(Modifiers::ALT),
+        Key::Delete,
+        "opens the security screen",
+    ),
+];
+
+/// Shortcuts that macOS handles before apps get them.
+const MAC: &[Reserved] = &[
+    (Modifiers::MAC_CMD, Key::Q, "quits apps"),
+    (Modifiers::MAC_CMD, Key::Tab, "switches apps"),
+    (Modifiers::MAC_CMD, Key::Space, "opens Spotlight"),
+    (Modifiers::MAC_CMD, Key::H, "hides apps"),
+    (Modifiers::MAC_CMD, Key::M, "minimizes windows"),
+    (Modifiers::MAC_CMD, Key::Backtick, "switches windows"),
+    (
+        Modifiers::MAC_CMD.plus(Modifiers::SHIFT),
+        Key::Num3,
+        "takes screenshots",
+    ),
+    (
+        Modifiers::MAC_CMD.plus(Modifiers::SHIFT),
+        Key::Num4,
+        "takes screenshots",
+    ),
+    (
+        Modifiers::MAC_CMD.plus(Modifiers::SHIFT),
+        Key::Num5,
+        "takes screenshots",
+    ),
+    (
+        Modifiers::MAC_CMD.plus(Modifiers::CTRL),
+        Key::Q,
+        "locks the screen",
+    ),
+    (
+        Modifiers::MAC_CMD.plus(Modifiers::ALT),
+        Key::Escape,
+        "opens Force Quit",
+    ),
+];
+
+/// Shortcuts that common Linux desktops handle before apps get them.
+const LINUX: &[Reserved] = &[
+    (Modifiers::ALT, Key::F4, "closes windows"),
+    (Modifiers::ALT, Key::Tab, "switches windows"),
+    (Modifiers::ALT, Key::F2, "opens the run dialog"),
+    (
+        Modifiers::CTRL.plus(Modifiers::ALT),
+        Key::Delete,
+        "logs out",
+    ),
+    (
+        Modifiers::CTRL.plus(Modifiers::ALT),
+        Key::T,
+        "opens a terminal",
+    ),
+];
+
+/// Get the name of an operating system for messages, e.g. `"macOS"`.
+pub(crate) fn os_name(os: OperatingSystem) -> Option<&'static str> {
+    match os {
+        OperatingSystem::Windows => Some("Windows"),
+        OperatingSystem::Mac => Some("macOS"),
+        OperatingSystem::Nix => Some("Linux"),
+        _ => None,
+    }
+}
+
+/// Check whether a shortcut is used by the operating system, so apps
+/// usually never get it, e.g. Alt+F4 on Windows or Cmd+Q on macOS. Only
+/// well-known shortcuts of Windows, macOS and common Linux desktops are
+/// checked; other operating systems have none.
+///
+/// The command modifier counts as Ctrl outside of macOS and as Cmd on
+/// macOS, like in [`KeyboardShortcut::format`].
+///
+/// # Returns
+///
+/// What the shortcut does, e.g. `"closes windows"`, or [None] if it's free.
+///
+/// # Example
+///
+/// ```
+/// use egui::{os::OperatingSystem, Key, KeyboardShortcut, Modifiers};
+/// use egui_keybind::reserved_shortcut;
+///
+/// let alt_f4 = KeyboardShortcut::new(Modifiers::ALT, Key::F4);
+/// assert_eq!(reserved_shortcut(alt_f4, OperatingSystem::Windows), Some("closes windows"));
+/// assert_eq!(reserved_shortcut(alt_f4, OperatingSystem::Mac), None);
+///
+/// let quit = KeyboardShortcut::new(Modifiers::COMMAND, Key::Q);
+/// assert_eq!(reserved_shortcut(quit, OperatingSystem::Mac), Some("quits apps"));
+/// // Ctrl+Q on Windows
+/// assert_eq!(reserved_shortcut(quit, OperatingSystem::Windows), None);
+/// ```
+pub fn reserved_shortcut(shortcut: KeyboardShortcut, os: OperatingSystem) -> Option<&'static str> {
+    let reserved = match os {
+        OperatingSystem::Windows => WINDOWS,
+        OperatingSystem::Mac => MAC,
+        OperatingSystem::Nix => LINUX,
+        _ => return None,
+    };
+    let modifiers = normalize_modifiers(shortcut.modifiers, os);
+    reserved
+        .iter()
+        .find(|(reserved, key, _)| {
+            *key == shortcut.logical_key && normalize_modifiers(*reserved, os) == modifiers
+        })
+        .map(|(_, _, action)| *action)
+}
+
+/// Resolve the command modifier to Ctrl or Cmd, so modifiers can be compared.
+fn normalize_modifiers(modifiers: Modifiers, os: OperatingSystem) -> Modifiers {
+    let is_mac = os == OperatingSystem::Mac;
+    Modifiers {
+        alt: modifiers.alt,
+        ctrl: modifiers.ctrl || (!is_mac && modifiers.command),
+        shift: modifiers.shift,
+        mac_cmd: is_mac && (modifiers.mac_cmd || modifiers.command),
+        command: false,
+    }
+}
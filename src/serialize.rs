@@ -0,0 +1,668 @@
+use crate::{
+    compact::{decode_modifiers, encode_modifiers, invalid, VERSION_2},
+    names::pointer_button_name,
+    parse::parse_pointer_button,
+    Bind, CommandPolicy, MatchPolicy, PointerTrigger, Shortcut, TextKey,
+};
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use serde::{
+    de::{Error as _, SeqAccess},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+/// The names of the modifiers in human-readable formats, in the order they
+/// are written.
+const MODIFIER_NAMES: [(Modifiers, &str); 5] = [
+    (Modifiers::CTRL, "Ctrl"),
+    (Modifiers::ALT, "Alt"),
+    (Modifiers::SHIFT, "Shift"),
+    (Modifiers::MAC_CMD, "MacCmd"),
+    (Modifiers::COMMAND, "Command"),
+];
+
+/// Write modifiers like `"Ctrl+MacCmd"`. Unlike [`Bind::format`], every
+/// field of [Modifiers] has its own name, so nothing is lost.
+fn format_modifiers(modifiers: Modifiers) -> String {
+    let held = [
+        modifiers.ctrl,
+        modifiers.alt,
+        modifiers.shift,
+        modifiers.mac_cmd,
+        modifiers.command,
+    ];
+    MODIFIER_NAMES
+        .iter()
+        .zip(held)
+        .filter(|(_, held)| *held)
+        .map(|((_, name), _)| *name)
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Parse modifiers written by [format_modifiers], case-insensitive.
+fn parse_modifiers<E: serde::de::Error>(s: &str) -> Result<Modifiers, E> {
+    let mut modifiers = Modifiers::NONE;
+    for name in s.split('+').map(str::trim).filter(|name| !name.is_empty()) {
+        let (modifier, _) = MODIFIER_NAMES
+            .iter()
+            .find(|(_, known)| known.eq_ignore_ascii_case(name))
+            .ok_or_else(|| E::custom(format!("unknown modifier \"{name}\"")))?;
+        modifiers = modifiers | *modifier;
+    }
+    Ok(modifiers)
+}
+
+fn parse_key<E: serde::de::Error>(name: &str) -> Result<Key, E> {
+    Key::from_name(name).ok_or_else(|| E::custom(format!("unknown key \"{name}\"")))
+}
+
+fn parse_button<E: serde::de::Error>(name: &str) -> Result<egui::PointerButton, E> {
+    parse_pointer_button(name)
+        .ok_or_else(|| E::custom(format!("unknown pointer button \"{name}\"")))
+}
+
+/// Serialize a [PointerButton](egui::PointerButton) by its name, like in
+/// [Shortcut], for fields of other binds. egui's `serde` feature isn't
+/// enabled, so the button can't be serialized on its own.
+pub(crate) mod button_by_name {
+    use super::{parse_button, pointer_button_name};
+    use egui::PointerButton;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        button: &PointerButton,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        pointer_button_name(*button).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PointerButton, D::Error> {
+        parse_button(&String::deserialize(deserializer)?)
+    }
+}
+
+/// Serialize [Modifiers] by their names, see [format_modifiers], for fields
+/// of other binds.
+pub(crate) mod modifiers_by_name {
+    use super::{format_modifiers, parse_modifiers};
+    use egui::Modifiers;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(
+        modifiers: &Modifiers,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        format_modifiers(*modifiers).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Modifiers, D::Error> {
+        parse_modifiers(&String::deserialize(deserializer)?)
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+/// A [Shortcut] in human-readable formats. Fields that are unset or have
+/// their default value are left out.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ShortcutFields {
+    /// The key of the keyboard shortcut, by [`Key::name`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    /// The character of the text key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<char>,
+    /// The modifiers of the key or text key, see [format_modifiers].
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    modifiers: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pointer: Option<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    trigger: PointerTrigger,
+    #[serde(default, skip_serializing_if = "is_default")]
+    match_policy: MatchPolicy,
+    #[serde(default, skip_serializing_if = "is_default")]
+    command_policy: CommandPolicy,
+}
+
+impl ShortcutFields {
+    fn new(shortcut: &Shortcut) -> Self {
+        let keyboard = shortcut.keyboard();
+        let text = shortcut.text();
+        let modifiers = keyboard
+            .map(|keyboard| keyboard.modifiers)
+            .or(text.map(|text| text.modifiers))
+            .unwrap_or_default();
+        Self {
+            key: keyboard.map(|keyboard| keyboard.logical_key.name().to_string()),
+            text: text.map(|text| text.character),
+            modifiers: format_modifiers(modifiers),
+            pointer: shortcut
+                .pointer()
+                .map(|button| pointer_button_name(button).to_string()),
+            trigger: shortcut.pointer_trigger(),
+            match_policy: shortcut.match_policy(),
+            command_policy: shortcut.command_policy(),
+        }
+    }
+
+    fn into_shortcut<E: serde::de::Error>(self) -> Result<Shortcut, E> {
+        let modifiers = parse_modifiers(&self.modifiers)?;
+        let pointer = self.pointer.as_deref().map(parse_button).transpose()?;
+        let mut shortcut = match (self.key, self.text) {
+            (Some(key), None) => Shortcut::new(
+                Some(KeyboardShortcut::new(modifiers, parse_key(&key)?)),
+                pointer,
+            ),
+            (None, Some(character)) => {
+                let mut shortcut = Shortcut::NONE;
+                shortcut.set_text(TextKey::new(modifiers, character), pointer);
+                shortcut
+            }
+            (None, None) if modifiers.is_none() => Shortcut::new(None, pointer),
+            (None, None) => return Err(E::custom("modifiers without a key")),
+            (Some(_), Some(_)) => return Err(E::custom("both a key and a text key")),
+        };
+        shortcut = shortcut
+            .with_pointer_trigger(self.trigger)
+            .with_match_policy(self.match_policy)
+            .with_command_policy(self.command_policy);
+        Ok(shortcut)
+    }
+}
+
+/// [Modifiers] as they were serialized by egui.
+#[derive(Deserialize)]
+struct LegacyModifiers {
+    alt: bool,
+    ctrl: bool,
+    shift: bool,
+    mac_cmd: bool,
+    command: bool,
+}
+
+impl From<LegacyModifiers> for Modifiers {
+    fn from(value: LegacyModifiers) -> Self {
+        Modifiers {
+            alt: value.alt,
+            ctrl: value.ctrl,
+            shift: value.shift,
+            mac_cmd: value.mac_cmd,
+            command: value.command,
+        }
+    }
+}
+
+/// [KeyboardShortcut] as it was serialized by egui. Keys were written by
+/// their variant name, e.g. `"Num1"`.
+#[derive(Deserialize)]
+struct LegacyKeyboard {
+    modifiers: LegacyModifiers,
+    logical_key: String,
+}
+
+#[derive(Deserialize)]
+struct LegacyTextKey {
+    modifiers: LegacyModifiers,
+    character: char,
+}
+
+/// The keys by their ordinal in egui 0.29, which binary formats wrote for
+/// [Shortcut]s in version 0.4 of this crate. Never change these.
+const LEGACY_KEYS: [Key; 104] = [
+    Key::ArrowDown,
+    Key::ArrowLeft,
+    Key::ArrowRight,
+    Key::ArrowUp,
+    Key::Escape,
+    Key::Tab,
+    Key::Backspace,
+    Key::Enter,
+    Key::Space,
+    Key::Insert,
+    Key::Delete,
+    Key::Home,
+    Key::End,
+    Key::PageUp,
+    Key::PageDown,
+    Key::Copy,
+    Key::Cut,
+    Key::Paste,
+    Key::Colon,
+    Key::Comma,
+    Key::Backslash,
+    Key::Slash,
+    Key::Pipe,
+    Key::Questionmark,
+    Key::OpenBracket,
+    Key::CloseBracket,
+    Key::Backtick,
+    Key::Minus,
+    Key::Period,
+    Key::Plus,
+    Key::Equals,
+    Key::Semicolon,
+    Key::Quote,
+    Key::Num0,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+    Key::F1,
+    Key::F2,
+    Key::F3,
+    Key::F4,
+    Key::F5,
+    Key::F6,
+    Key::F7,
+    Key::F8,
+    Key::F9,
+    Key::F10,
+    Key::F11,
+    Key::F12,
+    Key::F13,
+    Key::F14,
+    Key::F15,
+    Key::F16,
+    Key::F17,
+    Key::F18,
+    Key::F19,
+    Key::F20,
+    Key::F21,
+    Key::F22,
+    Key::F23,
+    Key::F24,
+    Key::F25,
+    Key::F26,
+    Key::F27,
+    Key::F28,
+    Key::F29,
+    Key::F30,
+    Key::F31,
+    Key::F32,
+    Key::F33,
+    Key::F34,
+    Key::F35,
+];
+
+/// The pointer buttons by their ordinal in egui 0.29, like [LEGACY_KEYS].
+const LEGACY_BUTTONS: [PointerButton; 5] = [
+    PointerButton::Primary,
+    PointerButton::Secondary,
+    PointerButton::Middle,
+    PointerButton::Extra1,
+    PointerButton::Extra2,
+];
+
+/// Get the next field of a [Shortcut] in a binary format.
+fn element<'de, T: Deserialize<'de>, A: SeqAccess<'de>>(seq: &mut A) -> Result<T, A::Error> {
+    seq.next_element()?
+        .ok_or_else(|| A::Error::custom("the shortcut ends early"))
+}
+
+/// Look up an ordinal that egui 0.29 wrote in a table like [LEGACY_KEYS].
+fn legacy_ordinal<T: Copy, E: serde::de::Error>(
+    field: &'static str,
+    table: &[T],
+    ordinal: u32,
+) -> Result<T, E> {
+    let value = usize::try_from(ordinal)
+        .ok()
+        .and_then(|index| table.get(index));
+    value
+        .copied()
+        .ok_or_else(|| E::custom(invalid(field, ordinal)))
+}
+
+/// Read the rest of a [Shortcut] that a binary format wrote in version 0.4
+/// of this crate, after the tag of the [Option] of the keyboard shortcut.
+/// Its two fields were derived, `(Option<KeyboardShortcut>,
+/// Option<PointerButton>)`, so the key and the button are ordinals.
+fn legacy_binary<'de, A: SeqAccess<'de>>(keyboard: u8, mut seq: A) -> Result<Shortcut, A::Error> {
+    let keyboard = match keyboard {
+        0 => None,
+        _ => {
+            let modifiers: LegacyModifiers = element(&mut seq)?;
+            let key = legacy_ordinal("key", &LEGACY_KEYS, element(&mut seq)?)?;
+            Some(KeyboardShortcut::new(modifiers.into(), key))
+        }
+    };
+    let pointer = element::<Option<u32>, _>(&mut seq)?
+        .map(|ordinal| legacy_ordinal("button", &LEGACY_BUTTONS, ordinal))
+        .transpose()?;
+    Ok(Shortcut::new(keyboard, pointer))
+}
+
+/// A [Shortcut] as version 0.4 of this crate serialized it, with its two
+/// fields derived.
+#[derive(Deserialize)]
+struct LegacyShortcut {
+    keyboard: Option<LegacyKeyboard>,
+    pointer: Option<String>,
+}
+
+impl LegacyShortcut {
+    fn into_shortcut<E: serde::de::Error>(self) -> Result<Shortcut, E> {
+        let keyboard = match self.keyboard {
+            Some(keyboard) => {
+                let name = keyboard.logical_key;
+                let key = Key::ALL
+                    .iter()
+                    .copied()
+                    .find(|key| format!("{key:?}") == name)
+                    .ok_or_else(|| E::custom(format!("unknown key \"{name}\"")))?;
+                Some(KeyboardShortcut::new(keyboard.modifiers.into(), key))
+            }
+            None => None,
+        };
+        let pointer = self.pointer.as_deref().map(parse_button).transpose()?;
+        Ok(Shortcut::new(keyboard, pointer))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedShortcut {
+    Fields(ShortcutFields),
+    Legacy(LegacyShortcut),
+}
+
+/// Human-readable formats like JSON or RON get a map with the key by
+/// [`Key::name`], the modifiers by name, the pointer button and the settings
+/// that aren't the default. Binary formats like postcard or bincode get the
+/// bytes of [`Shortcut::to_compact_bytes`] as a tuple. Neither depends on how
+/// [egui] serializes its types, so the `serde` feature doesn't need egui's.
+///
+/// Data that version 0.4 of this crate saved, with the fields of
+/// [KeyboardShortcut] and [Modifiers] as egui 0.29 serialized them, still
+/// loads, but is saved in the new format. Binary formats can tell the two apart by the first byte, see
+/// [`Shortcut::to_compact_bytes`].
+///
+/// # Example
+///
+/// ```
+/// use egui_keybind::Shortcut;
+///
+/// let mut shortcuts: Vec<Shortcut> = ["Ctrl+S", "Alt+§", "Ctrl+Z+Mouse Right Release"]
+///     .iter()
+///     .map(|s| s.parse().unwrap())
+///     .collect();
+/// shortcuts.push(Shortcut::NONE);
+///
+/// let json = serde_json::to_string(&shortcuts).unwrap();
+/// assert_eq!(
+///     json,
+///     concat!(
+///         r#"[{"key":"S","modifiers":"Ctrl"},"#,
+///         r#"{"text":"§","modifiers":"Alt"},"#,
+///         r#"{"key":"Z","modifiers":"Ctrl","pointer":"Mouse Right","trigger":"Release"},"#,
+///         r#"{}]"#,
+///     )
+/// );
+/// assert_eq!(serde_json::from_str::<Vec<Shortcut>>(&json).unwrap(), shortcuts);
+///
+/// // the shape version 0.4 derived from egui's types
+/// let legacy = r#"{
+///     "keyboard": {
+///         "modifiers": { "alt": false, "ctrl": true, "shift": false, "mac_cmd": false, "command": false },
+///         "logical_key": "Num1"
+///     },
+///     "pointer": "Extra1"
+/// }"#;
+/// let loaded: Shortcut = serde_json::from_str(legacy).unwrap();
+/// assert_eq!(loaded.to_string(), "Ctrl+1+Mouse 4");
+/// assert_eq!(
+///     serde_json::to_string(&loaded).unwrap(),
+///     r#"{"key":"1","modifiers":"Ctrl","pointer":"Mouse 4"}"#
+/// );
+///
+/// for &key in egui::Key::ALL {
+///     let shortcut = Shortcut::new(Some(egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key)), None);
+///     let json = serde_json::to_string(&shortcut).unwrap();
+///     assert_eq!(serde_json::from_str::<Shortcut>(&json).unwrap(), shortcut, "{json}");
+/// }
+///
+/// let error = serde_json::from_str::<Shortcut>(r#"{"key":"Foo"}"#).unwrap_err();
+/// assert!(error.to_string().contains("unknown key \"Foo\""), "{error}");
+///
+/// let blob = postcard::to_allocvec(&shortcuts).unwrap();
+/// assert_eq!(&blob[..12], [4, 2, 1, 2, b'S', 0, 0, 0, 0, 0, 0, 0]);
+/// assert_eq!(postcard::from_bytes::<Vec<Shortcut>>(&blob).unwrap(), shortcuts);
+///
+/// let blob = bincode::serialize(&shortcuts).unwrap();
+/// assert_eq!(bincode::deserialize::<Vec<Shortcut>>(&blob).unwrap(), shortcuts);
+///
+/// // a postcard blob of a `Vec<Shortcut>` that version 0.4 wrote
+/// let old = [2, 1, 0, 1, 0, 0, 0, 61, 0, 0, 1, 0];
+/// let loaded: Vec<Shortcut> = postcard::from_bytes(&old).unwrap();
+/// let names: Vec<String> = loaded.iter().map(|s| s.to_string()).collect();
+/// assert_eq!(names, ["Ctrl+S", "Mouse Left"]);
+/// ```
+impl Serialize for Shortcut {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            ShortcutFields::new(self).serialize(serializer)
+        } else {
+            let mut tuple = serializer.serialize_tuple(Self::COMPACT_LEN)?;
+            for byte in self.to_compact_bytes() {
+                tuple.serialize_element(&byte)?;
+            }
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Shortcut {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CompactVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CompactVisitor {
+            type Value = Shortcut;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an encoded shortcut")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Shortcut, A::Error> {
+                let version: u8 = element(&mut seq)?;
+                if version <= 1 {
+                    return legacy_binary(version, seq);
+                }
+                let mut bytes = [0; Shortcut::COMPACT_LEN];
+                bytes[0] = version;
+                if version == VERSION_2 {
+                    for byte in &mut bytes[1..] {
+                        *byte = element(&mut seq)?;
+                    }
+                }
+                Shortcut::from_compact_bytes(&bytes).map_err(A::Error::custom)
+            }
+        }
+
+        if !deserializer.is_human_readable() {
+            // longer than the derived layout, which is read field by field
+            return deserializer.deserialize_tuple(Shortcut::COMPACT_LEN, CompactVisitor);
+        }
+        match SavedShortcut::deserialize(deserializer)? {
+            SavedShortcut::Fields(fields) => fields.into_shortcut(),
+            SavedShortcut::Legacy(legacy) => legacy.into_shortcut(),
+        }
+    }
+}
+
+/// A [TextKey] in human-readable formats.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TextKeyFields {
+    character: char,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    modifiers: String,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SavedTextKey {
+    Fields(TextKeyFields),
+    Legacy(LegacyTextKey),
+}
+
+/// Human-readable formats get the character and the modifiers by name, like
+/// [Shortcut]. Binary formats get the modifiers as bits and the character.
+/// Human-readable data with the modifiers as egui serialized them still
+/// loads.
+///
+/// # Example
+///
+/// ```
+/// use egui::Modifiers;
+/// use egui_keybind::TextKey;
+///
+/// let key = TextKey::new(Modifiers::ALT | Modifiers::SHIFT, 'Ñ');
+/// let json = serde_json::to_string(&key).unwrap();
+/// assert_eq!(json, r#"{"character":"Ñ","modifiers":"Alt+Shift"}"#);
+/// assert_eq!(serde_json::from_str::<TextKey>(&json).unwrap(), key);
+///
+/// let legacy = r#"{
+///     "modifiers": { "alt": true, "ctrl": false, "shift": true, "mac_cmd": false, "command": false },
+///     "character": "Ñ"
+/// }"#;
+/// assert_eq!(serde_json::from_str::<TextKey>(legacy).unwrap(), key);
+///
+/// let blob = postcard::to_allocvec(&key).unwrap();
+/// assert_eq!(postcard::from_bytes::<TextKey>(&blob).unwrap(), key);
+/// let blob = bincode::serialize(&key).unwrap();
+/// assert_eq!(bincode::deserialize::<TextKey>(&blob).unwrap(), key);
+/// ```
+impl Serialize for TextKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            TextKeyFields {
+                character: self.character,
+                modifiers: format_modifiers(self.modifiers),
+            }
+            .serialize(serializer)
+        } else {
+            (encode_modifiers(self.modifiers), self.character).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TextKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            let (bits, character) = <(u8, char)>::deserialize(deserializer)?;
+            let modifiers = decode_modifiers(bits).map_err(D::Error::custom)?;
+            return Ok(TextKey::new(modifiers, character));
+        }
+        Ok(match SavedTextKey::deserialize(deserializer)? {
+            SavedTextKey::Fields(fields) => {
+                TextKey::new(parse_modifiers(&fields.modifiers)?, fields.character)
+            }
+            SavedTextKey::Legacy(legacy) => TextKey::new(legacy.modifiers.into(), legacy.character),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Ctrl+A, and Ctrl+S with the primary button, as egui-keybind 0.4.1
+    /// wrote them with postcard and bincode.
+    const POSTCARD_CTRL_A: [u8; 8] = [1, 0, 1, 0, 0, 0, 43, 0];
+    const POSTCARD_CTRL_S_CLICK: [u8; 9] = [1, 0, 1, 0, 0, 0, 61, 1, 0];
+    const BINCODE_CTRL_A: [u8; 11] = [1, 0, 1, 0, 0, 0, 43, 0, 0, 0, 0];
+    const BINCODE_CTRL_S_CLICK: [u8; 15] = [1, 0, 1, 0, 0, 0, 61, 0, 0, 0, 1, 0, 0, 0, 0];
+
+    fn keyboard(key: Key, pointer: Option<PointerButton>) -> Shortcut {
+        Shortcut::new(Some(KeyboardShortcut::new(Modifiers::CTRL, key)), pointer)
+    }
+
+    #[test]
+    fn postcard_reads_0_4_1() {
+        let load = |bytes: &[u8]| postcard::from_bytes::<Shortcut>(bytes).unwrap();
+        assert_eq!(load(&POSTCARD_CTRL_A), keyboard(Key::A, None));
+        let click = keyboard(Key::S, Some(PointerButton::Primary));
+        assert_eq!(load(&POSTCARD_CTRL_S_CLICK), click);
+        assert_eq!(
+            load(&[0, 1, 1]),
+            Shortcut::new(None, Some(PointerButton::Secondary))
+        );
+        assert_eq!(load(&[0, 0]), Shortcut::NONE);
+
+        // each shortcut only reads its own bytes, whichever layout it has
+        let mut pair = POSTCARD_CTRL_A.to_vec();
+        pair.extend(click.to_compact_bytes());
+        let loaded: (Shortcut, Shortcut) = postcard::from_bytes(&pair).unwrap();
+        assert_eq!(loaded, (keyboard(Key::A, None), click));
+        let mut pair = click.to_compact_bytes().to_vec();
+        pair.extend(POSTCARD_CTRL_A);
+        let loaded: (Shortcut, Shortcut) = postcard::from_bytes(&pair).unwrap();
+        assert_eq!(loaded, (click, keyboard(Key::A, None)));
+
+        assert!(postcard::from_bytes::<Shortcut>(&POSTCARD_CTRL_S_CLICK[..7]).is_err());
+        assert!(postcard::from_bytes::<Shortcut>(&[0, 1, 5]).is_err());
+        assert!(postcard::from_bytes::<Shortcut>(&[3; 11]).is_err());
+    }
+
+    #[test]
+    fn bincode_reads_0_4_1() {
+        let load = |bytes: &[u8]| bincode::deserialize::<Shortcut>(bytes).unwrap();
+        assert_eq!(load(&BINCODE_CTRL_A), keyboard(Key::A, None));
+        let click = keyboard(Key::S, Some(PointerButton::Primary));
+        assert_eq!(load(&BINCODE_CTRL_S_CLICK), click);
+        let secondary = [0, 1, 1, 0, 0, 0];
+        assert_eq!(
+            load(&secondary),
+            Shortcut::new(None, Some(PointerButton::Secondary))
+        );
+
+        let mut pair = BINCODE_CTRL_S_CLICK.to_vec();
+        pair.extend(bincode::serialize(&keyboard(Key::A, None)).unwrap());
+        let loaded: (Shortcut, Shortcut) = bincode::deserialize(&pair).unwrap();
+        assert_eq!(loaded, (click, keyboard(Key::A, None)));
+
+        assert!(bincode::deserialize::<Shortcut>(&BINCODE_CTRL_A[..8]).is_err());
+        assert!(bincode::deserialize::<Shortcut>(&[0, 1, 5, 0, 0, 0]).is_err());
+    }
+}
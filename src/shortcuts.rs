@@ -0,0 +1,180 @@
+use crate::{Bind, KeyNameMap, Shortcut, TextKey};
+use egui::{InputState, KeyboardShortcut, ModifierNames, PointerButton};
+
+/// A list of alternate [Shortcut]s for the same action, any of which can
+/// trigger it.
+///
+/// The first shortcut is the primary one. It is the one that is displayed
+/// and set by [Keybind](crate::Keybind), the others are alternates.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Shortcuts {
+    shortcuts: Vec<Shortcut>,
+}
+
+impl Shortcuts {
+    /// No shortcuts.
+    pub const NONE: Self = Self {
+        shortcuts: Vec::new(),
+    };
+
+    /// Create a new [Shortcuts] from a list of shortcuts. The first one is
+    /// the primary shortcut.
+    pub fn new(shortcuts: Vec<Shortcut>) -> Self {
+        Self { shortcuts }
+    }
+
+    /// The primary shortcut, if any.
+    #[inline]
+    pub fn primary(&self) -> Option<&Shortcut> {
+        self.shortcuts.first()
+    }
+
+    /// All shortcuts, starting with the primary one.
+    #[inline]
+    pub fn as_slice(&self) -> &[Shortcut] {
+        &self.shortcuts
+    }
+
+    /// All shortcuts, starting with the primary one.
+    #[inline]
+    pub fn as_mut_vec(&mut self) -> &mut Vec<Shortcut> {
+        &mut self.shortcuts
+    }
+
+    /// Add an alternate shortcut at the end of the list.
+    pub fn push(&mut self, shortcut: Shortcut) {
+        self.shortcuts.push(shortcut);
+    }
+
+    /// Remove the shortcut at `index` and return it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Shortcut {
+        self.shortcuts.remove(index)
+    }
+
+    /// Number of shortcuts, including the primary one.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.shortcuts.len()
+    }
+
+    /// Whether there are no shortcuts.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.shortcuts.is_empty()
+    }
+
+    /// Iterate over all shortcuts, starting with the primary one.
+    pub fn iter(&self) -> std::slice::Iter<'_, Shortcut> {
+        self.shortcuts.iter()
+    }
+}
+
+impl From<Vec<Shortcut>> for Shortcuts {
+    fn from(shortcuts: Vec<Shortcut>) -> Self {
+        Self::new(shortcuts)
+    }
+}
+
+impl FromIterator<Shortcut> for Shortcuts {
+    fn from_iter<T: IntoIterator<Item = Shortcut>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Shortcuts {
+    type Item = &'a Shortcut;
+    type IntoIter = std::slice::Iter<'a, Shortcut>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A [Bind] implementation for a list of alternate shortcuts. Setting the
+/// bind sets the primary shortcut, and any shortcut can trigger it.
+impl Bind for Shortcuts {
+    fn set(&mut self, keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        let shortcut = Shortcut::new(keyboard, pointer);
+        match self.shortcuts.first_mut() {
+            Some(primary) => *primary = shortcut,
+            None => self.shortcuts.push(shortcut),
+        }
+    }
+
+    fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+        let mut string = String::new();
+        self.format_into(&mut string, names, is_mac);
+        string
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        match self.primary() {
+            Some(primary) => primary.format_into(out, names, is_mac),
+            None => out.push_str("None"),
+        }
+    }
+
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        self.primary().map_or_else(
+            || "None".to_string(),
+            |primary| primary.format_with_key_names(names, key_names, is_mac),
+        )
+    }
+
+    fn pressed(&self, input: &mut InputState) -> bool {
+        // stops at the first match, so only that shortcut is consumed
+        self.shortcuts
+            .iter()
+            .any(|shortcut| shortcut.pressed(input))
+    }
+
+    fn clear(&mut self) {
+        self.shortcuts.clear();
+    }
+
+    fn is_bound(&self) -> bool {
+        self.shortcuts.iter().any(Bind::is_bound)
+    }
+
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        self.primary().and_then(Bind::keyboard)
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        self.primary().and_then(Bind::pointer)
+    }
+
+    fn text(&self) -> Option<TextKey> {
+        self.primary().and_then(Bind::text)
+    }
+
+    fn set_text(&mut self, text: TextKey, pointer: Option<PointerButton>) -> bool {
+        let mut shortcut = Shortcut::NONE;
+        shortcut.set_text(text, pointer);
+        match self.shortcuts.first_mut() {
+            Some(primary) => *primary = shortcut,
+            None => self.shortcuts.push(shortcut),
+        }
+        true
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        // only the primary shortcut is formatted
+        Some(egui::util::hash(self.primary().map(Bind::cache_key)))
+    }
+
+    fn alternates(&self) -> &[Shortcut] {
+        self.shortcuts.get(1..).unwrap_or_default()
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{Hotkeys, Triggered};
+use std::{collections::HashMap, fmt::Write};
+
+/// How often an action was triggered, see [`Hotkeys::set_stats_enabled`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActionStats {
+    count: u64,
+    last_triggered: f64,
+}
+
+impl ActionStats {
+    /// How many times the action was triggered.
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// When the action was last triggered, in seconds since the app started
+    /// (see [`egui::InputState::time`]).
+    #[inline]
+    pub fn last_triggered(&self) -> f64 {
+        self.last_triggered
+    }
+}
+
+impl Hotkeys {
+    /// Count how often each action is triggered by [`Hotkeys::triggered`],
+    /// e.g. to find out which shortcuts are actually used. Disabled by
+    /// default, and disabling it drops the counts.
+    ///
+    /// Changing shortcuts doesn't count as using them. The counts are not a
+    /// part of the keymap, so they're not saved with it or compared, but can
+    /// be exported with [`Hotkeys::export_stats_json`] and
+    /// [`Hotkeys::export_stats_csv`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Key, Modifiers, RawInput};
+    /// use egui_keybind::Hotkeys;
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("open", "Ctrl+O".parse().unwrap())
+    ///     .set_stats_enabled(true);
+    ///
+    /// let ctx = egui::Context::default();
+    /// let press = |hotkeys: &mut Hotkeys, key| {
+    ///     let mut input = RawInput::default();
+    ///     input.events.push(Event::Key {
+    ///         key,
+    ///         physical_key: None,
+    ///         pressed: true,
+    ///         repeat: false,
+    ///         modifiers: Modifiers::CTRL,
+    ///     });
+    ///     let _ = ctx.run(input, |ctx| {
+    ///         let _ = hotkeys.triggered(ctx);
+    ///     });
+    /// };
+    /// press(&mut hotkeys, Key::S);
+    /// press(&mut hotkeys, Key::S);
+    /// // nothing is bound to Ctrl+P
+    /// press(&mut hotkeys, Key::P);
+    /// assert_eq!(hotkeys.stats_of("save").map(|stats| stats.count()), Some(2));
+    /// assert!(hotkeys.stats_of("open").is_none());
+    ///
+    /// // rebinding an action doesn't count as using it
+    /// hotkeys.assign("open", "Ctrl+P".parse().unwrap());
+    /// hotkeys.set("save", "Ctrl+Shift+S".parse().unwrap());
+    /// assert!(hotkeys.stats_of("open").is_none());
+    /// assert_eq!(hotkeys.stats_of("save").map(|stats| stats.count()), Some(2));
+    ///
+    /// press(&mut hotkeys, Key::P);
+    /// let counts: Vec<_> = hotkeys
+    ///     .stats()
+    ///     .into_iter()
+    ///     .map(|(id, stats)| (id, stats.count()))
+    ///     .collect();
+    /// assert_eq!(counts, [("save", 2), ("open", 1)]);
+    ///
+    /// hotkeys.reset_stats();
+    /// assert!(hotkeys.stats().is_empty());
+    /// ```
+    pub fn set_stats_enabled(&mut self, enabled: bool) -> &mut Self {
+        match enabled {
+            true => {
+                self.stats.get_or_insert_with(HashMap::new);
+            }
+            false => self.stats = None,
+        }
+        self
+    }
+
+    /// Whether the actions are counted, see [`Hotkeys::set_stats_enabled`].
+    #[inline]
+    pub fn is_stats_enabled(&self) -> bool {
+        self.stats.is_some()
+    }
+
+    /// Get the actions that were triggered since the counts were enabled or
+    /// reset, most used first. Actions that were used equally often are
+    /// sorted by ID. See [`Hotkeys::set_stats_enabled`].
+    pub fn stats(&self) -> Vec<(&str, &ActionStats)> {
+        let mut stats: Vec<(&str, &ActionStats)> = self
+            .stats
+            .iter()
+            .flatten()
+            .map(|(id, stats)| (id.as_str(), stats))
+            .collect();
+        stats.sort_by(|(a, a_stats), (b, b_stats)| {
+            b_stats.count.cmp(&a_stats.count).then_with(|| a.cmp(b))
+        });
+        stats
+    }
+
+    /// Get how often an action was triggered, or [None] if it wasn't or the
+    /// actions are not counted. See [`Hotkeys::set_stats_enabled`].
+    pub fn stats_of(&self, id: &str) -> Option<&ActionStats> {
+        self.stats.as_ref()?.get(id)
+    }
+
+    /// Forget how often the actions were triggered, but keep counting them
+    /// if enabled.
+    pub fn reset_stats(&mut self) -> &mut Self {
+        if let Some(stats) = &mut self.stats {
+            stats.clear();
+        }
+        self
+    }
+
+    /// Export the counts of [`Hotkeys::stats`] as a JSON array, e.g.
+    /// `[{"id": "save", "count": 2, "last_triggered": 1.5}]`.
+    pub fn export_stats_json(&self) -> String {
+        let mut json = String::from("[");
+        for (index, (id, stats)) in self.stats().into_iter().enumerate() {
+            if index > 0 {
+                json.push_str(", ");
+            }
+            let _ = write!(
+                json,
+                "{{\"id\": {}, \"count\": {}, \"last_triggered\": {}}}",
+                json_string(id),
+                stats.count,
+                stats.last_triggered
+            );
+        }
+        json.push(']');
+        json
+    }
+
+    /// Export the counts of [`Hotkeys::stats`] as CSV with a header row,
+    /// e.g. `"id,count,last_triggered\nsave,2,1.5\n"`. IDs are quoted if
+    /// needed.
+    pub fn export_stats_csv(&self) -> String {
+        let mut csv = String::from("id,count,last_triggered\n");
+        for (id, stats) in self.stats() {
+            let _ = writeln!(
+                csv,
+                "{},{},{}",
+                csv_field(id),
+                stats.count,
+                stats.last_triggered
+            );
+        }
+        csv
+    }
+
+    /// Count the actions that were triggered at `time`, if enabled.
+    pub(crate) fn count_triggered(&mut self, time: f64, triggered: &[Triggered]) {
+        let Some(stats) = &mut self.stats else {
+            return;
+        };
+        for action in triggered {
+            match stats.get_mut(action.id()) {
+                Some(stats) => {
+                    stats.count += 1;
+                    stats.last_triggered = time;
+                }
+                None => {
+                    let first = ActionStats {
+                        count: 1,
+                        last_triggered: time,
+                    };
+                    stats.insert(action.id().to_string(), first);
+                }
+            }
+        }
+    }
+}
+
+/// Quote a string for JSON.
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Quote a CSV field if it has commas, quotes or line breaks.
+fn csv_field(s: &str) -> String {
+    match s.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", s.replace('"', "\"\"")),
+        false => s.to_string(),
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Hotkeys, ImportMode, ImportReport, ParseShortcutError, Shortcut};
+use eframe::Storage;
+
+impl Hotkeys {
+    /// Save the current shortcuts in an eframe [Storage], e.g. in
+    /// `App::save`. They're saved as text in the format of
+    /// [`Hotkeys::export_string`], which doesn't depend on [egui]'s types,
+    /// so they can still be loaded after upgrading egui.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage, e.g. the one eframe passes to `App::save`.
+    /// * `key` - The key to save the shortcuts under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Hotkeys;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Default)]
+    /// struct MemoryStorage(HashMap<String, String>);
+    ///
+    /// impl eframe::Storage for MemoryStorage {
+    ///     fn get_string(&self, key: &str) -> Option<String> {
+    ///         self.0.get(key).cloned()
+    ///     }
+    ///     fn set_string(&mut self, key: &str, value: String) {
+    ///         self.0.insert(key.to_string(), value);
+    ///     }
+    ///     fn flush(&mut self) {}
+    /// }
+    ///
+    /// let defaults = || {
+    ///     let mut hotkeys = Hotkeys::new();
+    ///     hotkeys
+    ///         .register("save", "Ctrl+S".parse().unwrap())
+    ///         .register("delete", "Delete".parse().unwrap());
+    ///     hotkeys
+    /// };
+    ///
+    /// let mut storage = MemoryStorage::default();
+    /// let mut hotkeys = defaults();
+    /// hotkeys.set("save", "Ctrl+Shift+S".parse().unwrap());
+    /// hotkeys.set("delete", "Shift+Delete".parse().unwrap());
+    /// hotkeys.save_to_storage(&mut storage, "hotkeys");
+    ///
+    /// // the next time the app starts
+    /// let mut hotkeys = defaults();
+    /// let report = hotkeys.load_from_storage(&storage, "hotkeys").unwrap();
+    /// assert!(report.is_clean());
+    /// assert_eq!(hotkeys.current("save").unwrap().to_string(), "Ctrl+Shift+S");
+    ///
+    /// // after a later version renamed "delete"
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register("save", "Ctrl+S".parse().unwrap())
+    ///     .register("delete_selection", "Delete".parse().unwrap())
+    ///     .migrate_id("delete", "delete_selection");
+    /// hotkeys.load_from_storage(&storage, "hotkeys");
+    /// assert_eq!(
+    ///     hotkeys.current("delete_selection").unwrap().to_string(),
+    ///     "Shift+Delete"
+    /// );
+    ///
+    /// // nothing was saved under this key
+    /// assert!(hotkeys.load_from_storage(&storage, "other").is_none());
+    /// ```
+    pub fn save_to_storage(&self, storage: &mut dyn Storage, key: &str) {
+        storage.set_string(key, self.export_string());
+    }
+
+    /// Load shortcuts saved with [`Hotkeys::save_to_storage`], e.g. when
+    /// the app is created. Register the actions and their migrations (see
+    /// [`Hotkeys::migrate_id`]) first.
+    ///
+    /// Lines that can't be loaded are skipped, so one broken line doesn't
+    /// lose all other shortcuts (see [`ImportMode::Partial`]). Actions that
+    /// were added since keep their default, and saved shortcuts of actions
+    /// that were removed are kept, like with [`Hotkeys::load_saved`].
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage, e.g. `CreationContext::storage`.
+    /// * `key` - The key the shortcuts were saved under.
+    ///
+    /// # Returns
+    ///
+    /// What was loaded, see [`Hotkeys::apply_from_text`]. [None] if nothing
+    /// was saved under `key`.
+    pub fn load_from_storage(&mut self, storage: &dyn Storage, key: &str) -> Option<ImportReport> {
+        let saved = storage.get_string(key)?;
+        Some(self.apply_from_text_with_mode(&saved, ImportMode::Partial))
+    }
+}
+
+impl Shortcut {
+    /// Save the shortcut in an eframe [Storage], e.g. in `App::save`, for
+    /// apps that don't use [Hotkeys]. It's saved as text like
+    /// `"Ctrl+Shift+S"`, the same as its [Display](std::fmt::Display) output.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage, e.g. the one eframe passes to `App::save`.
+    /// * `key` - The key to save the shortcut under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::Shortcut;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Default)]
+    /// struct MemoryStorage(HashMap<String, String>);
+    ///
+    /// impl eframe::Storage for MemoryStorage {
+    ///     fn get_string(&self, key: &str) -> Option<String> {
+    ///         self.0.get(key).cloned()
+    ///     }
+    ///     fn set_string(&mut self, key: &str, value: String) {
+    ///         self.0.insert(key.to_string(), value);
+    ///     }
+    ///     fn flush(&mut self) {}
+    /// }
+    ///
+    /// let mut storage = MemoryStorage::default();
+    /// let shortcut: Shortcut = "Ctrl+K".parse().unwrap();
+    /// shortcut.save_to_storage(&mut storage, "search");
+    ///
+    /// let mut loaded = Shortcut::NONE;
+    /// assert_eq!(loaded.load_from_storage(&storage, "search"), Ok(true));
+    /// assert_eq!(loaded, shortcut);
+    ///
+    /// // nothing was saved under this key, so the shortcut is kept
+    /// assert_eq!(loaded.load_from_storage(&storage, "other"), Ok(false));
+    /// assert_eq!(loaded, shortcut);
+    ///
+    /// storage.0.insert("broken".to_string(), "Ctrl+Nope".to_string());
+    /// assert!(loaded.load_from_storage(&storage, "broken").is_err());
+    /// assert_eq!(loaded, shortcut);
+    /// ```
+    pub fn save_to_storage(&self, storage: &mut dyn Storage, key: &str) {
+        storage.set_string(key, self.to_string());
+    }
+
+    /// Load a shortcut saved with [`Shortcut::save_to_storage`] into this
+    /// one, e.g. when the app is created. The text form doesn't have the
+    /// match and command policies, so they're kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage, e.g. `CreationContext::storage`.
+    /// * `key` - The key the shortcut was saved under.
+    ///
+    /// # Returns
+    ///
+    /// Whether a shortcut was saved under `key` and loaded.
+    ///
+    /// # Errors
+    ///
+    /// A [ParseShortcutError] if the saved shortcut couldn't be parsed. The
+    /// shortcut is kept in that case.
+    pub fn load_from_storage(
+        &mut self,
+        storage: &dyn Storage,
+        key: &str,
+    ) -> Result<bool, ParseShortcutError> {
+        let Some(saved) = storage.get_string(key) else {
+            return Ok(false);
+        };
+        let shortcut: Shortcut = saved.parse()?;
+        *self = shortcut
+            .with_match_policy(self.match_policy())
+            .with_command_policy(self.command_policy());
+        Ok(true)
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{Bind, DisplayNames, Hotkey, Hotkeys};
+use egui::ModifierNames;
+
+/// The names of the Windows/Linux column, with the logo key that
+/// [`Modifiers::mac_cmd`](egui::Modifiers::mac_cmd) is for named for both.
+const WINDOWS_LINUX_NAMES: ModifierNames<'static> = ModifierNames {
+    mac_cmd: "Win/Super",
+    ..ModifierNames::NAMES
+};
+
+/// Options for exporting a table of shortcuts with
+/// [`Hotkeys::export_markdown_with`] or [`Hotkeys::export_html_with`].
+#[derive(Default)]
+pub struct TableOptions<'a> {
+    display_names: Option<DisplayNames<'a>>,
+    platform_columns: bool,
+    show_unbound: bool,
+}
+
+impl<'a> TableOptions<'a> {
+    /// Create new [TableOptions] with the defaults: the names of
+    /// [`Hotkeys::display_name`] and [`Hotkeys::translate`], one shortcut
+    /// column and no unbound actions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a function that gets the name to show for an action or category
+    /// from its ID, e.g. to translate it. By default the name of
+    /// [`Hotkeys::display_name`] is shown for actions, and the category
+    /// itself, translated with [`Hotkeys::translate`], for categories.
+    pub fn with_display_names(mut self, display_names: impl Fn(&str) -> String + 'a) -> Self {
+        self.display_names = Some(Box::new(display_names));
+        self
+    }
+
+    /// Show the shortcuts in two columns, one with the names used on
+    /// Windows and Linux (e.g. `Ctrl+S`, or `Win/Super+S` for the logo key)
+    /// and one with the symbols used on macOS (e.g. `⌘S`). By default there
+    /// is one column with the Windows and Linux names.
+    pub fn with_platform_columns(mut self, platform_columns: bool) -> Self {
+        self.platform_columns = platform_columns;
+        self
+    }
+
+    /// List the actions without any shortcut, with "Unbound" in italics as
+    /// their shortcut. By default they are left out.
+    pub fn with_unbound(mut self, show_unbound: bool) -> Self {
+        self.show_unbound = show_unbound;
+        self
+    }
+
+    /// Get the name to show for a category.
+    fn name(&self, hotkeys: &Hotkeys, category: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(category),
+            None => hotkeys.translate(category),
+        }
+    }
+
+    /// Get the name to show for an action.
+    fn action_name(&self, hotkeys: &Hotkeys, id: &str) -> String {
+        match &self.display_names {
+            Some(display_names) => display_names(id),
+            None => hotkeys.display_name(id),
+        }
+    }
+
+    /// Get the header and the cells of each row, with the shortcut cells as
+    /// the formatted shortcuts of the action, which are empty if it's
+    /// unbound.
+    fn rows(&self, hotkeys: &Hotkeys) -> (Vec<&'static str>, Vec<Row>) {
+        let mut header = vec!["Category", "Action"];
+        let mut platforms = vec![(&WINDOWS_LINUX_NAMES, false)];
+        if self.platform_columns {
+            header.extend(["Windows/Linux", "macOS"]);
+            platforms.push((&ModifierNames::SYMBOLS, true));
+        } else {
+            header.push("Shortcut");
+        }
+
+        let mut rows = vec![];
+        for (category, group) in hotkeys.grouped() {
+            let category = category.map_or_else(|| "Other".to_string(), |c| self.name(hotkeys, c));
+            for hotkey in group {
+                let shortcuts: Vec<Vec<String>> = platforms
+                    .iter()
+                    .map(|(names, is_mac)| format_bindings(hotkey, names, *is_mac))
+                    .collect();
+                if self.show_unbound || !shortcuts[0].is_empty() {
+                    rows.push(Row {
+                        category: category.clone(),
+                        action: self.action_name(hotkeys, hotkey.id()),
+                        shortcuts,
+                    });
+                }
+            }
+        }
+        (header, rows)
+    }
+}
+
+/// A row of an exported table.
+struct Row {
+    category: String,
+    action: String,
+    /// The formatted shortcuts of each shortcut column.
+    shortcuts: Vec<Vec<String>>,
+}
+
+/// Format the bound shortcuts of an action, with the second step of a chord
+/// after the first, like the [CheatSheet](crate::CheatSheet).
+fn format_bindings(hotkey: &Hotkey, names: &ModifierNames<'_>, is_mac: bool) -> Vec<String> {
+    hotkey
+        .bindings()
+        .filter(|shortcut| shortcut.is_bound())
+        .map(|shortcut| {
+            let mut text = shortcut.format(names, is_mac);
+            if let Some(second) = hotkey.second_step() {
+                text.push(' ');
+                second.format_into(&mut text, names, is_mac);
+            }
+            text
+        })
+        .collect()
+}
+
+/// Escape text for a Markdown table cell, so that characters like `|`, `*`
+/// or `<` are shown as they are.
+fn escape_markdown(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '|' | '~' | '#' | '&' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Escape text for HTML.
+fn escape_html(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+impl Hotkeys {
+    /// Export the current shortcuts as a Markdown table with the category,
+    /// name and shortcuts of each action, e.g. for documentation. See
+    /// [`Hotkeys::export_markdown_with`] for the options.
+    pub fn export_markdown(&self) -> String {
+        self.export_markdown_with(&TableOptions::default())
+    }
+
+    /// Export the current shortcuts as a Markdown table like
+    /// [`Hotkeys::export_markdown`], with the given options.
+    ///
+    /// There is a row for each action, in the order of [`Hotkeys::grouped`],
+    /// so the table only changes when the actions or their shortcuts do.
+    /// Actions without a category are in the `Other` category. Alternate
+    /// shortcuts are separated by commas, and the second step of a chord
+    /// follows the first. Characters that mean something in Markdown, like
+    /// `|`, `*` or `<`, are escaped with a backslash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, Shortcut, TableOptions};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register_in("file", "print", Shortcut::NONE)
+    ///     .register_in("view", "zoom_in", "Ctrl+Plus".parse().unwrap())
+    ///     .register("a | b", "Alt+|".parse().unwrap())
+    ///     .register("<tag>", "Ctrl+[".parse().unwrap())
+    ///     .register_chord("comment", "Ctrl+K".parse().unwrap(), "Ctrl+C".parse().unwrap());
+    /// hotkeys.add_binding("zoom_in", "Ctrl+=".parse().unwrap());
+    ///
+    /// assert_eq!(
+    ///     hotkeys.export_markdown(),
+    ///     r"| Category | Action | Shortcut |
+    /// | --- | --- | --- |
+    /// | file | save | Ctrl+S |
+    /// | view | zoom\_in | Ctrl+Plus, Ctrl+Equals |
+    /// | Other | a \| b | Alt+Pipe |
+    /// | Other | \<tag\> | Ctrl+OpenBracket |
+    /// | Other | comment | Ctrl+K Ctrl+C |
+    /// "
+    /// );
+    ///
+    /// let options = TableOptions::new()
+    ///     .with_platform_columns(true)
+    ///     .with_unbound(true)
+    ///     .with_display_names(|id| id.replace('_', " ").to_uppercase());
+    /// assert_eq!(
+    ///     hotkeys.export_markdown_with(&options),
+    ///     r"| Category | Action | Windows/Linux | macOS |
+    /// | --- | --- | --- | --- |
+    /// | FILE | SAVE | Ctrl+S | ⌃S |
+    /// | FILE | PRINT | *Unbound* | *Unbound* |
+    /// | VIEW | ZOOM IN | Ctrl+Plus, Ctrl+Equals | ⌃+, ⌃= |
+    /// | Other | A \| B | Alt+Pipe | ⌥\| |
+    /// | Other | \<TAG\> | Ctrl+OpenBracket | ⌃\[ |
+    /// | Other | COMMENT | Ctrl+K Ctrl+C | ⌃K ⌃C |
+    /// "
+    /// );
+    /// ```
+    pub fn export_markdown_with(&self, options: &TableOptions<'_>) -> String {
+        let (header, rows) = options.rows(self);
+        let mut out = String::new();
+        out.push('|');
+        for title in &header {
+            out.push(' ');
+            out.push_str(title);
+            out.push_str(" |");
+        }
+        out.push_str("\n|");
+        out.push_str(&" --- |".repeat(header.len()));
+        out.push('\n');
+
+        for row in rows {
+            out.push_str("| ");
+            escape_markdown(&mut out, &row.category);
+            out.push_str(" | ");
+            escape_markdown(&mut out, &row.action);
+            for shortcuts in &row.shortcuts {
+                out.push_str(" | ");
+                if shortcuts.is_empty() {
+                    out.push_str("*Unbound*");
+                }
+                for (index, shortcut) in shortcuts.iter().enumerate() {
+                    if index > 0 {
+                        out.push_str(", ");
+                    }
+                    escape_markdown(&mut out, shortcut);
+                }
+            }
+            out.push_str(" |\n");
+        }
+        out
+    }
+
+    /// Export the current shortcuts as an HTML table with the category, name
+    /// and shortcuts of each action, e.g. for documentation. See
+    /// [`Hotkeys::export_html_with`] for the options.
+    pub fn export_html(&self) -> String {
+        self.export_html_with(&TableOptions::default())
+    }
+
+    /// Export the current shortcuts as an HTML table like
+    /// [`Hotkeys::export_html`], with the given options.
+    ///
+    /// The rows are the same as with [`Hotkeys::export_markdown_with`]. Each
+    /// shortcut is in a `<kbd>` element, and the shortcut cells of unbound
+    /// actions have the `unbound` class, so they can be styled. Text is
+    /// escaped, so `<`, `>`, `&` and quotes are shown as they are.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui_keybind::{Hotkeys, Shortcut, TableOptions};
+    ///
+    /// let mut hotkeys = Hotkeys::new();
+    /// hotkeys
+    ///     .register_in("file", "save", "Ctrl+S".parse().unwrap())
+    ///     .register_in("file", "print", Shortcut::NONE)
+    ///     .register("<indent>", "Tab".parse().unwrap());
+    /// hotkeys.add_binding("save", "F2".parse().unwrap());
+    ///
+    /// assert_eq!(
+    ///     hotkeys.export_html(),
+    ///     r#"<table>
+    ///   <thead>
+    ///     <tr><th>Category</th><th>Action</th><th>Shortcut</th></tr>
+    ///   </thead>
+    ///   <tbody>
+    ///     <tr><td>file</td><td>save</td><td><kbd>Ctrl+S</kbd>, <kbd>F2</kbd></td></tr>
+    ///     <tr><td>Other</td><td>&lt;indent&gt;</td><td><kbd>Tab</kbd></td></tr>
+    ///   </tbody>
+    /// </table>
+    /// "#
+    /// );
+    ///
+    /// let options = TableOptions::new().with_platform_columns(true).with_unbound(true);
+    /// assert_eq!(
+    ///     hotkeys.export_html_with(&options),
+    ///     r#"<table>
+    ///   <thead>
+    ///     <tr><th>Category</th><th>Action</th><th>Windows/Linux</th><th>macOS</th></tr>
+    ///   </thead>
+    ///   <tbody>
+    ///     <tr><td>file</td><td>save</td><td><kbd>Ctrl+S</kbd>, <kbd>F2</kbd></td><td><kbd>⌃S</kbd>, <kbd>F2</kbd></td></tr>
+    ///     <tr><td>file</td><td>print</td><td class="unbound"><em>Unbound</em></td><td class="unbound"><em>Unbound</em></td></tr>
+    ///     <tr><td>Other</td><td>&lt;indent&gt;</td><td><kbd>Tab</kbd></td><td><kbd>Tab</kbd></td></tr>
+    ///   </tbody>
+    /// </table>
+    /// "#
+    /// );
+    /// ```
+    pub fn export_html_with(&self, options: &TableOptions<'_>) -> String {
+        let (header, rows) = options.rows(self);
+        let mut out = String::from("<table>\n  <thead>\n    <tr>");
+        for title in &header {
+            out.push_str("<th>");
+            out.push_str(title);
+            out.push_str("</th>");
+        }
+        out.push_str("</tr>\n  </thead>\n  <tbody>\n");
+
+        for row in rows {
+            out.push_str("    <tr><td>");
+            escape_html(&mut out, &row.category);
+            out.push_str("</td><td>");
+            escape_html(&mut out, &row.action);
+            out.push_str("</td>");
+            for shortcuts in &row.shortcuts {
+                if shortcuts.is_empty() {
+                    out.push_str(r#"<td class="unbound"><em>Unbound</em></td>"#);
+                    continue;
+                }
+                out.push_str("<td>");
+                for (index, shortcut) in shortcuts.iter().enumerate() {
+                    if index > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str("<kbd>");
+                    escape_html(&mut out, shortcut);
+                    out.push_str("</kbd>");
+                }
+                out.push_str("</td>");
+            }
+            out.push_str("</tr>\n");
+        }
+        out.push_str("  </tbody>\n</table>\n");
+        out
+    }
+}
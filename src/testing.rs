@@ -0,0 +1,343 @@
+use crate::{
+    keybind::{is_capturing_with_persistent_id, start_capture_with_persistent_id},
+    TextKey,
+};
+use egui::{Context, Event, Id, IdMap, KeyboardShortcut, Modifiers, PointerButton, Ui};
+use egui_kittest::Harness;
+
+/// Where the [Keybind](crate::Keybind)s that were shown are kept in egui's
+/// memory, by their persistent ID, with the ID they were created with. All
+/// keybinds share this entry.
+fn registry_id() -> Id {
+    Id::new("egui_keybind_testing_ids")
+}
+
+/// Remember a keybind that is shown, so the helpers can find it by the ID it
+/// was created with.
+pub(crate) fn register(ui: &Ui, id: Id) {
+    let persistent_id = keybind_persistent_id(ui, id);
+    ui.ctx().memory_mut(|memory| {
+        memory
+            .data
+            .get_temp_mut_or_default::<IdMap<Id>>(registry_id())
+            .insert(persistent_id, id);
+    });
+}
+
+/// Get the persistent ID of a keybind that was shown before, from its
+/// persistent ID or the ID it was created with. [None] if no keybind was
+/// shown with it, or more than one was shown with the ID under different
+/// parents.
+fn persistent_id(ctx: &Context, id: Id) -> Option<Id> {
+    ctx.memory_mut(|memory| {
+        let shown = memory
+            .data
+            .get_temp_mut_or_default::<IdMap<Id>>(registry_id());
+        if shown.contains_key(&id) {
+            return Some(id);
+        }
+        let mut found = shown
+            .iter()
+            .filter(|(_, created_with)| **created_with == id)
+            .map(|(persistent_id, _)| *persistent_id);
+        match (found.next(), found.next()) {
+            (Some(persistent_id), None) => Some(persistent_id),
+            _ => None,
+        }
+    })
+}
+
+/// Get the persistent ID of a [Keybind](crate::Keybind) that is shown in
+/// `ui`, to tell the helpers of [KeybindHarnessExt] which one is meant when
+/// keybinds with the same ID are shown under different parents.
+///
+/// # Arguments
+///
+/// * `ui` - The [Ui] the keybind is added to.
+/// * `id` - The ID the keybind is created with, see [`Keybind::new`](crate::Keybind::new).
+pub fn keybind_persistent_id(ui: &Ui, id: impl Into<Id>) -> Id {
+    ui.make_persistent_id(id.into())
+}
+
+/// The input that [`KeybindHarnessExt::simulate_capture`] gives to a
+/// recording [Keybind](crate::Keybind).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureInput {
+    /// Press the key of a keyboard shortcut, with its modifiers held.
+    Keys(KeyboardShortcut),
+    /// Press a pointer button. Like with a real mouse, [`PointerButton::Primary`]
+    /// and [`PointerButton::Secondary`] aren't captured.
+    Pointer(PointerButton),
+    /// Type the character of a text key, with its modifiers held. It's only
+    /// captured with [`Keybind::with_text_fallback`](crate::Keybind::with_text_fallback).
+    Text(TextKey),
+}
+
+impl CaptureInput {
+    /// The modifiers that are held for the input.
+    pub fn modifiers(&self) -> Modifiers {
+        match self {
+            Self::Keys(keyboard) => keyboard.modifiers,
+            Self::Pointer(_) => Modifiers::NONE,
+            Self::Text(text) => text.modifiers,
+        }
+    }
+
+    /// The events that egui would get for the input.
+    pub fn events(&self) -> Vec<Event> {
+        let modifiers = self.modifiers();
+        match *self {
+            Self::Keys(keyboard) => vec![Event::Key {
+                key: keyboard.logical_key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers,
+            }],
+            Self::Pointer(button) => vec![Event::PointerButton {
+                pos: egui::Pos2::ZERO,
+                button,
+                pressed: true,
+                modifiers,
+            }],
+            Self::Text(text) => vec![Event::Text(text.character.to_string())],
+        }
+    }
+}
+
+impl From<KeyboardShortcut> for CaptureInput {
+    fn from(value: KeyboardShortcut) -> Self {
+        Self::Keys(value)
+    }
+}
+
+impl From<PointerButton> for CaptureInput {
+    fn from(value: PointerButton) -> Self {
+        Self::Pointer(value)
+    }
+}
+
+impl From<TextKey> for CaptureInput {
+    fn from(value: TextKey) -> Self {
+        Self::Text(value)
+    }
+}
+
+/// Helpers for [Keybind](crate::Keybind)s shown in an [egui_kittest]
+/// [Harness], e.g. in a test of an app's settings screen.
+///
+/// Keybinds are found by the ID they were created with (see
+/// [`Keybind::new`](crate::Keybind::new)), after they were shown on a frame.
+/// If keybinds with the same ID are shown under different parents, use the
+/// persistent ID of one of them, see [keybind_persistent_id].
+pub trait KeybindHarnessExt {
+    /// Make a [Keybind](crate::Keybind) record `input`, as if it was
+    /// clicked and the input was given, and run frames until the app
+    /// settles (see [`Harness::run`]). The keybind captures it the same way
+    /// as real input: its capture strategy, confirm mode, AltGr and command
+    /// policies all apply.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID the keybind was created with.
+    /// * `input` - The input to record.
+    ///
+    /// # Returns
+    ///
+    /// Whether the keybind was found. Nothing happens if not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{CaptureInput, Keybind, KeybindHarnessExt, Shortcut};
+    /// use egui_kittest::Harness;
+    ///
+    /// let mut harness = Harness::new_ui_state(
+    ///     |ui, shortcut: &mut Shortcut| {
+    ///         ui.add(Keybind::new(shortcut, "save"));
+    ///     },
+    ///     Shortcut::NONE,
+    /// );
+    /// let ctrl_k = KeyboardShortcut::new(Modifiers::CTRL, Key::K);
+    /// assert!(harness.simulate_capture("save", CaptureInput::Keys(ctrl_k)));
+    /// assert_eq!(harness.state().to_string(), "Ctrl+K");
+    /// ```
+    fn simulate_capture(&mut self, id: impl Into<Id>, input: CaptureInput) -> bool;
+
+    /// Check if a [Keybind](crate::Keybind) is recording, e.g. after it was
+    /// clicked.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID the keybind was created with, or its persistent ID.
+    ///
+    /// # Returns
+    ///
+    /// Whether the keybind is recording. False if it wasn't found.
+    fn is_capturing(&self, id: impl Into<Id>) -> bool;
+}
+
+impl<State> KeybindHarnessExt for Harness<'_, State> {
+    fn simulate_capture(&mut self, id: impl Into<Id>, input: CaptureInput) -> bool {
+        let Some(persistent_id) = persistent_id(&self.ctx, id.into()) else {
+            return false;
+        };
+        if !is_capturing_with_persistent_id(&self.ctx, persistent_id) {
+            start_capture_with_persistent_id(&self.ctx, persistent_id);
+        }
+        let raw = self.input_mut();
+        raw.modifiers = input.modifiers();
+        raw.events.extend(input.events());
+        self.step();
+        // the modifiers are let go after the frame of the input
+        self.input_mut().modifiers = Modifiers::NONE;
+        self.run();
+        true
+    }
+
+    fn is_capturing(&self, id: impl Into<Id>) -> bool {
+        persistent_id(&self.ctx, id.into())
+            .is_some_and(|id| is_capturing_with_persistent_id(&self.ctx, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BindPair, Keybind, Shortcut, Toggleable, ToggleableKeybind};
+    use egui::Key;
+    use egui_kittest::kittest::Queryable;
+
+    fn keys(modifiers: Modifiers, key: Key) -> CaptureInput {
+        CaptureInput::Keys(KeyboardShortcut::new(modifiers, key))
+    }
+
+    #[test]
+    fn keybind_records_the_input() {
+        let mut harness = Harness::new_ui_state(
+            |ui, shortcut: &mut Shortcut| {
+                ui.add(Keybind::new(shortcut, "save"));
+            },
+            Shortcut::NONE,
+        );
+        assert!(!harness.is_capturing("save"));
+        assert!(harness.simulate_capture("save", keys(Modifiers::CTRL, Key::K)));
+        assert!(!harness.is_capturing("save"));
+        assert_eq!(harness.state().to_string(), "Ctrl+K");
+
+        assert!(harness.simulate_capture("save", PointerButton::Extra1.into()));
+        assert_eq!(harness.state().to_string(), "Mouse 4");
+        // the primary button doesn't count, so the keybind keeps recording
+        assert!(harness.simulate_capture("save", PointerButton::Primary.into()));
+        assert!(harness.is_capturing("save"));
+        assert_eq!(harness.state().to_string(), "Mouse 4");
+
+        assert!(!harness.simulate_capture("unknown", keys(Modifiers::CTRL, Key::K)));
+        assert!(!harness.is_capturing("unknown"));
+    }
+
+    #[test]
+    fn same_ids_under_different_parents_need_the_persistent_id() {
+        let mut harness = Harness::new_ui_state(
+            |ui, (editor, viewer, viewer_id): &mut (Shortcut, Shortcut, Id)| {
+                ui.push_id("editor", |ui| ui.add(Keybind::new(editor, "save")));
+                ui.push_id("viewer", |ui| {
+                    *viewer_id = keybind_persistent_id(ui, "save");
+                    ui.add(Keybind::new(viewer, "save"));
+                });
+            },
+            (Shortcut::NONE, Shortcut::NONE, Id::NULL),
+        );
+        // "save" could be either of them
+        assert!(!harness.simulate_capture("save", keys(Modifiers::CTRL, Key::K)));
+        let viewer_id = harness.state().2;
+        assert!(harness.simulate_capture(viewer_id, keys(Modifiers::CTRL, Key::K)));
+        let (editor, viewer, _) = harness.state();
+        assert_eq!(*editor, Shortcut::NONE);
+        assert_eq!(viewer.to_string(), "Ctrl+K");
+    }
+
+    #[test]
+    fn disabled_toggleable_records() {
+        let bind = Toggleable::new("Ctrl+S".parse::<Shortcut>().unwrap()).with_enabled(false);
+        let mut harness = Harness::new_ui_state(
+            |ui, (bind, changed): &mut (Toggleable<Shortcut>, bool)| {
+                *changed |= ui.add(ToggleableKeybind::new(bind, "save")).changed();
+            },
+            (bind, false),
+        );
+        assert!(!harness.state().1);
+        assert!(harness.simulate_capture("save", keys(Modifiers::ALT, Key::S)));
+        let (bind, changed) = harness.state();
+        assert!(*changed);
+        assert_eq!(bind.to_string(), "Alt+S (disabled)");
+    }
+
+    #[test]
+    fn pair_records_one_half() {
+        let zoom_in: Shortcut = "Ctrl+Plus".parse().unwrap();
+        let zoom_out: Shortcut = "Ctrl+Minus".parse().unwrap();
+        let mut harness = Harness::new_ui_state(
+            |ui, (zoom_in, zoom_out, changed): &mut (Shortcut, Shortcut, bool)| {
+                let pair = BindPair::new("Zoom", zoom_in, zoom_out, "zoom");
+                *changed |= ui.add(pair).changed();
+            },
+            (zoom_in, zoom_out, false),
+        );
+        let [_, second] = BindPair::<Shortcut>::half_ids("zoom");
+        assert!(harness.simulate_capture(second, keys(Modifiers::ALT, Key::Minus)));
+        let (zoom_in, zoom_out, changed) = harness.state();
+        assert!(*changed);
+        assert_eq!(zoom_in.to_string(), "Ctrl+Plus");
+        assert_eq!(zoom_out.to_string(), "Alt+Minus");
+    }
+
+    #[test]
+    fn reserved_shortcuts_warn_or_are_blocked() {
+        // record a shortcut on Windows, and get the bind and the warning
+        fn record(keyboard: KeyboardShortcut, block: bool) -> (Shortcut, Option<String>) {
+            let mut harness = Harness::new_ui_state(
+                move |ui, bind: &mut Shortcut| {
+                    ui.add(Keybind::new(bind, "keybind").with_block_reserved(block));
+                },
+                Shortcut::NONE,
+            );
+            harness.ctx.set_os(egui::os::OperatingSystem::Windows);
+            harness
+                .ctx
+                .style_mut(|style| style.interaction.tooltip_delay = 0.0);
+            harness.simulate_capture("keybind", CaptureInput::Keys(keyboard));
+            // hover the keybind after recording
+            harness.get_by_role(egui::accesskit::Role::Button).hover();
+            harness.run();
+            let warning = harness
+                .output()
+                .shapes
+                .iter()
+                .find_map(|shape| match &shape.shape {
+                    egui::Shape::Text(text) if text.galley.text().contains("may not work") => {
+                        Some(text.galley.text().to_string())
+                    }
+                    _ => None,
+                });
+            (*harness.state(), warning)
+        }
+
+        let alt_f4 = KeyboardShortcut::new(Modifiers::ALT, Key::F4);
+        let (bind, warning) = record(alt_f4, false);
+        assert_eq!(bind.to_string(), "Alt+F4");
+        assert_eq!(
+            warning.as_deref(),
+            Some("Alt+F4 closes windows on Windows — this shortcut may not work")
+        );
+        // other shortcuts don't warn
+        let (bind, warning) = record(KeyboardShortcut::new(Modifiers::ALT, Key::F5), false);
+        assert_eq!(bind.to_string(), "Alt+F5");
+        assert_eq!(warning, None);
+        // blocked, it's not recorded
+        let (bind, warning) = record(alt_f4, true);
+        assert_eq!(bind, Shortcut::NONE);
+        assert_eq!(warning, None);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{names::push_modifiers, MatchPolicy};
+use egui::{Event, InputState, ModifierNames, Modifiers};
+
+/// A key that [egui] has no [Key](egui::Key) for, bound by the character it
+/// types, e.g. `§` or `ñ` on some keyboard layouts. See
+/// [`Keybind::with_text_fallback`](crate::Keybind::with_text_fallback).
+///
+/// Text keys depend on the keyboard layout: the same physical key types a
+/// different character (or none) on another layout, so the shortcut stops
+/// working when the layout changes. Keys that type their character with
+/// Shift need Shift held as well. Most platforms don't send text while Ctrl
+/// or Cmd is held, so text keys can't usually be combined with those.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, RawInput};
+/// use egui_keybind::{Bind, Shortcut, TextKey};
+///
+/// // there is no `Key` for "§", so it's a text key
+/// let shortcut: Shortcut = "Alt+§".parse().unwrap();
+/// assert_eq!(shortcut.text(), Some(TextKey::new(Modifiers::ALT, '§')));
+/// assert_eq!(shortcut.to_string(), "Alt+§");
+///
+/// let ctx = egui::Context::default();
+/// let input = RawInput {
+///     events: vec![Event::Text("§".into())],
+///     modifiers: Modifiers::ALT,
+///     ..Default::default()
+/// };
+/// let _ = ctx.run(input, |ctx| {
+///     assert!(ctx.input_mut(|input| shortcut.pressed(input)));
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextKey {
+    /// The modifiers that are held while typing the character.
+    pub modifiers: Modifiers,
+    /// The character the key types.
+    pub character: char,
+}
+
+impl TextKey {
+    /// Create a new [TextKey].
+    ///
+    /// # Arguments
+    ///
+    /// * `modifiers` - The modifiers that are held while typing the character.
+    /// * `character` - The character the key types.
+    pub const fn new(modifiers: Modifiers, character: char) -> Self {
+        Self {
+            modifiers,
+            character,
+        }
+    }
+
+    /// Get the text key that a text event types, with the `held` modifiers
+    /// (see [`InputState::modifiers`]). Only events with exactly one
+    /// character count.
+    pub(crate) fn typed(event: &Event, held: Modifiers) -> Option<Self> {
+        let Event::Text(text) = event else {
+            return None;
+        };
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(character), None) => Some(Self::new(held, character)),
+            _ => None,
+        }
+    }
+
+    /// Whether this key is typed by a text event, with modifiers that match
+    /// by `policy`.
+    pub(crate) fn is_typed_by(&self, event: &Event, held: Modifiers, policy: MatchPolicy) -> bool {
+        Self::typed(event, held).is_some_and(|typed| {
+            typed.character == self.character && policy.matches(typed.modifiers, self.modifiers)
+        })
+    }
+
+    /// Check if this key is typed with modifiers that match by `policy`, and
+    /// consume its text events if it is.
+    pub(crate) fn consume(&self, input: &mut InputState, policy: MatchPolicy) -> bool {
+        let held = input.modifiers;
+        let mut found = false;
+        input.events.retain(|event| {
+            let is_match = self.is_typed_by(event, held, policy);
+            found |= is_match;
+            !is_match
+        });
+        found
+    }
+
+    /// Format the key with its character as it is, e.g. `"Alt+§"`.
+    pub fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+        let mut string = String::new();
+        self.format_into(&mut string, names, is_mac);
+        string
+    }
+
+    /// Append the key to `out`, like [`TextKey::format`].
+    pub(crate) fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        let start = out.len();
+        push_modifiers(out, &self.modifiers, names, is_mac);
+        if out.len() > start {
+            out.push_str(names.concat);
+        }
+        out.push(self.character);
+    }
+}
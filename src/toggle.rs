@@ -0,0 +1,276 @@
+use crate::{
+    Bind, GestureDirection, InputKinds, KeyNameMap, Keybind, Shortcut, TextKey, WheelDirection,
+};
+use egui::{
+    Id, InputState, KeyboardShortcut, ModifierNames, Modifiers, PointerButton, Response, Ui,
+    Widget, WidgetText,
+};
+
+/// A bind that can be turned off without forgetting what it's bound to,
+/// e.g. to disable a hotkey for a while.
+///
+/// While it's disabled, [Bind::pressed] is always `false` and the bind is
+/// formatted with `" (disabled)"` after it. Everything else, like setting
+/// it, goes to the inner bind either way, so a [Keybind] can record it
+/// without enabling it first. [ToggleableKeybind] shows a checkbox for the
+/// flag next to the keybind.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Key, Modifiers, RawInput};
+/// use egui_keybind::{Bind, Shortcut, Toggleable};
+///
+/// let mut save = Toggleable::new("Ctrl+S".parse::<Shortcut>().unwrap());
+/// let ctx = egui::Context::default();
+/// let mut press = |bind: &Toggleable<Shortcut>| {
+///     let mut input = RawInput::default();
+///     input.events.push(Event::Key {
+///         key: Key::S,
+///         physical_key: None,
+///         pressed: true,
+///         repeat: false,
+///         modifiers: Modifiers::CTRL,
+///     });
+///     let mut pressed = false;
+///     let _ = ctx.run(input, |ctx| pressed = ctx.input_mut(|i| bind.pressed(i)));
+///     pressed
+/// };
+///
+/// assert!(press(&save));
+/// save.enabled = false;
+/// assert!(!press(&save));
+/// assert_eq!(save.to_string(), "Ctrl+S (disabled)");
+/// // it's still bound to Ctrl+S
+/// assert_eq!(save.inner.to_string(), "Ctrl+S");
+/// assert!(save.is_bound());
+///
+/// # #[cfg(feature = "serde")] {
+/// let json = serde_json::to_string(&save).unwrap();
+/// assert_eq!(serde_json::from_str::<Toggleable<Shortcut>>(&json).unwrap(), save);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Toggleable<B: Bind> {
+    /// Whether the bind is enabled.
+    pub enabled: bool,
+    /// The bind, which is kept while it's disabled.
+    pub inner: B,
+}
+
+impl<B: Bind> Toggleable<B> {
+    /// Create a new [Toggleable] that is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The bind.
+    pub fn new(inner: B) -> Self {
+        Self {
+            enabled: true,
+            inner,
+        }
+    }
+
+    /// Set whether the bind is enabled. By default it is.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<B: Bind + Default> Default for Toggleable<B> {
+    fn default() -> Self {
+        Self::new(B::default())
+    }
+}
+
+impl<B: Bind> std::fmt::Display for Toggleable<B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format(&ModifierNames::NAMES, false))
+    }
+}
+
+/// What is written after a disabled bind.
+const DISABLED_SUFFIX: &str = " (disabled)";
+
+/// Forwards everything to the inner bind, except that it isn't pressed while
+/// it's disabled.
+impl<B: Bind> Bind for Toggleable<B> {
+    fn set(&mut self, keyboard: Option<KeyboardShortcut>, pointer: Option<PointerButton>) {
+        self.inner.set(keyboard, pointer);
+    }
+
+    fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+        let mut out = String::new();
+        self.format_into(&mut out, names, is_mac);
+        out
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        self.inner.format_into(out, names, is_mac);
+        if !self.enabled {
+            out.push_str(DISABLED_SUFFIX);
+        }
+    }
+
+    fn format_with_key_names(
+        &self,
+        names: &ModifierNames<'_>,
+        key_names: &KeyNameMap,
+        is_mac: bool,
+    ) -> String {
+        let mut out = self.inner.format_with_key_names(names, key_names, is_mac);
+        if !self.enabled {
+            out.push_str(DISABLED_SUFFIX);
+        }
+        out
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+
+    /// Never triggered while the bind is disabled.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        self.enabled && self.inner.pressed(input)
+    }
+
+    fn is_bound(&self) -> bool {
+        self.inner.is_bound()
+    }
+
+    fn alternates(&self) -> &[Shortcut] {
+        self.inner.alternates()
+    }
+
+    fn keyboard(&self) -> Option<KeyboardShortcut> {
+        self.inner.keyboard()
+    }
+
+    fn pointer(&self) -> Option<PointerButton> {
+        self.inner.pointer()
+    }
+
+    fn text(&self) -> Option<TextKey> {
+        self.inner.text()
+    }
+
+    fn set_text(&mut self, text: TextKey, pointer: Option<PointerButton>) -> bool {
+        self.inner.set_text(text, pointer)
+    }
+
+    fn set_long_press(&mut self, button: PointerButton, duration: f32) -> bool {
+        self.inner.set_long_press(button, duration)
+    }
+
+    fn set_wheel(&mut self, modifiers: Modifiers, direction: WheelDirection) -> bool {
+        self.inner.set_wheel(modifiers, direction)
+    }
+
+    fn set_gesture(&mut self, button: PointerButton, direction: GestureDirection) -> bool {
+        self.inner.set_gesture(button, direction)
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        self.inner.input_kinds()
+    }
+
+    fn cache_key(&self) -> Option<u64> {
+        let key = self.inner.cache_key()?;
+        Some(egui::util::hash((key, self.enabled)))
+    }
+}
+
+/// A [Keybind] for a [Toggleable] bind, with a checkbox before it that
+/// turns the bind on and off. The returned [Response] is
+/// [changed](Response::changed) if the checkbox was clicked or the bind was
+/// recorded. Recording works while the bind is disabled, and keeps it
+/// disabled.
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, PointerButton, Pos2, RawInput};
+/// use egui_keybind::{Shortcut, Toggleable, ToggleableKeybind};
+///
+/// let ctx = egui::Context::default();
+/// let mut bind = Toggleable::new("Ctrl+S".parse::<Shortcut>().unwrap());
+/// let mut frame = |bind: &mut Toggleable<Shortcut>, events: Vec<Event>| {
+///     let mut response = None;
+///     let input = RawInput {
+///         events,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             response = Some(ui.add(ToggleableKeybind::new(bind, "save")));
+///         });
+///     });
+///     let response = response.unwrap();
+///     (response.rect, response.changed())
+/// };
+/// let click = |pos: Pos2| {
+///     let button = |pressed| Event::PointerButton {
+///         pos,
+///         button: PointerButton::Primary,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     };
+///     vec![Event::PointerMoved(pos), button(true), button(false)]
+/// };
+///
+/// // the checkbox is on the left
+/// let (rect, _) = frame(&mut bind, vec![]);
+/// let checkbox = rect.left_center() + egui::vec2(6.0, 0.0);
+/// let (_, changed) = frame(&mut bind, click(checkbox));
+/// assert!(changed);
+/// assert!(!bind.enabled);
+/// assert_eq!(bind.inner.to_string(), "Ctrl+S");
+/// frame(&mut bind, click(checkbox));
+/// assert!(bind.enabled);
+/// ```
+pub struct ToggleableKeybind<'a, B: Bind> {
+    bind: &'a mut Toggleable<B>,
+    id: Id,
+    text: WidgetText,
+}
+
+impl<'a, B: Bind> ToggleableKeybind<'a, B> {
+    /// Create a new [ToggleableKeybind].
+    ///
+    /// # Arguments
+    ///
+    /// * `bind` - The bind to show and turn on and off.
+    /// * `id` - ID for the [Keybind] in [egui]'s memory.
+    pub fn new(bind: &'a mut Toggleable<B>, id: impl Into<Id>) -> Self {
+        Self {
+            bind,
+            id: id.into(),
+            text: WidgetText::default(),
+        }
+    }
+
+    /// Set the text of the [Keybind], see [`Keybind::with_text`].
+    pub fn with_text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self
+    }
+}
+
+impl<'a, B: Bind> Widget for ToggleableKeybind<'a, B> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            let hover_text = match self.bind.enabled {
+                true => "Disable this keybind",
+                false => "Enable this keybind",
+            };
+            let checkbox = ui
+                .checkbox(&mut self.bind.enabled, "")
+                .on_hover_text(hover_text);
+            let keybind = ui.add(Keybind::new(self.bind, self.id).with_text(self.text));
+            checkbox | keybind
+        })
+        .inner
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Bind, KeyNameMap};
+use egui::{os::OperatingSystem, ModifierNames, Response};
+
+/// Append the formatted bind to a text in parentheses, e.g. `"Save (Ctrl+S)"`,
+/// like it's shown in a [Keybind](crate::Keybind). The text is returned as it
+/// is if the bind is not bound (see [`Bind::is_bound`]).
+///
+/// # Arguments
+///
+/// * `text` - The text to append the bind to, e.g. `"Save"`.
+/// * `bind` - The bind to format.
+/// * `names` - The [ModifierNames] to format the bind with.
+/// * `key_names` - The table of key and pointer button names to format the
+///   bind with, or [None] for the English names.
+/// * `is_mac` - Whether to use MacOS symbols.
+///
+/// # Example
+///
+/// ```
+/// use egui::ModifierNames;
+/// use egui_keybind::{bind_tooltip, Shortcut};
+///
+/// let save: Shortcut = "Ctrl+S".parse().unwrap();
+/// let text = bind_tooltip("Save", &save, &ModifierNames::NAMES, None, false);
+/// assert_eq!(text, "Save (Ctrl+S)");
+///
+/// let text = bind_tooltip("Save", &Shortcut::NONE, &ModifierNames::NAMES, None, false);
+/// assert_eq!(text, "Save");
+/// ```
+pub fn bind_tooltip<B: Bind>(
+    text: &str,
+    bind: &B,
+    names: &ModifierNames<'_>,
+    key_names: Option<&KeyNameMap>,
+    is_mac: bool,
+) -> String {
+    if !bind.is_bound() {
+        return text.to_string();
+    }
+    let formatted = match key_names {
+        Some(key_names) => bind.format_with_key_names(names, key_names, is_mac),
+        None => bind.format(names, is_mac),
+    };
+    format!("{text} ({formatted})")
+}
+
+/// Adds hover text with a bind to a [Response], e.g. for toolbar buttons.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_keybind::{BindTooltip, Shortcut};
+///
+/// let save: Shortcut = "Ctrl+S".parse().unwrap();
+/// if ui.button("💾").on_hover_bind("Save", &save).clicked() {
+///     // save
+/// }
+/// # });
+/// ```
+pub trait BindTooltip {
+    /// Show the text with the bind appended as hover text (see
+    /// [bind_tooltip]), formatted with [`ModifierNames::NAMES`], or the Mac
+    /// symbols on Mac.
+    fn on_hover_bind<B: Bind>(self, text: &str, bind: &B) -> Self;
+
+    /// Show the text with the bind appended as hover text (see
+    /// [bind_tooltip]), formatted with the given names, e.g. to match a
+    /// [Keybind](crate::Keybind) with
+    /// [`Keybind::with_modifier_names`](crate::Keybind::with_modifier_names)
+    /// and [`Keybind::with_key_names`](crate::Keybind::with_key_names).
+    fn on_hover_bind_with<B: Bind>(
+        self,
+        text: &str,
+        bind: &B,
+        names: &ModifierNames<'_>,
+        key_names: Option<&KeyNameMap>,
+    ) -> Self;
+}
+
+impl BindTooltip for Response {
+    fn on_hover_bind<B: Bind>(self, text: &str, bind: &B) -> Self {
+        let names = match self.ctx.os() == OperatingSystem::Mac {
+            true => &ModifierNames::SYMBOLS,
+            false => &ModifierNames::NAMES,
+        };
+        self.on_hover_bind_with(text, bind, names, None)
+    }
+
+    fn on_hover_bind_with<B: Bind>(
+        self,
+        text: &str,
+        bind: &B,
+        names: &ModifierNames<'_>,
+        key_names: Option<&KeyNameMap>,
+    ) -> Self {
+        let is_mac = self.ctx.os() == OperatingSystem::Mac;
+        let text = bind_tooltip(text, bind, names, key_names, is_mac);
+        self.on_hover_text(text)
+    }
+}
@@ -0,0 +1,466 @@
+use crate::{Bind, ParseShortcutError, PointerTrigger, Shortcut, TextKey};
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use std::fmt;
+
+/// An error that can occur when parsing a [Shortcut] from Vim notation, see
+/// [`Shortcut::parse_vim`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVimError {
+    /// The byte offset in the string where the error is.
+    pub position: usize,
+    /// What went wrong.
+    pub kind: ParseShortcutError,
+}
+
+impl fmt::Display for ParseVimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for ParseVimError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+/// Pointer buttons and their names in Vim notation, e.g. `"Left"` of
+/// `<LeftMouse>`.
+const VIM_POINTER_BUTTONS: [(PointerButton, &str); 5] = [
+    (PointerButton::Primary, "Left"),
+    (PointerButton::Secondary, "Right"),
+    (PointerButton::Middle, "Middle"),
+    (PointerButton::Extra1, "X1"),
+    (PointerButton::Extra2, "X2"),
+];
+
+/// Get the character a key is written as in Vim notation, e.g. `'s'` or
+/// `'['`, if it isn't written by name.
+fn vim_key_char(key: Key) -> Option<char> {
+    Some(match key {
+        Key::Colon => ':',
+        Key::Comma => ',',
+        Key::Slash => '/',
+        Key::Questionmark => '?',
+        Key::OpenBracket => '[',
+        Key::CloseBracket => ']',
+        Key::Backtick => '`',
+        Key::Minus => '-',
+        Key::Period => '.',
+        Key::Plus => '+',
+        Key::Equals => '=',
+        Key::Semicolon => ';',
+        Key::Quote => '\'',
+        _ => {
+            let mut chars = key.name().chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => c.to_ascii_lowercase(),
+                _ => return None,
+            }
+        }
+    })
+}
+
+/// Get the name of a key in Vim notation, e.g. `"CR"` of `<CR>`.
+fn vim_key_name(key: Key) -> &'static str {
+    match key {
+        Key::Escape => "Esc",
+        Key::Backspace => "BS",
+        Key::Enter => "CR",
+        Key::Delete => "Del",
+        Key::Pipe => "Bar",
+        Key::Backslash => "Bslash",
+        _ => key.name(),
+    }
+}
+
+/// Parse a key name in Vim notation, e.g. `"CR"` or `"PageUp"`.
+/// Case-insensitive.
+fn parse_vim_key_name(name: &str) -> Option<Key> {
+    Some(match name.to_ascii_lowercase().as_str() {
+        "esc" => Key::Escape,
+        "bs" => Key::Backspace,
+        "cr" | "return" => Key::Enter,
+        "del" => Key::Delete,
+        "ins" => Key::Insert,
+        "bar" => Key::Pipe,
+        "bslash" => Key::Backslash,
+        _ => {
+            return Key::ALL
+                .iter()
+                .copied()
+                .find(|key| key.name().len() > 1 && key.name().eq_ignore_ascii_case(name))
+        }
+    })
+}
+
+/// Parse a pointer button name in Vim notation, e.g. `"LeftMouse"` or
+/// `"X1Release"`. Case-insensitive.
+fn parse_vim_pointer(name: &str) -> Option<(PointerButton, PointerTrigger)> {
+    let name = name.to_ascii_lowercase();
+    let (button, trigger) = match name.strip_suffix("mouse") {
+        Some(button) => (button, PointerTrigger::Press),
+        None => (name.strip_suffix("release")?, PointerTrigger::Release),
+    };
+    VIM_POINTER_BUTTONS
+        .iter()
+        .find(|(_, vim_name)| vim_name.eq_ignore_ascii_case(button))
+        .map(|(button, _)| (*button, trigger))
+}
+
+/// Split the modifiers off the start of a `<...>` notation, e.g. `"C-S-"` of
+/// `"C-S-s>"`. The name after the modifiers is never empty, so `"C-->"` is
+/// Ctrl and `"->"`.
+fn split_vim_modifiers(mut inner: &str) -> (Modifiers, &str) {
+    let mut modifiers = Modifiers::NONE;
+    loop {
+        let mut chars = inner.chars();
+        let modifier = match (chars.next(), chars.next(), chars.next()) {
+            (Some(c), Some('-'), Some(_)) => match c.to_ascii_uppercase() {
+                'C' => Modifiers::CTRL,
+                'S' => Modifiers::SHIFT,
+                'A' | 'M' => Modifiers::ALT,
+                'D' => Modifiers::MAC_CMD,
+                _ => break,
+            },
+            _ => break,
+        };
+        modifiers = modifiers | modifier;
+        inner = &inner[2..];
+    }
+    (modifiers, inner)
+}
+
+/// Append the modifiers of Vim notation, e.g. `"C-S-"`.
+fn push_vim_modifiers(out: &mut String, modifiers: Modifiers) {
+    if modifiers.ctrl || modifiers.command && !modifiers.mac_cmd {
+        out.push_str("C-");
+    }
+    if modifiers.shift {
+        out.push_str("S-");
+    }
+    if modifiers.alt {
+        out.push_str("A-");
+    }
+    if modifiers.mac_cmd {
+        out.push_str("D-");
+    }
+}
+
+/// Append a key or the character of a text key in Vim notation, e.g. `"s"`,
+/// `"S"`, `"<C-s>"` or `"<lt>"`.
+fn push_vim_key(out: &mut String, modifiers: Modifiers, key: Result<Key, char>) {
+    let character = match key {
+        Ok(key) => vim_key_char(key),
+        Err(character) => Some(character).filter(|&c| c != '<'),
+    };
+    match character {
+        Some(c) if modifiers.is_none() => out.push(c),
+        Some(c) if modifiers == Modifiers::SHIFT && c.is_ascii_lowercase() && key.is_ok() => {
+            out.push(c.to_ascii_uppercase())
+        }
+        _ => {
+            out.push('<');
+            push_vim_modifiers(out, modifiers);
+            match (character, key) {
+                (Some(c), _) => out.push(c),
+                (None, Ok(key)) => out.push_str(vim_key_name(key)),
+                (None, Err(_)) => out.push_str("lt"),
+            }
+            out.push('>');
+        }
+    }
+}
+
+impl Shortcut {
+    /// Parse a shortcut written in Vim notation, e.g. `"<C-s>"`, `"<S-F5>"`,
+    /// `"<CR>"` or `"g"`, as written by [`Shortcut::to_vim_string`].
+    ///
+    /// Unmodified keys can be written as bare characters, e.g. `"g"` or
+    /// `"["`. Anything else is written in angle brackets, with modifiers
+    /// before the key: `C-` is Ctrl, `S-` is Shift, `A-` or `M-` is Alt and
+    /// `D-` is Cmd. Keys are characters or names like `CR` (or `Enter`),
+    /// `Esc`, `Space`, `Tab`, `BS`, `Del`, `Up`, `PageUp`, `Bar` (`|`),
+    /// `Bslash` (`\`) and `F1` to `F35`, and `lt` is a literal `<`. Mouse
+    /// buttons are `<LeftMouse>`, `<RightMouse>`, `<MiddleMouse>`,
+    /// `<X1Mouse>` and `<X2Mouse>`, or `<LeftRelease>` etc. to trigger on
+    /// release, and can follow a key, e.g. `"<C-s><MiddleMouse>"`, to bind
+    /// both. `"<Nop>"` is no shortcut. Characters without a key, e.g. `"§"`,
+    /// are bound as a [TextKey].
+    ///
+    /// # Case sensitivity
+    ///
+    /// Modifiers and key names are case-insensitive, so `<c-cr>` is the same
+    /// as `<C-CR>`. Letters are not: an uppercase letter includes Shift, so
+    /// `"S"` is Shift+S and `<A-S>` is Alt+Shift+S. Like in Vim, Ctrl is the
+    /// exception, so `<C-S>` is the same as `<C-s>`; write Ctrl+Shift+S as
+    /// `<C-S-s>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The shortcut in Vim notation, e.g. `"<C-s>"`.
+    ///
+    /// # Returns
+    ///
+    /// The shortcut, or an error with the byte offset where parsing failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{Bind, ParseShortcutError, PointerTrigger, Shortcut, TextKey};
+    ///
+    /// let key = |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+    /// let ctrl_shift = Modifiers::CTRL | Modifiers::SHIFT;
+    /// let cases = [
+    ///     ("<C-s>", key(Modifiers::CTRL, Key::S)),
+    ///     ("<C-S>", key(Modifiers::CTRL, Key::S)),
+    ///     ("<c-s>", key(Modifiers::CTRL, Key::S)),
+    ///     ("<C-S-s>", key(ctrl_shift, Key::S)),
+    ///     ("<S-C-s>", key(ctrl_shift, Key::S)),
+    ///     ("s", key(Modifiers::NONE, Key::S)),
+    ///     ("S", key(Modifiers::SHIFT, Key::S)),
+    ///     ("<A-S>", key(Modifiers::ALT | Modifiers::SHIFT, Key::S)),
+    ///     ("<M-x>", key(Modifiers::ALT, Key::X)),
+    ///     ("<D-s>", key(Modifiers::MAC_CMD, Key::S)),
+    ///     ("<S-F5>", key(Modifiers::SHIFT, Key::F5)),
+    ///     ("<F12>", key(Modifiers::NONE, Key::F12)),
+    ///     ("<CR>", key(Modifiers::NONE, Key::Enter)),
+    ///     ("<Enter>", key(Modifiers::NONE, Key::Enter)),
+    ///     ("<C-cr>", key(Modifiers::CTRL, Key::Enter)),
+    ///     ("<Esc>", key(Modifiers::NONE, Key::Escape)),
+    ///     ("<Space>", key(Modifiers::NONE, Key::Space)),
+    ///     ("<C-Space>", key(Modifiers::CTRL, Key::Space)),
+    ///     ("<S-Tab>", key(Modifiers::SHIFT, Key::Tab)),
+    ///     ("<BS>", key(Modifiers::NONE, Key::Backspace)),
+    ///     ("<Del>", key(Modifiers::NONE, Key::Delete)),
+    ///     ("<A-Up>", key(Modifiers::ALT, Key::ArrowUp)),
+    ///     ("<C-PageDown>", key(Modifiers::CTRL, Key::PageDown)),
+    ///     ("<Bar>", key(Modifiers::NONE, Key::Pipe)),
+    ///     ("<C-Bslash>", key(Modifiers::CTRL, Key::Backslash)),
+    ///     ("[", key(Modifiers::NONE, Key::OpenBracket)),
+    ///     ("<C-->", key(Modifiers::CTRL, Key::Minus)),
+    ///     ("<C-+>", key(Modifiers::CTRL, Key::Plus)),
+    ///     ("1", key(Modifiers::NONE, Key::Num1)),
+    ///     ("<LeftMouse>", Shortcut::new(None, Some(PointerButton::Primary))),
+    ///     (
+    ///         "<X1Release>",
+    ///         Shortcut::new(None, Some(PointerButton::Extra1))
+    ///             .with_pointer_trigger(PointerTrigger::Release),
+    ///     ),
+    ///     (
+    ///         "<C-s><MiddleMouse>",
+    ///         Shortcut::new(
+    ///             Some(KeyboardShortcut::new(Modifiers::CTRL, Key::S)),
+    ///             Some(PointerButton::Middle),
+    ///         ),
+    ///     ),
+    ///     ("<Nop>", Shortcut::NONE),
+    /// ];
+    /// for (s, expected) in cases {
+    ///     assert_eq!(Shortcut::parse_vim(s), Ok(expected), "{s:?}");
+    /// }
+    ///
+    /// let mut text = Shortcut::NONE;
+    /// text.set_text(TextKey::new(Modifiers::ALT, '§'), None);
+    /// assert_eq!(Shortcut::parse_vim("<A-§>"), Ok(text));
+    /// text.set_text(TextKey::new(Modifiers::NONE, '<'), None);
+    /// assert_eq!(Shortcut::parse_vim("<lt>"), Ok(text));
+    ///
+    /// let error = |s| Shortcut::parse_vim(s).map_err(|error| (error.position, error.kind));
+    /// assert_eq!(error(""), Err((0, ParseShortcutError::Empty)));
+    /// assert_eq!(error("<>"), Err((0, ParseShortcutError::Empty)));
+    /// assert_eq!(error("<C-Foo>"), Err((3, ParseShortcutError::UnknownName("Foo".into()))));
+    /// assert_eq!(error("<C-s"), Err((0, ParseShortcutError::UnknownName("<C-s".into()))));
+    /// assert_eq!(error("gg"), Err((1, ParseShortcutError::MultipleKeys)));
+    /// assert_eq!(error("<C-s><F5>"), Err((5, ParseShortcutError::MultipleKeys)));
+    /// assert_eq!(
+    ///     error("<LeftMouse><RightMouse>"),
+    ///     Err((11, ParseShortcutError::MultiplePointerButtons)),
+    /// );
+    /// assert_eq!(
+    ///     error("<C-LeftMouse>"),
+    ///     Err((3, ParseShortcutError::Unsupported("LeftMouse".into()))),
+    /// );
+    /// assert_eq!(
+    ///     Shortcut::parse_vim("<C-Foo>").unwrap_err().to_string(),
+    ///     "unknown key or button \"Foo\" at position 3",
+    /// );
+    /// ```
+    pub fn parse_vim(s: &str) -> Result<Self, ParseVimError> {
+        let error = |position, kind| ParseVimError { position, kind };
+        if s.is_empty() {
+            return Err(error(0, ParseShortcutError::Empty));
+        }
+        if s.eq_ignore_ascii_case("<nop>") {
+            return Ok(Self::NONE);
+        }
+
+        let mut keyboard = None;
+        let mut text = None;
+        let mut pointer = None;
+        let mut rest = s;
+        while let Some(first) = rest.chars().next() {
+            let position = s.len() - rest.len();
+            let (mut modifiers, name, name_position, len) = if first == '<' {
+                let (modifiers, after) = split_vim_modifiers(&rest[1..]);
+                if after.starts_with('>') {
+                    return Err(error(position, ParseShortcutError::Empty));
+                }
+                // the name has at least one character, so "<C->>" is Ctrl+>
+                let start = after.chars().next().map_or(0, char::len_utf8);
+                let Some(end) = after.get(start..).and_then(|after| after.find('>')) else {
+                    return Err(error(
+                        position,
+                        ParseShortcutError::UnknownName(rest.to_string()),
+                    ));
+                };
+                let name = &after[..start + end];
+                let name_position = s.len() - after.len();
+                (
+                    modifiers,
+                    name,
+                    name_position,
+                    rest.len() - after.len() + name.len() + 1,
+                )
+            } else {
+                let len = first.len_utf8();
+                (Modifiers::NONE, &rest[..len], position, len)
+            };
+            rest = &rest[len..];
+
+            let mut chars = name.chars();
+            let parsed = match (chars.next(), chars.next()) {
+                (Some(c), None) => {
+                    // an uppercase letter includes Shift, except with Ctrl
+                    if c.is_ascii_uppercase() && !modifiers.ctrl {
+                        modifiers = modifiers | Modifiers::SHIFT;
+                    }
+                    Ok(Key::from_name(c.encode_utf8(&mut [0; 4])).ok_or(c))
+                }
+                _ if name.eq_ignore_ascii_case("lt") => Ok(Err('<')),
+                _ => match parse_vim_pointer(name) {
+                    Some(_) if !modifiers.is_none() => Err(error(
+                        name_position,
+                        ParseShortcutError::Unsupported(name.to_string()),
+                    )),
+                    Some(parsed) => {
+                        if pointer.replace(parsed).is_some() {
+                            return Err(error(
+                                position,
+                                ParseShortcutError::MultiplePointerButtons,
+                            ));
+                        }
+                        continue;
+                    }
+                    None => parse_vim_key_name(name).map(Ok).ok_or_else(|| {
+                        error(
+                            name_position,
+                            ParseShortcutError::UnknownName(name.to_string()),
+                        )
+                    }),
+                },
+            }?;
+
+            if keyboard.is_some() || text.is_some() {
+                return Err(error(position, ParseShortcutError::MultipleKeys));
+            }
+            match parsed {
+                Ok(key) => keyboard = Some(KeyboardShortcut::new(modifiers, key)),
+                Err(character) => text = Some(TextKey::new(modifiers, character)),
+            }
+        }
+
+        let (button, trigger) = pointer.unzip();
+        let mut shortcut = Self::new(keyboard, button)
+            .with_pointer_trigger(trigger.unwrap_or(PointerTrigger::Press));
+        if let Some(text) = text {
+            shortcut.set_text(text, button);
+        }
+        Ok(shortcut)
+    }
+
+    /// Write the shortcut in Vim notation, e.g. `"<C-s>"`, `"S"` or
+    /// `"<S-F5>"`, which [`Shortcut::parse_vim`] parses back into the same
+    /// shortcut.
+    ///
+    /// Letters are written lowercase, with Shift as `S-`, except for
+    /// Shift+letter alone, which is written as the uppercase letter. Vim has
+    /// no cross-platform modifier, so [`Modifiers::COMMAND`] is written as
+    /// `C-`, and no click trigger, so [`PointerTrigger::Click`] is written
+    /// like [`PointerTrigger::Press`]. No shortcut is `"<Nop>"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+    /// use egui_keybind::{PointerTrigger, Shortcut};
+    ///
+    /// let key = |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+    /// assert_eq!(key(Modifiers::CTRL, Key::S).to_vim_string(), "<C-s>");
+    /// assert_eq!(key(Modifiers::SHIFT, Key::S).to_vim_string(), "S");
+    /// assert_eq!(key(Modifiers::CTRL | Modifiers::SHIFT, Key::S).to_vim_string(), "<C-S-s>");
+    /// assert_eq!(key(Modifiers::SHIFT, Key::F5).to_vim_string(), "<S-F5>");
+    /// assert_eq!(key(Modifiers::NONE, Key::Enter).to_vim_string(), "<CR>");
+    /// assert_eq!(key(Modifiers::NONE, Key::Pipe).to_vim_string(), "<Bar>");
+    /// assert_eq!(key(Modifiers::NONE, Key::Minus).to_vim_string(), "-");
+    /// assert_eq!(Shortcut::NONE.to_vim_string(), "<Nop>");
+    ///
+    /// // every key round-trips with every combination of modifiers
+    /// let all = [Modifiers::CTRL, Modifiers::SHIFT, Modifiers::ALT, Modifiers::MAC_CMD];
+    /// for bits in 0..1 << all.len() {
+    ///     let modifiers = (0..all.len())
+    ///         .filter(|i| bits & 1 << i != 0)
+    ///         .fold(Modifiers::NONE, |modifiers, i| modifiers | all[i]);
+    ///     for &k in Key::ALL {
+    ///         let shortcut = key(modifiers, k);
+    ///         let vim = shortcut.to_vim_string();
+    ///         assert_eq!(Shortcut::parse_vim(&vim), Ok(shortcut), "{vim:?}");
+    ///     }
+    /// }
+    ///
+    /// // and so does every pointer button, alone and with a key
+    /// let buttons = [
+    ///     PointerButton::Primary,
+    ///     PointerButton::Secondary,
+    ///     PointerButton::Middle,
+    ///     PointerButton::Extra1,
+    ///     PointerButton::Extra2,
+    /// ];
+    /// for button in buttons {
+    ///     for trigger in [PointerTrigger::Press, PointerTrigger::Release] {
+    ///         for keyboard in [None, Some(KeyboardShortcut::new(Modifiers::ALT, Key::F4))] {
+    ///             let shortcut = Shortcut::new(keyboard, Some(button)).with_pointer_trigger(trigger);
+    ///             let vim = shortcut.to_vim_string();
+    ///             assert_eq!(Shortcut::parse_vim(&vim), Ok(shortcut), "{vim:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn to_vim_string(&self) -> String {
+        let mut out = String::new();
+        match (self.text(), self.keyboard()) {
+            (Some(text), _) => push_vim_key(&mut out, text.modifiers, Err(text.character)),
+            (None, Some(keyboard)) => {
+                push_vim_key(&mut out, keyboard.modifiers, Ok(keyboard.logical_key))
+            }
+            (None, None) => {}
+        }
+        if let Some(button) = self.pointer() {
+            let (_, name) = VIM_POINTER_BUTTONS
+                .iter()
+                .find(|(vim_button, _)| *vim_button == button)
+                .expect("every pointer button has a Vim name");
+            out.push('<');
+            out.push_str(name);
+            out.push_str(match self.pointer_trigger() {
+                PointerTrigger::Release => "Release>",
+                PointerTrigger::Press | PointerTrigger::Click => "Mouse>",
+            });
+        }
+        if out.is_empty() {
+            out.push_str("<Nop>");
+        }
+        out
+    }
+}
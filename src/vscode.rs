@@ -0,0 +1,261 @@
+use crate::{parse::parse_key, ParseShortcutError, Shortcut};
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+/// Parse a VS Code modifier name, e.g. `"ctrl"`.
+fn parse_vscode_modifier(name: &str) -> Option<Modifiers> {
+    Some(match name {
+        "ctrl" => Modifiers::CTRL,
+        "shift" => Modifiers::SHIFT,
+        "alt" => Modifiers::ALT,
+        "cmd" | "meta" => Modifiers::MAC_CMD,
+        _ => return None,
+    })
+}
+
+/// Parse a VS Code key name, e.g. `"pageup"` or `"numpad1"`.
+fn parse_vscode_key(name: &str) -> Option<Key> {
+    Some(match name {
+        "up" => Key::ArrowUp,
+        "down" => Key::ArrowDown,
+        "left" => Key::ArrowLeft,
+        "right" => Key::ArrowRight,
+        // egui reports the numpad like the main keyboard
+        "numpad0" => Key::Num0,
+        "numpad1" => Key::Num1,
+        "numpad2" => Key::Num2,
+        "numpad3" => Key::Num3,
+        "numpad4" => Key::Num4,
+        "numpad5" => Key::Num5,
+        "numpad6" => Key::Num6,
+        "numpad7" => Key::Num7,
+        "numpad8" => Key::Num8,
+        "numpad9" => Key::Num9,
+        "numpad_add" => Key::Plus,
+        "numpad_subtract" => Key::Minus,
+        "numpad_decimal" => Key::Period,
+        "numpad_divide" => Key::Slash,
+        _ => return parse_key(name),
+    })
+}
+
+/// Whether a VS Code name is a key or modifier that egui doesn't report.
+fn is_unsupported_vscode_name(name: &str) -> bool {
+    matches!(
+        name,
+        "win"
+            | "numpad_multiply"
+            | "numpad_separator"
+            | "capslock"
+            | "numlock"
+            | "scrolllock"
+            | "pausebreak"
+            | "printscreen"
+            | "contextmenu"
+    ) || name.starts_with("oem_")
+        // scan codes, e.g. "[KeyA]"
+        || name.len() > 1 && name.starts_with('[')
+}
+
+/// Parse a single VS Code chord step, e.g. `"ctrl+shift+p"`.
+fn parse_vscode_step(step: &str) -> Result<Shortcut, ParseShortcutError> {
+    let mut modifiers = Modifiers::NONE;
+    let mut key = None;
+
+    for name in step.split('+') {
+        let lowercase = name.to_ascii_lowercase();
+        if name.is_empty() {
+            return Err(ParseShortcutError::Empty);
+        } else if is_unsupported_vscode_name(&lowercase) {
+            return Err(ParseShortcutError::Unsupported(name.to_string()));
+        } else if let Some(modifier) = parse_vscode_modifier(&lowercase) {
+            modifiers = modifiers | modifier;
+        } else if let Some(parsed) = parse_vscode_key(&lowercase) {
+            if key.replace(parsed).is_some() {
+                return Err(ParseShortcutError::MultipleKeys);
+            }
+        } else {
+            return Err(ParseShortcutError::UnknownName(name.to_string()));
+        }
+    }
+
+    match key {
+        Some(key) => Ok(Shortcut::new(
+            Some(KeyboardShortcut::new(modifiers, key)),
+            None,
+        )),
+        None => Err(ParseShortcutError::MissingKey),
+    }
+}
+
+impl Shortcut {
+    /// Parse a shortcut written like the `"key"` of a VS Code
+    /// `keybindings.json` entry, e.g. `"ctrl+shift+p"` or `"alt+pageup"`.
+    ///
+    /// Names are joined with `+`, modifiers are `ctrl`, `shift`, `alt` and
+    /// `cmd` (or `meta`), and keys use VS Code's names, e.g. `"escape"`,
+    /// `"pagedown"`, `"f5"`, `"["` or `"numpad1"`. egui doesn't tell the
+    /// numpad apart from the main keyboard, so `"numpad1"` is the same as
+    /// `"1"` and `"numpad_add"` is the same as the plus key.
+    /// Keys egui doesn't report, like `"capslock"`, `"numpad_multiply"`, the
+    /// `win` modifier or scan codes like `"[KeyA]"`, are errors.
+    ///
+    /// Use [`Shortcut::parse_vscode_chord`] for chords like
+    /// `"ctrl+k ctrl+c"`.
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The VS Code keybinding, e.g. `"ctrl+shift+p"`.
+    ///
+    /// # Returns
+    ///
+    /// The shortcut, or an error naming the part that couldn't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{ParseShortcutError, Shortcut};
+    ///
+    /// let key = |modifiers, key| Ok(Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None));
+    /// let ctrl_shift = Modifiers::CTRL | Modifiers::SHIFT;
+    /// let cases = [
+    ///     ("ctrl+s", key(Modifiers::CTRL, Key::S)),
+    ///     ("ctrl+shift+p", key(ctrl_shift, Key::P)),
+    ///     ("shift+ctrl+p", key(ctrl_shift, Key::P)),
+    ///     ("Ctrl+Shift+P", key(ctrl_shift, Key::P)),
+    ///     ("cmd+shift+p", key(Modifiers::MAC_CMD | Modifiers::SHIFT, Key::P)),
+    ///     ("meta+w", key(Modifiers::MAC_CMD, Key::W)),
+    ///     ("alt+f4", key(Modifiers::ALT, Key::F4)),
+    ///     ("f5", key(Modifiers::NONE, Key::F5)),
+    ///     ("shift+f12", key(Modifiers::SHIFT, Key::F12)),
+    ///     ("ctrl+f19", key(Modifiers::CTRL, Key::F19)),
+    ///     ("escape", key(Modifiers::NONE, Key::Escape)),
+    ///     ("enter", key(Modifiers::NONE, Key::Enter)),
+    ///     ("ctrl+enter", key(Modifiers::CTRL, Key::Enter)),
+    ///     ("tab", key(Modifiers::NONE, Key::Tab)),
+    ///     ("shift+tab", key(Modifiers::SHIFT, Key::Tab)),
+    ///     ("space", key(Modifiers::NONE, Key::Space)),
+    ///     ("ctrl+space", key(Modifiers::CTRL, Key::Space)),
+    ///     ("backspace", key(Modifiers::NONE, Key::Backspace)),
+    ///     ("ctrl+backspace", key(Modifiers::CTRL, Key::Backspace)),
+    ///     ("delete", key(Modifiers::NONE, Key::Delete)),
+    ///     ("shift+delete", key(Modifiers::SHIFT, Key::Delete)),
+    ///     ("insert", key(Modifiers::NONE, Key::Insert)),
+    ///     ("ctrl+home", key(Modifiers::CTRL, Key::Home)),
+    ///     ("ctrl+end", key(Modifiers::CTRL, Key::End)),
+    ///     ("alt+pageup", key(Modifiers::ALT, Key::PageUp)),
+    ///     ("ctrl+pagedown", key(Modifiers::CTRL, Key::PageDown)),
+    ///     ("alt+up", key(Modifiers::ALT, Key::ArrowUp)),
+    ///     ("shift+alt+down", key(Modifiers::SHIFT | Modifiers::ALT, Key::ArrowDown)),
+    ///     ("ctrl+left", key(Modifiers::CTRL, Key::ArrowLeft)),
+    ///     ("cmd+right", key(Modifiers::MAC_CMD, Key::ArrowRight)),
+    ///     ("ctrl+`", key(Modifiers::CTRL, Key::Backtick)),
+    ///     ("ctrl+shift+`", key(ctrl_shift, Key::Backtick)),
+    ///     ("ctrl+-", key(Modifiers::CTRL, Key::Minus)),
+    ///     ("ctrl+=", key(Modifiers::CTRL, Key::Equals)),
+    ///     ("ctrl+[", key(Modifiers::CTRL, Key::OpenBracket)),
+    ///     ("ctrl+]", key(Modifiers::CTRL, Key::CloseBracket)),
+    ///     ("ctrl+\\", key(Modifiers::CTRL, Key::Backslash)),
+    ///     ("ctrl+;", key(Modifiers::CTRL, Key::Semicolon)),
+    ///     ("ctrl+'", key(Modifiers::CTRL, Key::Quote)),
+    ///     ("ctrl+,", key(Modifiers::CTRL, Key::Comma)),
+    ///     ("ctrl+.", key(Modifiers::CTRL, Key::Period)),
+    ///     ("ctrl+/", key(Modifiers::CTRL, Key::Slash)),
+    ///     ("ctrl+1", key(Modifiers::CTRL, Key::Num1)),
+    ///     ("numpad1", key(Modifiers::NONE, Key::Num1)),
+    ///     ("ctrl+numpad0", key(Modifiers::CTRL, Key::Num0)),
+    ///     ("ctrl+numpad_add", key(Modifiers::CTRL, Key::Plus)),
+    ///     ("ctrl+numpad_subtract", key(Modifiers::CTRL, Key::Minus)),
+    ///     ("numpad_decimal", key(Modifiers::NONE, Key::Period)),
+    ///     ("numpad_divide", key(Modifiers::NONE, Key::Slash)),
+    ///     ("  ctrl+s  ", key(Modifiers::CTRL, Key::S)),
+    ///     ("", Err(ParseShortcutError::Empty)),
+    ///     ("ctrl+", Err(ParseShortcutError::Empty)),
+    ///     ("ctrl+shift", Err(ParseShortcutError::MissingKey)),
+    ///     ("ctrl+a+b", Err(ParseShortcutError::MultipleKeys)),
+    ///     ("ctrl+Foo", Err(ParseShortcutError::UnknownName("Foo".into()))),
+    ///     ("hyper+a", Err(ParseShortcutError::UnknownName("hyper".into()))),
+    ///     ("win+e", Err(ParseShortcutError::Unsupported("win".into()))),
+    ///     ("capslock", Err(ParseShortcutError::Unsupported("capslock".into()))),
+    ///     ("pausebreak", Err(ParseShortcutError::Unsupported("pausebreak".into()))),
+    ///     ("numpad_multiply", Err(ParseShortcutError::Unsupported("numpad_multiply".into()))),
+    ///     ("ctrl+oem_8", Err(ParseShortcutError::Unsupported("oem_8".into()))),
+    ///     ("ctrl+[KeyA]", Err(ParseShortcutError::Unsupported("[KeyA]".into()))),
+    ///     ("ctrl+k ctrl+c", Err(ParseShortcutError::TooManySteps)),
+    /// ];
+    /// for (s, expected) in cases {
+    ///     assert_eq!(Shortcut::parse_vscode(s), expected, "{s:?}");
+    /// }
+    ///
+    /// let error = Shortcut::parse_vscode("ctrl+capslock").unwrap_err();
+    /// assert_eq!(error.to_string(), "\"capslock\" can't be bound in egui");
+    /// ```
+    pub fn parse_vscode(s: &str) -> Result<Self, ParseShortcutError> {
+        match Self::parse_vscode_chord(s)? {
+            (shortcut, None) => Ok(shortcut),
+            (_, Some(_)) => Err(ParseShortcutError::TooManySteps),
+        }
+    }
+
+    /// Parse a shortcut or chord written like the `"key"` of a VS Code
+    /// `keybindings.json` entry, e.g. `"ctrl+k ctrl+c"`. Chord steps are
+    /// separated by spaces and each step is parsed like
+    /// [`Shortcut::parse_vscode`].
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The VS Code keybinding, e.g. `"ctrl+k ctrl+c"`.
+    ///
+    /// # Returns
+    ///
+    /// The first step and the second step if it's a chord, which can be
+    /// passed to [`Hotkeys::register_chord`](crate::Hotkeys::register_chord),
+    /// or an error naming the part that couldn't be parsed. Chords with more
+    /// than two steps are [`ParseShortcutError::TooManySteps`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// use egui_keybind::{Hotkeys, ParseShortcutError, Shortcut};
+    ///
+    /// let key = |modifiers, key| Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None);
+    /// let cases = [
+    ///     ("ctrl+s", Ok((key(Modifiers::CTRL, Key::S), None))),
+    ///     (
+    ///         "ctrl+k ctrl+c",
+    ///         Ok((key(Modifiers::CTRL, Key::K), Some(key(Modifiers::CTRL, Key::C)))),
+    ///     ),
+    ///     (
+    ///         "cmd+k  cmd+shift+s",
+    ///         Ok((
+    ///             key(Modifiers::MAC_CMD, Key::K),
+    ///             Some(key(Modifiers::MAC_CMD | Modifiers::SHIFT, Key::S)),
+    ///         )),
+    ///     ),
+    ///     ("ctrl+k v", Ok((key(Modifiers::CTRL, Key::K), Some(key(Modifiers::NONE, Key::V))))),
+    ///     ("ctrl+k ctrl+k ctrl+k", Err(ParseShortcutError::TooManySteps)),
+    ///     ("ctrl+k ctrl+foo", Err(ParseShortcutError::UnknownName("foo".into()))),
+    ///     ("   ", Err(ParseShortcutError::Empty)),
+    /// ];
+    /// for (s, expected) in cases {
+    ///     assert_eq!(Shortcut::parse_vscode_chord(s), expected, "{s:?}");
+    /// }
+    ///
+    /// let (first, second) = Shortcut::parse_vscode_chord("ctrl+k ctrl+c").unwrap();
+    /// let mut hotkeys = Hotkeys::new();
+    /// match second {
+    ///     Some(second) => hotkeys.register_chord("comment", first, second),
+    ///     None => hotkeys.register("comment", first),
+    /// };
+    /// ```
+    pub fn parse_vscode_chord(s: &str) -> Result<(Self, Option<Self>), ParseShortcutError> {
+        let mut steps = s.split_whitespace();
+        let first = parse_vscode_step(steps.next().ok_or(ParseShortcutError::Empty)?)?;
+        let second = steps.next().map(parse_vscode_step).transpose()?;
+        if steps.next().is_some() {
+            return Err(ParseShortcutError::TooManySteps);
+        }
+        Ok((first, second))
+    }
+}
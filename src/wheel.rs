@@ -0,0 +1,480 @@
+use crate::{names::push_modifiers, Bind, InputKinds, MatchPolicy, TextInputPolicy};
+use egui::{
+    Context, Event, Id, InputState, KeyboardShortcut, ModifierNames, Modifiers, MouseWheelUnit,
+    PointerButton, RawInput, Vec2,
+};
+use std::sync::Arc;
+
+/// Which way the mouse wheel is turned, see [WheelBind].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WheelDirection {
+    /// The wheel is turned away from the user, which scrolls up.
+    Up,
+    /// The wheel is turned towards the user, which scrolls down.
+    Down,
+    /// The wheel is tilted or swiped to scroll left.
+    Left,
+    /// The wheel is tilted or swiped to scroll right.
+    Right,
+}
+
+impl WheelDirection {
+    /// The name of the direction, e.g. `"WheelUp"`, as written after the
+    /// modifiers of a [WheelBind] by [`Bind::format`].
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Up => "WheelUp",
+            Self::Down => "WheelDown",
+            Self::Left => "WheelLeft",
+            Self::Right => "WheelRight",
+        }
+    }
+
+    /// Get the direction of a scroll delta, like the one of
+    /// [`Event::MouseWheel`], by its longer axis.
+    ///
+    /// # Returns
+    ///
+    /// The direction, or [None] if the delta is zero.
+    pub fn of(delta: Vec2) -> Option<Self> {
+        // egui's deltas move the content, so scrolling up moves it down
+        Some(if delta.y.abs() >= delta.x.abs() {
+            match delta.y {
+                y if y > 0.0 => Self::Up,
+                y if y < 0.0 => Self::Down,
+                _ => return None,
+            }
+        } else if delta.x > 0.0 {
+            Self::Left
+        } else {
+            Self::Right
+        })
+    }
+}
+
+/// Turning the mouse wheel with modifiers held, e.g. Ctrl+WheelUp to zoom
+/// in. [Bind::pressed] fires on every frame with a wheel event in the
+/// direction, so a touchpad swipe fires on many frames.
+///
+/// The modifiers are matched like the ones of a keyboard shortcut, see
+/// [`WheelBind::with_match_policy`]. egui turns the wheel into horizontal
+/// scrolling while Shift is held, but the direction of a [WheelBind] is the
+/// one the wheel was turned, so Shift+WheelUp is the wheel turned up with
+/// Shift held.
+///
+/// The [Keybind](crate::Keybind) widget records it when the wheel is turned,
+/// with the modifiers that are held then, see
+/// [`ShortcutCapture::with_wheel`](crate::ShortcutCapture::with_wheel).
+///
+/// # Example
+///
+/// ```
+/// use egui::{Event, Modifiers, MouseWheelUnit, RawInput, Vec2};
+/// use egui_keybind::{Bind, MatchPolicy, WheelBind, WheelDirection};
+///
+/// let zoom_in = WheelBind::new(Modifiers::CTRL, WheelDirection::Up);
+/// assert_eq!(zoom_in.to_string(), "Ctrl+WheelUp");
+/// let exact = zoom_in.with_match_policy(MatchPolicy::Exact);
+///
+/// let ctx = egui::Context::default();
+/// let turn = |y: f32, modifiers| {
+///     let mut input = RawInput::default();
+///     input.events.push(Event::MouseWheel {
+///         unit: MouseWheelUnit::Line,
+///         delta: Vec2::new(0.0, y),
+///         modifiers,
+///     });
+///     let mut fired = vec![];
+///     let _ = ctx.run(input, |ctx| {
+///         ctx.input_mut(|input| fired = vec![zoom_in.pressed(input), exact.pressed(input)]);
+///     });
+///     fired
+/// };
+///
+/// assert_eq!(turn(1.0, Modifiers::CTRL), [true, true]);
+/// assert_eq!(turn(-1.0, Modifiers::CTRL), [false, false]);
+/// assert_eq!(turn(1.0, Modifiers::NONE), [false, false]);
+/// // extra modifiers only match leniently
+/// assert_eq!(turn(1.0, Modifiers::CTRL | Modifiers::SHIFT), [true, false]);
+///
+/// # #[cfg(feature = "serde")] {
+/// let json = serde_json::to_string(&zoom_in).unwrap();
+/// assert!(json.starts_with(r#"{"modifiers":"Ctrl","direction":"Up""#));
+/// assert_eq!(serde_json::from_str::<WheelBind>(&json).unwrap(), zoom_in);
+/// # }
+/// ```
+///
+/// Recording one with the [Keybind](crate::Keybind) widget:
+///
+/// ```
+/// use egui::{Event, Modifiers, MouseWheelUnit, PointerButton, Pos2, RawInput, Rect, Vec2};
+/// use egui_keybind::{Keybind, WheelBind, WheelDirection};
+///
+/// let ctx = egui::Context::default();
+/// let mut bind = WheelBind::new(Modifiers::CTRL, WheelDirection::Up);
+/// let mut frame = |events: Vec<Event>, modifiers| {
+///     let mut rect = Rect::NOTHING;
+///     let input = RawInput {
+///         events,
+///         modifiers,
+///         ..Default::default()
+///     };
+///     let _ = ctx.run(input, |ctx| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             rect = ui.add(Keybind::new(&mut bind, "zoom")).rect;
+///         });
+///     });
+///     rect
+/// };
+/// let click = |pos: Pos2| {
+///     let button = |pressed| Event::PointerButton {
+///         pos,
+///         button: PointerButton::Primary,
+///         pressed,
+///         modifiers: Modifiers::NONE,
+///     };
+///     vec![Event::PointerMoved(pos), button(true), button(false)]
+/// };
+///
+/// let pos = frame(vec![], Modifiers::NONE).center();
+/// frame(click(pos), Modifiers::NONE);
+/// let wheel = Event::MouseWheel {
+///     unit: MouseWheelUnit::Line,
+///     delta: Vec2::new(0.0, -1.0),
+///     modifiers: Modifiers::ALT,
+/// };
+/// frame(vec![wheel], Modifiers::ALT);
+/// drop(frame);
+/// assert_eq!(bind.to_string(), "Alt+WheelDown");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WheelBind {
+    /// The modifiers that have to be held.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::modifiers_by_name"))]
+    pub modifiers: Modifiers,
+    /// Which way the wheel is turned.
+    pub direction: WheelDirection,
+    /// Whether extra modifiers keep the bind from matching.
+    #[cfg_attr(feature = "serde", serde(default))]
+    match_policy: MatchPolicy,
+    /// Whether the scroll is consumed when the bind fires.
+    #[cfg_attr(feature = "serde", serde(default))]
+    consume_scroll: bool,
+}
+
+impl WheelBind {
+    /// Create a new [WheelBind].
+    ///
+    /// # Arguments
+    ///
+    /// * `modifiers` - The modifiers that have to be held.
+    /// * `direction` - Which way the wheel is turned.
+    pub const fn new(modifiers: Modifiers, direction: WheelDirection) -> Self {
+        Self {
+            modifiers,
+            direction,
+            match_policy: MatchPolicy::Lenient,
+            consume_scroll: false,
+        }
+    }
+
+    /// Set whether extra modifiers keep the bind from matching, like
+    /// [`Shortcut::with_match_policy`](crate::Shortcut::with_match_policy).
+    /// By default this is [`MatchPolicy::Lenient`].
+    pub fn with_match_policy(mut self, policy: MatchPolicy) -> Self {
+        self.match_policy = policy;
+        self
+    }
+
+    /// Get whether extra modifiers keep the bind from matching, see
+    /// [`WheelBind::with_match_policy`].
+    #[inline]
+    pub fn match_policy(&self) -> MatchPolicy {
+        self.match_policy
+    }
+
+    /// Set whether the scroll of the frame is consumed when the bind fires,
+    /// so that a [ScrollArea](egui::ScrollArea) under the pointer doesn't
+    /// also scroll. This removes the wheel events of the bind and clears
+    /// [`InputState::raw_scroll_delta`] and
+    /// [`InputState::smooth_scroll_delta`].
+    ///
+    /// egui spreads the scroll of wheels that turn in lines (most mice) over
+    /// a few frames. [`Bind::pressed_unless_typing`] and
+    /// [`Bind::pressed_with_policy`] consume the rest of it on the following
+    /// frames as well, but [Bind::pressed] only has the [InputState] of the
+    /// frame, so it only consumes the part of this frame. The events can
+    /// also be taken out of the raw input before egui gets them, see
+    /// [`WheelBind::take_from_raw_input`].
+    ///
+    /// By default this is `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, MouseWheelUnit, Pos2, RawInput, Rect, Vec2};
+    /// use egui_keybind::{Bind, WheelBind, WheelDirection};
+    ///
+    /// let scroll = |bind: WheelBind| {
+    ///     let ctx = egui::Context::default();
+    ///     let mut offset = 0.0;
+    ///     // a notch of a mouse wheel, which egui scrolls over many frames
+    ///     for frame in 0..30 {
+    ///         let turn = frame == 1;
+    ///         let mut input = RawInput {
+    ///             screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(400.0, 300.0))),
+    ///             ..Default::default()
+    ///         };
+    ///         input.events.push(Event::PointerMoved(Pos2::new(50.0, 50.0)));
+    ///         if turn {
+    ///             input.events.push(Event::MouseWheel {
+    ///                 unit: MouseWheelUnit::Line,
+    ///                 delta: Vec2::new(0.0, -1.0),
+    ///                 modifiers: Modifiers::ALT,
+    ///             });
+    ///         }
+    ///         let _ = ctx.run(input, |ctx| {
+    ///             assert_eq!(bind.pressed_unless_typing(ctx), turn);
+    ///             egui::CentralPanel::default().show(ctx, |ui| {
+    ///                 let output = egui::ScrollArea::vertical().show(ui, |ui| {
+    ///                     for row in 0..100 {
+    ///                         ui.label(format!("Row {row}"));
+    ///                     }
+    ///                 });
+    ///                 offset = output.state.offset.y;
+    ///             });
+    ///         });
+    ///     }
+    ///     offset
+    /// };
+    ///
+    /// let bind = WheelBind::new(Modifiers::ALT, WheelDirection::Down);
+    /// // the scroll area scrolls down as well
+    /// assert!(scroll(bind) > 0.0);
+    /// // unless the bind consumes the scroll
+    /// assert_eq!(scroll(bind.with_consume_scroll(true)), 0.0);
+    /// ```
+    pub fn with_consume_scroll(mut self, consume: bool) -> Self {
+        self.consume_scroll = consume;
+        self
+    }
+
+    /// Get whether the scroll is consumed when the bind fires, see
+    /// [`WheelBind::with_consume_scroll`].
+    #[inline]
+    pub fn consume_scroll(&self) -> bool {
+        self.consume_scroll
+    }
+
+    /// Check if an event turns the wheel in the direction of the bind, with
+    /// its modifiers held.
+    pub fn matches_event(&self, event: &Event) -> bool {
+        match event {
+            Event::MouseWheel {
+                delta, modifiers, ..
+            } => {
+                WheelDirection::of(*delta) == Some(self.direction)
+                    && self.match_policy.matches(*modifiers, self.modifiers)
+            }
+            _ => false,
+        }
+    }
+
+    /// Take the wheel events of the bind out of the raw input before egui
+    /// gets it, e.g. in eframe's `App::raw_input_hook`, so egui doesn't
+    /// scroll at all. The bind doesn't fire from [Bind::pressed] then, use
+    /// the returned value instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw_input` - The input that egui is about to get.
+    ///
+    /// # Returns
+    ///
+    /// Whether the bind fired, i.e. if any events were taken.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use egui::{Event, Modifiers, MouseWheelUnit, RawInput, Vec2};
+    /// use egui_keybind::{WheelBind, WheelDirection};
+    ///
+    /// let bind = WheelBind::new(Modifiers::ALT, WheelDirection::Down);
+    /// let mut input = RawInput::default();
+    /// input.events.push(Event::MouseWheel {
+    ///     unit: MouseWheelUnit::Line,
+    ///     delta: Vec2::new(0.0, -1.0),
+    ///     modifiers: Modifiers::ALT,
+    /// });
+    /// assert!(bind.take_from_raw_input(&mut input));
+    /// assert!(input.events.is_empty());
+    ///
+    /// let ctx = egui::Context::default();
+    /// let _ = ctx.run(input, |ctx| assert_eq!(ctx.input(|i| i.smooth_scroll_delta), Vec2::ZERO));
+    /// ```
+    pub fn take_from_raw_input(&self, raw_input: &mut RawInput) -> bool {
+        let before = raw_input.events.len();
+        raw_input.events.retain(|event| !self.matches_event(event));
+        raw_input.events.len() != before
+    }
+}
+
+impl WheelBind {
+    /// Get the scroll of the wheel events of the bind on this frame that
+    /// egui spreads over several frames, in points, like egui adds it up.
+    fn spread_scroll(&self, input: &InputState, line_scroll_speed: f32) -> Vec2 {
+        let mut spread = Vec2::ZERO;
+        for event in input
+            .events
+            .iter()
+            .filter(|event| self.matches_event(event))
+        {
+            if let Event::MouseWheel {
+                unit,
+                delta,
+                modifiers,
+            } = event
+            {
+                let mut delta = match unit {
+                    MouseWheelUnit::Point => *delta,
+                    MouseWheelUnit::Line => line_scroll_speed * *delta,
+                    MouseWheelUnit::Page => input.screen_rect.height() * *delta,
+                };
+                if modifiers.shift {
+                    delta = Vec2::new(delta.x + delta.y, 0.0);
+                }
+                // short touchpad scrolls are applied right away, and Ctrl
+                // and Cmd zoom instead
+                let is_smooth = *unit == MouseWheelUnit::Point && delta.length() < 8.0;
+                let is_zoom = modifiers.ctrl || modifiers.mac_cmd || modifiers.command;
+                if !is_smooth && !is_zoom {
+                    spread += delta;
+                }
+            }
+        }
+        spread
+    }
+}
+
+/// The ID of the scroll that is still to be consumed on the next frames, see
+/// [`consume_spread_scroll`].
+fn spread_scroll_id() -> Id {
+    Id::new("egui_keybind::WheelBind::spread_scroll")
+}
+
+/// Keep consuming `scroll` from [`InputState::smooth_scroll_delta`] on the
+/// next frames, as egui applies it.
+fn consume_spread_scroll(ctx: &Context, scroll: Vec2) {
+    let id = spread_scroll_id();
+    let installed = ctx.data_mut(|data| {
+        let (installed, left) = data.get_temp_mut_or_default::<(bool, Vec2)>(id);
+        *left += scroll;
+        std::mem::replace(installed, true)
+    });
+    if !installed {
+        ctx.on_begin_pass("egui_keybind::WheelBind", Arc::new(consume_left_scroll));
+    }
+}
+
+/// Consume what's left of the scroll of [`consume_spread_scroll`] as egui
+/// applies it, at the start of a frame.
+fn consume_left_scroll(ctx: &Context) {
+    let id = spread_scroll_id();
+    let mut left = ctx.data(|data| data.get_temp::<(bool, Vec2)>(id).unwrap_or_default().1);
+    if left == Vec2::ZERO {
+        return;
+    }
+    ctx.input_mut(|input| {
+        for axis in 0..2 {
+            let smooth = input.smooth_scroll_delta[axis];
+            // egui applied all of it, or the wheel is turned the other way now
+            if smooth == 0.0 || smooth.signum() != left[axis].signum() {
+                left[axis] = 0.0;
+                continue;
+            }
+            let consumed = match smooth.abs() < left[axis].abs() {
+                true => smooth,
+                false => left[axis],
+            };
+            input.smooth_scroll_delta[axis] -= consumed;
+            left[axis] -= consumed;
+        }
+    });
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<(bool, Vec2)>(id).1 = left);
+}
+
+impl std::fmt::Display for WheelBind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.format(&ModifierNames::NAMES, false))
+    }
+}
+
+/// Ignores keyboard shortcuts and pointer buttons, it's set by turning the
+/// wheel, see [`Bind::set_wheel`].
+impl Bind for WheelBind {
+    fn set(&mut self, _keyboard: Option<KeyboardShortcut>, _pointer: Option<PointerButton>) {}
+
+    fn format(&self, names: &ModifierNames<'_>, is_mac: bool) -> String {
+        let mut out = String::new();
+        self.format_into(&mut out, names, is_mac);
+        out
+    }
+
+    fn format_into(&self, out: &mut String, names: &ModifierNames<'_>, is_mac: bool) {
+        let start = out.len();
+        push_modifiers(out, &self.modifiers, names, is_mac);
+        if out.len() > start {
+            out.push_str(names.concat);
+        }
+        out.push_str(self.direction.name());
+    }
+
+    /// Like [Bind::pressed], and keeps consuming the scroll that egui spreads
+    /// over the following frames if [`WheelBind::with_consume_scroll`] is
+    /// set.
+    fn pressed_with_policy(&self, ctx: &Context, policy: TextInputPolicy) -> bool {
+        // `wants_keyboard_input` and `input_mut` lock the context one after the other
+        let typing = ctx.wants_keyboard_input();
+        if typing && !policy.allows(self) {
+            return false;
+        }
+        let line_scroll_speed = ctx.options(|options| options.line_scroll_speed);
+        let (fired, spread) = ctx.input_mut(|input| {
+            let spread = self.spread_scroll(input, line_scroll_speed) - input.smooth_scroll_delta;
+            (self.pressed(input), spread)
+        });
+        if fired && self.consume_scroll {
+            consume_spread_scroll(ctx, spread);
+        }
+        fired
+    }
+
+    /// Triggered on every frame with a wheel event in the direction, and
+    /// consumes the scroll of the frame if [`WheelBind::with_consume_scroll`]
+    /// is set.
+    fn pressed(&self, input: &mut InputState) -> bool {
+        let fired = input.events.iter().any(|event| self.matches_event(event));
+        if fired && self.consume_scroll {
+            input.events.retain(|event| !self.matches_event(event));
+            // Shift turns vertical scrolling horizontal, so both axes are cleared
+            input.raw_scroll_delta = Vec2::ZERO;
+            input.smooth_scroll_delta = Vec2::ZERO;
+        }
+        fired
+    }
+
+    fn set_wheel(&mut self, modifiers: Modifiers, direction: WheelDirection) -> bool {
+        self.modifiers = modifiers;
+        self.direction = direction;
+        true
+    }
+
+    fn input_kinds(&self) -> InputKinds {
+        InputKinds {
+            keyboard: false,
+            pointer: false,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Loading shortcuts that postcard and bincode wrote with version 0.4 of the
+//! crate, when the two fields of `Shortcut` and egui's types were derived.
+//! The blobs were written by egui-keybind 0.4.1, with egui 0.29.1.
+#![cfg(feature = "serde")]
+
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use egui_keybind::Shortcut;
+
+const POSTCARD: &[u8] = include_bytes!("fixtures/shortcuts_0.4.1.postcard");
+const BINCODE: &[u8] = include_bytes!("fixtures/shortcuts_0.4.1.bincode");
+
+/// The shortcuts in the blobs.
+fn shortcuts() -> Vec<Shortcut> {
+    let keyboard = |modifiers, key| Some(KeyboardShortcut::new(modifiers, key));
+    vec![
+        Shortcut::new(keyboard(Modifiers::CTRL, Key::S), None),
+        Shortcut::NONE,
+        Shortcut::new(None, Some(PointerButton::Secondary)),
+        Shortcut::new(
+            keyboard(Modifiers::COMMAND, Key::F35),
+            Some(PointerButton::Extra1),
+        ),
+        Shortcut::new(
+            keyboard(Modifiers::MAC_CMD | Modifiers::SHIFT, Key::ArrowDown),
+            None,
+        ),
+        Shortcut::new(
+            keyboard(Modifiers::ALT, Key::Space),
+            Some(PointerButton::Middle),
+        ),
+        Shortcut::new(None, Some(PointerButton::Extra2)),
+    ]
+}
+
+#[test]
+fn old_postcard_blob_loads() {
+    let loaded: Vec<Shortcut> = postcard::from_bytes(POSTCARD).unwrap();
+    assert_eq!(loaded, shortcuts());
+
+    let saved = postcard::to_allocvec(&loaded).unwrap();
+    assert_ne!(saved, POSTCARD);
+    assert_eq!(
+        postcard::from_bytes::<Vec<Shortcut>>(&saved).unwrap(),
+        loaded
+    );
+}
+
+#[test]
+fn old_bincode_blob_loads() {
+    let loaded: Vec<Shortcut> = bincode::deserialize(BINCODE).unwrap();
+    assert_eq!(loaded, shortcuts());
+
+    let saved = bincode::serialize(&loaded).unwrap();
+    assert_ne!(saved, BINCODE);
+    assert_eq!(
+        bincode::deserialize::<Vec<Shortcut>>(&saved).unwrap(),
+        loaded
+    );
+}
+
+#[test]
+fn every_key_loads_from_the_old_layout() {
+    for &key in Key::ALL {
+        // `Some`, Ctrl, the ordinal, and no pointer. egui 0.30 declares keys in the
+        // same order as 0.29, so the discriminant of a key is its ordinal
+        let old = [1, 0, 1, 0, 0, 0, key as u8, 0];
+        let loaded: Shortcut = postcard::from_bytes(&old).unwrap();
+        let expected = Shortcut::new(Some(KeyboardShortcut::new(Modifiers::CTRL, key)), None);
+        assert_eq!(loaded, expected, "{key:?}");
+    }
+    let unknown = [1, 0, 0, 0, 0, 0, 104, 0];
+    let error = postcard::from_bytes::<Shortcut>(&unknown).unwrap_err();
+    assert!(matches!(error, postcard::Error::SerdeDeCustom));
+}
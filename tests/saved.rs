@@ -0,0 +1,143 @@
+//! Loading shortcuts saved by an older version of an app, whose actions
+//! were different, and saving them again.
+#![cfg(feature = "serde")]
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_keybind::{Hotkeys, Shortcut};
+
+/// The shortcuts saved by the old version, which had a "print" action.
+const OLD: &str = include_str!("fixtures/hotkeys_old.json");
+
+fn shortcut(modifiers: Modifiers, key: Key) -> Shortcut {
+    Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None)
+}
+
+/// The actions of the new version, which has no "print", but a new "find".
+fn registered() -> Hotkeys {
+    let mut hotkeys = Hotkeys::new();
+    hotkeys
+        .register("save", shortcut(Modifiers::CTRL, Key::S))
+        .register("open", shortcut(Modifiers::CTRL, Key::O))
+        .register("close_tab", shortcut(Modifiers::CTRL, Key::W))
+        .register("zoom_in", shortcut(Modifiers::CTRL, Key::Plus))
+        .register("find", shortcut(Modifiers::CTRL, Key::F));
+    hotkeys
+}
+
+#[test]
+fn old_file_merges_with_registered_actions() {
+    let mut hotkeys = registered();
+    let not_applied = hotkeys
+        .load(&mut serde_json::Deserializer::from_str(OLD))
+        .unwrap();
+
+    // the removed action is reported, not an error
+    assert_eq!(not_applied, ["print"]);
+    // the new action keeps its default
+    assert_eq!(
+        hotkeys.current("find"),
+        Some(&shortcut(Modifiers::CTRL, Key::F))
+    );
+    assert!(!hotkeys.is_modified("find"));
+
+    // Mac shortcuts keep their exact modifiers
+    assert_eq!(
+        hotkeys.current("save"),
+        Some(&shortcut(Modifiers::MAC_CMD, Key::S))
+    );
+    assert_eq!(
+        hotkeys.current("open"),
+        Some(&shortcut(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::O))
+    );
+    assert_eq!(
+        hotkeys.current("zoom_in"),
+        Some(&shortcut(Modifiers::COMMAND, Key::Plus))
+    );
+    let close_tab = hotkeys.get("close_tab").unwrap();
+    assert_eq!(close_tab.current(), &shortcut(Modifiers::CTRL, Key::W));
+    assert_eq!(
+        close_tab.alternates().iter().collect::<Vec<_>>(),
+        [&shortcut(Modifiers::MAC_CMD, Key::W)]
+    );
+}
+
+#[test]
+fn saving_again_keeps_everything() {
+    let mut hotkeys = registered();
+    hotkeys
+        .load(&mut serde_json::Deserializer::from_str(OLD))
+        .unwrap();
+    let saved = serde_json::to_string(&hotkeys).unwrap();
+    assert_eq!(
+        saved,
+        r#"{"save":"Cmd+S","open":"CmdOrCtrl+Cmd+O","close_tab":"Ctrl+W, Cmd+W","zoom_in":"CmdOrCtrl+Plus","find":"Ctrl+F","print":"Ctrl+P"}"#
+    );
+
+    // the unknown action survives, and is applied once it's registered again
+    let mut reloaded = registered();
+    reloaded.register(
+        "print",
+        shortcut(Modifiers::CTRL | Modifiers::SHIFT, Key::P),
+    );
+    let not_applied = reloaded
+        .load(&mut serde_json::Deserializer::from_str(&saved))
+        .unwrap();
+    assert!(not_applied.is_empty());
+    assert_eq!(
+        reloaded.current("print"),
+        Some(&shortcut(Modifiers::CTRL, Key::P))
+    );
+    for hotkey in &hotkeys {
+        assert_eq!(
+            reloaded
+                .get(hotkey.id())
+                .map(|reloaded| reloaded.bindings().collect::<Vec<_>>()),
+            Some(hotkey.bindings().collect()),
+            "{}",
+            hotkey.id()
+        );
+    }
+}
+
+#[test]
+fn recorded_mac_shortcuts_survive_to_saved() {
+    let mut hotkeys = registered();
+    // what recording Cmd+S and Ctrl+S on macOS gives
+    hotkeys.set(
+        "save",
+        shortcut(Modifiers::MAC_CMD | Modifiers::COMMAND, Key::S),
+    );
+    hotkeys.set("find", shortcut(Modifiers::CTRL, Key::F));
+    // Cmd+Shift+O as parsed from a VS Code or Emacs keymap
+    hotkeys.set(
+        "open",
+        shortcut(Modifiers::MAC_CMD | Modifiers::SHIFT, Key::O),
+    );
+
+    let mut reloaded = registered();
+    assert!(reloaded.load_saved(hotkeys.to_saved()).is_empty());
+    for id in ["save", "find", "open"] {
+        assert_eq!(reloaded.current(id), hotkeys.current(id), "{id}");
+    }
+    assert!(!reloaded.is_modified("find"));
+}
+
+#[test]
+fn gamepad_buttons_load_with_or_without_the_feature() {
+    // saved by a build with the `gamepad` feature
+    let saved = vec![
+        ("save".to_string(), "Ctrl+S, Gamepad South".to_string()),
+        ("find".to_string(), "Ctrl+Shift+F, Gamepad None".to_string()),
+    ];
+    let mut hotkeys = registered();
+    assert!(hotkeys.load_saved(saved).is_empty());
+    assert_eq!(
+        hotkeys.current("save"),
+        Some(&shortcut(Modifiers::CTRL, Key::S))
+    );
+    assert_eq!(
+        hotkeys.current("find"),
+        Some(&shortcut(Modifiers::CTRL | Modifiers::SHIFT, Key::F))
+    );
+    assert!(hotkeys.get("find").unwrap().alternates().is_empty());
+}
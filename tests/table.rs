@@ -0,0 +1,71 @@
+//! The Markdown and HTML tables of shortcuts, compared with checked-in
+//! files so their format only changes on purpose.
+
+use egui::{Key, KeyboardShortcut, Modifiers, PointerButton};
+use egui_keybind::{Hotkeys, Shortcut, TableOptions};
+
+const MARKDOWN: &str = include_str!("fixtures/table.md");
+const MARKDOWN_PLATFORMS: &str = include_str!("fixtures/table_platforms.md");
+const HTML: &str = include_str!("fixtures/table.html");
+const HTML_PLATFORMS: &str = include_str!("fixtures/table_platforms.html");
+
+fn shortcut(modifiers: Modifiers, key: Key) -> Shortcut {
+    Shortcut::new(Some(KeyboardShortcut::new(modifiers, key)), None)
+}
+
+/// Actions with every kind of shortcut, and names that need escaping.
+fn hotkeys() -> Hotkeys {
+    let mut hotkeys = Hotkeys::new();
+    hotkeys
+        .register_in("file", "save", "CmdOrCtrl+S".parse().unwrap())
+        .register_in("file", "print", Shortcut::NONE)
+        .register_in("file", "close_window", shortcut(Modifiers::MAC_CMD, Key::W))
+        .register_in("view", "zoom_in", "Ctrl+Plus".parse().unwrap())
+        .register_in(
+            "view",
+            "launcher",
+            shortcut(Modifiers::MAC_CMD | Modifiers::SHIFT, Key::K),
+        )
+        .register_in("edit & <select>", "select *all*", "Ctrl+A".parse().unwrap())
+        .register("a | b", "Alt+|".parse().unwrap())
+        .register("\"quoted\" 'name'", "Shift+F2".parse().unwrap())
+        .register(
+            "context_menu",
+            Shortcut::new(None, Some(PointerButton::Secondary)),
+        )
+        .register_chord(
+            "comment",
+            "Ctrl+K".parse().unwrap(),
+            "Ctrl+C".parse().unwrap(),
+        );
+    hotkeys.add_binding("zoom_in", "Ctrl+=".parse().unwrap());
+    hotkeys
+}
+
+fn platform_options() -> TableOptions<'static> {
+    TableOptions::new()
+        .with_platform_columns(true)
+        .with_unbound(true)
+}
+
+#[test]
+fn markdown_matches_the_golden_file() {
+    assert_eq!(hotkeys().export_markdown(), MARKDOWN);
+}
+
+#[test]
+fn markdown_with_platform_columns_matches_the_golden_file() {
+    let table = hotkeys().export_markdown_with(&platform_options());
+    assert_eq!(table, MARKDOWN_PLATFORMS);
+}
+
+#[test]
+fn html_matches_the_golden_file() {
+    assert_eq!(hotkeys().export_html(), HTML);
+}
+
+#[test]
+fn html_with_platform_columns_matches_the_golden_file() {
+    let table = hotkeys().export_html_with(&platform_options());
+    assert_eq!(table, HTML_PLATFORMS);
+}